@@ -0,0 +1,37 @@
+//! On-chain sizes of the fixed-size accounts, discriminator included, and their rent-exempt
+//! minimums.
+use crate::*;
+use solana_program::rent::Rent;
+
+pub const USER_STATE_ACCOUNT_LEN: usize = 8 + 912;
+pub const LENDING_MARKET_ACCOUNT_LEN: usize = 8 + 4656;
+pub const OBLIGATION_ACCOUNT_LEN: usize = 8 + 3336;
+pub const REFERRER_STATE_ACCOUNT_LEN: usize = 8 + 64;
+pub const REFERRER_TOKEN_STATE_ACCOUNT_LEN: usize = 8 + 352;
+pub const USER_METADATA_ACCOUNT_LEN: usize = 8 + 1024;
+pub const RESERVE_ACCOUNT_LEN: usize = 8 + 8616;
+
+macro_rules! impl_account_len {
+    ($($account:ident => $len:ident),* $(,)?) => {
+        $(
+            impl $account {
+                pub const LEN: usize = $len;
+
+                /// Lamports for an account of [`Self::LEN`] bytes to be rent exempt.
+                pub fn rent_exempt_minimum(rent: &Rent) -> u64 {
+                    rent.minimum_balance(Self::LEN)
+                }
+            }
+        )*
+    };
+}
+
+impl_account_len!(
+    UserStateAccount => USER_STATE_ACCOUNT_LEN,
+    LendingMarketAccount => LENDING_MARKET_ACCOUNT_LEN,
+    ObligationAccount => OBLIGATION_ACCOUNT_LEN,
+    ReferrerStateAccount => REFERRER_STATE_ACCOUNT_LEN,
+    ReferrerTokenStateAccount => REFERRER_TOKEN_STATE_ACCOUNT_LEN,
+    UserMetadataAccount => USER_METADATA_ACCOUNT_LEN,
+    ReserveAccount => RESERVE_ACCOUNT_LEN,
+);
@@ -6,9 +6,12 @@ pub const USER_STATE_ACCOUNT_DISCM: [u8; 8] = [72, 177, 85, 249, 76, 167, 186, 1
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserState {
     pub user_id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
     pub is_farm_delegated: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding0: [u8; 7],
     pub rewards_tally_scaled: [u128; 10],
     pub rewards_issued_unclaimed: [u64; 10],
@@ -19,11 +22,14 @@ pub struct UserState {
     pub pending_withdrawal_unstake_scaled: u128,
     pub pending_withdrawal_unstake_ts: u64,
     pub bump: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub delegatee: Pubkey,
     pub last_stake_ts: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding1: [u64; 50],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserStateAccount(pub UserState);
 impl UserStateAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -60,8 +66,11 @@ pub const LENDING_MARKET_ACCOUNT_DISCM: [u8; 8] = [246, 114, 50, 98, 72, 157, 28
 pub struct LendingMarket {
     pub version: u64,
     pub bump_seed: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner_cached: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::name"))]
     pub quote_currency: [u8; 32],
     pub referral_fee_bps: u16,
     pub emergency_mode: u8,
@@ -72,20 +81,28 @@ pub struct LendingMarket {
     pub insolvency_risk_unhealthy_ltv_pct: u8,
     pub min_full_liquidation_value_threshold: u64,
     pub max_liquidatable_debt_market_value_at_once: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved0: [u8; 8],
     pub global_allowed_borrow_value: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub risk_council: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved1: [u8; 8],
     pub elevation_groups: [ElevationGroup; 32],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub elevation_group_padding: [u64; 90],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub min_net_value_in_obligation_sf: u128,
     pub min_value_skip_liquidation_ltv_checks: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::name"))]
     pub name: [u8; 32],
     pub min_value_skip_liquidation_bf_checks: u64,
     pub individual_autodeleverage_margin_call_period_secs: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding1: [u64; 171],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LendingMarketAccount(pub LendingMarket);
 impl LendingMarketAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -122,31 +139,42 @@ pub const OBLIGATION_ACCOUNT_DISCM: [u8; 8] = [168, 206, 141, 106, 88, 76, 172,
 pub struct Obligation {
     pub tag: u64,
     pub last_update: LastUpdate,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
     pub deposits: [ObligationCollateral; 8],
     pub lowest_reserve_deposit_liquidation_ltv: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub deposited_value_sf: u128,
     pub borrows: [ObligationLiquidity; 5],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub borrow_factor_adjusted_debt_value_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub borrowed_assets_market_value_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub allowed_borrow_value_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub unhealthy_borrow_value_sf: u128,
     pub deposits_asset_tiers: [u8; 8],
     pub borrows_asset_tiers: [u8; 5],
     pub elevation_group: u8,
     pub num_of_obsolete_reserves: u8,
     pub has_debt: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
     pub borrowing_disabled: u8,
     pub autodeleverage_target_ltv_pct: u8,
     pub lowest_reserve_deposit_max_ltv_pct: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved: [u8; 5],
     pub highest_borrow_factor_pct: u64,
     pub autodeleverage_margin_call_started_timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding3: [u64; 125],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObligationAccount(pub Obligation);
 impl ObligationAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -181,10 +209,13 @@ pub const REFERRER_STATE_ACCOUNT_DISCM: [u8; 8] = [194, 81, 217, 103, 12, 19, 12
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferrerState {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub short_url: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferrerStateAccount(pub ReferrerState);
 impl ReferrerStateAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -228,14 +259,20 @@ pub const REFERRER_TOKEN_STATE_ACCOUNT_DISCM: [u8; 8] = [
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferrerTokenState {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub amount_unclaimed_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub amount_cumulative_sf: u128,
     pub bump: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: [u64; 31],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferrerTokenStateAccount(pub ReferrerTokenState);
 impl ReferrerTokenStateAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -270,10 +307,12 @@ pub const SHORT_URL_ACCOUNT_DISCM: [u8; 8] = [28, 89, 174, 25, 226, 124, 126, 21
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShortUrl {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
     pub short_url: String,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShortUrlAccount(pub ShortUrl);
 impl ShortUrlAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -308,14 +347,20 @@ pub const USER_METADATA_ACCOUNT_DISCM: [u8; 8] = [157, 214, 220, 235, 98, 135, 1
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserMetadata {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
     pub bump: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_lookup_table: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding1: [u64; 51],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding2: [u64; 64],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserMetadataAccount(pub UserMetadata);
 impl UserMetadataAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -352,20 +397,28 @@ pub const RESERVE_ACCOUNT_DISCM: [u8; 8] = [43, 242, 204, 202, 26, 247, 59, 127]
 pub struct Reserve {
     pub version: u64,
     pub last_update: LastUpdate,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farm_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farm_debt: Pubkey,
     pub liquidity: ReserveLiquidity,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub reserve_liquidity_padding: [u64; 150],
     pub collateral: ReserveCollateral,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub reserve_collateral_padding: [u64; 150],
     pub config: ReserveConfig,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub config_padding: [u64; 116],
     pub borrowed_amount_outside_elevation_group: u64,
     pub borrowed_amounts_against_this_reserve_in_elevation_groups: [u64; 32],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding: [u64; 207],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReserveAccount(pub Reserve);
 impl ReserveAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -0,0 +1,159 @@
+//! Anchor trait implementations, enabled by the `anchor` feature, so the account types can be
+//! used as `Account<'info, Reserve>` and the instruction account structs passed to a
+//! `CpiContext` like those of any other anchor program.
+use crate::*;
+use anchor_lang::error::{AnchorError, Error, ErrorCode};
+use anchor_lang::prelude::{AccountInfo, AccountMeta, Pubkey};
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, Discriminator, Owner, ToAccountInfos, ToAccountMetas,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+macro_rules! impl_anchor_account {
+    ($($account:ty, $discm:expr, $owner:expr;)*) => {
+        $(
+            impl Discriminator for $account {
+                const DISCRIMINATOR: [u8; 8] = $discm;
+            }
+
+            impl Owner for $account {
+                fn owner() -> Pubkey {
+                    $owner
+                }
+            }
+
+            impl AccountDeserialize for $account {
+                fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                    if buf.len() < $discm.len() {
+                        return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+                    }
+                    if buf[..$discm.len()] != $discm {
+                        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+                    }
+                    Self::try_deserialize_unchecked(buf)
+                }
+
+                fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                    let mut data = buf.get($discm.len()..).unwrap_or_default();
+                    Self::deserialize(&mut data)
+                        .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+                }
+            }
+
+            impl AccountSerialize for $account {
+                fn try_serialize<W: std::io::Write>(
+                    &self,
+                    writer: &mut W,
+                ) -> anchor_lang::Result<()> {
+                    writer
+                        .write_all(&$discm)
+                        .and_then(|()| self.serialize(writer))
+                        .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_anchor_account!(
+    UserState, USER_STATE_ACCOUNT_DISCM, FARMS_PROGRAM_ID;
+    LendingMarket, LENDING_MARKET_ACCOUNT_DISCM, crate::ID;
+    Obligation, OBLIGATION_ACCOUNT_DISCM, crate::ID;
+    ReferrerState, REFERRER_STATE_ACCOUNT_DISCM, crate::ID;
+    ReferrerTokenState, REFERRER_TOKEN_STATE_ACCOUNT_DISCM, crate::ID;
+    ShortUrl, SHORT_URL_ACCOUNT_DISCM, crate::ID;
+    UserMetadata, USER_METADATA_ACCOUNT_DISCM, crate::ID;
+    Reserve, RESERVE_ACCOUNT_DISCM, crate::ID;
+    FarmState, FARM_STATE_ACCOUNT_DISCM, FARMS_PROGRAM_ID;
+    GlobalConfig, GLOBAL_CONFIG_ACCOUNT_DISCM, FARMS_PROGRAM_ID;
+    OraclePrices, ORACLE_PRICES_ACCOUNT_DISCM, SCOPE_PROGRAM_ID;
+);
+
+/// `is_signer: Some(true)` marks every account as a signer on top of the instruction's own
+/// signers; `None` and `Some(false)` leave the instruction's flags as they are.
+macro_rules! impl_anchor_instruction_accounts {
+    ($($keys:ident, $accounts:ident, $len:ident;)*) => {
+        $(
+            impl ToAccountMetas for $keys {
+                fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+                    let mut metas = <[AccountMeta; $len]>::from(*self);
+                    if is_signer == Some(true) {
+                        metas.iter_mut().for_each(|meta| meta.is_signer = true);
+                    }
+                    metas.to_vec()
+                }
+            }
+
+            impl ToAccountMetas for $accounts<'_, '_> {
+                fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+                    $keys::from(*self).to_account_metas(is_signer)
+                }
+            }
+
+            impl<'info> ToAccountInfos<'info> for $accounts<'_, 'info> {
+                fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+                    <[AccountInfo<'info>; $len]>::from(*self).to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_anchor_instruction_accounts!(
+    InitLendingMarketKeys, InitLendingMarketAccounts, INIT_LENDING_MARKET_IX_ACCOUNTS_LEN;
+    UpdateLendingMarketKeys, UpdateLendingMarketAccounts, UPDATE_LENDING_MARKET_IX_ACCOUNTS_LEN;
+    UpdateLendingMarketOwnerKeys, UpdateLendingMarketOwnerAccounts, UPDATE_LENDING_MARKET_OWNER_IX_ACCOUNTS_LEN;
+    InitReserveKeys, InitReserveAccounts, INIT_RESERVE_IX_ACCOUNTS_LEN;
+    InitFarmsForReserveKeys, InitFarmsForReserveAccounts, INIT_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    UpdateReserveConfigKeys, UpdateReserveConfigAccounts, UPDATE_RESERVE_CONFIG_IX_ACCOUNTS_LEN;
+    RedeemFeesKeys, RedeemFeesAccounts, REDEEM_FEES_IX_ACCOUNTS_LEN;
+    WithdrawProtocolFeeKeys, WithdrawProtocolFeeAccounts, WITHDRAW_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    SocializeLossKeys, SocializeLossAccounts, SOCIALIZE_LOSS_IX_ACCOUNTS_LEN;
+    SocializeLossV2Keys, SocializeLossV2Accounts, SOCIALIZE_LOSS_V2_IX_ACCOUNTS_LEN;
+    MarkObligationForDeleveragingKeys, MarkObligationForDeleveragingAccounts, MARK_OBLIGATION_FOR_DELEVERAGING_IX_ACCOUNTS_LEN;
+    RefreshReserveKeys, RefreshReserveAccounts, REFRESH_RESERVE_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidityKeys, DepositReserveLiquidityAccounts, DEPOSIT_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RedeemReserveCollateralKeys, RedeemReserveCollateralAccounts, REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    InitObligationKeys, InitObligationAccounts, INIT_OBLIGATION_IX_ACCOUNTS_LEN;
+    InitObligationFarmsForReserveKeys, InitObligationFarmsForReserveAccounts, INIT_OBLIGATION_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    RefreshObligationFarmsForReserveKeys, RefreshObligationFarmsForReserveAccounts, REFRESH_OBLIGATION_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    RefreshObligationKeys, RefreshObligationAccounts, REFRESH_OBLIGATION_IX_ACCOUNTS_LEN;
+    DepositObligationCollateralKeys, DepositObligationCollateralAccounts, DEPOSIT_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    DepositObligationCollateralV2Keys, DepositObligationCollateralV2Accounts, DEPOSIT_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralKeys, WithdrawObligationCollateralAccounts, WITHDRAW_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralV2Keys, WithdrawObligationCollateralV2Accounts, WITHDRAW_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    BorrowObligationLiquidityKeys, BorrowObligationLiquidityAccounts, BORROW_OBLIGATION_LIQUIDITY_IX_ACCOUNTS_LEN;
+    BorrowObligationLiquidityV2Keys, BorrowObligationLiquidityV2Accounts, BORROW_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN;
+    RepayObligationLiquidityKeys, RepayObligationLiquidityAccounts, REPAY_OBLIGATION_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RepayObligationLiquidityV2Keys, RepayObligationLiquidityV2Accounts, REPAY_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN;
+    RepayAndWithdrawAndRedeemKeys, RepayAndWithdrawAndRedeemAccounts, REPAY_AND_WITHDRAW_AND_REDEEM_IX_ACCOUNTS_LEN;
+    DepositAndWithdrawKeys, DepositAndWithdrawAccounts, DEPOSIT_AND_WITHDRAW_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidityAndObligationCollateralKeys, DepositReserveLiquidityAndObligationCollateralAccounts, DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidityAndObligationCollateralV2Keys, DepositReserveLiquidityAndObligationCollateralV2Accounts, DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralAndRedeemReserveCollateralKeys, WithdrawObligationCollateralAndRedeemReserveCollateralAccounts, WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralAndRedeemReserveCollateralV2Keys, WithdrawObligationCollateralAndRedeemReserveCollateralV2Accounts, WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    LiquidateObligationAndRedeemReserveCollateralKeys, LiquidateObligationAndRedeemReserveCollateralAccounts, LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    LiquidateObligationAndRedeemReserveCollateralV2Keys, LiquidateObligationAndRedeemReserveCollateralV2Accounts, LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    FlashRepayReserveLiquidityKeys, FlashRepayReserveLiquidityAccounts, FLASH_REPAY_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    FlashBorrowReserveLiquidityKeys, FlashBorrowReserveLiquidityAccounts, FLASH_BORROW_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RequestElevationGroupKeys, RequestElevationGroupAccounts, REQUEST_ELEVATION_GROUP_IX_ACCOUNTS_LEN;
+    InitReferrerTokenStateKeys, InitReferrerTokenStateAccounts, INIT_REFERRER_TOKEN_STATE_IX_ACCOUNTS_LEN;
+    InitUserMetadataKeys, InitUserMetadataAccounts, INIT_USER_METADATA_IX_ACCOUNTS_LEN;
+    WithdrawReferrerFeesKeys, WithdrawReferrerFeesAccounts, WITHDRAW_REFERRER_FEES_IX_ACCOUNTS_LEN;
+    InitReferrerStateAndShortUrlKeys, InitReferrerStateAndShortUrlAccounts, INIT_REFERRER_STATE_AND_SHORT_URL_IX_ACCOUNTS_LEN;
+    DeleteReferrerStateAndShortUrlKeys, DeleteReferrerStateAndShortUrlAccounts, DELETE_REFERRER_STATE_AND_SHORT_URL_IX_ACCOUNTS_LEN;
+    IdlMissingTypesKeys, IdlMissingTypesAccounts, IDL_MISSING_TYPES_IX_ACCOUNTS_LEN;
+);
+
+impl From<KaminoLendingError> for Error {
+    fn from(e: KaminoLendingError) -> Self {
+        AnchorError {
+            error_name: format!("{e:?}"),
+            error_code_number: e as u32,
+            error_msg: e.to_string(),
+            error_origin: None,
+            compared_values: None,
+        }
+        .into()
+    }
+}
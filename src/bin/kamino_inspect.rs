@@ -0,0 +1,415 @@
+//! `kamino-inspect`: decodes Kamino Lending account or instruction data and prints it as JSON or
+//! a table, with `_sf` values as decimals and derived reserve and obligation metrics.
+use base64::Engine;
+use clap::{Parser, ValueEnum};
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use serde_json::{json, Value};
+use solana_program::pubkey::Pubkey;
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Parser)]
+#[command(name = "kamino-inspect", version, about)]
+struct Args {
+    /// Path to a file holding the data, or the data itself as base58 or base64.
+    input: String,
+    #[arg(long, value_enum, default_value_t = Encoding::Auto)]
+    encoding: Encoding,
+    #[arg(long, value_enum, default_value_t = Kind::Auto)]
+    kind: Kind,
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Unix timestamp used for withdrawal caps and deleveraging; defaults to the current time.
+    #[arg(long)]
+    now: Option<i64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Encoding {
+    /// A file if the path exists, otherwise base64 if the string has characters base58 lacks,
+    /// else base58 unless only the base64 decoding is Kamino Lending data.
+    Auto,
+    /// Raw bytes, or base58/base64 text, read from a file.
+    File,
+    Base58,
+    Base64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Kind {
+    /// An account if the data starts with an account discriminator, an instruction otherwise.
+    Auto,
+    Account,
+    Instruction,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Table,
+}
+
+/// Characters of the base64 alphabet, or its padding, that base58 leaves out.
+const BASE64_ONLY: &[char] = &['0', 'O', 'I', 'l', '+', '/', '='];
+
+fn is_kamino_data(data: &[u8]) -> bool {
+    KaminoLendingAccount::is_account_data(data) || KaminoLendingProgramIx::deserialize(data).is_ok()
+}
+
+/// Base64 if `text` has characters base58 lacks; otherwise base58, unless only the base64
+/// decoding is Kamino Lending data.
+fn decode_text(text: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let text = text.trim();
+    let base64 = || base64::engine::general_purpose::STANDARD.decode(text);
+    if text.contains(BASE64_ONLY) {
+        return Ok(base64()?);
+    }
+    match (bs58::decode(text).into_vec(), base64()) {
+        (Ok(base58), Ok(base64)) if !is_kamino_data(&base58) && is_kamino_data(&base64) => {
+            Ok(base64)
+        }
+        (Ok(base58), _) => Ok(base58),
+        (Err(_), base64) => Ok(base64?),
+    }
+}
+
+fn read_input(args: &Args) -> Result<Vec<u8>, Box<dyn Error>> {
+    match args.encoding {
+        Encoding::Base58 => Ok(bs58::decode(args.input.trim()).into_vec()?),
+        Encoding::Base64 => {
+            Ok(base64::engine::general_purpose::STANDARD.decode(args.input.trim())?)
+        }
+        Encoding::File => read_file(&args.input),
+        Encoding::Auto if Path::new(&args.input).is_file() => read_file(&args.input),
+        Encoding::Auto => decode_text(&args.input),
+    }
+}
+
+/// File contents, decoded from base58 or base64 when the file is text.
+fn read_file(path: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let data = std::fs::read(path)?;
+    match std::str::from_utf8(&data) {
+        Ok(text) if !KaminoLendingAccount::is_account_data(&data) && !text.trim().is_empty() => {
+            decode_text(text).or(Ok(data))
+        }
+        _ => Ok(data),
+    }
+}
+
+fn decimal(value: Fraction) -> Value {
+    Value::String(value.to_string())
+}
+
+fn decimal_sf(bits: u128) -> Value {
+    decimal(Fraction::from_bits(bits))
+}
+
+fn key(pubkey: &Pubkey) -> Value {
+    Value::String(pubkey.to_string())
+}
+
+fn ratio(numerator: Fraction, denominator: Fraction) -> Value {
+    numerator
+        .checked_div(denominator)
+        .map_or(Value::Null, decimal)
+}
+
+fn reserve_view(reserve: &Reserve, now: i64) -> Value {
+    let liquidity = &reserve.liquidity;
+    let config = &reserve.config;
+    let total_supply = reserve.total_supply();
+    let flags = reserve.price_status();
+    let missing_checks: Vec<Value> = PriceCheck::ALL
+        .into_iter()
+        .filter(|check| !flags.contains(check.flag()))
+        .map(|check| Value::String(format!("{check:?}")))
+        .collect();
+    json!({
+        "version": reserve.version,
+        "lending_market": key(&reserve.lending_market),
+        "name": config.token_info.name_str(),
+        "status": reserve.status().map_or(Value::Null, |status| json!(format!("{status:?}"))),
+        "asset_tier": reserve.asset_tier().map_or(Value::Null, |tier| json!(format!("{tier:?}"))),
+        "last_update": {
+            "slot": reserve.last_update.slot,
+            "stale": reserve.last_update.stale != 0,
+            "price_checks_missing": missing_checks,
+        },
+        "farm_collateral": key(&reserve.farm_collateral),
+        "farm_debt": key(&reserve.farm_debt),
+        "liquidity": {
+            "mint": key(&liquidity.mint_pubkey),
+            "mint_decimals": liquidity.mint_decimals,
+            "supply_vault": key(&liquidity.supply_vault),
+            "available_amount": liquidity.available_amount,
+            "borrowed_amount": decimal(liquidity.borrowed_amount()),
+            "total_supply": decimal(total_supply),
+            "market_price": decimal(liquidity.market_price()),
+            "market_price_last_updated_ts": liquidity.market_price_last_updated_ts,
+            "cumulative_borrow_rate": decimal(liquidity.cumulative_borrow_rate()),
+            "accumulated_protocol_fees": decimal_sf(liquidity.accumulated_protocol_fees_sf),
+            "accumulated_referrer_fees": decimal_sf(liquidity.accumulated_referrer_fees_sf),
+            "pending_referrer_fees": decimal_sf(liquidity.pending_referrer_fees_sf),
+        },
+        "collateral": {
+            "mint": key(&reserve.collateral.mint_pubkey),
+            "mint_total_supply": reserve.collateral.mint_total_supply,
+            "exchange_rate": decimal(reserve.collateral_to_liquidity(1_000_000).checked_div_int(1_000_000).unwrap_or(Fraction::ZERO)),
+        },
+        "config": {
+            "loan_to_value_pct": config.loan_to_value_pct,
+            "liquidation_threshold_pct": config.liquidation_threshold_pct,
+            "min_liquidation_bonus_bps": config.min_liquidation_bonus_bps,
+            "max_liquidation_bonus_bps": config.max_liquidation_bonus_bps,
+            "borrow_factor_pct": config.borrow_factor_pct,
+            "deposit_limit": config.deposit_limit,
+            "borrow_limit": config.borrow_limit,
+            "utilization_limit_block_borrowing_above_pct": config.utilization_limit_block_borrowing_above_pct,
+            "borrow_fee": decimal(config.fees.borrow_fee_rate()),
+            "flash_loan_fee": decimal(config.fees.flash_loan_fee_rate()),
+            "protocol_take_rate_pct": config.protocol_take_rate_pct,
+            "elevation_groups": config.elevation_groups.iter().filter(|id| **id != 0).collect::<Vec<_>>(),
+            "max_age_price_seconds": config.token_info.max_age_price_seconds,
+            "scope_price_chain": config.token_info.scope_configuration.price_chain,
+        },
+        "health": {
+            "utilization_rate": decimal(reserve.utilization_rate()),
+            "deposit_limit_usage": ratio(total_supply, Fraction::from_num(config.deposit_limit)),
+            "borrow_limit_usage": ratio(liquidity.borrowed_amount(), Fraction::from_num(config.borrow_limit)),
+            "max_withdrawable_liquidity": reserve.max_withdrawable_liquidity(now),
+            "max_borrowable_liquidity": reserve.max_borrowable_liquidity(now),
+            "deposit_withdrawal_cap_remaining": cap_remaining(&config.deposit_withdrawal_cap, now),
+            "debt_withdrawal_cap_remaining": cap_remaining(&config.debt_withdrawal_cap, now),
+        },
+    })
+}
+
+fn cap_remaining(caps: &WithdrawalCaps, now: i64) -> Value {
+    if caps.is_enabled() {
+        json!(caps.remaining_capacity(now))
+    } else {
+        Value::Null
+    }
+}
+
+fn obligation_view(obligation: &Obligation) -> Value {
+    let deposits: Vec<Value> = obligation
+        .active_deposits()
+        .map(|(_, deposit)| {
+            json!({
+                "reserve": key(&deposit.deposit_reserve),
+                "deposited_amount": deposit.deposited_amount,
+                "market_value": decimal_sf(deposit.market_value_sf),
+            })
+        })
+        .collect();
+    let borrows: Vec<Value> = obligation
+        .active_borrows()
+        .map(|(_, borrow)| {
+            json!({
+                "reserve": key(&borrow.borrow_reserve),
+                "borrowed_amount": decimal(borrow.borrowed_amount()),
+                "market_value": decimal_sf(borrow.market_value_sf),
+                "borrow_factor_adjusted_market_value": decimal_sf(borrow.borrow_factor_adjusted_market_value_sf),
+            })
+        })
+        .collect();
+    let bf_debt = obligation.borrow_factor_adjusted_debt_value();
+    json!({
+        "lending_market": key(&obligation.lending_market),
+        "owner": key(&obligation.owner),
+        "tag": obligation.tag,
+        "last_update_slot": obligation.last_update.slot,
+        "elevation_group": obligation.elevation_group,
+        "referrer": key(&obligation.referrer),
+        "deposits": deposits,
+        "borrows": borrows,
+        "values": {
+            "deposited_value": decimal(obligation.deposited_value()),
+            "borrowed_assets_market_value": decimal_sf(obligation.borrowed_assets_market_value_sf),
+            "borrow_factor_adjusted_debt_value": decimal(bf_debt),
+            "allowed_borrow_value": decimal(obligation.allowed_borrow_value()),
+            "unhealthy_borrow_value": decimal(obligation.unhealthy_borrow_value()),
+        },
+        "ltv": {
+            "loan_to_value": decimal(obligation.loan_to_value()),
+            "max_loan_to_value": ratio(obligation.allowed_borrow_value(), obligation.deposited_value()),
+            "unhealthy_loan_to_value": decimal(obligation.unhealthy_loan_to_value()),
+            "liquidatable": !bf_debt.is_zero() && bf_debt >= obligation.unhealthy_borrow_value(),
+        },
+        "borrowing_disabled": obligation.borrowing_disabled != 0,
+        "marked_for_deleveraging": obligation.is_marked_for_deleveraging(),
+        "autodeleverage_target_ltv_pct": obligation.autodeleverage_target_ltv_pct,
+        "autodeleverage_margin_call_started_timestamp": obligation.autodeleverage_margin_call_started_timestamp,
+    })
+}
+
+fn elevation_group_view(group: &ElevationGroup) -> Value {
+    json!({
+        "id": group.id,
+        "ltv_pct": group.ltv_pct,
+        "liquidation_threshold_pct": group.liquidation_threshold_pct,
+        "max_liquidation_bonus_bps": group.max_liquidation_bonus_bps,
+        "allow_new_loans": group.allow_new_loans != 0,
+        "max_reserves_as_collateral": group.max_reserves_as_collateral,
+        "debt_reserve": key(&group.debt_reserve),
+    })
+}
+
+fn lending_market_view(market: &LendingMarket) -> Value {
+    json!({
+        "version": market.version,
+        "name": market.name_str(),
+        "quote_currency": market.quote_currency_str(),
+        "owner": key(&market.lending_market_owner),
+        "owner_cached": key(&market.lending_market_owner_cached),
+        "risk_council": key(&market.risk_council),
+        "emergency_mode": market.is_emergency_mode(),
+        "borrow_disabled": market.is_borrowing_disabled(),
+        "autodeleverage_enabled": market.autodeleverage_enabled != 0,
+        "referral_fee_bps": market.referral_fee_bps,
+        "price_refresh_trigger_to_max_age_pct": market.price_refresh_trigger_to_max_age_pct,
+        "liquidation_max_debt_close_factor_pct": market.liquidation_max_debt_close_factor_pct,
+        "insolvency_risk_unhealthy_ltv_pct": market.insolvency_risk_unhealthy_ltv_pct,
+        "min_full_liquidation_value_threshold": market.min_full_liquidation_value_threshold,
+        "max_liquidatable_debt_market_value_at_once": market.max_liquidatable_debt_market_value_at_once,
+        "global_allowed_borrow_value": market.global_allowed_borrow_value,
+        "min_net_value_in_obligation": decimal(market.min_net_value_in_obligation()),
+        "individual_autodeleverage_margin_call_period_secs": market.individual_autodeleverage_margin_call_period_secs,
+        "elevation_groups": market.configured_elevation_groups().map(elevation_group_view).collect::<Vec<_>>(),
+    })
+}
+
+fn account_view(account: &KaminoLendingAccount, now: i64) -> Value {
+    let view = match account {
+        KaminoLendingAccount::Reserve(reserve) => reserve_view(reserve, now),
+        KaminoLendingAccount::Obligation(obligation) => obligation_view(obligation),
+        KaminoLendingAccount::LendingMarket(market) => lending_market_view(market),
+        KaminoLendingAccount::UserMetadata(metadata) => json!({
+            "owner": key(&metadata.owner),
+            "referrer": key(&metadata.referrer),
+            "user_lookup_table": key(&metadata.user_lookup_table),
+            "bump": metadata.bump,
+        }),
+        KaminoLendingAccount::ReferrerState(state) => json!({
+            "owner": key(&state.owner),
+            "short_url": key(&state.short_url),
+        }),
+        KaminoLendingAccount::ReferrerTokenState(state) => json!({
+            "referrer": key(&state.referrer),
+            "mint": key(&state.mint),
+            "amount_unclaimed": decimal_sf(state.amount_unclaimed_sf),
+            "amount_cumulative": decimal_sf(state.amount_cumulative_sf),
+            "bump": state.bump,
+        }),
+        KaminoLendingAccount::ShortUrl(short_url) => json!({
+            "referrer": key(&short_url.referrer),
+            "short_url": short_url.short_url,
+        }),
+        KaminoLendingAccount::UserState(user_state) => json!({
+            "user_id": user_state.user_id,
+            "farm_state": key(&user_state.farm_state),
+            "owner": key(&user_state.owner),
+            "delegatee": key(&user_state.delegatee),
+            "is_farm_delegated": user_state.is_farm_delegated != 0,
+            "active_stake_scaled": user_state.active_stake_scaled.to_string(),
+            "pending_deposit_stake_scaled": user_state.pending_deposit_stake_scaled.to_string(),
+            "pending_withdrawal_unstake_scaled": user_state.pending_withdrawal_unstake_scaled.to_string(),
+            "rewards_issued_unclaimed": user_state.rewards_issued_unclaimed,
+            "last_stake_ts": user_state.last_stake_ts,
+        }),
+    };
+    json!({ "account": account.name(), "data": view })
+}
+
+fn instruction_view(data: &[u8]) -> Result<Value, Box<dyn Error>> {
+    let ix = KaminoLendingProgramIx::deserialize(data)?;
+    let meta = ix.metadata();
+    // Externally tagged: `{"<Name>": {args}}`, or just `"<Name>"` without args.
+    let args = match serde_json::to_value(&ix)? {
+        Value::Object(variant) => variant
+            .into_iter()
+            .next()
+            .map_or(Value::Null, |(_, args)| args),
+        _ => Value::Null,
+    };
+    Ok(json!({
+        "instruction": meta.name,
+        "discriminator": data[..8],
+        "args": args,
+        "accounts": meta.accounts.iter().map(|account| account.name).collect::<Vec<_>>(),
+    }))
+}
+
+/// Flattens `value` into `(path, value)` rows.
+fn table_rows(prefix: &str, value: &Value, rows: &mut Vec<(String, String)>) {
+    let child_path = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    };
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .for_each(|(key, value)| table_rows(&child_path(key), value, rows)),
+        Value::Array(items) if items.iter().any(|item| item.is_object()) => items
+            .iter()
+            .enumerate()
+            .for_each(|(index, item)| table_rows(&format!("{prefix}[{index}]"), item, rows)),
+        Value::String(s) => rows.push((prefix.to_string(), s.clone())),
+        other => rows.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+fn write_table(out: &mut impl Write, value: &Value) -> io::Result<()> {
+    let mut rows = Vec::new();
+    table_rows("", value, &mut rows);
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, value) in rows {
+        writeln!(out, "{key:<width$}  {value}")?;
+    }
+    Ok(())
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let data = read_input(&args)?;
+    let now = match args.now {
+        Some(now) => now,
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .as_secs()
+            .try_into()?,
+    };
+    let is_account = match args.kind {
+        Kind::Account => true,
+        Kind::Instruction => false,
+        Kind::Auto => KaminoLendingAccount::is_account_data(&data),
+    };
+    let view = if is_account {
+        account_view(&KaminoLendingAccount::deserialize(&data)?, now)
+    } else {
+        instruction_view(&data)?
+    };
+    let mut out = io::stdout().lock();
+    let written = match args.format {
+        Format::Json => writeln!(out, "{}", serde_json::to_string_pretty(&view)?),
+        Format::Table => write_table(&mut out, &view),
+    };
+    match written {
+        // Output piped into e.g. `head` that exited early.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        other => Ok(other?),
+    }
+}
+
+fn main() {
+    if let Err(err) = run(Args::parse()) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,90 @@
+//! Decoding of any program account by its discriminator, the account-side counterpart of
+//! [`KaminoLendingProgramIx`].
+use crate::*;
+use borsh::BorshDeserialize;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KaminoLendingAccount {
+    UserState(UserState),
+    LendingMarket(Box<LendingMarket>),
+    Obligation(Box<Obligation>),
+    ReferrerState(ReferrerState),
+    ReferrerTokenState(ReferrerTokenState),
+    ShortUrl(ShortUrl),
+    UserMetadata(UserMetadata),
+    Reserve(Box<Reserve>),
+}
+
+impl KaminoLendingAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let discm = Self::discriminator_of(buf).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "account data shorter than discriminator",
+            )
+        })?;
+        let mut reader = &buf[8..];
+        match discm {
+            USER_STATE_ACCOUNT_DISCM => Ok(Self::UserState(UserState::deserialize(&mut reader)?)),
+            LENDING_MARKET_ACCOUNT_DISCM => Ok(Self::LendingMarket(Box::new(
+                LendingMarket::deserialize(&mut reader)?,
+            ))),
+            OBLIGATION_ACCOUNT_DISCM => Ok(Self::Obligation(Box::new(Obligation::deserialize(
+                &mut reader,
+            )?))),
+            REFERRER_STATE_ACCOUNT_DISCM => Ok(Self::ReferrerState(ReferrerState::deserialize(
+                &mut reader,
+            )?)),
+            REFERRER_TOKEN_STATE_ACCOUNT_DISCM => Ok(Self::ReferrerTokenState(
+                ReferrerTokenState::deserialize(&mut reader)?,
+            )),
+            SHORT_URL_ACCOUNT_DISCM => Ok(Self::ShortUrl(ShortUrl::deserialize(&mut reader)?)),
+            USER_METADATA_ACCOUNT_DISCM => {
+                Ok(Self::UserMetadata(UserMetadata::deserialize(&mut reader)?))
+            }
+            RESERVE_ACCOUNT_DISCM => {
+                Ok(Self::Reserve(Box::new(Reserve::deserialize(&mut reader)?)))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", discm),
+            )),
+        }
+    }
+
+    /// The first 8 bytes of `buf`, if it is that long.
+    pub fn discriminator_of(buf: &[u8]) -> Option<[u8; 8]> {
+        buf.get(..8)?.try_into().ok()
+    }
+
+    /// Whether `buf` starts with the discriminator of an account type.
+    pub fn is_account_data(buf: &[u8]) -> bool {
+        Self::discriminator_of(buf).is_some_and(|discm| {
+            [
+                USER_STATE_ACCOUNT_DISCM,
+                LENDING_MARKET_ACCOUNT_DISCM,
+                OBLIGATION_ACCOUNT_DISCM,
+                REFERRER_STATE_ACCOUNT_DISCM,
+                REFERRER_TOKEN_STATE_ACCOUNT_DISCM,
+                SHORT_URL_ACCOUNT_DISCM,
+                USER_METADATA_ACCOUNT_DISCM,
+                RESERVE_ACCOUNT_DISCM,
+            ]
+            .contains(&discm)
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UserState(_) => "UserState",
+            Self::LendingMarket(_) => "LendingMarket",
+            Self::Obligation(_) => "Obligation",
+            Self::ReferrerState(_) => "ReferrerState",
+            Self::ReferrerTokenState(_) => "ReferrerTokenState",
+            Self::ShortUrl(_) => "ShortUrl",
+            Self::UserMetadata(_) => "UserMetadata",
+            Self::Reserve(_) => "Reserve",
+        }
+    }
+}
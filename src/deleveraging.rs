@@ -0,0 +1,128 @@
+//! Progress of obligations marked with `mark_obligation_for_deleveraging`.
+//!
+//! Once the margin call period has elapsed since `autodeleverage_margin_call_started_timestamp`,
+//! the obligation's liquidation threshold starts at `autodeleverage_target_ltv_pct` and decreases
+//! by `deleveraging_threshold_decrease_bps_per_day`, while the liquidation bonus grows from
+//! `min_liquidation_bonus_bps` by `deleveraging_bonus_increase_bps_per_day`, up to
+//! `max_liquidation_bonus_bps`. Rates apply per second, pro rata.
+use crate::fraction::Fraction;
+use crate::*;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+const BPS_SECONDS_PER_DAY: u64 = 10_000 * SECONDS_PER_DAY;
+
+/// Where a marked obligation stands at a given timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeleveragingState {
+    pub margin_call_started_at: u64,
+    pub margin_call_expires_at: u64,
+    /// Seconds the margin call has been expired for, zero while it is running.
+    pub seconds_since_expiry: u64,
+    /// LTV at or above which the obligation can be liquidated; only in force once the margin call
+    /// has expired.
+    pub liquidation_threshold: Fraction,
+    pub liquidation_bonus_bps: u64,
+}
+
+impl DeleveragingState {
+    pub fn is_margin_call_expired(&self, now: i64) -> bool {
+        u64::try_from(now).is_ok_and(|now| now >= self.margin_call_expires_at)
+    }
+
+    /// Whether an obligation at `loan_to_value` can be liquidated for deleveraging at `now`.
+    pub fn is_liquidatable(&self, loan_to_value: Fraction, now: i64) -> bool {
+        self.is_margin_call_expired(now) && loan_to_value >= self.liquidation_threshold
+    }
+}
+
+impl Obligation {
+    pub fn is_marked_for_deleveraging(&self) -> bool {
+        self.autodeleverage_margin_call_started_timestamp != 0
+    }
+
+    fn deleveraging_margin_call_expiry(
+        &self,
+        lending_market: &LendingMarket,
+        reserve: &Reserve,
+    ) -> u64 {
+        let period = match lending_market.individual_autodeleverage_margin_call_period_secs {
+            0 => reserve.config.deleveraging_margin_call_period_secs,
+            period => period,
+        };
+        self.autodeleverage_margin_call_started_timestamp
+            .saturating_add(period)
+    }
+
+    /// Deleveraging state at unix timestamp `now`, `None` if the obligation is not marked.
+    ///
+    /// `reserve` is the reserve whose config drives the process; the margin call period is the
+    /// market's `individual_autodeleverage_margin_call_period_secs` when set, the reserve's
+    /// `deleveraging_margin_call_period_secs` otherwise.
+    pub fn deleveraging_state(
+        &self,
+        lending_market: &LendingMarket,
+        reserve: &Reserve,
+        now: i64,
+    ) -> Option<DeleveragingState> {
+        if !self.is_marked_for_deleveraging() {
+            return None;
+        }
+        let config = &reserve.config;
+        let margin_call_expires_at = self.deleveraging_margin_call_expiry(lending_market, reserve);
+        let seconds_since_expiry = u64::try_from(now)
+            .unwrap_or(0)
+            .saturating_sub(margin_call_expires_at);
+        let threshold_decrease = Fraction::checked_from_ratio(
+            u128::from(config.deleveraging_threshold_decrease_bps_per_day)
+                * u128::from(seconds_since_expiry),
+            u128::from(BPS_SECONDS_PER_DAY),
+        )
+        .unwrap_or(Fraction::MAX);
+        let bonus_increase_bps = u128::from(config.deleveraging_bonus_increase_bps_per_day)
+            * u128::from(seconds_since_expiry)
+            / u128::from(SECONDS_PER_DAY);
+        let min_bonus_bps = u128::from(config.min_liquidation_bonus_bps);
+        let liquidation_bonus_bps = (min_bonus_bps + bonus_increase_bps)
+            .min(u128::from(config.max_liquidation_bonus_bps))
+            .max(min_bonus_bps);
+        Some(DeleveragingState {
+            margin_call_started_at: self.autodeleverage_margin_call_started_timestamp,
+            margin_call_expires_at,
+            seconds_since_expiry,
+            liquidation_threshold: Fraction::from_percent(self.autodeleverage_target_ltv_pct)
+                .saturating_sub(threshold_decrease),
+            liquidation_bonus_bps: u64::try_from(liquidation_bonus_bps).unwrap_or(u64::MAX),
+        })
+    }
+
+    /// Unix timestamp from which the obligation, staying at `loan_to_value`, can be liquidated for
+    /// deleveraging; `None` if it is not marked or the threshold never decreases to
+    /// `loan_to_value`.
+    pub fn deleveraging_liquidatable_at(
+        &self,
+        lending_market: &LendingMarket,
+        reserve: &Reserve,
+        loan_to_value: Fraction,
+    ) -> Option<u64> {
+        if !self.is_marked_for_deleveraging() {
+            return None;
+        }
+        let margin_call_expires_at = self.deleveraging_margin_call_expiry(lending_market, reserve);
+        let gap = Fraction::from_percent(self.autodeleverage_target_ltv_pct)
+            .saturating_sub(loan_to_value);
+        if gap.is_zero() {
+            return Some(margin_call_expires_at);
+        }
+        let rate = reserve.config.deleveraging_threshold_decrease_bps_per_day;
+        if rate == 0 {
+            return None;
+        }
+        let seconds = gap
+            .checked_mul_int(BPS_SECONDS_PER_DAY)?
+            .checked_div_int(rate)?
+            .to_ceil()?;
+        margin_call_expires_at.checked_add(seconds)
+    }
+}
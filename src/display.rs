@@ -0,0 +1,447 @@
+//! Human-readable output: on-chain names as text, one-line `Display` summaries of the main
+//! accounts and a `Debug` view without padding and reserved fields.
+use crate::fraction::Fraction;
+use crate::*;
+use core::fmt;
+use std::borrow::Cow;
+
+/// Zero-padded on-chain byte string as text, invalid UTF-8 replaced.
+pub fn name_str(bytes: &[u8]) -> Cow<'_, str> {
+    let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&bytes[..len])
+}
+
+impl LendingMarket {
+    pub fn name_str(&self) -> Cow<'_, str> {
+        name_str(&self.name)
+    }
+
+    pub fn quote_currency_str(&self) -> Cow<'_, str> {
+        name_str(&self.quote_currency)
+    }
+}
+
+impl TokenInfo {
+    pub fn name_str(&self) -> Cow<'_, str> {
+        name_str(&self.name)
+    }
+}
+
+/// `Debug` output without padding and reserved fields, with names as text and `_sf`/`_bsf` fields
+/// as [`Fraction`]s. Use through [`DebugCompact::compact`]:
+///
+/// ```ignore
+/// println!("{:#?}", reserve.compact());
+/// ```
+pub trait DebugCompact {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn compact(&self) -> Compact<'_, Self> {
+        Compact(self)
+    }
+}
+
+/// Wrapper whose `Debug` is [`DebugCompact::fmt_compact`].
+pub struct Compact<'a, T: ?Sized>(pub &'a T);
+
+impl<T: DebugCompact + ?Sized> fmt::Debug for Compact<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_compact(f)
+    }
+}
+
+impl<T: DebugCompact, const N: usize> DebugCompact for [T; N] {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter().map(Compact)).finish()
+    }
+}
+
+impl<T: DebugCompact + ?Sized> DebugCompact for Box<T> {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_compact(f)
+    }
+}
+
+macro_rules! compact_field {
+    (plain, $value:expr) => {
+        &$value
+    };
+    (compact, $value:expr) => {
+        &Compact(&$value)
+    };
+    (name, $value:expr) => {
+        &name_str(&$value)
+    };
+    (sf, $value:expr) => {
+        &Fraction::from_bits(u128::from($value))
+    };
+    (bsf, $value:expr) => {
+        &$value.to_fraction()
+    };
+}
+
+macro_rules! impl_debug_compact {
+    ($($ty:ident { $($field:ident: $kind:ident),* $(,)? },)*) => {
+        $(
+            impl DebugCompact for $ty {
+                fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct(stringify!($ty))
+                        $(.field(stringify!($field), compact_field!($kind, self.$field)))*
+                        .finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_debug_compact!(
+    UserState {
+        user_id: plain,
+        farm_state: plain,
+        owner: plain,
+        is_farm_delegated: plain,
+        rewards_tally_scaled: plain,
+        rewards_issued_unclaimed: plain,
+        last_claim_ts: plain,
+        active_stake_scaled: plain,
+        pending_deposit_stake_scaled: plain,
+        pending_deposit_stake_ts: plain,
+        pending_withdrawal_unstake_scaled: plain,
+        pending_withdrawal_unstake_ts: plain,
+        bump: plain,
+        delegatee: plain,
+        last_stake_ts: plain,
+    },
+    LendingMarket {
+        version: plain,
+        bump_seed: plain,
+        lending_market_owner: plain,
+        lending_market_owner_cached: plain,
+        quote_currency: name,
+        referral_fee_bps: plain,
+        emergency_mode: plain,
+        autodeleverage_enabled: plain,
+        borrow_disabled: plain,
+        price_refresh_trigger_to_max_age_pct: plain,
+        liquidation_max_debt_close_factor_pct: plain,
+        insolvency_risk_unhealthy_ltv_pct: plain,
+        min_full_liquidation_value_threshold: plain,
+        max_liquidatable_debt_market_value_at_once: plain,
+        global_allowed_borrow_value: plain,
+        risk_council: plain,
+        elevation_groups: compact,
+        min_net_value_in_obligation_sf: sf,
+        min_value_skip_liquidation_ltv_checks: plain,
+        name: name,
+        min_value_skip_liquidation_bf_checks: plain,
+        individual_autodeleverage_margin_call_period_secs: plain,
+    },
+    Obligation {
+        tag: plain,
+        last_update: compact,
+        lending_market: plain,
+        owner: plain,
+        deposits: compact,
+        lowest_reserve_deposit_liquidation_ltv: plain,
+        deposited_value_sf: sf,
+        borrows: compact,
+        borrow_factor_adjusted_debt_value_sf: sf,
+        borrowed_assets_market_value_sf: sf,
+        allowed_borrow_value_sf: sf,
+        unhealthy_borrow_value_sf: sf,
+        deposits_asset_tiers: plain,
+        borrows_asset_tiers: plain,
+        elevation_group: plain,
+        num_of_obsolete_reserves: plain,
+        has_debt: plain,
+        referrer: plain,
+        borrowing_disabled: plain,
+        autodeleverage_target_ltv_pct: plain,
+        lowest_reserve_deposit_max_ltv_pct: plain,
+        highest_borrow_factor_pct: plain,
+        autodeleverage_margin_call_started_timestamp: plain,
+    },
+    ReferrerState {
+        short_url: plain,
+        owner: plain,
+    },
+    ReferrerTokenState {
+        referrer: plain,
+        mint: plain,
+        amount_unclaimed_sf: sf,
+        amount_cumulative_sf: sf,
+        bump: plain,
+    },
+    ShortUrl {
+        referrer: plain,
+        short_url: plain,
+    },
+    UserMetadata {
+        referrer: plain,
+        bump: plain,
+        user_lookup_table: plain,
+        owner: plain,
+    },
+    Reserve {
+        version: plain,
+        last_update: compact,
+        lending_market: plain,
+        farm_collateral: plain,
+        farm_debt: plain,
+        liquidity: compact,
+        collateral: compact,
+        config: compact,
+        borrowed_amount_outside_elevation_group: plain,
+        borrowed_amounts_against_this_reserve_in_elevation_groups: plain,
+    },
+    LastUpdate {
+        slot: plain,
+        stale: plain,
+        price_status: plain,
+        placeholder: plain,
+    },
+    ElevationGroup {
+        max_liquidation_bonus_bps: plain,
+        id: plain,
+        ltv_pct: plain,
+        liquidation_threshold_pct: plain,
+        allow_new_loans: plain,
+        max_reserves_as_collateral: plain,
+        debt_reserve: plain,
+    },
+    InitObligationArgs {
+        tag: plain,
+        id: plain,
+    },
+    ObligationCollateral {
+        deposit_reserve: plain,
+        deposited_amount: plain,
+        market_value_sf: sf,
+        borrowed_amount_against_this_collateral_in_elevation_group: plain,
+    },
+    ObligationLiquidity {
+        borrow_reserve: plain,
+        cumulative_borrow_rate_bsf: bsf,
+        borrowed_amount_sf: sf,
+        market_value_sf: sf,
+        borrow_factor_adjusted_market_value_sf: sf,
+        borrowed_amount_outside_elevation_groups: plain,
+    },
+    BigFractionBytes { value: plain },
+    ReserveCollateral {
+        mint_pubkey: plain,
+        mint_total_supply: plain,
+        supply_vault: plain,
+    },
+    ReserveConfig {
+        status: plain,
+        asset_tier: plain,
+        host_fixed_interest_rate_bps: plain,
+        protocol_take_rate_pct: plain,
+        protocol_liquidation_fee_pct: plain,
+        loan_to_value_pct: plain,
+        liquidation_threshold_pct: plain,
+        min_liquidation_bonus_bps: plain,
+        max_liquidation_bonus_bps: plain,
+        bad_debt_liquidation_bonus_bps: plain,
+        deleveraging_margin_call_period_secs: plain,
+        deleveraging_threshold_decrease_bps_per_day: plain,
+        fees: compact,
+        borrow_rate_curve: compact,
+        borrow_factor_pct: plain,
+        deposit_limit: plain,
+        borrow_limit: plain,
+        token_info: compact,
+        deposit_withdrawal_cap: compact,
+        debt_withdrawal_cap: compact,
+        elevation_groups: plain,
+        disable_usage_as_coll_outside_emode: plain,
+        utilization_limit_block_borrowing_above_pct: plain,
+        autodeleverage_enabled: plain,
+        borrow_limit_outside_elevation_group: plain,
+        borrow_limit_against_this_collateral_in_elevation_group: plain,
+        deleveraging_bonus_increase_bps_per_day: plain,
+    },
+    ReserveFees {
+        borrow_fee_sf: sf,
+        flash_loan_fee_sf: sf,
+    },
+    ReserveLiquidity {
+        mint_pubkey: plain,
+        supply_vault: plain,
+        fee_vault: plain,
+        available_amount: plain,
+        borrowed_amount_sf: sf,
+        market_price_sf: sf,
+        market_price_last_updated_ts: plain,
+        mint_decimals: plain,
+        deposit_limit_crossed_timestamp: plain,
+        borrow_limit_crossed_timestamp: plain,
+        cumulative_borrow_rate_bsf: bsf,
+        accumulated_protocol_fees_sf: sf,
+        accumulated_referrer_fees_sf: sf,
+        pending_referrer_fees_sf: sf,
+        absolute_referral_rate_sf: sf,
+        token_program: plain,
+    },
+    WithdrawalCaps {
+        config_capacity: plain,
+        current_total: plain,
+        last_interval_start_timestamp: plain,
+        config_interval_length_seconds: plain,
+    },
+    PriceHeuristic {
+        lower: plain,
+        upper: plain,
+        exp: plain,
+    },
+    PythConfiguration { price: plain },
+    ScopeConfiguration {
+        price_feed: plain,
+        price_chain: plain,
+        twap_chain: plain,
+    },
+    SwitchboardConfiguration {
+        price_aggregator: plain,
+        twap_aggregator: plain,
+    },
+    TokenInfo {
+        name: name,
+        heuristic: compact,
+        max_twap_divergence_bps: plain,
+        max_age_price_seconds: plain,
+        max_age_twap_seconds: plain,
+        scope_configuration: compact,
+        switchboard_configuration: compact,
+        pyth_configuration: compact,
+        block_price_usage: plain,
+    },
+    BorrowRateCurve { points: compact },
+    CurvePoint {
+        utilization_rate_bps: plain,
+        borrow_rate_bps: plain,
+    },
+);
+
+impl DebugCompact for KaminoLendingAccount {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UserState(account) => account.fmt_compact(f),
+            Self::LendingMarket(account) => account.fmt_compact(f),
+            Self::Obligation(account) => account.fmt_compact(f),
+            Self::ReferrerState(account) => account.fmt_compact(f),
+            Self::ReferrerTokenState(account) => account.fmt_compact(f),
+            Self::ShortUrl(account) => account.fmt_compact(f),
+            Self::UserMetadata(account) => account.fmt_compact(f),
+            Self::Reserve(account) => account.fmt_compact(f),
+        }
+    }
+}
+
+/// `value` as a percentage with two decimals.
+struct Percent(Fraction);
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.checked_mul_int(100) {
+            Some(percent) => write!(f, "{percent:.2}%"),
+            None => f.write_str("overflow"),
+        }
+    }
+}
+
+/// `USDC reserve EPjF...: price 0.99998, supply 222222.2211, borrowed 98765.4321, utilization
+/// 44.44%, LTV 80%, liquidation threshold 85%`, amounts in whole tokens.
+impl fmt::Display for Reserve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tokens = |amount: Fraction| {
+            amount
+                .checked_div_int(self.liquidity.mint_factor().max(1))
+                .unwrap_or(Fraction::ZERO)
+        };
+        write!(
+            f,
+            "{} reserve {}: price {:.6}, supply {:.4}, borrowed {:.4}, utilization {}, LTV {}%, liquidation threshold {}%",
+            self.config.token_info.name_str(),
+            self.liquidity.mint_pubkey,
+            self.liquidity.market_price(),
+            tokens(self.total_supply()),
+            tokens(self.liquidity.borrowed_amount()),
+            Percent(self.utilization_rate()),
+            self.config.loan_to_value_pct,
+            self.config.liquidation_threshold_pct,
+        )?;
+        match self.status() {
+            Some(ReserveStatus::Active) | None => Ok(()),
+            Some(status) => write!(f, " ({status:?})"),
+        }
+    }
+}
+
+/// `Obligation of <owner>: 1 deposit(s) worth 6543.21, 1 borrow(s) worth 2999.94, LTV 45.85% (max
+/// 65.00%, unhealthy 75.00%)`, as of the last refresh.
+impl fmt::Display for Obligation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Obligation of {}: {} deposit(s) worth {:.2}, {} borrow(s) worth {:.2}, LTV {} (max {}, unhealthy {})",
+            self.owner,
+            self.active_deposits().count(),
+            self.deposited_value(),
+            self.active_borrows().count(),
+            Fraction::from_bits(self.borrowed_assets_market_value_sf),
+            Percent(self.loan_to_value()),
+            Percent(self.max_loan_to_value()),
+            Percent(self.unhealthy_loan_to_value()),
+        )?;
+        if self.is_in_elevation_group() {
+            write!(f, ", elevation group {}", self.elevation_group)?;
+        }
+        if self.is_marked_for_deleveraging() {
+            f.write_str(", marked for deleveraging")?;
+        }
+        Ok(())
+    }
+}
+
+/// `Lending market "Main Market" (USD) owned by <owner>, 1 elevation group(s)`.
+impl fmt::Display for LendingMarket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lending market {:?} ({}) owned by {}, {} elevation group(s)",
+            self.name_str(),
+            self.quote_currency_str(),
+            self.lending_market_owner,
+            self.configured_elevation_groups().count(),
+        )?;
+        if self.is_emergency_mode() {
+            f.write_str(", emergency mode")?;
+        }
+        if self.is_borrowing_disabled() {
+            f.write_str(", borrowing disabled")?;
+        }
+        Ok(())
+    }
+}
+
+/// `Elevation group 1: LTV 90%, liquidation threshold 92%, max bonus 100 bps, debt reserve <address>,
+/// up to 4 collateral reserves`.
+impl fmt::Display for ElevationGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Elevation group {}: LTV {}%, liquidation threshold {}%, max bonus {} bps, debt reserve {}, up to {} collateral reserves",
+            self.id,
+            self.ltv_pct,
+            self.liquidation_threshold_pct,
+            self.max_liquidation_bonus_bps,
+            self.debt_reserve,
+            self.max_reserves_as_collateral,
+        )?;
+        if self.allow_new_loans == 0 {
+            f.write_str(", new loans disabled")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+//! Which elevation groups an obligation can switch to with `request_elevation_group`, and what
+//! switching does to its borrowing power.
+use crate::fraction::Fraction;
+use crate::lending_market::ELEVATION_GROUP_NONE;
+use crate::obligation::ObligationValues;
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+/// An allowed elevation group switch, with the obligation's values before and after.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElevationGroupSwitch {
+    pub elevation_group: u8,
+    pub current: ObligationValues,
+    pub target: ObligationValues,
+}
+
+impl ElevationGroupSwitch {
+    /// Increase of the max LTV, zero if it decreases.
+    pub fn max_ltv_increase(&self) -> Fraction {
+        self.target
+            .max_loan_to_value()
+            .saturating_sub(self.current.max_loan_to_value())
+    }
+
+    /// Increase of the LTV at which the obligation becomes liquidatable, zero if it decreases.
+    pub fn unhealthy_ltv_increase(&self) -> Fraction {
+        self.target
+            .unhealthy_loan_to_value()
+            .saturating_sub(self.current.unhealthy_loan_to_value())
+    }
+
+    /// Extra borrow-factor-adjusted value that can be borrowed after switching, zero if it
+    /// decreases.
+    pub fn additional_borrow_value(&self) -> Fraction {
+        self.target
+            .remaining_borrow_value()
+            .saturating_sub(self.current.remaining_borrow_value())
+    }
+
+    /// Whether switching leaves more room to borrow.
+    pub fn is_improvement(&self) -> bool {
+        self.target.remaining_borrow_value() > self.current.remaining_borrow_value()
+    }
+}
+
+/// Outcome of checking one elevation group: the switch, or the error `request_elevation_group`
+/// would fail with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElevationGroupEligibility {
+    pub elevation_group: u8,
+    pub result: Result<ElevationGroupSwitch, KaminoLendingError>,
+}
+
+impl ElevationGroupEligibility {
+    pub fn is_eligible(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl LendingMarket {
+    /// Elevation groups set up in the market, i.e. with a non-zero id.
+    pub fn configured_elevation_groups(&self) -> impl Iterator<Item = &ElevationGroup> {
+        self.elevation_groups
+            .iter()
+            .filter(|group| group.id != ELEVATION_GROUP_NONE)
+    }
+}
+
+impl Obligation {
+    fn ensure_elevation_group_allowed(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+        elevation_group: u8,
+    ) -> Result<(), KaminoLendingError> {
+        if lending_market.is_emergency_mode() {
+            return Err(KaminoLendingError::GlobalEmergencyMode);
+        }
+        if elevation_group == self.elevation_group {
+            return Err(KaminoLendingError::ElevationGroupAlreadyActivated);
+        }
+        let borrows: Vec<&ObligationLiquidity> =
+            self.active_borrows().map(|(_, borrow)| borrow).collect();
+        let debt_amount = match borrows.first() {
+            Some(borrow) => {
+                let reserve = reserves
+                    .reserve(&borrow.borrow_reserve)
+                    .ok_or(KaminoLendingError::InvalidObligationLiquidity)?;
+                borrow
+                    .accrued_borrowed_amount(reserve)
+                    .to_ceil()
+                    .unwrap_or(u64::MAX)
+            }
+            None => 0,
+        };
+        if elevation_group == ELEVATION_GROUP_NONE {
+            if let Some(borrow) = borrows.first() {
+                let reserve = reserves
+                    .reserve(&borrow.borrow_reserve)
+                    .ok_or(KaminoLendingError::InvalidObligationLiquidity)?;
+                let outside_total = reserve
+                    .borrowed_amount_outside_elevation_group
+                    .saturating_add(debt_amount);
+                if outside_total > reserve.config.borrow_limit_outside_elevation_group {
+                    return Err(KaminoLendingError::ElevationGroupBorrowLimitExceeded);
+                }
+            }
+            return Ok(());
+        }
+        let Some(group) = lending_market
+            .elevation_group(elevation_group)
+            .filter(|group| group.id == elevation_group)
+        else {
+            return Err(KaminoLendingError::InvalidElevationGroup);
+        };
+        if group.debt_reserve == Pubkey::default() {
+            return Err(KaminoLendingError::ElevationGroupWithoutDebtReserve);
+        }
+        if group.max_reserves_as_collateral == 0 {
+            return Err(KaminoLendingError::ElevationGroupMaxCollateralReserveZero);
+        }
+        if group.allow_new_loans == 0 {
+            return Err(KaminoLendingError::ElevationGroupNewLoansDisabled);
+        }
+        if borrows.len() > 1 {
+            return Err(KaminoLendingError::ObligationElevationGroupMultipleDebtReserve);
+        }
+        if let Some(borrow) = borrows.first() {
+            if borrow.borrow_reserve != group.debt_reserve {
+                return Err(KaminoLendingError::ElevationGroupHasAnotherDebtReserve);
+            }
+            let reserve = reserves
+                .reserve(&borrow.borrow_reserve)
+                .ok_or(KaminoLendingError::InvalidObligationLiquidity)?;
+            if !reserve.is_in_elevation_group(group.id) {
+                return Err(KaminoLendingError::InconsistentElevationGroup);
+            }
+        }
+        if self.active_deposits().count() > usize::from(group.max_reserves_as_collateral) {
+            return Err(KaminoLendingError::ObligationCollateralExceedsElevationGroupLimit);
+        }
+        let group_index = usize::from(group.id - 1);
+        for (_, deposit) in self.active_deposits() {
+            if deposit.deposit_reserve == group.debt_reserve {
+                return Err(KaminoLendingError::ElevationGroupDebtReserveAsCollateral);
+            }
+            let reserve = reserves
+                .reserve(&deposit.deposit_reserve)
+                .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+            if !reserve.is_in_elevation_group(group.id) {
+                return Err(KaminoLendingError::InconsistentElevationGroup);
+            }
+            let limit = reserve
+                .config
+                .borrow_limit_against_this_collateral_in_elevation_group
+                .get(group_index)
+                .copied()
+                .unwrap_or(0);
+            let borrowed = reserve
+                .borrowed_amounts_against_this_reserve_in_elevation_groups
+                .get(group_index)
+                .copied()
+                .unwrap_or(0);
+            if debt_amount > 0 && borrowed.saturating_add(debt_amount) > limit {
+                return Err(KaminoLendingError::ElevationGroupBorrowLimitExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether `request_elevation_group` would move the obligation to `elevation_group`
+    /// ([`ELEVATION_GROUP_NONE`] to leave its current group).
+    ///
+    /// Fails with the error the program would reject the switch with, or with
+    /// [`KaminoLendingError::InvalidObligationCollateral`] or
+    /// [`KaminoLendingError::InvalidObligationLiquidity`] when a reserve is missing from
+    /// `reserves`.
+    pub fn check_elevation_group(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+        elevation_group: u8,
+    ) -> Result<ElevationGroupSwitch, KaminoLendingError> {
+        self.ensure_elevation_group_allowed(lending_market, reserves, elevation_group)?;
+        let current = self.compute_values(lending_market, reserves)?;
+        let target =
+            self.compute_values_in_elevation_group(lending_market, reserves, elevation_group)?;
+        if target.has_debt()
+            && target.borrow_factor_adjusted_debt_value > target.allowed_borrow_value
+        {
+            return Err(KaminoLendingError::UnhealthyElevationGroupLtv);
+        }
+        Ok(ElevationGroupSwitch {
+            elevation_group,
+            current,
+            target,
+        })
+    }
+
+    /// Every configured group of the market other than the current one, plus
+    /// [`ELEVATION_GROUP_NONE`] when the obligation is in a group, each with its switch or the
+    /// reason it is rejected.
+    ///
+    /// Fails when one of the obligation's reserves is missing from `reserves`.
+    pub fn elevation_group_eligibility(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+    ) -> Result<Vec<ElevationGroupEligibility>, KaminoLendingError> {
+        self.compute_values(lending_market, reserves)?;
+        let mut candidates: Vec<u8> = lending_market
+            .configured_elevation_groups()
+            .map(|group| group.id)
+            .filter(|id| *id != self.elevation_group)
+            .collect();
+        if self.is_in_elevation_group() {
+            candidates.insert(0, ELEVATION_GROUP_NONE);
+        }
+        Ok(candidates
+            .into_iter()
+            .map(|elevation_group| ElevationGroupEligibility {
+                elevation_group,
+                result: self.check_elevation_group(lending_market, reserves, elevation_group),
+            })
+            .collect())
+    }
+
+    /// The allowed switches.
+    pub fn eligible_elevation_groups(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+    ) -> Result<Vec<ElevationGroupSwitch>, KaminoLendingError> {
+        Ok(self
+            .elevation_group_eligibility(lending_market, reserves)?
+            .into_iter()
+            .filter_map(|eligibility| eligibility.result.ok())
+            .collect())
+    }
+
+    /// The allowed switch that leaves the most room to borrow, if any improves on staying put.
+    pub fn recommended_elevation_group(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+    ) -> Result<Option<ElevationGroupSwitch>, KaminoLendingError> {
+        Ok(self
+            .eligible_elevation_groups(lending_market, reserves)?
+            .into_iter()
+            .filter(ElevationGroupSwitch::is_improvement)
+            .max_by(|a, b| {
+                a.target
+                    .remaining_borrow_value()
+                    .cmp(&b.target.remaining_borrow_value())
+            }))
+    }
+}
@@ -3,6 +3,7 @@ use solana_program::{
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KaminoLendingError {
     #[error("Market authority is invalid")]
     InvalidMarketAuthority = 6000,
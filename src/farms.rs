@@ -0,0 +1,465 @@
+//! Kamino farms program accounts referenced by the lending program.
+//!
+//! Reserves point to farms through `Reserve::farm_collateral` and `Reserve::farm_debt`, and each
+//! obligation taking part in a farm owns a [`UserState`] derived from the farm and the obligation.
+use crate::math::{mul_div_floor, WAD};
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+pub const FARMS_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("FarmsPZpWu9i7Kky8tPN37rs2TpmMrAZrC7S7vJa91Hr");
+pub const FARMS_MAX_REWARDS_TOKENS: usize = 10;
+pub const FARMS_REWARD_CURVE_POINTS: usize = 20;
+pub const FARM_USER_STATE_SEED: &[u8] = b"user";
+pub const FARM_VAULT_SEED: &[u8] = b"fvault";
+pub const FARM_REWARD_VAULT_SEED: &[u8] = b"rvault";
+pub const FARM_VAULTS_AUTHORITY_SEED: &[u8] = b"authority";
+pub const FARM_TREASURY_VAULT_SEED: &[u8] = b"tvault";
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FarmTokenInfo {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub mint: Pubkey,
+    pub decimals: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub padding: [u64; 6],
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardPerTimeUnitPoint {
+    pub ts_start: u64,
+    pub reward_per_time_unit: u64,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardScheduleCurve {
+    pub points: [RewardPerTimeUnitPoint; 20],
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardInfo {
+    pub token: FarmTokenInfo,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub rewards_vault: Pubkey,
+    pub rewards_available: u64,
+    pub reward_schedule_curve: RewardScheduleCurve,
+    pub min_claim_duration_seconds: u64,
+    pub last_issuance_ts: u64,
+    pub rewards_issued_unclaimed: u64,
+    pub rewards_issued_cumulative: u64,
+    pub reward_per_share_scaled: u128,
+    pub placeholder0: u64,
+    pub reward_type: u8,
+    pub rewards_per_second_decimals: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub padding0: [u8; 6],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub padding1: [u64; 20],
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RewardType {
+    Proportional,
+    Constant,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    Seconds,
+    Slots,
+}
+pub const FARM_STATE_ACCOUNT_DISCM: [u8; 8] = [198, 102, 216, 74, 63, 66, 163, 190];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FarmState {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub farm_admin: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub global_config: Pubkey,
+    pub token: FarmTokenInfo,
+    pub reward_infos: [RewardInfo; 10],
+    pub num_reward_tokens: u64,
+    pub num_users: u64,
+    pub total_staked_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub farm_vault: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub farm_vaults_authority: Pubkey,
+    pub farm_vaults_authority_bump: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub delegate_authority: Pubkey,
+    pub time_unit: u8,
+    pub is_farm_frozen: u8,
+    pub is_farm_delegated: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub padding0: [u8; 5],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub withdraw_authority: Pubkey,
+    pub deposit_warmup_period: u32,
+    pub withdrawal_cooldown_period: u32,
+    pub total_active_stake_scaled: u128,
+    pub total_pending_stake_scaled: u128,
+    pub total_pending_amount: u64,
+    pub slashed_amount_current: u64,
+    pub slashed_amount_cumulative: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub slashed_amount_spill_address: Pubkey,
+    pub locking_mode: u64,
+    pub locking_start_timestamp: u64,
+    pub locking_duration: u64,
+    pub locking_early_withdrawal_penalty_bps: u64,
+    pub deposit_cap_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub scope_prices: Pubkey,
+    pub scope_oracle_price_id: u64,
+    pub scope_oracle_max_age: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub pending_farm_admin: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub strategy_id: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub delegated_rps_admin: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub vault_id: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub second_delegated_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "crate::serde_helpers::zeroed",
+            with = "crate::serde_helpers::big_array"
+        )
+    )]
+    pub padding: [u64; 74],
+}
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FarmStateAccount(pub FarmState);
+impl FarmStateAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != FARM_STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    FARM_STATE_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(FarmState::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&FARM_STATE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const GLOBAL_CONFIG_ACCOUNT_DISCM: [u8; 8] = [149, 8, 156, 202, 160, 252, 176, 217];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalConfig {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub global_admin: Pubkey,
+    pub treasury_fee_bps: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub treasury_vaults_authority: Pubkey,
+    pub treasury_vaults_authority_bump: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub pending_global_admin: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "crate::serde_helpers::zeroed",
+            with = "crate::serde_helpers::big_array"
+        )
+    )]
+    pub padding1: [u128; 126],
+}
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalConfigAccount(pub GlobalConfig);
+impl GlobalConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GLOBAL_CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    GLOBAL_CONFIG_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(GlobalConfig::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GLOBAL_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// `UserState` of `obligation` in `farm_state`, as created by `init_obligation_farms_for_reserve`.
+pub fn find_obligation_farm_user_state_address_with_program_id(
+    farms_program_id: Pubkey,
+    farm_state: &Pubkey,
+    obligation: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FARM_USER_STATE_SEED,
+            farm_state.as_ref(),
+            obligation.as_ref(),
+        ],
+        &farms_program_id,
+    )
+}
+pub fn find_obligation_farm_user_state_address(
+    farm_state: &Pubkey,
+    obligation: &Pubkey,
+) -> (Pubkey, u8) {
+    find_obligation_farm_user_state_address_with_program_id(
+        FARMS_PROGRAM_ID,
+        farm_state,
+        obligation,
+    )
+}
+/// Vault holding the rewards of `reward_mint` distributed by `farm_state`.
+pub fn find_farm_reward_vault_address_with_program_id(
+    farms_program_id: Pubkey,
+    farm_state: &Pubkey,
+    reward_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FARM_REWARD_VAULT_SEED,
+            farm_state.as_ref(),
+            reward_mint.as_ref(),
+        ],
+        &farms_program_id,
+    )
+}
+pub fn find_farm_reward_vault_address(farm_state: &Pubkey, reward_mint: &Pubkey) -> (Pubkey, u8) {
+    find_farm_reward_vault_address_with_program_id(FARMS_PROGRAM_ID, farm_state, reward_mint)
+}
+/// Vault holding the staked tokens of `farm_state`. Unused by delegated (lending) farms.
+pub fn find_farm_vault_address_with_program_id(
+    farms_program_id: Pubkey,
+    farm_state: &Pubkey,
+    token_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FARM_VAULT_SEED, farm_state.as_ref(), token_mint.as_ref()],
+        &farms_program_id,
+    )
+}
+pub fn find_farm_vault_address(farm_state: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    find_farm_vault_address_with_program_id(FARMS_PROGRAM_ID, farm_state, token_mint)
+}
+/// Authority owning the farm and reward vaults of `farm_state`.
+pub fn find_farm_vaults_authority_address_with_program_id(
+    farms_program_id: Pubkey,
+    farm_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FARM_VAULTS_AUTHORITY_SEED, farm_state.as_ref()],
+        &farms_program_id,
+    )
+}
+pub fn find_farm_vaults_authority_address(farm_state: &Pubkey) -> (Pubkey, u8) {
+    find_farm_vaults_authority_address_with_program_id(FARMS_PROGRAM_ID, farm_state)
+}
+/// Treasury vault collecting the `GlobalConfig::treasury_fee_bps` cut of `reward_mint` rewards.
+pub fn find_farm_treasury_vault_address_with_program_id(
+    farms_program_id: Pubkey,
+    global_config: &Pubkey,
+    reward_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FARM_TREASURY_VAULT_SEED,
+            global_config.as_ref(),
+            reward_mint.as_ref(),
+        ],
+        &farms_program_id,
+    )
+}
+pub fn find_farm_treasury_vault_address(
+    global_config: &Pubkey,
+    reward_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_farm_treasury_vault_address_with_program_id(FARMS_PROGRAM_ID, global_config, reward_mint)
+}
+impl Reserve {
+    /// Farm attached to the given side of the reserve, if any.
+    pub fn farm(&self, kind: ReserveFarmKind) -> Option<Pubkey> {
+        let farm = match kind {
+            ReserveFarmKind::Collateral => self.farm_collateral,
+            ReserveFarmKind::Debt => self.farm_debt,
+        };
+        (farm != Pubkey::default()).then_some(farm)
+    }
+}
+impl RewardScheduleCurve {
+    /// Sum of `reward_per_time_unit * duration` over `[from, to)`, still multiplied by
+    /// `10^rewards_per_second_decimals`.
+    ///
+    /// Each point's rate applies from its `ts_start` until the next point's `ts_start`; the last
+    /// point's rate applies indefinitely.
+    pub fn cumulative_amount_issued(&self, from: u64, to: u64) -> Option<u128> {
+        if to <= from {
+            return Some(0);
+        }
+        let mut total = 0u128;
+        for (i, point) in self.points.iter().enumerate() {
+            let segment_end = self
+                .points
+                .get(i + 1)
+                .map_or(u64::MAX, |next| next.ts_start);
+            let start = point.ts_start.max(from);
+            let end = segment_end.min(to);
+            if end <= start {
+                continue;
+            }
+            let issued =
+                u128::from(point.reward_per_time_unit).checked_mul(u128::from(end - start))?;
+            total = total.checked_add(issued)?;
+            if segment_end >= to {
+                break;
+            }
+        }
+        Some(total)
+    }
+}
+impl RewardInfo {
+    pub fn reward_type(&self) -> Option<RewardType> {
+        match self.reward_type {
+            0 => Some(RewardType::Proportional),
+            1 => Some(RewardType::Constant),
+            _ => None,
+        }
+    }
+    /// Reward tokens the farm would issue between `last_issuance_ts` and `now`, capped by
+    /// `rewards_available`, and the matching increase of `reward_per_share_scaled`.
+    ///
+    /// Returns `(issued, reward_per_share_scaled_increase)`.
+    pub fn accrue(&self, total_active_stake_scaled: u128, now: u64) -> Option<(u64, u128)> {
+        if total_active_stake_scaled == 0 || now <= self.last_issuance_ts {
+            return Some((0, 0));
+        }
+        let decimals = 10u128.checked_pow(u32::from(self.rewards_per_second_decimals))?;
+        let per_unit_issued = self
+            .reward_schedule_curve
+            .cumulative_amount_issued(self.last_issuance_ts, now)?;
+        let available = u128::from(self.rewards_available);
+        let (issued, rps_increase) = match self.reward_type()? {
+            RewardType::Proportional => {
+                let issued = (per_unit_issued / decimals).min(available);
+                let rps_increase =
+                    mul_div_floor(issued.checked_mul(WAD)?, WAD, total_active_stake_scaled)?;
+                (issued, rps_increase)
+            }
+            RewardType::Constant => {
+                let wanted = mul_div_floor(
+                    per_unit_issued,
+                    total_active_stake_scaled,
+                    decimals.checked_mul(WAD)?,
+                )?;
+                let issued = wanted.min(available);
+                let rps_increase = if issued == wanted {
+                    mul_div_floor(per_unit_issued, WAD, decimals)?
+                } else {
+                    mul_div_floor(issued.checked_mul(WAD)?, WAD, total_active_stake_scaled)?
+                };
+                (issued, rps_increase)
+            }
+        };
+        Some((u64::try_from(issued).ok()?, rps_increase))
+    }
+}
+impl FarmState {
+    pub fn time_unit(&self) -> Option<TimeUnit> {
+        match self.time_unit {
+            0 => Some(TimeUnit::Seconds),
+            1 => Some(TimeUnit::Slots),
+            _ => None,
+        }
+    }
+    /// Reward infos currently in use, paired with their index in `reward_infos`.
+    pub fn active_rewards(&self) -> impl Iterator<Item = (usize, &RewardInfo)> {
+        let num_reward_tokens = usize::try_from(self.num_reward_tokens)
+            .unwrap_or(FARMS_MAX_REWARDS_TOKENS)
+            .min(FARMS_MAX_REWARDS_TOKENS);
+        self.reward_infos[..num_reward_tokens].iter().enumerate()
+    }
+    /// `reward_per_share_scaled` of reward `reward_index` once global rewards are refreshed at
+    /// `now`, expressed in the farm's [`TimeUnit`].
+    pub fn projected_reward_per_share_scaled(&self, reward_index: usize, now: u64) -> Option<u128> {
+        let reward_info = self.reward_infos.get(reward_index)?;
+        let (_, increase) = reward_info.accrue(self.total_active_stake_scaled, now)?;
+        reward_info.reward_per_share_scaled.checked_add(increase)
+    }
+}
+/// Rewards a [`UserState`] can harvest for one of its farm's reward tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingReward {
+    pub reward_index: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub rewards_vault: Pubkey,
+    pub amount: u64,
+}
+impl UserState {
+    /// Rewards of `reward_index` the user could harvest at `now` (in the farm's [`TimeUnit`]):
+    /// already issued but unclaimed rewards plus what refreshing the farm and user would accrue.
+    pub fn pending_reward(
+        &self,
+        farm_state: &FarmState,
+        reward_index: usize,
+        now: u64,
+    ) -> Option<u64> {
+        let reward_per_share_scaled =
+            farm_state.projected_reward_per_share_scaled(reward_index, now)?;
+        let tally_scaled = mul_div_floor(self.active_stake_scaled, reward_per_share_scaled, WAD)?;
+        let previous_tally_scaled = *self.rewards_tally_scaled.get(reward_index)?;
+        let accrued = tally_scaled.saturating_sub(previous_tally_scaled) / WAD;
+        let unclaimed = *self.rewards_issued_unclaimed.get(reward_index)?;
+        unclaimed.checked_add(u64::try_from(accrued).ok()?)
+    }
+    /// Every reward of `farm_state` with a non-zero pending amount at `now`.
+    pub fn pending_rewards(&self, farm_state: &FarmState, now: u64) -> Vec<PendingReward> {
+        farm_state
+            .active_rewards()
+            .filter_map(|(reward_index, reward_info)| {
+                let amount = self.pending_reward(farm_state, reward_index, now)?;
+                (amount > 0).then_some(PendingReward {
+                    reward_index,
+                    mint: reward_info.token.mint,
+                    token_program: reward_info.token.token_program,
+                    rewards_vault: reward_info.rewards_vault,
+                    amount,
+                })
+            })
+            .collect()
+    }
+}
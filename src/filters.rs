@@ -0,0 +1,132 @@
+//! Ready-made `getProgramAccounts` filters: discriminator, data size and `memcmp` on the
+//! fields commonly searched by.
+use crate::offsets::*;
+use crate::snapshot::AccountFilter;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+/// Filters matching every account of one type.
+pub fn account_filters(discm: [u8; 8], account_len: usize) -> Vec<AccountFilter> {
+    vec![
+        AccountFilter::memcmp(0, discm),
+        AccountFilter::DataSize(account_len as u64),
+    ]
+}
+
+fn with_pubkey(mut filters: Vec<AccountFilter>, offset: usize, key: &Pubkey) -> Vec<AccountFilter> {
+    filters.push(AccountFilter::memcmp(offset, key.to_bytes()));
+    filters
+}
+
+pub fn lending_markets() -> Vec<AccountFilter> {
+    account_filters(LENDING_MARKET_ACCOUNT_DISCM, LENDING_MARKET_ACCOUNT_LEN)
+}
+
+pub fn lending_markets_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        lending_markets(),
+        LENDING_MARKET_LENDING_MARKET_OWNER_OFFSET,
+        owner,
+    )
+}
+
+pub fn reserves() -> Vec<AccountFilter> {
+    account_filters(RESERVE_ACCOUNT_DISCM, RESERVE_ACCOUNT_LEN)
+}
+
+pub fn reserves_in_market(lending_market: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(reserves(), RESERVE_LENDING_MARKET_OFFSET, lending_market)
+}
+
+/// Reserves lending `mint`, in any market.
+pub fn reserves_by_mint(mint: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(reserves(), RESERVE_LIQUIDITY_MINT_PUBKEY_OFFSET, mint)
+}
+
+pub fn reserve_in_market_by_mint(lending_market: &Pubkey, mint: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        reserves_in_market(lending_market),
+        RESERVE_LIQUIDITY_MINT_PUBKEY_OFFSET,
+        mint,
+    )
+}
+
+pub fn obligations() -> Vec<AccountFilter> {
+    account_filters(OBLIGATION_ACCOUNT_DISCM, OBLIGATION_ACCOUNT_LEN)
+}
+
+pub fn obligations_in_market(lending_market: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        obligations(),
+        OBLIGATION_LENDING_MARKET_OFFSET,
+        lending_market,
+    )
+}
+
+/// Obligations of `owner`, in any market.
+pub fn obligations_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(obligations(), OBLIGATION_OWNER_OFFSET, owner)
+}
+
+pub fn obligations_in_market_by_owner(
+    lending_market: &Pubkey,
+    owner: &Pubkey,
+) -> Vec<AccountFilter> {
+    with_pubkey(
+        obligations_in_market(lending_market),
+        OBLIGATION_OWNER_OFFSET,
+        owner,
+    )
+}
+
+pub fn referrer_states_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(REFERRER_STATE_ACCOUNT_DISCM, REFERRER_STATE_ACCOUNT_LEN),
+        REFERRER_STATE_OWNER_OFFSET,
+        owner,
+    )
+}
+
+pub fn referrer_token_states_by_referrer(referrer: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(
+            REFERRER_TOKEN_STATE_ACCOUNT_DISCM,
+            REFERRER_TOKEN_STATE_ACCOUNT_LEN,
+        ),
+        REFERRER_TOKEN_STATE_REFERRER_OFFSET,
+        referrer,
+    )
+}
+
+pub fn user_metadata_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(USER_METADATA_ACCOUNT_DISCM, USER_METADATA_ACCOUNT_LEN),
+        USER_METADATA_OWNER_OFFSET,
+        owner,
+    )
+}
+
+pub fn user_metadata_by_referrer(referrer: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(USER_METADATA_ACCOUNT_DISCM, USER_METADATA_ACCOUNT_LEN),
+        USER_METADATA_REFERRER_OFFSET,
+        referrer,
+    )
+}
+
+/// Farm user states, owned by the farms program, staking in `farm_state`.
+pub fn user_states_by_farm(farm_state: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(USER_STATE_ACCOUNT_DISCM, USER_STATE_ACCOUNT_LEN),
+        USER_STATE_FARM_STATE_OFFSET,
+        farm_state,
+    )
+}
+
+pub fn user_states_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        account_filters(USER_STATE_ACCOUNT_DISCM, USER_STATE_ACCOUNT_LEN),
+        USER_STATE_OWNER_OFFSET,
+        owner,
+    )
+}
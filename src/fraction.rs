@@ -0,0 +1,357 @@
+//! Fixed-point numbers matching the program's `_sf` ("scaled fraction") fields.
+//!
+//! The lending program stores prices, market values and borrowed amounts as `U68F60` fixed-point
+//! numbers: a `u128` whose low 60 bits are the fractional part. [`Fraction`] wraps those raw bits
+//! so values read from accounts can be combined without losing precision.
+use crate::math::{div_wide, mul_wide};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use core::str::FromStr;
+
+/// `U68F60` fixed-point number, bit-compatible with the program's `*_sf` fields.
+///
+/// Arithmetic operators panic on overflow, underflow and division by zero, like the program does;
+/// use the `checked_*` methods where the inputs are untrusted.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fraction(u128);
+
+impl Fraction {
+    pub const FRAC_BITS: u32 = 60;
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+    pub const MAX: Self = Self(u128::MAX);
+
+    /// Wraps the raw bits of an `_sf` field.
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Raw bits, as stored in an `_sf` field.
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    pub const fn from_num(value: u64) -> Self {
+        Self((value as u128) << Self::FRAC_BITS)
+    }
+
+    pub fn from_percent(percent: impl Into<u128>) -> Self {
+        Self::from_ratio(percent.into(), 100)
+    }
+
+    pub fn from_bps(bps: impl Into<u128>) -> Self {
+        Self::from_ratio(bps.into(), 10_000)
+    }
+
+    /// `numerator / denominator`, rounded down. Panics if `denominator` is zero.
+    pub fn from_ratio(numerator: u128, denominator: u128) -> Self {
+        Self::checked_from_ratio(numerator, denominator).expect("fraction overflow")
+    }
+
+    pub fn checked_from_ratio(numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let (hi, lo) = (
+            numerator >> (128 - Self::FRAC_BITS),
+            numerator << Self::FRAC_BITS,
+        );
+        div_wide(hi, lo, denominator).map(|(bits, _)| Self(bits))
+    }
+
+    /// `value * 10^-exp`, the decimal representation used by oracles.
+    pub fn from_decimal(value: u64, exp: u32) -> Option<Self> {
+        Self::checked_from_ratio(u128::from(value), 10u128.checked_pow(exp)?)
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (hi, lo) = mul_wide(self.0, rhs.0);
+        if hi >> Self::FRAC_BITS != 0 {
+            return None;
+        }
+        Some(Self(
+            (hi << (128 - Self::FRAC_BITS)) | (lo >> Self::FRAC_BITS),
+        ))
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        let (hi, lo) = (self.0 >> (128 - Self::FRAC_BITS), self.0 << Self::FRAC_BITS);
+        div_wide(hi, lo, rhs.0).map(|(bits, _)| Self(bits))
+    }
+
+    pub fn checked_mul_int(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(u128::from(rhs)).map(Self)
+    }
+
+    pub fn checked_div_int(self, rhs: u64) -> Option<Self> {
+        self.0.checked_div(u128::from(rhs)).map(Self)
+    }
+
+    /// Integer part, or `None` if it does not fit in a `u64`.
+    pub fn to_floor(self) -> Option<u64> {
+        u64::try_from(self.0 >> Self::FRAC_BITS).ok()
+    }
+
+    pub fn to_ceil(self) -> Option<u64> {
+        let floor = self.to_floor()?;
+        if self.frac_bits() == 0 {
+            Some(floor)
+        } else {
+            floor.checked_add(1)
+        }
+    }
+
+    pub fn to_round(self) -> Option<u64> {
+        let floor = self.to_floor()?;
+        if self.frac_bits() >= 1 << (Self::FRAC_BITS - 1) {
+            floor.checked_add(1)
+        } else {
+            Some(floor)
+        }
+    }
+
+    /// Lossy conversion for display and analytics.
+    pub fn to_f64(self) -> f64 {
+        (self.0 >> Self::FRAC_BITS) as f64
+            + self.frac_bits() as f64 / (1u128 << Self::FRAC_BITS) as f64
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    fn frac_bits(self) -> u128 {
+        self.0 & ((1 << Self::FRAC_BITS) - 1)
+    }
+
+    /// Integer part and the first `digits` (at most 19) decimals, truncated or rounded half up.
+    fn decimal_parts(self, digits: u32, round: bool) -> (u128, u128) {
+        let scale = 10u128.pow(digits);
+        let mut int = self.0 >> Self::FRAC_BITS;
+        let mut frac = if round {
+            (self.frac_bits() * scale + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS
+        } else {
+            (self.frac_bits() * scale) >> Self::FRAC_BITS
+        };
+        if frac >= scale {
+            int += 1;
+            frac -= scale;
+        }
+        (int, frac)
+    }
+
+    /// Shortest-form decimal that parses back to exactly `self`: 19 decimals are enough to tell
+    /// apart values one `2^-60` step apart.
+    pub fn to_exact_string(self) -> String {
+        let (int, frac) = self.decimal_parts(19, true);
+        let frac = format!("{frac:019}");
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            int.to_string()
+        } else {
+            format!("{int}.{frac}")
+        }
+    }
+}
+
+impl From<u64> for Fraction {
+    fn from(value: u64) -> Self {
+        Self::from_num(value)
+    }
+}
+
+impl Add for Fraction {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("fraction overflow")
+    }
+}
+
+impl AddAssign for Fraction {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("fraction underflow")
+    }
+}
+
+impl SubAssign for Fraction {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("fraction overflow")
+    }
+}
+
+impl Div for Fraction {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(rhs)
+            .expect("fraction division overflow or by zero")
+    }
+}
+
+impl PartialEq<u64> for Fraction {
+    fn eq(&self, other: &u64) -> bool {
+        *self == Self::from_num(*other)
+    }
+}
+
+impl PartialOrd<u64> for Fraction {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        Some(self.cmp(&Self::from_num(*other)))
+    }
+}
+
+impl core::iter::Sum for Fraction {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+/// Exact decimal representation; honours `{:.N}` precision (rounded half up), otherwise prints up
+/// to 18 decimals without trailing zeros.
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let digits = precision.unwrap_or(18).min(18) as u32;
+        let (int, frac) = self.decimal_parts(digits, precision.is_some());
+        let mut frac = if digits == 0 {
+            String::new()
+        } else {
+            format!("{:0width$}", frac, width = digits as usize)
+        };
+        if precision.is_none() {
+            frac.truncate(frac.trim_end_matches('0').len());
+        } else if let Some(extra) = precision.filter(|p| *p > 18) {
+            frac.extend(core::iter::repeat_n('0', extra - 18));
+        }
+        let int = int.to_string();
+        let s = if frac.is_empty() {
+            int
+        } else {
+            format!("{int}.{frac}")
+        };
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl fmt::Debug for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fraction({self})")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFractionError;
+
+impl fmt::Display for ParseFractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid or out of range decimal fraction")
+    }
+}
+
+impl std::error::Error for ParseFractionError {}
+
+/// Parses a non-negative decimal such as `"1.25"`, rounded to the nearest representable value.
+impl FromStr for Fraction {
+    type Err = ParseFractionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if int.is_empty() && frac.is_empty()
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseFractionError);
+        }
+        let int: u128 = if int.is_empty() {
+            0
+        } else {
+            int.parse().map_err(|_| ParseFractionError)?
+        };
+        if int >> (128 - Self::FRAC_BITS) != 0 {
+            return Err(ParseFractionError);
+        }
+        // Digits past the 38th cannot move the result by a 2^-60 step.
+        let frac = &frac[..frac.len().min(38)];
+        let frac_bits = if frac.is_empty() {
+            0
+        } else {
+            let scale = 10u128.pow(frac.len() as u32);
+            let digits: u128 = frac.parse().map_err(|_| ParseFractionError)?;
+            let (high, low) = mul_wide(digits, 1 << Self::FRAC_BITS);
+            let (quotient, remainder) = div_wide(high, low, scale).ok_or(ParseFractionError)?;
+            quotient + u128::from(remainder >= scale - remainder)
+        };
+        (int << Self::FRAC_BITS)
+            .checked_add(frac_bits)
+            .map(Self)
+            .ok_or(ParseFractionError)
+    }
+}
+
+/// Human-readable formats get the exact decimal string, binary formats the raw bits.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_exact_string())
+        } else {
+            serializer.serialize_u128(self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        } else {
+            u128::deserialize(deserializer).map(Self)
+        }
+    }
+}
@@ -8,6 +8,7 @@ use solana_program::{
 use std::io::Read;
 use crate::*;
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KaminoLendingProgramIx {
     InitLendingMarket(InitLendingMarketIxArgs),
     UpdateLendingMarket(UpdateLendingMarketIxArgs),
@@ -561,11 +562,17 @@ pub struct InitLendingMarketAccounts<'me, 'info> {
     pub rent: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitLendingMarketKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
 }
 impl From<InitLendingMarketAccounts<'_, '_>> for InitLendingMarketKeys {
@@ -651,9 +658,11 @@ pub const INIT_LENDING_MARKET_IX_DISCM: [u8; 8] = [34, 162, 116, 14, 101, 137, 9
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitLendingMarketIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::name"))]
     pub quote_currency: [u8; 32],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitLendingMarketIxData(pub InitLendingMarketIxArgs);
 impl From<InitLendingMarketIxArgs> for InitLendingMarketIxData {
     fn from(args: InitLendingMarketIxArgs) -> Self {
@@ -790,8 +799,11 @@ pub struct UpdateLendingMarketAccounts<'me, 'info> {
     pub lending_market: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLendingMarketKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
 }
 impl From<UpdateLendingMarketAccounts<'_, '_>> for UpdateLendingMarketKeys {
@@ -849,9 +861,11 @@ pub const UPDATE_LENDING_MARKET_IX_DISCM: [u8; 8] = [209, 157, 53, 210, 97, 180,
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLendingMarketIxArgs {
     pub mode: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::big_array"))]
     pub value: [u8; 72],
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLendingMarketIxData(pub UpdateLendingMarketIxArgs);
 impl From<UpdateLendingMarketIxArgs> for UpdateLendingMarketIxData {
     fn from(args: UpdateLendingMarketIxArgs) -> Self {
@@ -985,8 +999,11 @@ pub struct UpdateLendingMarketOwnerAccounts<'me, 'info> {
     pub lending_market: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLendingMarketOwnerKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner_cached: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
 }
 impl From<UpdateLendingMarketOwnerAccounts<'_, '_>> for UpdateLendingMarketOwnerKeys {
@@ -1054,6 +1071,7 @@ pub const UPDATE_LENDING_MARKET_OWNER_IX_DISCM: [u8; 8] = [
     89,
 ];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLendingMarketOwnerIxData;
 impl UpdateLendingMarketOwnerIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -1185,19 +1203,33 @@ pub struct InitReserveAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReserveKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitReserveAccounts<'_, '_>> for InitReserveKeys {
@@ -1351,6 +1383,7 @@ for InitReserveAccounts<'me, 'info> {
 }
 pub const INIT_RESERVE_IX_DISCM: [u8; 8] = [138, 245, 71, 225, 153, 4, 3, 43];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReserveIxData;
 impl InitReserveIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -1493,16 +1526,27 @@ pub struct InitFarmsForReserveAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitFarmsForReserveKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_global_config: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_vault_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitFarmsForReserveAccounts<'_, '_>> for InitFarmsForReserveKeys {
@@ -1637,6 +1681,7 @@ pub struct InitFarmsForReserveIxArgs {
     pub mode: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitFarmsForReserveIxData(pub InitFarmsForReserveIxArgs);
 impl From<InitFarmsForReserveIxArgs> for InitFarmsForReserveIxData {
     fn from(args: InitFarmsForReserveIxArgs) -> Self {
@@ -1788,9 +1833,13 @@ pub struct UpdateReserveConfigAccounts<'me, 'info> {
     pub reserve: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateReserveConfigKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
 }
 impl From<UpdateReserveConfigAccounts<'_, '_>> for UpdateReserveConfigKeys {
@@ -1864,6 +1913,7 @@ pub struct UpdateReserveConfigIxArgs {
     pub skip_validation: bool,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateReserveConfigIxData(pub UpdateReserveConfigIxArgs);
 impl From<UpdateReserveConfigIxArgs> for UpdateReserveConfigIxData {
     fn from(args: UpdateReserveConfigIxArgs) -> Self {
@@ -2003,13 +2053,21 @@ pub struct RedeemFeesAccounts<'me, 'info> {
     pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedeemFeesKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_supply_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
 }
 impl From<RedeemFeesAccounts<'_, '_>> for RedeemFeesKeys {
@@ -2109,6 +2167,7 @@ for RedeemFeesAccounts<'me, 'info> {
 }
 pub const REDEEM_FEES_IX_DISCM: [u8; 8] = [215, 39, 180, 41, 173, 46, 248, 220];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedeemFeesIxData;
 impl RedeemFeesIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -2232,14 +2291,23 @@ pub struct WithdrawProtocolFeeAccounts<'me, 'info> {
     pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawProtocolFeeKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub fee_vault: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner_ata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
 }
 impl From<WithdrawProtocolFeeAccounts<'_, '_>> for WithdrawProtocolFeeKeys {
@@ -2365,6 +2433,7 @@ pub struct WithdrawProtocolFeeIxArgs {
     pub amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawProtocolFeeIxData(pub WithdrawProtocolFeeIxArgs);
 impl From<WithdrawProtocolFeeIxArgs> for WithdrawProtocolFeeIxData {
     fn from(args: WithdrawProtocolFeeIxArgs) -> Self {
@@ -2507,11 +2576,17 @@ pub struct SocializeLossAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocializeLossKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub risk_council: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<SocializeLossAccounts<'_, '_>> for SocializeLossKeys {
@@ -2598,6 +2673,7 @@ pub struct SocializeLossIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocializeLossIxData(pub SocializeLossIxArgs);
 impl From<SocializeLossIxArgs> for SocializeLossIxData {
     fn from(args: SocializeLossIxArgs) -> Self {
@@ -2741,15 +2817,25 @@ pub struct SocializeLossV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocializeLossV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub socialize_loss_accounts_risk_council: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub socialize_loss_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub socialize_loss_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub socialize_loss_accounts_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub socialize_loss_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<SocializeLossV2Accounts<'_, '_>> for SocializeLossV2Keys {
@@ -2886,6 +2972,7 @@ pub struct SocializeLossV2IxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocializeLossV2IxData(pub SocializeLossV2IxArgs);
 impl From<SocializeLossV2IxArgs> for SocializeLossV2IxData {
     fn from(args: SocializeLossV2IxArgs) -> Self {
@@ -3053,9 +3140,13 @@ pub struct MarkObligationForDeleveragingAccounts<'me, 'info> {
     pub lending_market: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarkObligationForDeleveragingKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub risk_council: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
 }
 impl From<MarkObligationForDeleveragingAccounts<'_, '_>>
@@ -3143,6 +3234,7 @@ pub struct MarkObligationForDeleveragingIxArgs {
     pub autodeleverage_target_ltv_pct: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarkObligationForDeleveragingIxData(pub MarkObligationForDeleveragingIxArgs);
 impl From<MarkObligationForDeleveragingIxArgs> for MarkObligationForDeleveragingIxData {
     fn from(args: MarkObligationForDeleveragingIxArgs) -> Self {
@@ -3295,12 +3387,19 @@ pub struct RefreshReserveAccounts<'me, 'info> {
     pub scope_prices: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshReserveKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub pyth_oracle: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub switchboard_price_oracle: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub switchboard_twap_oracle: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub scope_prices: Pubkey,
 }
 impl From<RefreshReserveAccounts<'_, '_>> for RefreshReserveKeys {
@@ -3391,6 +3490,7 @@ for RefreshReserveAccounts<'me, 'info> {
 }
 pub const REFRESH_RESERVE_IX_DISCM: [u8; 8] = [2, 218, 138, 235, 79, 201, 25, 102];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshReserveIxData;
 impl RefreshReserveIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -3511,6 +3611,7 @@ pub struct RefreshReservesBatchIxArgs {
     pub skip_price_updates: bool,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshReservesBatchIxData(pub RefreshReservesBatchIxArgs);
 impl From<RefreshReservesBatchIxArgs> for RefreshReservesBatchIxData {
     fn from(args: RefreshReservesBatchIxArgs) -> Self {
@@ -3602,18 +3703,31 @@ pub struct DepositReserveLiquidityAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<DepositReserveLiquidityAccounts<'_, '_>> for DepositReserveLiquidityKeys {
@@ -3779,6 +3893,7 @@ pub struct DepositReserveLiquidityIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityIxData(pub DepositReserveLiquidityIxArgs);
 impl From<DepositReserveLiquidityIxArgs> for DepositReserveLiquidityIxData {
     fn from(args: DepositReserveLiquidityIxArgs) -> Self {
@@ -3943,18 +4058,31 @@ pub struct RedeemReserveCollateralAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedeemReserveCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<RedeemReserveCollateralAccounts<'_, '_>> for RedeemReserveCollateralKeys {
@@ -4120,6 +4248,7 @@ pub struct RedeemReserveCollateralIxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedeemReserveCollateralIxData(pub RedeemReserveCollateralIxArgs);
 impl From<RedeemReserveCollateralIxArgs> for RedeemReserveCollateralIxData {
     fn from(args: RedeemReserveCollateralIxArgs) -> Self {
@@ -4281,15 +4410,25 @@ pub struct InitObligationAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitObligationKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub fee_payer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub seed1_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub seed2_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner_user_metadata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitObligationAccounts<'_, '_>> for InitObligationKeys {
@@ -4412,6 +4551,7 @@ pub struct InitObligationIxArgs {
     pub args: InitObligationArgs,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitObligationIxData(pub InitObligationIxArgs);
 impl From<InitObligationIxArgs> for InitObligationIxData {
     fn from(args: InitObligationIxArgs) -> Self {
@@ -4561,17 +4701,29 @@ pub struct InitObligationFarmsForReserveAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitObligationFarmsForReserveKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation_farm: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitObligationFarmsForReserveAccounts<'_, '_>>
@@ -4731,6 +4883,7 @@ pub struct InitObligationFarmsForReserveIxArgs {
     pub mode: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitObligationFarmsForReserveIxData(pub InitObligationFarmsForReserveIxArgs);
 impl From<InitObligationFarmsForReserveIxArgs> for InitObligationFarmsForReserveIxData {
     fn from(args: InitObligationFarmsForReserveIxArgs) -> Self {
@@ -4901,16 +5054,27 @@ pub struct RefreshObligationFarmsForReserveAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshObligationFarmsForReserveKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub crank: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub base_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<RefreshObligationFarmsForReserveAccounts<'_, '_>>
@@ -5069,6 +5233,7 @@ pub struct RefreshObligationFarmsForReserveIxArgs {
     pub mode: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshObligationFarmsForReserveIxData(
     pub RefreshObligationFarmsForReserveIxArgs,
 );
@@ -5243,8 +5408,11 @@ pub struct RefreshObligationAccounts<'me, 'info> {
     pub obligation: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshObligationKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
 }
 impl From<RefreshObligationAccounts<'_, '_>> for RefreshObligationKeys {
@@ -5296,6 +5464,7 @@ for RefreshObligationAccounts<'me, 'info> {
 }
 pub const REFRESH_OBLIGATION_IX_DISCM: [u8; 8] = [33, 132, 147, 228, 151, 192, 72, 89];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RefreshObligationIxData;
 impl RefreshObligationIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -5411,14 +5580,23 @@ pub struct DepositObligationCollateralAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositObligationCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<DepositObligationCollateralAccounts<'_, '_>>
@@ -5549,6 +5727,7 @@ pub struct DepositObligationCollateralIxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositObligationCollateralIxData(pub DepositObligationCollateralIxArgs);
 impl From<DepositObligationCollateralIxArgs> for DepositObligationCollateralIxData {
     fn from(args: DepositObligationCollateralIxArgs) -> Self {
@@ -5712,18 +5891,31 @@ pub struct DepositObligationCollateralV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositObligationCollateralV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_deposit_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_user_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<DepositObligationCollateralV2Accounts<'_, '_>>
@@ -5906,6 +6098,7 @@ pub struct DepositObligationCollateralV2IxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositObligationCollateralV2IxData(pub DepositObligationCollateralV2IxArgs);
 impl From<DepositObligationCollateralV2IxArgs> for DepositObligationCollateralV2IxData {
     fn from(args: DepositObligationCollateralV2IxArgs) -> Self {
@@ -6101,15 +6294,25 @@ pub struct WithdrawObligationCollateralAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<WithdrawObligationCollateralAccounts<'_, '_>>
@@ -6249,6 +6452,7 @@ pub struct WithdrawObligationCollateralIxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralIxData(pub WithdrawObligationCollateralIxArgs);
 impl From<WithdrawObligationCollateralIxArgs> for WithdrawObligationCollateralIxData {
     fn from(args: WithdrawObligationCollateralIxArgs) -> Self {
@@ -6410,18 +6614,31 @@ pub struct WithdrawObligationCollateralV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<WithdrawObligationCollateralV2Accounts<'_, '_>>
@@ -6608,6 +6825,7 @@ pub struct WithdrawObligationCollateralV2IxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralV2IxData(
     pub WithdrawObligationCollateralV2IxArgs,
 );
@@ -6812,18 +7030,31 @@ pub struct BorrowObligationLiquidityAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorrowObligationLiquidityKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<BorrowObligationLiquidityAccounts<'_, '_>> for BorrowObligationLiquidityKeys {
@@ -6991,6 +7222,7 @@ pub struct BorrowObligationLiquidityIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorrowObligationLiquidityIxData(pub BorrowObligationLiquidityIxArgs);
 impl From<BorrowObligationLiquidityIxArgs> for BorrowObligationLiquidityIxData {
     fn from(args: BorrowObligationLiquidityIxArgs) -> Self {
@@ -7165,21 +7397,37 @@ pub struct BorrowObligationLiquidityV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorrowObligationLiquidityV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_borrow_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_borrow_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_reserve_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_borrow_reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<BorrowObligationLiquidityV2Accounts<'_, '_>>
@@ -7391,6 +7639,7 @@ pub struct BorrowObligationLiquidityV2IxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorrowObligationLiquidityV2IxData(pub BorrowObligationLiquidityV2IxArgs);
 impl From<BorrowObligationLiquidityV2IxArgs> for BorrowObligationLiquidityV2IxData {
     fn from(args: BorrowObligationLiquidityV2IxArgs) -> Self {
@@ -7595,15 +7844,25 @@ pub struct RepayObligationLiquidityAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayObligationLiquidityKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<RepayObligationLiquidityAccounts<'_, '_>> for RepayObligationLiquidityKeys {
@@ -7742,6 +8001,7 @@ pub struct RepayObligationLiquidityIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayObligationLiquidityIxData(pub RepayObligationLiquidityIxArgs);
 impl From<RepayObligationLiquidityIxArgs> for RepayObligationLiquidityIxData {
     fn from(args: RepayObligationLiquidityIxArgs) -> Self {
@@ -7906,19 +8166,33 @@ pub struct RepayObligationLiquidityV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayObligationLiquidityV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_reserve_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<RepayObligationLiquidityV2Accounts<'_, '_>>
@@ -8106,6 +8380,7 @@ pub struct RepayObligationLiquidityV2IxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayObligationLiquidityV2IxData(pub RepayObligationLiquidityV2IxArgs);
 impl From<RepayObligationLiquidityV2IxArgs> for RepayObligationLiquidityV2IxData {
     fn from(args: RepayObligationLiquidityV2IxArgs) -> Self {
@@ -8312,34 +8587,63 @@ pub struct RepayAndWithdrawAndRedeemAccounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayAndWithdrawAndRedeemKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_reserve_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub debt_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub debt_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<RepayAndWithdrawAndRedeemAccounts<'_, '_>> for RepayAndWithdrawAndRedeemKeys {
@@ -8690,6 +8994,7 @@ pub struct RepayAndWithdrawAndRedeemIxArgs {
     pub withdraw_collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepayAndWithdrawAndRedeemIxData(pub RepayAndWithdrawAndRedeemIxArgs);
 impl From<RepayAndWithdrawAndRedeemIxArgs> for RepayAndWithdrawAndRedeemIxData {
     fn from(args: RepayAndWithdrawAndRedeemIxArgs) -> Self {
@@ -8972,39 +9277,73 @@ pub struct DepositAndWithdrawAccounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositAndWithdrawKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_destination_deposit_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<DepositAndWithdrawAccounts<'_, '_>> for DepositAndWithdrawKeys {
@@ -9401,6 +9740,7 @@ pub struct DepositAndWithdrawIxArgs {
     pub withdraw_collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositAndWithdrawIxData(pub DepositAndWithdrawIxArgs);
 impl From<DepositAndWithdrawIxArgs> for DepositAndWithdrawIxData {
     fn from(args: DepositAndWithdrawIxArgs) -> Self {
@@ -9680,20 +10020,35 @@ pub struct DepositReserveLiquidityAndObligationCollateralAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityAndObligationCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_destination_deposit_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<DepositReserveLiquidityAndObligationCollateralAccounts<'_, '_>>
@@ -9893,6 +10248,7 @@ pub struct DepositReserveLiquidityAndObligationCollateralIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityAndObligationCollateralIxData(
     pub DepositReserveLiquidityAndObligationCollateralIxArgs,
 );
@@ -10114,23 +10470,41 @@ pub struct DepositReserveLiquidityAndObligationCollateralV2Accounts<'me, 'info>
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityAndObligationCollateralV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_reserve_destination_deposit_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<DepositReserveLiquidityAndObligationCollateralV2Accounts<'_, '_>>
@@ -10380,6 +10754,7 @@ pub struct DepositReserveLiquidityAndObligationCollateralV2IxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositReserveLiquidityAndObligationCollateralV2IxData(
     pub DepositReserveLiquidityAndObligationCollateralV2IxArgs,
 );
@@ -10638,20 +11013,35 @@ pub struct WithdrawObligationCollateralAndRedeemReserveCollateralAccounts<'me, '
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralAndRedeemReserveCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<WithdrawObligationCollateralAndRedeemReserveCollateralAccounts<'_, '_>>
@@ -10857,6 +11247,7 @@ pub struct WithdrawObligationCollateralAndRedeemReserveCollateralIxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralAndRedeemReserveCollateralIxData(
     pub WithdrawObligationCollateralAndRedeemReserveCollateralIxArgs,
 );
@@ -11082,23 +11473,41 @@ pub struct WithdrawObligationCollateralAndRedeemReserveCollateralV2Accounts<'me,
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralAndRedeemReserveCollateralV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_source_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_placeholder_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<WithdrawObligationCollateralAndRedeemReserveCollateralV2Accounts<'_, '_>>
@@ -11357,6 +11766,7 @@ pub struct WithdrawObligationCollateralAndRedeemReserveCollateralV2IxArgs {
     pub collateral_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawObligationCollateralAndRedeemReserveCollateralV2IxData(
     pub WithdrawObligationCollateralAndRedeemReserveCollateralV2IxArgs,
 );
@@ -11639,26 +12049,47 @@ pub struct LiquidateObligationAndRedeemReserveCollateralAccounts<'me, 'info> {
     pub instruction_sysvar_account: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateObligationAndRedeemReserveCollateralKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidator: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve_collateral_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub repay_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub withdraw_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub instruction_sysvar_account: Pubkey,
 }
 impl From<LiquidateObligationAndRedeemReserveCollateralAccounts<'_, '_>>
@@ -11922,6 +12353,7 @@ pub struct LiquidateObligationAndRedeemReserveCollateralIxArgs {
     pub max_allowed_ltv_override_percent: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateObligationAndRedeemReserveCollateralIxData(
     pub LiquidateObligationAndRedeemReserveCollateralIxArgs,
 );
@@ -12179,31 +12611,57 @@ pub struct LiquidateObligationAndRedeemReserveCollateralV2Accounts<'me, 'info> {
     pub farms_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateObligationAndRedeemReserveCollateralV2Keys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_liquidator: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_repay_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_repay_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve_collateral_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve_collateral_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve_liquidity_supply: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_user_destination_collateral: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_collateral_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_repay_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_withdraw_liquidity_token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub liquidation_accounts_instruction_sysvar_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub collateral_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub debt_farms_accounts_obligation_farm_user_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub debt_farms_accounts_reserve_farm_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub farms_program: Pubkey,
 }
 impl From<LiquidateObligationAndRedeemReserveCollateralV2Accounts<'_, '_>>
@@ -12552,6 +13010,7 @@ pub struct LiquidateObligationAndRedeemReserveCollateralV2IxArgs {
     pub max_allowed_ltv_override_percent: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateObligationAndRedeemReserveCollateralV2IxData(
     pub LiquidateObligationAndRedeemReserveCollateralV2IxArgs,
 );
@@ -12853,18 +13312,31 @@ pub struct FlashRepayReserveLiquidityAccounts<'me, 'info> {
     pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FlashRepayReserveLiquidityKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_transfer_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub sysvar_info: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
 }
 impl From<FlashRepayReserveLiquidityAccounts<'_, '_>>
@@ -13032,6 +13504,7 @@ pub struct FlashRepayReserveLiquidityIxArgs {
     pub borrow_instruction_index: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FlashRepayReserveLiquidityIxData(pub FlashRepayReserveLiquidityIxArgs);
 impl From<FlashRepayReserveLiquidityIxArgs> for FlashRepayReserveLiquidityIxData {
     fn from(args: FlashRepayReserveLiquidityIxArgs) -> Self {
@@ -13204,18 +13677,31 @@ pub struct FlashBorrowReserveLiquidityAccounts<'me, 'info> {
     pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FlashBorrowReserveLiquidityKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_transfer_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_source_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_destination_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_fee_receiver: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub sysvar_info: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
 }
 impl From<FlashBorrowReserveLiquidityAccounts<'_, '_>>
@@ -13382,6 +13868,7 @@ pub struct FlashBorrowReserveLiquidityIxArgs {
     pub liquidity_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FlashBorrowReserveLiquidityIxData(pub FlashBorrowReserveLiquidityIxArgs);
 impl From<FlashBorrowReserveLiquidityIxArgs> for FlashBorrowReserveLiquidityIxData {
     fn from(args: FlashBorrowReserveLiquidityIxArgs) -> Self {
@@ -13542,9 +14029,13 @@ pub struct RequestElevationGroupAccounts<'me, 'info> {
     pub lending_market: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequestElevationGroupKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
 }
 impl From<RequestElevationGroupAccounts<'_, '_>> for RequestElevationGroupKeys {
@@ -13626,6 +14117,7 @@ pub struct RequestElevationGroupIxArgs {
     pub elevation_group: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequestElevationGroupIxData(pub RequestElevationGroupIxArgs);
 impl From<RequestElevationGroupIxArgs> for RequestElevationGroupIxData {
     fn from(args: RequestElevationGroupIxArgs) -> Self {
@@ -13770,13 +14262,21 @@ pub struct InitReferrerTokenStateAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReferrerTokenStateKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitReferrerTokenStateAccounts<'_, '_>> for InitReferrerTokenStateKeys {
@@ -13892,6 +14392,7 @@ pub const INIT_REFERRER_TOKEN_STATE_IX_DISCM: [u8; 8] = [
     115,
 ];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReferrerTokenStateIxData;
 impl InitReferrerTokenStateIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -14021,12 +14522,19 @@ pub struct InitUserMetadataAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitUserMetadataKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub fee_payer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_metadata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_user_metadata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitUserMetadataAccounts<'_, '_>> for InitUserMetadataKeys {
@@ -14119,9 +14627,11 @@ pub const INIT_USER_METADATA_IX_DISCM: [u8; 8] = [117, 169, 176, 69, 197, 23, 15
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitUserMetadataIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub user_lookup_table: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitUserMetadataIxData(pub InitUserMetadataIxArgs);
 impl From<InitUserMetadataIxArgs> for InitUserMetadataIxData {
     fn from(args: InitUserMetadataIxArgs) -> Self {
@@ -14266,15 +14776,25 @@ pub struct WithdrawReferrerFeesAccounts<'me, 'info> {
     pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawReferrerFeesKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_liquidity_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve_supply_liquidity: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_token_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
 }
 impl From<WithdrawReferrerFeesAccounts<'_, '_>> for WithdrawReferrerFeesKeys {
@@ -14405,6 +14925,7 @@ pub const WITHDRAW_REFERRER_FEES_IX_DISCM: [u8; 8] = [
     228,
 ];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawReferrerFeesIxData;
 impl WithdrawReferrerFeesIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -14542,12 +15063,19 @@ pub struct InitReferrerStateAndShortUrlAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReferrerStateAndShortUrlKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_short_url: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_user_metadata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<InitReferrerStateAndShortUrlAccounts<'_, '_>>
@@ -14662,6 +15190,7 @@ pub struct InitReferrerStateAndShortUrlIxArgs {
     pub short_url: String,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitReferrerStateAndShortUrlIxData(pub InitReferrerStateAndShortUrlIxArgs);
 impl From<InitReferrerStateAndShortUrlIxArgs> for InitReferrerStateAndShortUrlIxData {
     fn from(args: InitReferrerStateAndShortUrlIxArgs) -> Self {
@@ -14820,11 +15349,17 @@ pub struct DeleteReferrerStateAndShortUrlAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteReferrerStateAndShortUrlKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub referrer_state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub short_url: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub rent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub system_program: Pubkey,
 }
 impl From<DeleteReferrerStateAndShortUrlAccounts<'_, '_>>
@@ -14927,6 +15462,7 @@ pub const DELETE_REFERRER_STATE_AND_SHORT_URL_IX_DISCM: [u8; 8] = [
     150,
 ];
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteReferrerStateAndShortUrlIxData;
 impl DeleteReferrerStateAndShortUrlIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -15060,9 +15596,13 @@ pub struct IdlMissingTypesAccounts<'me, 'info> {
     pub reserve: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdlMissingTypesKeys {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub lending_market: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub reserve: Pubkey,
 }
 impl From<IdlMissingTypesAccounts<'_, '_>> for IdlMissingTypesKeys {
@@ -15137,6 +15677,7 @@ pub struct IdlMissingTypesIxArgs {
     pub update_lending_market_config_mode: UpdateLendingMarketMode,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdlMissingTypesIxData(pub IdlMissingTypesIxArgs);
 impl From<IdlMissingTypesIxArgs> for IdlMissingTypesIxData {
     fn from(args: IdlMissingTypesIxArgs) -> Self {
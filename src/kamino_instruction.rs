@@ -0,0 +1,209 @@
+//! [`KaminoInstruction`], implemented by every instruction's `(XxxKeys, XxxIxArgs)` pair, or by
+//! `XxxKeys` alone for instructions without arguments, so batching, decoding or metrics code can
+//! be written once instead of per instruction. `RefreshReservesBatch` has no keys struct: it is
+//! implemented by `(Vec<AccountMeta>, RefreshReservesBatchIxArgs)` with the reserves to refresh,
+//! each followed by its oracle accounts, as remaining accounts.
+use crate::*;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program;
+use solana_program::pubkey::Pubkey;
+
+pub trait KaminoInstruction {
+    /// First 8 bytes of the instruction data.
+    const DISCM: [u8; 8];
+    /// Number of fixed accounts, i.e. the length of [`Self::account_metas`] for every instruction
+    /// but `RefreshReservesBatch`, whose accounts are all supplied by the caller.
+    const ACCOUNTS_LEN: usize;
+    /// Name of the instruction, as in [`KaminoLendingProgramIx`].
+    const NAME: &'static str;
+
+    fn account_metas(&self) -> Vec<AccountMeta>;
+
+    /// Discriminator followed by the borsh-serialized arguments.
+    fn data(&self) -> std::io::Result<Vec<u8>>;
+
+    fn to_instruction(&self, program_id: Pubkey) -> std::io::Result<Instruction> {
+        Ok(Instruction {
+            program_id,
+            accounts: self.account_metas(),
+            data: self.data()?,
+        })
+    }
+}
+
+/// Invokes `ix` on the lending program; `account_infos` must hold every account of `ix`.
+pub fn invoke<I: KaminoInstruction>(ix: &I, account_infos: &[AccountInfo]) -> ProgramResult {
+    invoke_with_program_id(crate::ID, ix, account_infos)
+}
+
+pub fn invoke_with_program_id<I: KaminoInstruction>(
+    program_id: Pubkey,
+    ix: &I,
+    account_infos: &[AccountInfo],
+) -> ProgramResult {
+    program::invoke(&ix.to_instruction(program_id)?, account_infos)
+}
+
+pub fn invoke_signed<I: KaminoInstruction>(
+    ix: &I,
+    account_infos: &[AccountInfo],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed_with_program_id(crate::ID, ix, account_infos, seeds)
+}
+
+pub fn invoke_signed_with_program_id<I: KaminoInstruction>(
+    program_id: Pubkey,
+    ix: &I,
+    account_infos: &[AccountInfo],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    program::invoke_signed(&ix.to_instruction(program_id)?, account_infos, seeds)
+}
+
+impl KaminoInstruction for (Vec<AccountMeta>, RefreshReservesBatchIxArgs) {
+    const DISCM: [u8; 8] = REFRESH_RESERVES_BATCH_IX_DISCM;
+    const ACCOUNTS_LEN: usize = 0;
+    const NAME: &'static str = "RefreshReservesBatch";
+
+    /// The caller-supplied remaining accounts.
+    fn account_metas(&self) -> Vec<AccountMeta> {
+        self.0.clone()
+    }
+
+    fn data(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::from(REFRESH_RESERVES_BATCH_IX_DISCM);
+        self.1.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+
+macro_rules! impl_kamino_instruction {
+    (@consts $name:ident, $discm:ident, $len:ident) => {
+        const DISCM: [u8; 8] = $discm;
+        const ACCOUNTS_LEN: usize = $len;
+        const NAME: &'static str = stringify!($name);
+    };
+    (@impl $name:ident, $keys:ident, ($args:ident), $discm:ident, $len:ident) => {
+        impl KaminoInstruction for ($keys, $args) {
+            impl_kamino_instruction!(@consts $name, $discm, $len);
+
+            fn account_metas(&self) -> Vec<AccountMeta> {
+                Vec::from(<[AccountMeta; $len]>::from(self.0))
+            }
+
+            fn data(&self) -> std::io::Result<Vec<u8>> {
+                let mut data = Vec::from($discm);
+                self.1.serialize(&mut data)?;
+                Ok(data)
+            }
+        }
+    };
+    (@impl $name:ident, $keys:ident, (), $discm:ident, $len:ident) => {
+        impl KaminoInstruction for $keys {
+            impl_kamino_instruction!(@consts $name, $discm, $len);
+
+            fn account_metas(&self) -> Vec<AccountMeta> {
+                Vec::from(<[AccountMeta; $len]>::from(*self))
+            }
+
+            fn data(&self) -> std::io::Result<Vec<u8>> {
+                Ok(Vec::from($discm))
+            }
+        }
+    };
+    ($($name:ident($keys:ident $(, $args:ident)?) => $discm:ident, $len:ident;)*) => {
+        $(
+            impl_kamino_instruction!(@impl $name, $keys, ($($args)?), $discm, $len);
+        )*
+    };
+}
+
+impl_kamino_instruction!(
+    InitLendingMarket(InitLendingMarketKeys, InitLendingMarketIxArgs) =>
+        INIT_LENDING_MARKET_IX_DISCM, INIT_LENDING_MARKET_IX_ACCOUNTS_LEN;
+    UpdateLendingMarket(UpdateLendingMarketKeys, UpdateLendingMarketIxArgs) =>
+        UPDATE_LENDING_MARKET_IX_DISCM, UPDATE_LENDING_MARKET_IX_ACCOUNTS_LEN;
+    UpdateLendingMarketOwner(UpdateLendingMarketOwnerKeys) =>
+        UPDATE_LENDING_MARKET_OWNER_IX_DISCM, UPDATE_LENDING_MARKET_OWNER_IX_ACCOUNTS_LEN;
+    InitReserve(InitReserveKeys) => INIT_RESERVE_IX_DISCM, INIT_RESERVE_IX_ACCOUNTS_LEN;
+    InitFarmsForReserve(InitFarmsForReserveKeys, InitFarmsForReserveIxArgs) =>
+        INIT_FARMS_FOR_RESERVE_IX_DISCM, INIT_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    UpdateReserveConfig(UpdateReserveConfigKeys, UpdateReserveConfigIxArgs) =>
+        UPDATE_RESERVE_CONFIG_IX_DISCM, UPDATE_RESERVE_CONFIG_IX_ACCOUNTS_LEN;
+    RedeemFees(RedeemFeesKeys) => REDEEM_FEES_IX_DISCM, REDEEM_FEES_IX_ACCOUNTS_LEN;
+    WithdrawProtocolFee(WithdrawProtocolFeeKeys, WithdrawProtocolFeeIxArgs) =>
+        WITHDRAW_PROTOCOL_FEE_IX_DISCM, WITHDRAW_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    SocializeLoss(SocializeLossKeys, SocializeLossIxArgs) =>
+        SOCIALIZE_LOSS_IX_DISCM, SOCIALIZE_LOSS_IX_ACCOUNTS_LEN;
+    SocializeLossV2(SocializeLossV2Keys, SocializeLossV2IxArgs) =>
+        SOCIALIZE_LOSS_V2_IX_DISCM, SOCIALIZE_LOSS_V2_IX_ACCOUNTS_LEN;
+    MarkObligationForDeleveraging(MarkObligationForDeleveragingKeys, MarkObligationForDeleveragingIxArgs) =>
+        MARK_OBLIGATION_FOR_DELEVERAGING_IX_DISCM, MARK_OBLIGATION_FOR_DELEVERAGING_IX_ACCOUNTS_LEN;
+    RefreshReserve(RefreshReserveKeys) => REFRESH_RESERVE_IX_DISCM, REFRESH_RESERVE_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidity(DepositReserveLiquidityKeys, DepositReserveLiquidityIxArgs) =>
+        DEPOSIT_RESERVE_LIQUIDITY_IX_DISCM, DEPOSIT_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RedeemReserveCollateral(RedeemReserveCollateralKeys, RedeemReserveCollateralIxArgs) =>
+        REDEEM_RESERVE_COLLATERAL_IX_DISCM, REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    InitObligation(InitObligationKeys, InitObligationIxArgs) =>
+        INIT_OBLIGATION_IX_DISCM, INIT_OBLIGATION_IX_ACCOUNTS_LEN;
+    InitObligationFarmsForReserve(InitObligationFarmsForReserveKeys, InitObligationFarmsForReserveIxArgs) =>
+        INIT_OBLIGATION_FARMS_FOR_RESERVE_IX_DISCM, INIT_OBLIGATION_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    RefreshObligationFarmsForReserve(RefreshObligationFarmsForReserveKeys, RefreshObligationFarmsForReserveIxArgs) =>
+        REFRESH_OBLIGATION_FARMS_FOR_RESERVE_IX_DISCM, REFRESH_OBLIGATION_FARMS_FOR_RESERVE_IX_ACCOUNTS_LEN;
+    RefreshObligation(RefreshObligationKeys) =>
+        REFRESH_OBLIGATION_IX_DISCM, REFRESH_OBLIGATION_IX_ACCOUNTS_LEN;
+    DepositObligationCollateral(DepositObligationCollateralKeys, DepositObligationCollateralIxArgs) =>
+        DEPOSIT_OBLIGATION_COLLATERAL_IX_DISCM, DEPOSIT_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    DepositObligationCollateralV2(DepositObligationCollateralV2Keys, DepositObligationCollateralV2IxArgs) =>
+        DEPOSIT_OBLIGATION_COLLATERAL_V2_IX_DISCM, DEPOSIT_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateral(WithdrawObligationCollateralKeys, WithdrawObligationCollateralIxArgs) =>
+        WITHDRAW_OBLIGATION_COLLATERAL_IX_DISCM, WITHDRAW_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralV2(WithdrawObligationCollateralV2Keys, WithdrawObligationCollateralV2IxArgs) =>
+        WITHDRAW_OBLIGATION_COLLATERAL_V2_IX_DISCM, WITHDRAW_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    BorrowObligationLiquidity(BorrowObligationLiquidityKeys, BorrowObligationLiquidityIxArgs) =>
+        BORROW_OBLIGATION_LIQUIDITY_IX_DISCM, BORROW_OBLIGATION_LIQUIDITY_IX_ACCOUNTS_LEN;
+    BorrowObligationLiquidityV2(BorrowObligationLiquidityV2Keys, BorrowObligationLiquidityV2IxArgs) =>
+        BORROW_OBLIGATION_LIQUIDITY_V2_IX_DISCM, BORROW_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN;
+    RepayObligationLiquidity(RepayObligationLiquidityKeys, RepayObligationLiquidityIxArgs) =>
+        REPAY_OBLIGATION_LIQUIDITY_IX_DISCM, REPAY_OBLIGATION_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RepayObligationLiquidityV2(RepayObligationLiquidityV2Keys, RepayObligationLiquidityV2IxArgs) =>
+        REPAY_OBLIGATION_LIQUIDITY_V2_IX_DISCM, REPAY_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN;
+    RepayAndWithdrawAndRedeem(RepayAndWithdrawAndRedeemKeys, RepayAndWithdrawAndRedeemIxArgs) =>
+        REPAY_AND_WITHDRAW_AND_REDEEM_IX_DISCM, REPAY_AND_WITHDRAW_AND_REDEEM_IX_ACCOUNTS_LEN;
+    DepositAndWithdraw(DepositAndWithdrawKeys, DepositAndWithdrawIxArgs) =>
+        DEPOSIT_AND_WITHDRAW_IX_DISCM, DEPOSIT_AND_WITHDRAW_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidityAndObligationCollateral(DepositReserveLiquidityAndObligationCollateralKeys, DepositReserveLiquidityAndObligationCollateralIxArgs) =>
+        DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_IX_DISCM, DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_IX_ACCOUNTS_LEN;
+    DepositReserveLiquidityAndObligationCollateralV2(DepositReserveLiquidityAndObligationCollateralV2Keys, DepositReserveLiquidityAndObligationCollateralV2IxArgs) =>
+        DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2_IX_DISCM, DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralAndRedeemReserveCollateral(WithdrawObligationCollateralAndRedeemReserveCollateralKeys, WithdrawObligationCollateralAndRedeemReserveCollateralIxArgs) =>
+        WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_IX_DISCM, WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    WithdrawObligationCollateralAndRedeemReserveCollateralV2(WithdrawObligationCollateralAndRedeemReserveCollateralV2Keys, WithdrawObligationCollateralAndRedeemReserveCollateralV2IxArgs) =>
+        WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_DISCM, WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    LiquidateObligationAndRedeemReserveCollateral(LiquidateObligationAndRedeemReserveCollateralKeys, LiquidateObligationAndRedeemReserveCollateralIxArgs) =>
+        LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_IX_DISCM, LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_IX_ACCOUNTS_LEN;
+    LiquidateObligationAndRedeemReserveCollateralV2(LiquidateObligationAndRedeemReserveCollateralV2Keys, LiquidateObligationAndRedeemReserveCollateralV2IxArgs) =>
+        LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_DISCM, LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_ACCOUNTS_LEN;
+    FlashRepayReserveLiquidity(FlashRepayReserveLiquidityKeys, FlashRepayReserveLiquidityIxArgs) =>
+        FLASH_REPAY_RESERVE_LIQUIDITY_IX_DISCM, FLASH_REPAY_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    FlashBorrowReserveLiquidity(FlashBorrowReserveLiquidityKeys, FlashBorrowReserveLiquidityIxArgs) =>
+        FLASH_BORROW_RESERVE_LIQUIDITY_IX_DISCM, FLASH_BORROW_RESERVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    RequestElevationGroup(RequestElevationGroupKeys, RequestElevationGroupIxArgs) =>
+        REQUEST_ELEVATION_GROUP_IX_DISCM, REQUEST_ELEVATION_GROUP_IX_ACCOUNTS_LEN;
+    InitReferrerTokenState(InitReferrerTokenStateKeys) =>
+        INIT_REFERRER_TOKEN_STATE_IX_DISCM, INIT_REFERRER_TOKEN_STATE_IX_ACCOUNTS_LEN;
+    InitUserMetadata(InitUserMetadataKeys, InitUserMetadataIxArgs) =>
+        INIT_USER_METADATA_IX_DISCM, INIT_USER_METADATA_IX_ACCOUNTS_LEN;
+    WithdrawReferrerFees(WithdrawReferrerFeesKeys) =>
+        WITHDRAW_REFERRER_FEES_IX_DISCM, WITHDRAW_REFERRER_FEES_IX_ACCOUNTS_LEN;
+    InitReferrerStateAndShortUrl(InitReferrerStateAndShortUrlKeys, InitReferrerStateAndShortUrlIxArgs) =>
+        INIT_REFERRER_STATE_AND_SHORT_URL_IX_DISCM, INIT_REFERRER_STATE_AND_SHORT_URL_IX_ACCOUNTS_LEN;
+    DeleteReferrerStateAndShortUrl(DeleteReferrerStateAndShortUrlKeys) =>
+        DELETE_REFERRER_STATE_AND_SHORT_URL_IX_DISCM, DELETE_REFERRER_STATE_AND_SHORT_URL_IX_ACCOUNTS_LEN;
+    IdlMissingTypes(IdlMissingTypesKeys, IdlMissingTypesIxArgs) =>
+        IDL_MISSING_TYPES_IX_DISCM, IDL_MISSING_TYPES_IX_ACCOUNTS_LEN;
+);
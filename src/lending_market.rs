@@ -0,0 +1,30 @@
+//! Lending market helpers shared by the obligation calculators.
+use crate::fraction::Fraction;
+use crate::*;
+
+/// `Obligation::elevation_group` value of obligations outside any elevation group.
+pub const ELEVATION_GROUP_NONE: u8 = 0;
+pub const MAX_NUM_ELEVATION_GROUPS: u8 = 32;
+
+impl LendingMarket {
+    /// Elevation group `id`, stored at index `id - 1`; `None` for [`ELEVATION_GROUP_NONE`] and
+    /// out-of-range ids.
+    pub fn elevation_group(&self, id: u8) -> Option<&ElevationGroup> {
+        if id == ELEVATION_GROUP_NONE {
+            return None;
+        }
+        self.elevation_groups.get(usize::from(id - 1))
+    }
+
+    pub fn is_emergency_mode(&self) -> bool {
+        self.emergency_mode != 0
+    }
+
+    pub fn is_borrowing_disabled(&self) -> bool {
+        self.borrow_disabled != 0
+    }
+
+    pub fn min_net_value_in_obligation(&self) -> Fraction {
+        Fraction::from_bits(self.min_net_value_in_obligation_sf)
+    }
+}
@@ -0,0 +1,652 @@
+//! The effects of user instructions on reserve and obligation state as pure functions: each runs
+//! the program's checks, updates the accounts the instruction writes to and returns the tokens it
+//! moves, or fails with the error the program would raise and leaves the accounts unchanged.
+//!
+//! Like the program, obligation instructions rely on the values cached by the last refresh: call
+//! [`Obligation::refresh`] after prices or positions change. Interest is not accrued and farms
+//! are not updated.
+use crate::fraction::Fraction;
+use crate::liquidation::{collateral_priority_error, liquidation_bonus};
+use crate::max_amounts::{borrow_blocker, borrow_debt_bounds, withdraw_all_amount};
+use crate::obligation::ObligationValues;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+/// Tokens moved by an instruction, from the signer's side.
+///
+/// For liquidations, liquidity is paid in the repaid reserve's token and received in the
+/// withdrawn reserve's token, which also holds the protocol fee.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenMovements {
+    /// Liquidity sent to the reserve's supply vault.
+    pub liquidity_paid: u64,
+    /// Liquidity received from the reserve's supply vault.
+    pub liquidity_received: u64,
+    /// cTokens burned or locked in the obligation.
+    pub collateral_paid: u64,
+    /// cTokens minted or released from the obligation.
+    pub collateral_received: u64,
+    /// Liquidity moved from the supply vault to the fee vault: borrow origination fees and
+    /// protocol liquidation fees.
+    pub fees: u64,
+}
+
+/// Values cached in `obligation` by its last refresh, without the per-position breakdown.
+fn refreshed_values(obligation: &Obligation) -> ObligationValues {
+    ObligationValues {
+        elevation_group: obligation.elevation_group,
+        deposits: Vec::new(),
+        borrows: Vec::new(),
+        deposited_value: obligation.deposited_value(),
+        borrowed_value: Fraction::from_bits(obligation.borrowed_assets_market_value_sf),
+        borrow_factor_adjusted_debt_value: obligation.borrow_factor_adjusted_debt_value(),
+        allowed_borrow_value: obligation.allowed_borrow_value(),
+        unhealthy_borrow_value: obligation.unhealthy_borrow_value(),
+    }
+}
+
+fn check_reserve(obligation: &Obligation, reserve: &Reserve) -> Result<(), KaminoLendingError> {
+    if reserve.lending_market != obligation.lending_market {
+        return Err(KaminoLendingError::InvalidAccountInput);
+    }
+    Ok(())
+}
+
+fn checked_add(a: u64, b: u64) -> Result<u64, KaminoLendingError> {
+    a.checked_add(b).ok_or(KaminoLendingError::MathOverflow)
+}
+
+/// `deposit_reserve_liquidity`: `liquidity_amount` into `reserve` for newly minted cTokens.
+pub fn deposit_reserve_liquidity(
+    lending_market: &LendingMarket,
+    reserve: &mut Reserve,
+    liquidity_amount: u64,
+    now: i64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if liquidity_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if lending_market.is_emergency_mode() {
+        return Err(KaminoLendingError::GlobalEmergencyMode);
+    }
+    if reserve.status() == Some(ReserveStatus::Obsolete) {
+        return Err(KaminoLendingError::ReserveObsolete);
+    }
+    let total_supply = reserve
+        .total_supply()
+        .checked_add(Fraction::from_num(liquidity_amount))
+        .ok_or(KaminoLendingError::MathOverflow)?;
+    if total_supply > reserve.config.deposit_limit {
+        return Err(KaminoLendingError::DepositLimitExceeded);
+    }
+    let collateral_amount = reserve.liquidity_to_collateral(Fraction::from_num(liquidity_amount));
+    if collateral_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    let available_amount = checked_add(reserve.liquidity.available_amount, liquidity_amount)?;
+    let mint_total_supply = checked_add(reserve.collateral.mint_total_supply, collateral_amount)?;
+    reserve.liquidity.available_amount = available_amount;
+    reserve.collateral.mint_total_supply = mint_total_supply;
+    reserve.config.deposit_withdrawal_cap = reserve
+        .config
+        .deposit_withdrawal_cap
+        .after_deposit(liquidity_amount, now);
+    Ok(TokenMovements {
+        liquidity_paid: liquidity_amount,
+        collateral_received: collateral_amount,
+        ..TokenMovements::default()
+    })
+}
+
+/// `redeem_reserve_collateral`: burns `collateral_amount` cTokens of `reserve` for liquidity.
+pub fn redeem_reserve_collateral(
+    lending_market: &LendingMarket,
+    reserve: &mut Reserve,
+    collateral_amount: u64,
+    now: i64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if collateral_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if lending_market.is_emergency_mode() {
+        return Err(KaminoLendingError::GlobalEmergencyMode);
+    }
+    if collateral_amount > reserve.collateral.mint_total_supply {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    let liquidity_amount = reserve
+        .collateral_to_liquidity(collateral_amount)
+        .to_floor()
+        .unwrap_or(u64::MAX);
+    if liquidity_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if liquidity_amount > reserve.liquidity.available_amount {
+        return Err(KaminoLendingError::InsufficientLiquidity);
+    }
+    let caps = reserve
+        .config
+        .deposit_withdrawal_cap
+        .after_withdrawal(liquidity_amount, now)?;
+    reserve.config.deposit_withdrawal_cap = caps;
+    redeem(reserve, collateral_amount, liquidity_amount);
+    Ok(TokenMovements {
+        liquidity_received: liquidity_amount,
+        collateral_paid: collateral_amount,
+        ..TokenMovements::default()
+    })
+}
+
+fn redeem(reserve: &mut Reserve, collateral_amount: u64, liquidity_amount: u64) {
+    reserve.liquidity.available_amount -= liquidity_amount;
+    reserve.collateral.mint_total_supply -= collateral_amount;
+}
+
+/// `deposit_obligation_collateral`: locks `collateral_amount` cTokens of `reserve` in
+/// `obligation`.
+pub fn deposit_obligation_collateral(
+    lending_market: &LendingMarket,
+    obligation: &mut Obligation,
+    reserve_address: &Pubkey,
+    reserve: &Reserve,
+    collateral_amount: u64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if collateral_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if lending_market.is_emergency_mode() {
+        return Err(KaminoLendingError::GlobalEmergencyMode);
+    }
+    check_reserve(obligation, reserve)?;
+    if reserve.status() == Some(ReserveStatus::Obsolete) {
+        return Err(KaminoLendingError::ReserveObsolete);
+    }
+    let group = lending_market.elevation_group(obligation.elevation_group);
+    if let Some(group) = group {
+        if !reserve.is_in_elevation_group(group.id) {
+            return Err(KaminoLendingError::InconsistentElevationGroup);
+        }
+        if group.debt_reserve == *reserve_address {
+            return Err(KaminoLendingError::ElevationGroupDebtReserveAsCollateral);
+        }
+    }
+    let asset_tier = reserve.config.asset_tier;
+    let isolated_collateral = AssetTier::IsolatedCollateral as u8;
+    let has_other_deposits = obligation
+        .active_deposits()
+        .any(|(_, deposit)| deposit.deposit_reserve != *reserve_address);
+    let other_is_isolated = obligation.active_deposits().any(|(index, deposit)| {
+        deposit.deposit_reserve != *reserve_address
+            && obligation.deposits_asset_tiers[index] == isolated_collateral
+    });
+    if has_other_deposits && (asset_tier == isolated_collateral || other_is_isolated) {
+        return Err(KaminoLendingError::IsolatedAssetTierViolation);
+    }
+    let existing = obligation
+        .active_deposits()
+        .find(|(_, deposit)| deposit.deposit_reserve == *reserve_address)
+        .map(|(index, deposit)| (index, deposit.deposited_amount));
+    let (index, deposited_amount) = match existing {
+        Some((index, deposited_amount)) => {
+            (index, checked_add(deposited_amount, collateral_amount)?)
+        }
+        None => {
+            let index = obligation
+                .deposits
+                .iter()
+                .position(|deposit| deposit.deposit_reserve == Pubkey::default())
+                .ok_or(KaminoLendingError::ObligationReserveLimit)?;
+            if let Some(group) = group {
+                let count = obligation.active_deposits().count() + 1;
+                if count > usize::from(group.max_reserves_as_collateral) {
+                    return Err(KaminoLendingError::ObligationCollateralExceedsElevationGroupLimit);
+                }
+            }
+            obligation.deposits[index].deposit_reserve = *reserve_address;
+            obligation.deposits_asset_tiers[index] = asset_tier;
+            (index, collateral_amount)
+        }
+    };
+    obligation.deposits[index].deposited_amount = deposited_amount;
+    Ok(TokenMovements {
+        collateral_paid: collateral_amount,
+        ..TokenMovements::default()
+    })
+}
+
+fn remove_collateral(obligation: &mut Obligation, index: usize, collateral_amount: u64) {
+    let deposit = &mut obligation.deposits[index];
+    deposit.deposited_amount -= collateral_amount;
+    if deposit.deposited_amount == 0 {
+        deposit.deposit_reserve = Pubkey::default();
+        deposit.market_value_sf = 0;
+        deposit.borrowed_amount_against_this_collateral_in_elevation_group = 0;
+    }
+}
+
+/// `withdraw_obligation_collateral`: releases `collateral_amount` cTokens of `reserve` from
+/// `obligation`. `u64::MAX` releases the whole deposit without debt, and with debt the share of
+/// it worth at most the value the obligation's LTV allows to withdraw.
+pub fn withdraw_obligation_collateral(
+    lending_market: &LendingMarket,
+    obligation: &mut Obligation,
+    reserve_address: &Pubkey,
+    reserve: &Reserve,
+    collateral_amount: u64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if collateral_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if lending_market.is_emergency_mode() {
+        return Err(KaminoLendingError::GlobalEmergencyMode);
+    }
+    check_reserve(obligation, reserve)?;
+    let (index, deposit) = obligation
+        .active_deposits()
+        .find(|(_, deposit)| deposit.deposit_reserve == *reserve_address)
+        .ok_or(KaminoLendingError::ObligationCollateralEmpty)?;
+    let mut amount = collateral_amount.min(deposit.deposited_amount);
+    let deposit_value = Fraction::from_bits(deposit.market_value_sf);
+    let values = refreshed_values(obligation);
+    if values.has_debt() {
+        let group = lending_market.elevation_group(obligation.elevation_group);
+        let (max_ltv_pct, _) = reserve.ltv_and_liquidation_threshold(group);
+        let max_withdraw_value = values.max_withdraw_value(max_ltv_pct);
+        if collateral_amount == u64::MAX {
+            if max_withdraw_value.is_zero() {
+                return Err(KaminoLendingError::WithdrawTooLarge);
+            }
+            amount = withdraw_all_amount(amount, deposit_value, max_withdraw_value);
+        }
+        let withdraw_value = reserve.market_value(reserve.collateral_to_liquidity(amount));
+        if collateral_amount != u64::MAX && withdraw_value > max_withdraw_value {
+            return Err(KaminoLendingError::WithdrawTooLarge);
+        }
+        let min_net_value = lending_market.min_net_value_in_obligation();
+        if values.net_value().saturating_sub(withdraw_value) < min_net_value {
+            return Err(KaminoLendingError::NetValueRemainingTooSmall);
+        }
+    }
+    remove_collateral(obligation, index, amount);
+    Ok(TokenMovements {
+        collateral_received: amount,
+        ..TokenMovements::default()
+    })
+}
+
+fn borrow_limit_error(constraint: AmountConstraint) -> KaminoLendingError {
+    match constraint {
+        AmountConstraint::AvailableLiquidity => KaminoLendingError::InsufficientLiquidity,
+        AmountConstraint::WithdrawalCap => KaminoLendingError::WithdrawalCapReached,
+        AmountConstraint::BorrowLimit => KaminoLendingError::BorrowLimitExceeded,
+        AmountConstraint::UtilizationLimit => {
+            KaminoLendingError::BorrowingAboveUtilizationRateDisabled
+        }
+        AmountConstraint::ElevationGroupBorrowLimit
+        | AmountConstraint::BorrowLimitOutsideElevationGroup => {
+            KaminoLendingError::ElevationGroupBorrowLimitExceeded
+        }
+        AmountConstraint::MinNetValue => KaminoLendingError::NetValueRemainingTooSmall,
+        AmountConstraint::Blocked(err) => err,
+        AmountConstraint::WalletBalance
+        | AmountConstraint::Position
+        | AmountConstraint::DepositLimit
+        | AmountConstraint::ObligationLtv => KaminoLendingError::BorrowTooLarge,
+    }
+}
+
+/// `borrow_obligation_liquidity`: `liquidity_amount` of `reserve` against `obligation`, whose
+/// debt also grows by the origination fee.
+///
+/// In an elevation group, `deposit_reserves` are the obligation's deposit reserves, passed to the
+/// program as remaining accounts: their borrow limits apply and their borrowed amounts grow.
+/// They are not read outside elevation groups.
+pub fn borrow_obligation_liquidity(
+    lending_market: &LendingMarket,
+    obligation: &mut Obligation,
+    reserve_address: &Pubkey,
+    reserve: &mut Reserve,
+    deposit_reserves: &mut [(Pubkey, Reserve)],
+    liquidity_amount: u64,
+    now: i64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if liquidity_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    if let Some(err) = borrow_blocker(obligation, lending_market, reserve, reserve_address) {
+        return Err(err);
+    }
+    if obligation.active_deposits().next().is_none() {
+        return Err(KaminoLendingError::ObligationDepositsEmpty);
+    }
+    let with_referrer =
+        obligation.referrer != Pubkey::default() && lending_market.referral_fee_bps > 0;
+    let fee = reserve.borrow_fee(liquidity_amount, FeeCalculation::Exclusive, with_referrer)?;
+    let debt_increase = checked_add(liquidity_amount, fee)?;
+    let values = refreshed_values(obligation);
+    let bounds = borrow_debt_bounds(
+        obligation,
+        lending_market,
+        &*deposit_reserves,
+        &values,
+        reserve,
+        now,
+    )?;
+    if let Some((_, constraint)) = bounds.iter().find(|(bound, _)| debt_increase > *bound) {
+        return Err(borrow_limit_error(*constraint));
+    }
+    let debt_withdrawal_cap = reserve
+        .config
+        .debt_withdrawal_cap
+        .after_withdrawal(debt_increase, now)?;
+    let existing = obligation
+        .active_borrows()
+        .find(|(_, borrow)| borrow.borrow_reserve == *reserve_address)
+        .map(|(index, borrow)| (index, borrow.accrued_borrowed_amount(reserve)));
+    let (index, accrued) = match existing {
+        Some(existing) => existing,
+        None => {
+            let index = obligation
+                .borrows
+                .iter()
+                .position(|borrow| borrow.borrow_reserve == Pubkey::default())
+                .ok_or(KaminoLendingError::ObligationReserveLimit)?;
+            (index, Fraction::ZERO)
+        }
+    };
+
+    let borrowed_amount = reserve
+        .liquidity
+        .borrowed_amount()
+        .checked_add(Fraction::from_num(debt_increase))
+        .ok_or(KaminoLendingError::MathOverflow)?;
+    let obligation_borrowed_amount = accrued
+        .checked_add(Fraction::from_num(debt_increase))
+        .ok_or(KaminoLendingError::MathOverflow)?;
+
+    reserve.liquidity.available_amount -= debt_increase;
+    reserve.liquidity.borrowed_amount_sf = borrowed_amount.to_bits();
+    reserve.config.debt_withdrawal_cap = debt_withdrawal_cap;
+    let group = lending_market.elevation_group(obligation.elevation_group);
+    if group.is_none() {
+        reserve.borrowed_amount_outside_elevation_group = reserve
+            .borrowed_amount_outside_elevation_group
+            .saturating_add(debt_increase);
+    }
+    update_elevation_group_borrows(
+        lending_market,
+        obligation,
+        deposit_reserves
+            .iter_mut()
+            .map(|(address, reserve)| (&*address, reserve)),
+        |borrowed| borrowed.saturating_add(debt_increase),
+    );
+    let borrow = &mut obligation.borrows[index];
+    if borrow.borrow_reserve == Pubkey::default() {
+        borrow.borrow_reserve = *reserve_address;
+        obligation.borrows_asset_tiers[index] = reserve.config.asset_tier;
+    }
+    let borrow = &mut obligation.borrows[index];
+    borrow.borrowed_amount_sf = obligation_borrowed_amount.to_bits();
+    borrow.cumulative_borrow_rate_bsf = reserve.liquidity.cumulative_borrow_rate_bsf.clone();
+    if group.is_none() {
+        borrow.borrowed_amount_outside_elevation_groups = borrow
+            .borrowed_amount_outside_elevation_groups
+            .saturating_add(debt_increase);
+    }
+    Ok(TokenMovements {
+        liquidity_received: liquidity_amount,
+        fees: fee,
+        ..TokenMovements::default()
+    })
+}
+
+/// Applies `update` to the amount borrowed in `obligation`'s elevation group against each of
+/// `deposit_reserves` it has a deposit in; nothing outside elevation groups.
+fn update_elevation_group_borrows<'a>(
+    lending_market: &LendingMarket,
+    obligation: &Obligation,
+    deposit_reserves: impl IntoIterator<Item = (&'a Pubkey, &'a mut Reserve)>,
+    update: impl Fn(u64) -> u64,
+) {
+    let Some(group) = lending_market.elevation_group(obligation.elevation_group) else {
+        return;
+    };
+    let group_index = usize::from(group.id - 1);
+    for (address, reserve) in deposit_reserves {
+        if obligation.find_deposit(address).is_none() {
+            continue;
+        }
+        if let Some(borrowed) = reserve
+            .borrowed_amounts_against_this_reserve_in_elevation_groups
+            .get_mut(group_index)
+        {
+            *borrowed = update(*borrowed);
+        }
+    }
+}
+
+/// Repays `amount`, at most the accrued debt rounded up, of the borrow at `index`.
+fn repay(
+    obligation: &mut Obligation,
+    index: usize,
+    reserve: &mut Reserve,
+    amount: u64,
+    now: i64,
+) -> Result<(), KaminoLendingError> {
+    let borrow = &obligation.borrows[index];
+    let accrued = borrow.accrued_borrowed_amount(reserve);
+    let debt = accrued.to_ceil().unwrap_or(u64::MAX);
+    let amount = amount.min(debt);
+    let outside_repaid = amount.min(borrow.borrowed_amount_outside_elevation_groups);
+    let available_amount = checked_add(reserve.liquidity.available_amount, amount)?;
+
+    reserve.liquidity.available_amount = available_amount;
+    reserve.liquidity.borrowed_amount_sf = reserve
+        .liquidity
+        .borrowed_amount()
+        .saturating_sub(Fraction::from_num(amount))
+        .to_bits();
+    reserve.config.debt_withdrawal_cap = reserve
+        .config
+        .debt_withdrawal_cap
+        .after_deposit(amount, now);
+    if !obligation.is_in_elevation_group() {
+        reserve.borrowed_amount_outside_elevation_group = reserve
+            .borrowed_amount_outside_elevation_group
+            .saturating_sub(outside_repaid);
+    }
+    let borrow = &mut obligation.borrows[index];
+    let remaining = accrued.saturating_sub(Fraction::from_num(amount));
+    borrow.borrowed_amount_outside_elevation_groups -= outside_repaid;
+    if remaining.is_zero() || amount == debt {
+        borrow.borrow_reserve = Pubkey::default();
+        borrow.borrowed_amount_sf = 0;
+        borrow.market_value_sf = 0;
+        borrow.borrow_factor_adjusted_market_value_sf = 0;
+        borrow.borrowed_amount_outside_elevation_groups = 0;
+    } else {
+        borrow.borrowed_amount_sf = remaining.to_bits();
+        borrow.cumulative_borrow_rate_bsf = reserve.liquidity.cumulative_borrow_rate_bsf.clone();
+    }
+    Ok(())
+}
+
+/// Borrow of `obligation` on `reserve_address` with its accrued amount.
+fn find_borrow(
+    obligation: &Obligation,
+    reserve_address: &Pubkey,
+    reserve: &Reserve,
+) -> Result<(usize, Fraction), KaminoLendingError> {
+    obligation
+        .active_borrows()
+        .find(|(_, borrow)| borrow.borrow_reserve == *reserve_address)
+        .map(|(index, borrow)| (index, borrow.accrued_borrowed_amount(reserve)))
+        .ok_or(KaminoLendingError::ObligationLiquidityEmpty)
+}
+
+/// `repay_obligation_liquidity`: `liquidity_amount` of `obligation`'s debt to `reserve`,
+/// `u64::MAX` for all of it.
+///
+/// In an elevation group, the borrowed amounts of `deposit_reserves`, the obligation's deposit
+/// reserves, shrink by the repaid amount as [`borrow_obligation_liquidity`] grows them.
+pub fn repay_obligation_liquidity(
+    lending_market: &LendingMarket,
+    obligation: &mut Obligation,
+    reserve_address: &Pubkey,
+    reserve: &mut Reserve,
+    deposit_reserves: &mut [(Pubkey, Reserve)],
+    liquidity_amount: u64,
+    now: i64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if liquidity_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    check_reserve(obligation, reserve)?;
+    let (index, accrued) = find_borrow(obligation, reserve_address, reserve)?;
+    let amount = liquidity_amount.min(accrued.to_ceil().unwrap_or(u64::MAX));
+    repay(obligation, index, reserve, amount, now)?;
+    update_elevation_group_borrows(
+        lending_market,
+        obligation,
+        deposit_reserves
+            .iter_mut()
+            .map(|(address, reserve)| (&*address, reserve)),
+        |borrowed| borrowed.saturating_sub(amount),
+    );
+    Ok(TokenMovements {
+        liquidity_paid: amount,
+        ..TokenMovements::default()
+    })
+}
+
+/// `liquidate_obligation_and_redeem_reserve_collateral`: repays up to `liquidity_amount` of
+/// `obligation`'s debt to `repay_reserve`, withdraws the collateral worth the repaid value plus
+/// the liquidation bonus from `withdraw_reserve` (`None` when it is `repay_reserve` itself) and
+/// redeems it, minus the protocol liquidation fee.
+///
+/// Unhealthy obligations use [`liquidation_bonus`]; obligations only liquidatable for
+/// deleveraging use the bonus of their [`DeleveragingState`] under `repay_reserve`'s config.
+///
+/// In an elevation group, the borrowed amounts of the withdrawn reserve and of
+/// `deposit_reserves`, the obligation's other deposit reserves, shrink by the repaid amount.
+#[allow(clippy::too_many_arguments)]
+pub fn liquidate_obligation_and_redeem_reserve_collateral(
+    lending_market: &LendingMarket,
+    obligation: &mut Obligation,
+    repay_reserve_address: &Pubkey,
+    repay_reserve: &mut Reserve,
+    withdraw_reserve_address: &Pubkey,
+    mut withdraw_reserve: Option<&mut Reserve>,
+    deposit_reserves: &mut [(Pubkey, Reserve)],
+    liquidity_amount: u64,
+    min_acceptable_received_liquidity_amount: u64,
+    now: i64,
+) -> Result<TokenMovements, KaminoLendingError> {
+    if liquidity_amount == 0 {
+        return Err(KaminoLendingError::InvalidAmount);
+    }
+    let collateral: &Reserve = withdraw_reserve.as_deref().unwrap_or(&*repay_reserve);
+    check_reserve(obligation, repay_reserve)?;
+    check_reserve(obligation, collateral)?;
+    let values = refreshed_values(obligation);
+    let (borrow_index, debt) = find_borrow(obligation, repay_reserve_address, repay_reserve)?;
+    let (deposit_index, deposited_amount) = obligation
+        .active_deposits()
+        .find(|(_, deposit)| deposit.deposit_reserve == *withdraw_reserve_address)
+        .map(|(index, deposit)| (index, deposit.deposited_amount))
+        .ok_or(KaminoLendingError::ObligationCollateralEmpty)?;
+    let group = lending_market.elevation_group(obligation.elevation_group);
+    let (withdraw_ltv_pct, _) = collateral.ltv_and_liquidation_threshold(group);
+    if let Some(err) = collateral_priority_error(
+        lending_market,
+        values.deposited_value,
+        withdraw_ltv_pct,
+        obligation.lowest_reserve_deposit_max_ltv_pct,
+    ) {
+        return Err(err);
+    }
+    let bonus = if values.is_liquidatable() {
+        liquidation_bonus(lending_market, &values, collateral, repay_reserve)
+    } else {
+        match obligation.deleveraging_state(lending_market, repay_reserve, now) {
+            Some(state) if state.is_liquidatable(values.loan_to_value(), now) => {
+                Fraction::from_bps(state.liquidation_bonus_bps)
+            }
+            _ => return Err(KaminoLendingError::ObligationHealthy),
+        }
+    };
+
+    let debt_value = repay_reserve.market_value(debt);
+    let max_repay_value = lending_market
+        .max_liquidatable_debt_value(&values)
+        .min(debt_value);
+    let mut repay_amount = Fraction::from_num(liquidity_amount)
+        .min(repay_reserve.liquidity_amount_for_value(max_repay_value))
+        .min(debt);
+    let deposit_value =
+        collateral.market_value(collateral.collateral_to_liquidity(deposited_amount));
+    let withdraw_value = repay_reserve.market_value(repay_amount) * (Fraction::ONE + bonus);
+    let withdraw_collateral_amount = if withdraw_value >= deposit_value {
+        repay_amount = repay_reserve
+            .liquidity_amount_for_value(deposit_value / (Fraction::ONE + bonus))
+            .min(debt);
+        deposited_amount
+    } else {
+        collateral
+            .liquidity_to_collateral(collateral.liquidity_amount_for_value(withdraw_value))
+            .min(deposited_amount)
+    };
+    let repay_amount = repay_amount.to_ceil().unwrap_or(u64::MAX);
+    if repay_amount == 0 || withdraw_collateral_amount == 0 {
+        return Err(KaminoLendingError::LiquidationTooSmall);
+    }
+    let withdraw_liquidity = collateral.collateral_to_liquidity(withdraw_collateral_amount);
+    let withdraw_liquidity_amount = withdraw_liquidity.to_floor().unwrap_or(u64::MAX);
+    let protocol_fee = (withdraw_liquidity * bonus / (Fraction::ONE + bonus)
+        * Fraction::from_percent(collateral.config.protocol_liquidation_fee_pct))
+    .to_floor()
+    .unwrap_or(u64::MAX);
+    let received = withdraw_liquidity_amount.saturating_sub(protocol_fee);
+    let available = match withdraw_reserve.is_some() {
+        true => collateral.liquidity.available_amount,
+        false => checked_add(collateral.liquidity.available_amount, repay_amount)?,
+    };
+    if withdraw_liquidity_amount > available {
+        return Err(KaminoLendingError::InsufficientLiquidity);
+    }
+    if received < min_acceptable_received_liquidity_amount {
+        return Err(KaminoLendingError::LiquidationRewardTooSmall);
+    }
+
+    repay(obligation, borrow_index, repay_reserve, repay_amount, now)?;
+    let withdrawn = withdraw_reserve
+        .as_deref_mut()
+        .unwrap_or(&mut *repay_reserve);
+    update_elevation_group_borrows(
+        lending_market,
+        obligation,
+        deposit_reserves
+            .iter_mut()
+            .map(|(address, reserve)| (&*address, reserve))
+            .chain([(withdraw_reserve_address, withdrawn)]),
+        |borrowed| borrowed.saturating_sub(repay_amount),
+    );
+    remove_collateral(obligation, deposit_index, withdraw_collateral_amount);
+    let collateral = match withdraw_reserve {
+        Some(reserve) => reserve,
+        None => repay_reserve,
+    };
+    redeem(
+        collateral,
+        withdraw_collateral_amount,
+        withdraw_liquidity_amount,
+    );
+    Ok(TokenMovements {
+        liquidity_paid: repay_amount,
+        liquidity_received: received,
+        fees: protocol_fee,
+        ..TokenMovements::default()
+    })
+}
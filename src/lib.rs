@@ -1,3 +1,6 @@
+// The generated decoders build io errors with `Error::new(ErrorKind::Other, ..)`, and so does the
+// rest of the crate.
+#![allow(clippy::io_other_error)]
 solana_program::declare_id!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
 pub mod accounts;
 pub use accounts::*;
@@ -7,3 +10,63 @@ pub mod instructions;
 pub use instructions::*;
 pub mod errors;
 pub use errors::*;
+pub mod farms;
+pub mod math;
+pub use farms::*;
+pub mod fraction;
+pub use fraction::Fraction;
+pub mod scope;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+pub use scope::*;
+#[cfg(feature = "oracle")]
+pub mod oracle;
+pub mod price_status;
+pub use price_status::*;
+pub mod reserve;
+pub mod withdrawal_caps;
+pub use reserve::*;
+pub mod lending_market;
+pub use lending_market::*;
+pub mod obligation;
+pub use obligation::*;
+pub mod max_amounts;
+pub use max_amounts::*;
+pub mod elevation_group;
+pub use elevation_group::*;
+pub mod deleveraging;
+pub use deleveraging::*;
+pub mod liquidation;
+pub use liquidation::*;
+pub mod liquidation_scanner;
+pub use liquidation_scanner::*;
+pub mod lending_operations;
+pub use lending_operations::*;
+pub mod simulation;
+pub use simulation::*;
+pub mod snapshot;
+pub use snapshot::*;
+pub mod stress_test;
+pub use stress_test::*;
+pub mod account_sizes;
+pub mod filters;
+pub mod offsets;
+pub use account_sizes::*;
+pub mod decode;
+pub use decode::*;
+pub mod display;
+pub use display::*;
+pub mod pda;
+pub use pda::*;
+pub mod resolve;
+pub use resolve::*;
+pub mod verify;
+pub use verify::*;
+pub mod kamino_instruction;
+pub use kamino_instruction::KaminoInstruction;
+pub mod metadata;
+pub use metadata::*;
+pub mod preview;
+pub use preview::*;
+#[cfg(feature = "anchor")]
+pub mod anchor;
@@ -0,0 +1,120 @@
+//! Liquidation limits and bonuses as `liquidate_obligation_and_redeem_reserve_collateral` derives
+//! them from an obligation's values: how much debt one liquidation may repay, the share of
+//! collateral on top of the repaid value paid to the liquidator and which collateral it may take.
+use crate::fraction::Fraction;
+use crate::obligation::ObligationValues;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+impl LendingMarket {
+    /// Largest debt market value one liquidation of an obligation with `values` may repay:
+    /// `liquidation_max_debt_close_factor_pct` of its debt, or all of it once its LTV reaches
+    /// `insolvency_risk_unhealthy_ltv_pct` or its debt is below
+    /// `min_full_liquidation_value_threshold`, at most `max_liquidatable_debt_market_value_at_once`.
+    pub fn max_liquidatable_debt_value(&self, values: &ObligationValues) -> Fraction {
+        let full = values.borrowed_value
+            < Fraction::from_num(self.min_full_liquidation_value_threshold)
+            || (self.insolvency_risk_unhealthy_ltv_pct != 0
+                && values.loan_to_value()
+                    >= Fraction::from_percent(self.insolvency_risk_unhealthy_ltv_pct));
+        let close_factor = if full {
+            Fraction::ONE
+        } else {
+            Fraction::from_percent(self.liquidation_max_debt_close_factor_pct)
+        };
+        values
+            .borrowed_value
+            .checked_mul(close_factor)
+            .unwrap_or(Fraction::MAX)
+            .min(Fraction::from_num(
+                self.max_liquidatable_debt_market_value_at_once,
+            ))
+    }
+}
+
+/// Liquidation bonus for repaying `debt` against `collateral` of an obligation with `values`: its
+/// LTV above the unhealthy LTV, between the reserves' higher `min_liquidation_bonus_bps` and their
+/// lower `max_liquidation_bonus_bps` (the elevation group's in one), and never more than the
+/// collateral can pay; the higher `bad_debt_liquidation_bonus_bps` once debt exceeds collateral.
+pub fn liquidation_bonus(
+    lending_market: &LendingMarket,
+    values: &ObligationValues,
+    collateral: &Reserve,
+    debt: &Reserve,
+) -> Fraction {
+    let loan_to_value = values.loan_to_value();
+    if loan_to_value >= Fraction::ONE {
+        return Fraction::from_bps(
+            collateral
+                .config
+                .bad_debt_liquidation_bonus_bps
+                .max(debt.config.bad_debt_liquidation_bonus_bps),
+        );
+    }
+    let min_bonus = Fraction::from_bps(
+        collateral
+            .config
+            .min_liquidation_bonus_bps
+            .max(debt.config.min_liquidation_bonus_bps),
+    );
+    let max_bonus_bps = match lending_market.elevation_group(values.elevation_group) {
+        Some(group) => group.max_liquidation_bonus_bps,
+        None => collateral
+            .config
+            .max_liquidation_bonus_bps
+            .min(debt.config.max_liquidation_bonus_bps),
+    };
+    let bonus = loan_to_value
+        .saturating_sub(values.unhealthy_loan_to_value())
+        .max(min_bonus)
+        .min(Fraction::from_bps(max_bonus_bps).max(min_bonus));
+    match Fraction::ONE.checked_div(loan_to_value) {
+        Some(inverse) => bonus.min(inverse.saturating_sub(Fraction::ONE)),
+        None => bonus,
+    }
+}
+
+/// Whether a liquidation may withdraw the collateral of `withdraw_reserve`: while the obligation
+/// holds zero-LTV deposits only those, otherwise only deposits with its lowest LTV, unless its
+/// deposited value is below `min_value_skip_liquidation_ltv_checks`.
+pub fn check_liquidation_collateral(
+    lending_market: &LendingMarket,
+    values: &ObligationValues,
+    withdraw_reserve: &Pubkey,
+) -> Result<(), KaminoLendingError> {
+    let withdraw = values
+        .deposit(withdraw_reserve)
+        .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+    let lowest_ltv_pct = values
+        .deposits
+        .iter()
+        .map(|deposit| deposit.max_ltv_pct)
+        .min()
+        .unwrap_or(0);
+    let error = collateral_priority_error(
+        lending_market,
+        values.deposited_value,
+        withdraw.max_ltv_pct,
+        lowest_ltv_pct,
+    );
+    error.map_or(Ok(()), Err)
+}
+
+/// [`check_liquidation_collateral`] from the withdrawn collateral's LTV and the obligation's
+/// lowest deposit LTV.
+pub(crate) fn collateral_priority_error(
+    lending_market: &LendingMarket,
+    deposited_value: Fraction,
+    withdraw_ltv_pct: u8,
+    lowest_ltv_pct: u8,
+) -> Option<KaminoLendingError> {
+    if deposited_value < Fraction::from_num(lending_market.min_value_skip_liquidation_ltv_checks)
+        || withdraw_ltv_pct <= lowest_ltv_pct
+    {
+        return None;
+    }
+    Some(match lowest_ltv_pct {
+        0 => KaminoLendingError::ZeroMaxLtvAssetsInDeposits,
+        _ => KaminoLendingError::MinLtvAssetsPriority,
+    })
+}
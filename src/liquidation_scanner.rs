@@ -0,0 +1,234 @@
+//! Liquidation opportunities across a set of obligations: those liquidatable for being unhealthy
+//! or for auto-deleveraging, each with its most profitable repay/withdraw reserve pair, ranked by
+//! estimated profit.
+//!
+//! Profit is the liquidation bonus on the repaid value, net of the withdraw reserve's
+//! `protocol_liquidation_fee_pct` of that bonus and of the flash loan fee for borrowing the repaid
+//! liquidity. Deleveraging thresholds and bonuses follow the config of the repaid reserve.
+use crate::fraction::Fraction;
+use crate::liquidation::{check_liquidation_collateral, liquidation_bonus};
+use crate::obligation::{BorrowPosition, DepositPosition, ObligationValues};
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use std::cmp::Reverse;
+
+/// `flash_loan_fee_sf` of reserves with flash loans disabled.
+const FLASH_LOANS_DISABLED: u64 = u64::MAX;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiquidationReason {
+    /// LTV at or above the unhealthy LTV.
+    Unhealthy,
+    /// Marked for deleveraging, margin call expired and LTV at or above the deleveraging
+    /// threshold.
+    Deleveraging,
+}
+
+/// The most profitable liquidation of one obligation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidationOpportunity {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub obligation: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub owner: Pubkey,
+    pub reason: LiquidationReason,
+    pub loan_to_value: Fraction,
+    /// Unhealthy LTV or deleveraging threshold the obligation is at or above.
+    pub liquidation_threshold: Fraction,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub repay_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub withdraw_reserve: Pubkey,
+    /// `liquidity_amount` to liquidate with, in the repaid reserve's base units.
+    pub repay_amount: u64,
+    pub repay_value: Fraction,
+    /// Liquidity of the withdraw reserve received, protocol fee deducted.
+    pub withdraw_liquidity_amount: Fraction,
+    pub withdraw_value: Fraction,
+    pub liquidation_bonus: Fraction,
+    pub protocol_fee_value: Fraction,
+    /// Zero when the repaid reserve has flash loans disabled.
+    pub flash_loan_fee_value: Fraction,
+    /// Bonus value net of fees, zero if the fees exceed it.
+    pub profit_value: Fraction,
+}
+
+struct Candidate<'a> {
+    reason: LiquidationReason,
+    liquidation_threshold: Fraction,
+    bonus: Fraction,
+    borrow: &'a BorrowPosition,
+    deposit: &'a DepositPosition,
+}
+
+/// Why and at which threshold an obligation with `values` can be liquidated by repaying `debt`,
+/// `None` if it cannot.
+fn liquidation_reason(
+    lending_market: &LendingMarket,
+    obligation: &Obligation,
+    values: &ObligationValues,
+    debt: &Reserve,
+    now: i64,
+) -> Option<(LiquidationReason, Fraction, Option<Fraction>)> {
+    if values.is_liquidatable() {
+        return Some((
+            LiquidationReason::Unhealthy,
+            values.unhealthy_loan_to_value(),
+            None,
+        ));
+    }
+    let state = obligation.deleveraging_state(lending_market, debt, now)?;
+    state.is_liquidatable(values.loan_to_value(), now).then(|| {
+        (
+            LiquidationReason::Deleveraging,
+            state.liquidation_threshold,
+            Some(Fraction::from_bps(state.liquidation_bonus_bps)),
+        )
+    })
+}
+
+fn opportunity(
+    lending_market: &LendingMarket,
+    address: &Pubkey,
+    obligation: &Obligation,
+    values: &ObligationValues,
+    reserves: &impl ReserveLookup,
+    candidate: Candidate,
+) -> Option<LiquidationOpportunity> {
+    let debt = reserves.reserve(&candidate.borrow.reserve)?;
+    let collateral = reserves.reserve(&candidate.deposit.reserve)?;
+    let repay_value = lending_market
+        .max_liquidatable_debt_value(values)
+        .min(candidate.borrow.market_value)
+        .min(
+            candidate
+                .deposit
+                .market_value
+                .checked_div(Fraction::ONE + candidate.bonus)
+                .unwrap_or(Fraction::ZERO),
+        );
+    let repay_amount = candidate
+        .borrow
+        .borrowed_amount
+        .checked_mul(repay_value)?
+        .checked_div(candidate.borrow.market_value)?
+        .to_ceil()?;
+    let bonus_value = repay_value.checked_mul(candidate.bonus)?;
+    let protocol_fee_value = bonus_value.checked_mul(Fraction::from_percent(
+        collateral.config.protocol_liquidation_fee_pct,
+    ))?;
+    let withdraw_value = (repay_value + bonus_value).saturating_sub(protocol_fee_value);
+    let withdraw_liquidity_amount = collateral.liquidity_amount_for_value(withdraw_value);
+    if repay_amount == 0 || collateral.liquidity_to_collateral(withdraw_liquidity_amount) == 0 {
+        return None;
+    }
+    let flash_loan_fee_value = match debt.config.fees.flash_loan_fee_sf {
+        FLASH_LOANS_DISABLED => Fraction::ZERO,
+        _ => repay_value.checked_mul(debt.config.fees.flash_loan_fee_rate())?,
+    };
+    Some(LiquidationOpportunity {
+        obligation: *address,
+        owner: obligation.owner,
+        reason: candidate.reason,
+        loan_to_value: values.loan_to_value(),
+        liquidation_threshold: candidate.liquidation_threshold,
+        repay_reserve: candidate.borrow.reserve,
+        withdraw_reserve: candidate.deposit.reserve,
+        repay_amount,
+        repay_value,
+        withdraw_liquidity_amount,
+        withdraw_value,
+        liquidation_bonus: candidate.bonus,
+        protocol_fee_value,
+        flash_loan_fee_value,
+        profit_value: bonus_value
+            .saturating_sub(protocol_fee_value)
+            .saturating_sub(flash_loan_fee_value),
+    })
+}
+
+impl Obligation {
+    /// Most profitable liquidation of the obligation at unix timestamp `now`, over every borrow
+    /// and every deposit [`check_liquidation_collateral`] allows; `None` if it cannot be
+    /// liquidated or a reserve is missing from `reserves`.
+    pub fn liquidation_opportunity(
+        &self,
+        address: &Pubkey,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+        now: i64,
+    ) -> Option<LiquidationOpportunity> {
+        let values = self.compute_values(lending_market, reserves).ok()?;
+        let mut best: Option<LiquidationOpportunity> = None;
+        for borrow in &values.borrows {
+            let debt = reserves.reserve(&borrow.reserve)?;
+            let Some((reason, liquidation_threshold, deleveraging_bonus)) =
+                liquidation_reason(lending_market, self, &values, debt, now)
+            else {
+                continue;
+            };
+            for deposit in &values.deposits {
+                if check_liquidation_collateral(lending_market, &values, &deposit.reserve).is_err()
+                {
+                    continue;
+                }
+                let bonus = match deleveraging_bonus {
+                    Some(bonus) => bonus,
+                    None => liquidation_bonus(
+                        lending_market,
+                        &values,
+                        reserves.reserve(&deposit.reserve)?,
+                        debt,
+                    ),
+                };
+                let candidate = Candidate {
+                    reason,
+                    liquidation_threshold,
+                    bonus,
+                    borrow,
+                    deposit,
+                };
+                let Some(found) =
+                    opportunity(lending_market, address, self, &values, reserves, candidate)
+                else {
+                    continue;
+                };
+                if best
+                    .as_ref()
+                    .is_none_or(|best| found.profit_value > best.profit_value)
+                {
+                    best = Some(found);
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Liquidation opportunities among `obligations` at unix timestamp `now`, most profitable first.
+/// Obligations whose reserves are missing from `reserves` are skipped.
+pub fn scan_liquidations<'a>(
+    lending_market: &LendingMarket,
+    obligations: impl IntoIterator<Item = (&'a Pubkey, &'a Obligation)>,
+    reserves: &impl ReserveLookup,
+    now: i64,
+) -> Vec<LiquidationOpportunity> {
+    let mut opportunities: Vec<_> = obligations
+        .into_iter()
+        .filter_map(|(address, obligation)| {
+            obligation.liquidation_opportunity(address, lending_market, reserves, now)
+        })
+        .collect();
+    opportunities.sort_by_key(|opportunity| Reverse(opportunity.profit_value));
+    opportunities
+}
+
+impl MarketSnapshot {
+    /// [`scan_liquidations`] over the snapshot's obligations.
+    pub fn liquidation_opportunities(&self, now: i64) -> Vec<LiquidationOpportunity> {
+        scan_liquidations(&self.lending_market, self.obligations(), self, now)
+    }
+}
@@ -0,0 +1,73 @@
+//! Wide integer helpers shared by the off-chain calculators.
+//!
+//! On-chain values such as `*_scaled` stakes and `*_sf` fractions are stored in `u128`, but their
+//! products routinely exceed 128 bits before being scaled back down, so intermediate results are
+//! carried in a minimal 256-bit representation.
+
+/// 10^18, the scale of the farms program `*_scaled` decimals.
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+
+/// Computes `a * b / denominator` rounded down, returning `None` on division by zero or if the
+/// result does not fit in a `u128`.
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (hi, lo) = mul_wide(a, b);
+    div_wide(hi, lo, denominator).map(|(quotient, _)| quotient)
+}
+
+/// Computes `a * b / denominator` rounded up, returning `None` on division by zero or if the
+/// result does not fit in a `u128`.
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (hi, lo) = mul_wide(a, b);
+    let (quotient, remainder) = div_wide(hi, lo, denominator)?;
+    if remainder == 0 {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+/// Full 256-bit product of two `u128`s as `(high, low)` halves.
+pub(crate) fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (cross << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    (high, low)
+}
+
+/// Divides the 256-bit value `(high, low)` by `denominator`, returning `(quotient, remainder)` or
+/// `None` if the quotient does not fit in a `u128`.
+pub(crate) fn div_wide(high: u128, low: u128, denominator: u128) -> Option<(u128, u128)> {
+    if high == 0 {
+        return Some((low / denominator, low % denominator));
+    }
+    if high >= denominator {
+        return None;
+    }
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
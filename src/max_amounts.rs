@@ -0,0 +1,449 @@
+//! Largest amounts that would pass the program's checks for the main user instructions, with the
+//! check that binds.
+//!
+//! Results reflect the reserves as given: refresh prices and interest (or use freshly fetched
+//! accounts) for amounts that match what the program will accept in the next slot.
+use crate::fraction::Fraction;
+use crate::obligation::ObligationValues;
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+/// The check limiting a [`MaxAmount`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AmountConstraint {
+    /// The token balance supplied by the caller.
+    WalletBalance,
+    /// The obligation's deposited collateral or outstanding debt in the reserve.
+    Position,
+    /// `ReserveLiquidity::available_amount`.
+    AvailableLiquidity,
+    /// `ReserveConfig::deposit_withdrawal_cap` or `debt_withdrawal_cap`.
+    WithdrawalCap,
+    /// `ReserveConfig::deposit_limit`.
+    DepositLimit,
+    /// `ReserveConfig::borrow_limit`.
+    BorrowLimit,
+    /// `ReserveConfig::utilization_limit_block_borrowing_above_pct`.
+    UtilizationLimit,
+    /// `ReserveConfig::borrow_limit_against_this_collateral_in_elevation_group` of a collateral.
+    ElevationGroupBorrowLimit,
+    /// `ReserveConfig::borrow_limit_outside_elevation_group`.
+    BorrowLimitOutsideElevationGroup,
+    /// The obligation's allowed borrow value, weighted by LTV and borrow factor.
+    ObligationLtv,
+    /// `LendingMarket::min_net_value_in_obligation_sf`.
+    MinNetValue,
+    /// The instruction would fail whatever the amount.
+    Blocked(KaminoLendingError),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxAmount {
+    pub amount: u64,
+    pub constraint: AmountConstraint,
+}
+
+impl MaxAmount {
+    pub fn blocked(err: KaminoLendingError) -> Self {
+        Self {
+            amount: 0,
+            constraint: AmountConstraint::Blocked(err),
+        }
+    }
+
+    pub fn is_blocked(&self) -> bool {
+        matches!(self.constraint, AmountConstraint::Blocked(_))
+    }
+
+    /// The tightest of `bounds`; ties go to the first bound listed.
+    fn tightest(bounds: impl IntoIterator<Item = (u64, AmountConstraint)>) -> Self {
+        bounds
+            .into_iter()
+            .map(|(amount, constraint)| Self { amount, constraint })
+            .reduce(|tightest, bound| {
+                if bound.amount < tightest.amount {
+                    bound
+                } else {
+                    tightest
+                }
+            })
+            .unwrap_or(Self {
+                amount: u64::MAX,
+                constraint: AmountConstraint::WalletBalance,
+            })
+    }
+}
+
+fn floor_u64(value: Fraction) -> u64 {
+    value.to_floor().unwrap_or(u64::MAX)
+}
+
+fn lookup<'a>(
+    reserves: &'a impl ReserveLookup,
+    address: &Pubkey,
+) -> Result<&'a Reserve, KaminoLendingError> {
+    reserves
+        .reserve(address)
+        .ok_or(KaminoLendingError::InvalidAccountInput)
+}
+
+/// Largest liquidity amount `deposit_reserve_liquidity` accepts from a wallet holding
+/// `wallet_balance`.
+pub fn max_deposit_amount(
+    lending_market: &LendingMarket,
+    reserve: &Reserve,
+    wallet_balance: u64,
+) -> MaxAmount {
+    if lending_market.is_emergency_mode() {
+        return MaxAmount::blocked(KaminoLendingError::GlobalEmergencyMode);
+    }
+    if reserve.status() == Some(ReserveStatus::Obsolete) {
+        return MaxAmount::blocked(KaminoLendingError::ReserveObsolete);
+    }
+    let deposit_room =
+        Fraction::from_num(reserve.config.deposit_limit).saturating_sub(reserve.total_supply());
+    MaxAmount::tightest([
+        (wallet_balance, AmountConstraint::WalletBalance),
+        (floor_u64(deposit_room), AmountConstraint::DepositLimit),
+    ])
+}
+
+/// Largest liquidity amount `repay_obligation_liquidity` would take for the debt in
+/// `repay_reserve`, from a wallet holding `wallet_balance`.
+pub fn max_repay_amount(
+    obligation: &Obligation,
+    reserves: &impl ReserveLookup,
+    repay_reserve: &Pubkey,
+    wallet_balance: u64,
+) -> Result<MaxAmount, KaminoLendingError> {
+    let reserve = lookup(reserves, repay_reserve)?;
+    let debt = obligation.find_borrow(repay_reserve).map_or(0, |borrow| {
+        borrow
+            .accrued_borrowed_amount(reserve)
+            .to_ceil()
+            .unwrap_or(u64::MAX)
+    });
+    Ok(MaxAmount::tightest([
+        (debt, AmountConstraint::Position),
+        (wallet_balance, AmountConstraint::WalletBalance),
+    ]))
+}
+
+pub(crate) fn borrow_blocker(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserve: &Reserve,
+    borrow_reserve: &Pubkey,
+) -> Option<KaminoLendingError> {
+    if lending_market.is_emergency_mode() {
+        return Some(KaminoLendingError::GlobalEmergencyMode);
+    }
+    if lending_market.is_borrowing_disabled() || obligation.borrowing_disabled != 0 {
+        return Some(KaminoLendingError::BorrowingDisabled);
+    }
+    if reserve.lending_market != obligation.lending_market {
+        return Some(KaminoLendingError::InvalidAccountInput);
+    }
+    if reserve.status() == Some(ReserveStatus::Obsolete) {
+        return Some(KaminoLendingError::ReserveObsolete);
+    }
+    if obligation.autodeleverage_margin_call_started_timestamp != 0 {
+        return Some(KaminoLendingError::ObligationCurrentlyMarkedForDeleveraging);
+    }
+    let existing = obligation.find_borrow(borrow_reserve).is_some();
+    if !existing && obligation.active_borrows().count() >= obligation.borrows.len() {
+        return Some(KaminoLendingError::ObligationReserveLimit);
+    }
+    let isolated_debt = AssetTier::IsolatedDebt as u8;
+    let other_borrows = obligation
+        .active_borrows()
+        .filter(|(_, borrow)| borrow.borrow_reserve != *borrow_reserve);
+    for (index, _) in other_borrows {
+        if reserve.config.asset_tier == isolated_debt
+            || obligation.borrows_asset_tiers.get(index) == Some(&isolated_debt)
+        {
+            return Some(KaminoLendingError::IsolatedAssetTierViolation);
+        }
+    }
+    if let Some(group) = lending_market.elevation_group(obligation.elevation_group) {
+        if group.allow_new_loans == 0 {
+            return Some(KaminoLendingError::ElevationGroupNewLoansDisabled);
+        }
+        if !reserve.is_in_elevation_group(group.id) {
+            return Some(KaminoLendingError::InconsistentElevationGroup);
+        }
+        if group.debt_reserve != *borrow_reserve {
+            return Some(KaminoLendingError::ElevationGroupHasAnotherDebtReserve);
+        }
+    }
+    None
+}
+
+/// Bounds on the obligation's debt increase (borrowed amount plus origination fee) when
+/// borrowing from `reserve`.
+pub(crate) fn borrow_debt_bounds(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &(impl ReserveLookup + ?Sized),
+    values: &ObligationValues,
+    reserve: &Reserve,
+    now: i64,
+) -> Result<Vec<(u64, AmountConstraint)>, KaminoLendingError> {
+    let liquidity = &reserve.liquidity;
+    let config = &reserve.config;
+    let borrowed = liquidity.borrowed_amount();
+    let mut bounds = vec![
+        (
+            liquidity.available_amount,
+            AmountConstraint::AvailableLiquidity,
+        ),
+        (
+            config.debt_withdrawal_cap.remaining_capacity(now),
+            AmountConstraint::WithdrawalCap,
+        ),
+        (
+            floor_u64(Fraction::from_num(config.borrow_limit).saturating_sub(borrowed)),
+            AmountConstraint::BorrowLimit,
+        ),
+    ];
+    if config.utilization_limit_block_borrowing_above_pct != 0 {
+        let max_borrowed = reserve
+            .total_supply()
+            .checked_mul(Fraction::from_percent(
+                config.utilization_limit_block_borrowing_above_pct,
+            ))
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        bounds.push((
+            floor_u64(max_borrowed.saturating_sub(borrowed)),
+            AmountConstraint::UtilizationLimit,
+        ));
+    }
+    match lending_market.elevation_group(obligation.elevation_group) {
+        Some(group) => {
+            let group_index = usize::from(group.id - 1);
+            for (_, deposit) in obligation.active_deposits() {
+                let collateral_reserve = reserves
+                    .reserve(&deposit.deposit_reserve)
+                    .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+                let limit = collateral_reserve
+                    .config
+                    .borrow_limit_against_this_collateral_in_elevation_group
+                    .get(group_index)
+                    .copied()
+                    .unwrap_or(0);
+                let used = collateral_reserve
+                    .borrowed_amounts_against_this_reserve_in_elevation_groups
+                    .get(group_index)
+                    .copied()
+                    .unwrap_or(0);
+                bounds.push((
+                    limit.saturating_sub(used),
+                    AmountConstraint::ElevationGroupBorrowLimit,
+                ));
+            }
+        }
+        None => bounds.push((
+            config
+                .borrow_limit_outside_elevation_group
+                .saturating_sub(reserve.borrowed_amount_outside_elevation_group),
+            AmountConstraint::BorrowLimitOutsideElevationGroup,
+        )),
+    }
+    let borrow_factor = reserve.borrow_factor(obligation.is_in_elevation_group());
+    let ltv_room = reserve
+        .liquidity_amount_for_value(values.remaining_borrow_value())
+        .checked_div(borrow_factor)
+        .unwrap_or(Fraction::ZERO);
+    bounds.push((floor_u64(ltv_room), AmountConstraint::ObligationLtv));
+    let min_net_value = lending_market.min_net_value_in_obligation();
+    if !min_net_value.is_zero() {
+        let net_value_room = values.net_value().saturating_sub(min_net_value);
+        bounds.push((
+            floor_u64(reserve.liquidity_amount_for_value(net_value_room)),
+            AmountConstraint::MinNetValue,
+        ));
+    }
+    Ok(bounds)
+}
+
+/// Largest `liquidity_amount` that `borrow_obligation_liquidity` accepts from `borrow_reserve` at
+/// unix timestamp `now`. The obligation's debt grows by this amount plus the origination fee,
+/// which all debt-side limits account for.
+///
+/// Fails when the borrow reserve or one of the obligation's reserves is missing from `reserves`.
+pub fn max_borrow_amount(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &impl ReserveLookup,
+    borrow_reserve: &Pubkey,
+    now: i64,
+) -> Result<MaxAmount, KaminoLendingError> {
+    let reserve = lookup(reserves, borrow_reserve)?;
+    if let Some(err) = borrow_blocker(obligation, lending_market, reserve, borrow_reserve) {
+        return Ok(MaxAmount::blocked(err));
+    }
+    let values = obligation.compute_values(lending_market, reserves)?;
+    let bounds = borrow_debt_bounds(obligation, lending_market, reserves, &values, reserve, now)?;
+    let max_debt = MaxAmount::tightest(bounds);
+    let with_referrer =
+        obligation.referrer != Pubkey::default() && lending_market.referral_fee_bps > 0;
+    Ok(MaxAmount {
+        amount: max_amount_before_fee(reserve, max_debt.amount, with_referrer),
+        constraint: max_debt.constraint,
+    })
+}
+
+/// Largest amount whose exclusive borrow fee keeps `amount + fee` within `max_debt`.
+fn max_amount_before_fee(reserve: &Reserve, max_debt: u64, with_referrer: bool) -> u64 {
+    let rate = reserve.config.fees.borrow_fee_rate();
+    if rate.is_zero() {
+        return max_debt;
+    }
+    let fits = |amount: u64| {
+        reserve
+            .borrow_fee(amount, FeeCalculation::Exclusive, with_referrer)
+            .ok()
+            .and_then(|fee| amount.checked_add(fee))
+            .is_some_and(|debt| debt <= max_debt)
+    };
+    let mut amount = Fraction::from_num(max_debt)
+        .checked_div(Fraction::ONE + rate)
+        .map_or(0, floor_u64);
+    while amount > 0 && !fits(amount) {
+        amount -= 1;
+    }
+    // The fee is rounded to the nearest unit, so the estimate can also fall short by one.
+    while amount < max_debt && fits(amount + 1) {
+        amount += 1;
+    }
+    amount
+}
+
+fn withdraw_blocker(lending_market: &LendingMarket) -> Option<KaminoLendingError> {
+    lending_market
+        .is_emergency_mode()
+        .then_some(KaminoLendingError::GlobalEmergencyMode)
+}
+
+/// cTokens `withdraw_obligation_collateral` releases for a `collateral_amount` of `u64::MAX` from
+/// a deposit of `deposited_amount` worth `deposit_value` while the obligation has debt: the share
+/// of the deposit worth at most `max_withdraw_value`, rounded down.
+pub(crate) fn withdraw_all_amount(
+    deposited_amount: u64,
+    deposit_value: Fraction,
+    max_withdraw_value: Fraction,
+) -> u64 {
+    if deposit_value <= max_withdraw_value {
+        return deposited_amount;
+    }
+    // Multiplying first keeps round values exact; dividing first only when that overflows.
+    Fraction::from_num(deposited_amount)
+        .checked_mul(max_withdraw_value)
+        .and_then(|value| value.checked_div(deposit_value))
+        .or_else(|| {
+            max_withdraw_value
+                .checked_div(deposit_value)?
+                .checked_mul_int(deposited_amount)
+        })
+        .map_or(deposited_amount, |amount| {
+            floor_u64(amount).min(deposited_amount)
+        })
+}
+
+/// Bounds on the cTokens withdrawn from the obligation's deposit in `withdraw_reserve`, whose
+/// account is `reserve`.
+fn withdraw_bounds(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &impl ReserveLookup,
+    withdraw_reserve: &Pubkey,
+    reserve: &Reserve,
+) -> Result<Vec<(u64, AmountConstraint)>, KaminoLendingError> {
+    let Some(deposit) = obligation.find_deposit(withdraw_reserve) else {
+        return Ok(vec![(0, AmountConstraint::Position)]);
+    };
+    let mut bounds = vec![(deposit.deposited_amount, AmountConstraint::Position)];
+    let values = obligation.compute_values(lending_market, reserves)?;
+    if !values.has_debt() {
+        return Ok(bounds);
+    }
+    let position = values
+        .deposit(withdraw_reserve)
+        .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+    let max_withdraw_value = values.max_withdraw_value(position.max_ltv_pct);
+    let ltv_room =
+        reserve.liquidity_to_collateral(reserve.liquidity_amount_for_value(max_withdraw_value));
+    bounds.push((ltv_room, AmountConstraint::ObligationLtv));
+    let min_net_value = lending_market.min_net_value_in_obligation();
+    if !min_net_value.is_zero() {
+        let net_value_room = values.net_value().saturating_sub(min_net_value);
+        bounds.push((
+            reserve.liquidity_to_collateral(reserve.liquidity_amount_for_value(net_value_room)),
+            AmountConstraint::MinNetValue,
+        ));
+    }
+    Ok(bounds)
+}
+
+/// Largest `collateral_amount` that `withdraw_obligation_collateral` accepts for the deposit in
+/// `withdraw_reserve`, in reserve collateral (cToken) units.
+pub fn max_withdraw_collateral_amount(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &impl ReserveLookup,
+    withdraw_reserve: &Pubkey,
+) -> Result<MaxAmount, KaminoLendingError> {
+    let reserve = lookup(reserves, withdraw_reserve)?;
+    if let Some(err) = withdraw_blocker(lending_market) {
+        return Ok(MaxAmount::blocked(err));
+    }
+    let bounds = withdraw_bounds(
+        obligation,
+        lending_market,
+        reserves,
+        withdraw_reserve,
+        reserve,
+    )?;
+    Ok(MaxAmount::tightest(bounds))
+}
+
+/// Largest `collateral_amount` that `withdraw_obligation_collateral_and_redeem_reserve_collateral`
+/// accepts at unix timestamp `now`: the collateral withdrawal must also be redeemable against the
+/// reserve's available liquidity and deposit withdrawal cap.
+pub fn max_withdraw_and_redeem_collateral_amount(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &impl ReserveLookup,
+    withdraw_reserve: &Pubkey,
+    now: i64,
+) -> Result<MaxAmount, KaminoLendingError> {
+    let reserve = lookup(reserves, withdraw_reserve)?;
+    if let Some(err) = withdraw_blocker(lending_market) {
+        return Ok(MaxAmount::blocked(err));
+    }
+    let mut bounds = withdraw_bounds(
+        obligation,
+        lending_market,
+        reserves,
+        withdraw_reserve,
+        reserve,
+    )?;
+    let available = reserve.liquidity.available_amount;
+    let cap = reserve
+        .config
+        .deposit_withdrawal_cap
+        .remaining_capacity(now);
+    let (liquidity, constraint) = if cap < available {
+        (cap, AmountConstraint::WithdrawalCap)
+    } else {
+        (available, AmountConstraint::AvailableLiquidity)
+    };
+    bounds.push((
+        reserve.liquidity_to_collateral(Fraction::from_num(liquidity)),
+        constraint,
+    ));
+    Ok(MaxAmount::tightest(bounds))
+}
@@ -0,0 +1,1457 @@
+//! Static instruction metadata, generated from `kamino_lending.json`: names, accounts with their
+//! writable, signer and optional flags, and argument names and types.
+//!
+//! Account and argument names are the snake_case field names of the generated `*Keys` and
+//! `*IxArgs` structs, nested account groups being flattened with their group name as prefix
+//! (`borrow_accounts_owner`). Accounts past the end of [`InstructionMeta::accounts`] are remaining
+//! accounts, such as the reserves passed to `refresh_obligation`.
+use crate::*;
+use solana_program::instruction::AccountMeta;
+use std::borrow::Cow;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionMeta {
+    /// Name of the instruction, as in [`KaminoLendingProgramIx`].
+    pub name: &'static str,
+    pub discm: [u8; 8],
+    pub accounts: &'static [InstructionAccountMeta],
+    pub args: &'static [InstructionArgMeta],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionAccountMeta {
+    pub name: &'static str,
+    pub is_writable: bool,
+    pub is_signer: bool,
+    /// Optional accounts left out are passed as the lending program id.
+    pub is_optional: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionArgMeta {
+    pub name: &'static str,
+    /// Rust type of the argument, e.g. `u64` or `InitObligationArgs`.
+    pub ty: &'static str,
+}
+
+impl InstructionMeta {
+    /// Metadata of the account at `index`, `None` for remaining accounts.
+    pub fn account(&self, index: usize) -> Option<&'static InstructionAccountMeta> {
+        self.accounts.get(index)
+    }
+
+    /// Pairs each account of an instruction with its name, remaining accounts being named
+    /// `remaining_account_<n>`.
+    pub fn label_accounts<'a>(
+        &self,
+        accounts: &'a [AccountMeta],
+    ) -> impl Iterator<Item = (Cow<'static, str>, &'a AccountMeta)> {
+        let names = self.accounts;
+        accounts.iter().enumerate().map(move |(i, meta)| {
+            let name = match names.get(i) {
+                Some(account) => Cow::Borrowed(account.name),
+                None => Cow::Owned(format!("remaining_account_{}", i - names.len())),
+            };
+            (name, meta)
+        })
+    }
+}
+
+/// Metadata of `discm`'s instruction.
+pub fn instruction_meta_by_discm(discm: [u8; 8]) -> Option<&'static InstructionMeta> {
+    INSTRUCTION_METAS.iter().find(|meta| meta.discm == discm)
+}
+
+pub fn instruction_meta_by_name(name: &str) -> Option<&'static InstructionMeta> {
+    INSTRUCTION_METAS.iter().find(|meta| meta.name == name)
+}
+
+impl KaminoLendingProgramIx {
+    pub fn metadata(&self) -> &'static InstructionMeta {
+        match self {
+            Self::InitLendingMarket(_) => &INSTRUCTION_METAS[0],
+            Self::UpdateLendingMarket(_) => &INSTRUCTION_METAS[1],
+            Self::UpdateLendingMarketOwner => &INSTRUCTION_METAS[2],
+            Self::InitReserve => &INSTRUCTION_METAS[3],
+            Self::InitFarmsForReserve(_) => &INSTRUCTION_METAS[4],
+            Self::UpdateReserveConfig(_) => &INSTRUCTION_METAS[5],
+            Self::RedeemFees => &INSTRUCTION_METAS[6],
+            Self::WithdrawProtocolFee(_) => &INSTRUCTION_METAS[7],
+            Self::SocializeLoss(_) => &INSTRUCTION_METAS[8],
+            Self::SocializeLossV2(_) => &INSTRUCTION_METAS[9],
+            Self::MarkObligationForDeleveraging(_) => &INSTRUCTION_METAS[10],
+            Self::RefreshReserve => &INSTRUCTION_METAS[11],
+            Self::RefreshReservesBatch(_) => &INSTRUCTION_METAS[12],
+            Self::DepositReserveLiquidity(_) => &INSTRUCTION_METAS[13],
+            Self::RedeemReserveCollateral(_) => &INSTRUCTION_METAS[14],
+            Self::InitObligation(_) => &INSTRUCTION_METAS[15],
+            Self::InitObligationFarmsForReserve(_) => &INSTRUCTION_METAS[16],
+            Self::RefreshObligationFarmsForReserve(_) => &INSTRUCTION_METAS[17],
+            Self::RefreshObligation => &INSTRUCTION_METAS[18],
+            Self::DepositObligationCollateral(_) => &INSTRUCTION_METAS[19],
+            Self::DepositObligationCollateralV2(_) => &INSTRUCTION_METAS[20],
+            Self::WithdrawObligationCollateral(_) => &INSTRUCTION_METAS[21],
+            Self::WithdrawObligationCollateralV2(_) => &INSTRUCTION_METAS[22],
+            Self::BorrowObligationLiquidity(_) => &INSTRUCTION_METAS[23],
+            Self::BorrowObligationLiquidityV2(_) => &INSTRUCTION_METAS[24],
+            Self::RepayObligationLiquidity(_) => &INSTRUCTION_METAS[25],
+            Self::RepayObligationLiquidityV2(_) => &INSTRUCTION_METAS[26],
+            Self::RepayAndWithdrawAndRedeem(_) => &INSTRUCTION_METAS[27],
+            Self::DepositAndWithdraw(_) => &INSTRUCTION_METAS[28],
+            Self::DepositReserveLiquidityAndObligationCollateral(_) => &INSTRUCTION_METAS[29],
+            Self::DepositReserveLiquidityAndObligationCollateralV2(_) => &INSTRUCTION_METAS[30],
+            Self::WithdrawObligationCollateralAndRedeemReserveCollateral(_) => {
+                &INSTRUCTION_METAS[31]
+            }
+            Self::WithdrawObligationCollateralAndRedeemReserveCollateralV2(_) => {
+                &INSTRUCTION_METAS[32]
+            }
+            Self::LiquidateObligationAndRedeemReserveCollateral(_) => &INSTRUCTION_METAS[33],
+            Self::LiquidateObligationAndRedeemReserveCollateralV2(_) => &INSTRUCTION_METAS[34],
+            Self::FlashRepayReserveLiquidity(_) => &INSTRUCTION_METAS[35],
+            Self::FlashBorrowReserveLiquidity(_) => &INSTRUCTION_METAS[36],
+            Self::RequestElevationGroup(_) => &INSTRUCTION_METAS[37],
+            Self::InitReferrerTokenState => &INSTRUCTION_METAS[38],
+            Self::InitUserMetadata(_) => &INSTRUCTION_METAS[39],
+            Self::WithdrawReferrerFees => &INSTRUCTION_METAS[40],
+            Self::InitReferrerStateAndShortUrl(_) => &INSTRUCTION_METAS[41],
+            Self::DeleteReferrerStateAndShortUrl => &INSTRUCTION_METAS[42],
+            Self::IdlMissingTypes(_) => &INSTRUCTION_METAS[43],
+        }
+    }
+}
+
+const fn account(
+    name: &'static str,
+    is_writable: bool,
+    is_signer: bool,
+    is_optional: bool,
+) -> InstructionAccountMeta {
+    InstructionAccountMeta {
+        name,
+        is_writable,
+        is_signer,
+        is_optional,
+    }
+}
+
+const fn arg(name: &'static str, ty: &'static str) -> InstructionArgMeta {
+    InstructionArgMeta { name, ty }
+}
+
+/// Every instruction of the IDL, in IDL order. `RefreshReservesBatch` takes its reserves and
+/// their lending markets as remaining accounts only.
+pub const INSTRUCTION_METAS: &[InstructionMeta] = &[
+    InstructionMeta {
+        name: "InitLendingMarket",
+        discm: [34, 162, 116, 14, 101, 137, 94, 239],
+        accounts: &[
+            account("lending_market_owner", true, true, false),
+            account("lending_market", true, false, false),
+            account("lending_market_authority", false, false, false),
+            account("system_program", false, false, false),
+            account("rent", false, false, false),
+        ],
+        args: &[arg("quote_currency", "[u8; 32]")],
+    },
+    InstructionMeta {
+        name: "UpdateLendingMarket",
+        discm: [209, 157, 53, 210, 97, 180, 31, 45],
+        accounts: &[
+            account("lending_market_owner", false, true, false),
+            account("lending_market", true, false, false),
+        ],
+        args: &[arg("mode", "u64"), arg("value", "[u8; 72]")],
+    },
+    InstructionMeta {
+        name: "UpdateLendingMarketOwner",
+        discm: [118, 224, 10, 62, 196, 230, 184, 89],
+        accounts: &[
+            account("lending_market_owner_cached", false, true, false),
+            account("lending_market", true, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "InitReserve",
+        discm: [138, 245, 71, 225, 153, 4, 3, 43],
+        accounts: &[
+            account("lending_market_owner", true, true, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_liquidity_supply", true, false, false),
+            account("fee_receiver", true, false, false),
+            account("reserve_collateral_mint", true, false, false),
+            account("reserve_collateral_supply", true, false, false),
+            account("rent", false, false, false),
+            account("liquidity_token_program", false, false, false),
+            account("collateral_token_program", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "InitFarmsForReserve",
+        discm: [218, 6, 62, 233, 1, 33, 232, 82],
+        accounts: &[
+            account("lending_market_owner", true, true, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve", true, false, false),
+            account("farms_program", false, false, false),
+            account("farms_global_config", false, false, false),
+            account("farm_state", true, false, false),
+            account("farms_vault_authority", false, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("mode", "u8")],
+    },
+    InstructionMeta {
+        name: "UpdateReserveConfig",
+        discm: [61, 148, 100, 70, 143, 107, 17, 13],
+        accounts: &[
+            account("lending_market_owner", false, true, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+        ],
+        args: &[
+            arg("mode", "u64"),
+            arg("value", "Vec<u8>"),
+            arg("skip_validation", "bool"),
+        ],
+    },
+    InstructionMeta {
+        name: "RedeemFees",
+        discm: [215, 39, 180, 41, 173, 46, 248, 220],
+        accounts: &[
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_liquidity_fee_receiver", true, false, false),
+            account("reserve_supply_liquidity", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("token_program", false, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "WithdrawProtocolFee",
+        discm: [158, 201, 158, 189, 33, 93, 162, 103],
+        accounts: &[
+            account("lending_market_owner", false, true, false),
+            account("lending_market", false, false, false),
+            account("reserve", false, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("fee_vault", true, false, false),
+            account("lending_market_owner_ata", true, false, false),
+            account("token_program", false, false, false),
+        ],
+        args: &[arg("amount", "u64")],
+    },
+    InstructionMeta {
+        name: "SocializeLoss",
+        discm: [245, 75, 91, 0, 236, 97, 19, 3],
+        accounts: &[
+            account("risk_council", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "SocializeLossV2",
+        discm: [238, 95, 98, 220, 187, 40, 204, 154],
+        accounts: &[
+            account("socialize_loss_accounts_risk_council", false, true, false),
+            account("socialize_loss_accounts_obligation", true, false, false),
+            account(
+                "socialize_loss_accounts_lending_market",
+                false,
+                false,
+                false,
+            ),
+            account("socialize_loss_accounts_reserve", true, false, false),
+            account(
+                "socialize_loss_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("lending_market_authority", false, false, false),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "MarkObligationForDeleveraging",
+        discm: [164, 35, 182, 19, 0, 116, 243, 127],
+        accounts: &[
+            account("risk_council", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+        ],
+        args: &[arg("autodeleverage_target_ltv_pct", "u8")],
+    },
+    InstructionMeta {
+        name: "RefreshReserve",
+        discm: [2, 218, 138, 235, 79, 201, 25, 102],
+        accounts: &[
+            account("reserve", true, false, false),
+            account("lending_market", false, false, false),
+            account("pyth_oracle", false, false, true),
+            account("switchboard_price_oracle", false, false, true),
+            account("switchboard_twap_oracle", false, false, true),
+            account("scope_prices", false, false, true),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "RefreshReservesBatch",
+        discm: [144, 110, 26, 103, 162, 204, 252, 147],
+        accounts: &[],
+        args: &[arg("skip_price_updates", "bool")],
+    },
+    InstructionMeta {
+        name: "DepositReserveLiquidity",
+        discm: [169, 201, 30, 126, 6, 205, 102, 68],
+        accounts: &[
+            account("owner", false, true, false),
+            account("reserve", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_liquidity_supply", true, false, false),
+            account("reserve_collateral_mint", true, false, false),
+            account("user_source_liquidity", true, false, false),
+            account("user_destination_collateral", true, false, false),
+            account("collateral_token_program", false, false, false),
+            account("liquidity_token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "RedeemReserveCollateral",
+        discm: [234, 117, 181, 125, 185, 142, 220, 29],
+        accounts: &[
+            account("owner", false, true, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_collateral_mint", true, false, false),
+            account("reserve_liquidity_supply", true, false, false),
+            account("user_source_collateral", true, false, false),
+            account("user_destination_liquidity", true, false, false),
+            account("collateral_token_program", false, false, false),
+            account("liquidity_token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "InitObligation",
+        discm: [251, 10, 231, 76, 27, 11, 159, 96],
+        accounts: &[
+            account("obligation_owner", false, true, false),
+            account("fee_payer", true, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("seed1_account", false, false, false),
+            account("seed2_account", false, false, false),
+            account("owner_user_metadata", false, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("args", "InitObligationArgs")],
+    },
+    InstructionMeta {
+        name: "InitObligationFarmsForReserve",
+        discm: [136, 63, 15, 186, 211, 152, 168, 164],
+        accounts: &[
+            account("payer", true, true, false),
+            account("owner", false, false, false),
+            account("obligation", true, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve", true, false, false),
+            account("reserve_farm_state", true, false, false),
+            account("obligation_farm", true, false, false),
+            account("lending_market", false, false, false),
+            account("farms_program", false, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("mode", "u8")],
+    },
+    InstructionMeta {
+        name: "RefreshObligationFarmsForReserve",
+        discm: [140, 144, 253, 21, 10, 74, 248, 3],
+        accounts: &[
+            account("crank", false, true, false),
+            account("base_accounts_obligation", false, false, false),
+            account(
+                "base_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("base_accounts_reserve", false, false, false),
+            account("base_accounts_reserve_farm_state", true, false, false),
+            account(
+                "base_accounts_obligation_farm_user_state",
+                true,
+                false,
+                false,
+            ),
+            account("base_accounts_lending_market", false, false, false),
+            account("farms_program", false, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("mode", "u8")],
+    },
+    InstructionMeta {
+        name: "RefreshObligation",
+        discm: [33, 132, 147, 228, 151, 192, 72, 89],
+        accounts: &[
+            account("lending_market", false, false, false),
+            account("obligation", true, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "DepositObligationCollateral",
+        discm: [108, 209, 4, 72, 21, 22, 118, 133],
+        accounts: &[
+            account("owner", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("deposit_reserve", true, false, false),
+            account("reserve_destination_collateral", true, false, false),
+            account("user_source_collateral", true, false, false),
+            account("token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "DepositObligationCollateralV2",
+        discm: [137, 145, 151, 94, 167, 113, 4, 145],
+        accounts: &[
+            account("deposit_accounts_owner", false, true, false),
+            account("deposit_accounts_obligation", true, false, false),
+            account("deposit_accounts_lending_market", false, false, false),
+            account("deposit_accounts_deposit_reserve", true, false, false),
+            account(
+                "deposit_accounts_reserve_destination_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_user_source_collateral",
+                true,
+                false,
+                false,
+            ),
+            account("deposit_accounts_token_program", false, false, false),
+            account(
+                "deposit_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account("lending_market_authority", false, false, false),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "WithdrawObligationCollateral",
+        discm: [37, 116, 205, 103, 243, 192, 92, 198],
+        accounts: &[
+            account("owner", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("withdraw_reserve", true, false, false),
+            account("reserve_source_collateral", true, false, false),
+            account("user_destination_collateral", true, false, false),
+            account("token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "WithdrawObligationCollateralV2",
+        discm: [202, 249, 117, 114, 231, 192, 47, 138],
+        accounts: &[
+            account("withdraw_accounts_owner", false, true, false),
+            account("withdraw_accounts_obligation", true, false, false),
+            account("withdraw_accounts_lending_market", false, false, false),
+            account(
+                "withdraw_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_withdraw_reserve", true, false, false),
+            account(
+                "withdraw_accounts_reserve_source_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_user_destination_collateral",
+                true,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_token_program", false, false, false),
+            account(
+                "withdraw_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "BorrowObligationLiquidity",
+        discm: [121, 127, 18, 204, 73, 245, 225, 65],
+        accounts: &[
+            account("owner", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("borrow_reserve", true, false, false),
+            account("borrow_reserve_liquidity_mint", false, false, false),
+            account("reserve_source_liquidity", true, false, false),
+            account("borrow_reserve_liquidity_fee_receiver", true, false, false),
+            account("user_destination_liquidity", true, false, false),
+            account("referrer_token_state", true, false, true),
+            account("token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "BorrowObligationLiquidityV2",
+        discm: [161, 128, 143, 245, 171, 199, 194, 6],
+        accounts: &[
+            account("borrow_accounts_owner", false, true, false),
+            account("borrow_accounts_obligation", true, false, false),
+            account("borrow_accounts_lending_market", false, false, false),
+            account(
+                "borrow_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("borrow_accounts_borrow_reserve", true, false, false),
+            account(
+                "borrow_accounts_borrow_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "borrow_accounts_reserve_source_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "borrow_accounts_borrow_reserve_liquidity_fee_receiver",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "borrow_accounts_user_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account("borrow_accounts_referrer_token_state", true, false, true),
+            account("borrow_accounts_token_program", false, false, false),
+            account(
+                "borrow_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "RepayObligationLiquidity",
+        discm: [145, 178, 13, 225, 76, 240, 147, 72],
+        accounts: &[
+            account("owner", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("repay_reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_destination_liquidity", true, false, false),
+            account("user_source_liquidity", true, false, false),
+            account("token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "RepayObligationLiquidityV2",
+        discm: [116, 174, 213, 76, 180, 53, 210, 144],
+        accounts: &[
+            account("repay_accounts_owner", false, true, false),
+            account("repay_accounts_obligation", true, false, false),
+            account("repay_accounts_lending_market", false, false, false),
+            account("repay_accounts_repay_reserve", true, false, false),
+            account("repay_accounts_reserve_liquidity_mint", false, false, false),
+            account(
+                "repay_accounts_reserve_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account("repay_accounts_user_source_liquidity", true, false, false),
+            account("repay_accounts_token_program", false, false, false),
+            account(
+                "repay_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("lending_market_authority", false, false, false),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "RepayAndWithdrawAndRedeem",
+        discm: [2, 54, 152, 3, 148, 96, 109, 218],
+        accounts: &[
+            account("repay_accounts_owner", false, true, false),
+            account("repay_accounts_obligation", true, false, false),
+            account("repay_accounts_lending_market", false, false, false),
+            account("repay_accounts_repay_reserve", true, false, false),
+            account("repay_accounts_reserve_liquidity_mint", false, false, false),
+            account(
+                "repay_accounts_reserve_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account("repay_accounts_user_source_liquidity", true, false, false),
+            account("repay_accounts_token_program", false, false, false),
+            account(
+                "repay_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_owner", true, true, false),
+            account("withdraw_accounts_obligation", true, false, false),
+            account("withdraw_accounts_lending_market", false, false, false),
+            account(
+                "withdraw_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_withdraw_reserve", true, false, false),
+            account(
+                "withdraw_accounts_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_source_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_user_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account(
+                "withdraw_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "collateral_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "collateral_farms_accounts_reserve_farm_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "debt_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("debt_farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[
+            arg("repay_amount", "u64"),
+            arg("withdraw_collateral_amount", "u64"),
+        ],
+    },
+    InstructionMeta {
+        name: "DepositAndWithdraw",
+        discm: [141, 153, 39, 15, 64, 61, 88, 84],
+        accounts: &[
+            account("deposit_accounts_owner", true, true, false),
+            account("deposit_accounts_obligation", true, false, false),
+            account("deposit_accounts_lending_market", false, false, false),
+            account(
+                "deposit_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("deposit_accounts_reserve", true, false, false),
+            account(
+                "deposit_accounts_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_destination_deposit_collateral",
+                true,
+                false,
+                false,
+            ),
+            account("deposit_accounts_user_source_liquidity", true, false, false),
+            account(
+                "deposit_accounts_placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account(
+                "deposit_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_owner", true, true, false),
+            account("withdraw_accounts_obligation", true, false, false),
+            account("withdraw_accounts_lending_market", false, false, false),
+            account(
+                "withdraw_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_withdraw_reserve", true, false, false),
+            account(
+                "withdraw_accounts_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_source_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_user_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account(
+                "withdraw_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "deposit_farms_accounts_reserve_farm_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "withdraw_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "withdraw_farms_accounts_reserve_farm_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_program", false, false, false),
+        ],
+        args: &[
+            arg("liquidity_amount", "u64"),
+            arg("withdraw_collateral_amount", "u64"),
+        ],
+    },
+    InstructionMeta {
+        name: "DepositReserveLiquidityAndObligationCollateral",
+        discm: [129, 199, 4, 2, 222, 39, 26, 46],
+        accounts: &[
+            account("owner", true, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_liquidity_supply", true, false, false),
+            account("reserve_collateral_mint", true, false, false),
+            account("reserve_destination_deposit_collateral", true, false, false),
+            account("user_source_liquidity", true, false, false),
+            account(
+                "placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account("collateral_token_program", false, false, false),
+            account("liquidity_token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "DepositReserveLiquidityAndObligationCollateralV2",
+        discm: [216, 224, 191, 27, 204, 151, 102, 175],
+        accounts: &[
+            account("deposit_accounts_owner", true, true, false),
+            account("deposit_accounts_obligation", true, false, false),
+            account("deposit_accounts_lending_market", false, false, false),
+            account(
+                "deposit_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("deposit_accounts_reserve", true, false, false),
+            account(
+                "deposit_accounts_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_reserve_destination_deposit_collateral",
+                true,
+                false,
+                false,
+            ),
+            account("deposit_accounts_user_source_liquidity", true, false, false),
+            account(
+                "deposit_accounts_placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account(
+                "deposit_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "deposit_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "WithdrawObligationCollateralAndRedeemReserveCollateral",
+        discm: [75, 93, 93, 220, 34, 150, 218, 196],
+        accounts: &[
+            account("owner", true, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("withdraw_reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_source_collateral", true, false, false),
+            account("reserve_collateral_mint", true, false, false),
+            account("reserve_liquidity_supply", true, false, false),
+            account("user_destination_liquidity", true, false, false),
+            account(
+                "placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account("collateral_token_program", false, false, false),
+            account("liquidity_token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "WithdrawObligationCollateralAndRedeemReserveCollateralV2",
+        discm: [235, 52, 119, 152, 149, 197, 20, 7],
+        accounts: &[
+            account("withdraw_accounts_owner", true, true, false),
+            account("withdraw_accounts_obligation", true, false, false),
+            account("withdraw_accounts_lending_market", false, false, false),
+            account(
+                "withdraw_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("withdraw_accounts_withdraw_reserve", true, false, false),
+            account(
+                "withdraw_accounts_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_source_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_user_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_placeholder_user_destination_collateral",
+                false,
+                false,
+                true,
+            ),
+            account(
+                "withdraw_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "withdraw_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[arg("collateral_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "LiquidateObligationAndRedeemReserveCollateral",
+        discm: [177, 71, 154, 188, 226, 133, 74, 55],
+        accounts: &[
+            account("liquidator", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("repay_reserve", true, false, false),
+            account("repay_reserve_liquidity_mint", false, false, false),
+            account("repay_reserve_liquidity_supply", true, false, false),
+            account("withdraw_reserve", true, false, false),
+            account("withdraw_reserve_liquidity_mint", false, false, false),
+            account("withdraw_reserve_collateral_mint", true, false, false),
+            account("withdraw_reserve_collateral_supply", true, false, false),
+            account("withdraw_reserve_liquidity_supply", true, false, false),
+            account(
+                "withdraw_reserve_liquidity_fee_receiver",
+                true,
+                false,
+                false,
+            ),
+            account("user_source_liquidity", true, false, false),
+            account("user_destination_collateral", true, false, false),
+            account("user_destination_liquidity", true, false, false),
+            account("collateral_token_program", false, false, false),
+            account("repay_liquidity_token_program", false, false, false),
+            account("withdraw_liquidity_token_program", false, false, false),
+            account("instruction_sysvar_account", false, false, false),
+        ],
+        args: &[
+            arg("liquidity_amount", "u64"),
+            arg("min_acceptable_received_liquidity_amount", "u64"),
+            arg("max_allowed_ltv_override_percent", "u64"),
+        ],
+    },
+    InstructionMeta {
+        name: "LiquidateObligationAndRedeemReserveCollateralV2",
+        discm: [162, 161, 35, 143, 30, 187, 185, 103],
+        accounts: &[
+            account("liquidation_accounts_liquidator", false, true, false),
+            account("liquidation_accounts_obligation", true, false, false),
+            account("liquidation_accounts_lending_market", false, false, false),
+            account(
+                "liquidation_accounts_lending_market_authority",
+                false,
+                false,
+                false,
+            ),
+            account("liquidation_accounts_repay_reserve", true, false, false),
+            account(
+                "liquidation_accounts_repay_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_repay_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account("liquidation_accounts_withdraw_reserve", true, false, false),
+            account(
+                "liquidation_accounts_withdraw_reserve_liquidity_mint",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_withdraw_reserve_collateral_mint",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_withdraw_reserve_collateral_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_withdraw_reserve_liquidity_supply",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_withdraw_reserve_liquidity_fee_receiver",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_user_source_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_user_destination_collateral",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_user_destination_liquidity",
+                true,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_collateral_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_repay_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_withdraw_liquidity_token_program",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "liquidation_accounts_instruction_sysvar_account",
+                false,
+                false,
+                false,
+            ),
+            account(
+                "collateral_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "collateral_farms_accounts_reserve_farm_state",
+                true,
+                false,
+                true,
+            ),
+            account(
+                "debt_farms_accounts_obligation_farm_user_state",
+                true,
+                false,
+                true,
+            ),
+            account("debt_farms_accounts_reserve_farm_state", true, false, true),
+            account("farms_program", false, false, false),
+        ],
+        args: &[
+            arg("liquidity_amount", "u64"),
+            arg("min_acceptable_received_liquidity_amount", "u64"),
+            arg("max_allowed_ltv_override_percent", "u64"),
+        ],
+    },
+    InstructionMeta {
+        name: "FlashRepayReserveLiquidity",
+        discm: [185, 117, 0, 203, 96, 245, 180, 186],
+        accounts: &[
+            account("user_transfer_authority", false, true, false),
+            account("lending_market_authority", false, false, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_destination_liquidity", true, false, false),
+            account("user_source_liquidity", true, false, false),
+            account("reserve_liquidity_fee_receiver", true, false, false),
+            account("referrer_token_state", true, false, true),
+            account("referrer_account", true, false, true),
+            account("sysvar_info", false, false, false),
+            account("token_program", false, false, false),
+        ],
+        args: &[
+            arg("liquidity_amount", "u64"),
+            arg("borrow_instruction_index", "u8"),
+        ],
+    },
+    InstructionMeta {
+        name: "FlashBorrowReserveLiquidity",
+        discm: [135, 231, 52, 167, 7, 52, 212, 193],
+        accounts: &[
+            account("user_transfer_authority", false, true, false),
+            account("lending_market_authority", false, false, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_source_liquidity", true, false, false),
+            account("user_destination_liquidity", true, false, false),
+            account("reserve_liquidity_fee_receiver", true, false, false),
+            account("referrer_token_state", true, false, true),
+            account("referrer_account", true, false, true),
+            account("sysvar_info", false, false, false),
+            account("token_program", false, false, false),
+        ],
+        args: &[arg("liquidity_amount", "u64")],
+    },
+    InstructionMeta {
+        name: "RequestElevationGroup",
+        discm: [36, 119, 251, 129, 34, 240, 7, 147],
+        accounts: &[
+            account("owner", false, true, false),
+            account("obligation", true, false, false),
+            account("lending_market", false, false, false),
+        ],
+        args: &[arg("elevation_group", "u8")],
+    },
+    InstructionMeta {
+        name: "InitReferrerTokenState",
+        discm: [116, 45, 66, 148, 58, 13, 218, 115],
+        accounts: &[
+            account("payer", true, true, false),
+            account("lending_market", false, false, false),
+            account("reserve", false, false, false),
+            account("referrer", false, false, false),
+            account("referrer_token_state", true, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "InitUserMetadata",
+        discm: [117, 169, 176, 69, 197, 23, 15, 162],
+        accounts: &[
+            account("owner", false, true, false),
+            account("fee_payer", true, true, false),
+            account("user_metadata", true, false, false),
+            account("referrer_user_metadata", false, false, true),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("user_lookup_table", "Pubkey")],
+    },
+    InstructionMeta {
+        name: "WithdrawReferrerFees",
+        discm: [171, 118, 121, 201, 233, 140, 23, 228],
+        accounts: &[
+            account("referrer", true, true, false),
+            account("referrer_token_state", true, false, false),
+            account("reserve", true, false, false),
+            account("reserve_liquidity_mint", false, false, false),
+            account("reserve_supply_liquidity", true, false, false),
+            account("referrer_token_account", true, false, false),
+            account("lending_market", false, false, false),
+            account("lending_market_authority", false, false, false),
+            account("token_program", false, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "InitReferrerStateAndShortUrl",
+        discm: [165, 19, 25, 127, 100, 55, 31, 90],
+        accounts: &[
+            account("referrer", true, true, false),
+            account("referrer_state", true, false, false),
+            account("referrer_short_url", true, false, false),
+            account("referrer_user_metadata", false, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[arg("short_url", "String")],
+    },
+    InstructionMeta {
+        name: "DeleteReferrerStateAndShortUrl",
+        discm: [153, 185, 99, 28, 228, 179, 187, 150],
+        accounts: &[
+            account("referrer", true, true, false),
+            account("referrer_state", true, false, false),
+            account("short_url", true, false, false),
+            account("rent", false, false, false),
+            account("system_program", false, false, false),
+        ],
+        args: &[],
+    },
+    InstructionMeta {
+        name: "IdlMissingTypes",
+        discm: [130, 80, 38, 153, 80, 212, 182, 253],
+        accounts: &[
+            account("lending_market_owner", false, true, false),
+            account("lending_market", false, false, false),
+            account("reserve", true, false, false),
+        ],
+        args: &[
+            arg("reserve_farm_kind", "ReserveFarmKind"),
+            arg("asset_tier", "AssetTier"),
+            arg("fee_calculation", "FeeCalculation"),
+            arg("reserve_status", "ReserveStatus"),
+            arg("update_config_mode", "UpdateConfigMode"),
+            arg(
+                "update_lending_market_config_value",
+                "UpdateLendingMarketConfigValue",
+            ),
+            arg(
+                "update_lending_market_config_mode",
+                "UpdateLendingMarketMode",
+            ),
+        ],
+    },
+];
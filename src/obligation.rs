@@ -0,0 +1,379 @@
+//! Obligation positions and values, recomputed from current reserve state the way
+//! `refresh_obligation` does.
+use crate::fraction::Fraction;
+use crate::lending_market::ELEVATION_GROUP_NONE;
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+/// A collateral deposit valued at its reserve's current exchange rate and price.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositPosition {
+    /// Index in `Obligation::deposits`.
+    pub index: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub reserve: Pubkey,
+    pub collateral_amount: u64,
+    pub liquidity_amount: Fraction,
+    pub market_value: Fraction,
+    pub max_ltv_pct: u8,
+    pub liquidation_threshold_pct: u8,
+}
+
+/// A debt position with interest accrued up to its reserve's cumulative borrow rate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BorrowPosition {
+    /// Index in `Obligation::borrows`.
+    pub index: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub reserve: Pubkey,
+    pub borrowed_amount: Fraction,
+    pub market_value: Fraction,
+    pub borrow_factor: Fraction,
+    pub borrow_factor_adjusted_value: Fraction,
+}
+
+/// Obligation values as `refresh_obligation` would compute them from the given reserves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObligationValues {
+    pub elevation_group: u8,
+    pub deposits: Vec<DepositPosition>,
+    pub borrows: Vec<BorrowPosition>,
+    pub deposited_value: Fraction,
+    pub borrowed_value: Fraction,
+    pub borrow_factor_adjusted_debt_value: Fraction,
+    pub allowed_borrow_value: Fraction,
+    pub unhealthy_borrow_value: Fraction,
+}
+
+fn ratio(numerator: Fraction, denominator: Fraction) -> Fraction {
+    if denominator.is_zero() {
+        return Fraction::ZERO;
+    }
+    numerator.checked_div(denominator).unwrap_or(Fraction::MAX)
+}
+
+/// `total + value * factor`, failing with [`KaminoLendingError::MathOverflow`] instead of
+/// panicking on saturated market values.
+fn add_weighted(
+    total: Fraction,
+    value: Fraction,
+    factor: Fraction,
+) -> Result<Fraction, KaminoLendingError> {
+    value
+        .checked_mul(factor)
+        .and_then(|weighted| total.checked_add(weighted))
+        .ok_or(KaminoLendingError::MathOverflow)
+}
+
+impl ObligationValues {
+    /// Borrow-factor-adjusted debt over deposited value.
+    pub fn loan_to_value(&self) -> Fraction {
+        ratio(self.borrow_factor_adjusted_debt_value, self.deposited_value)
+    }
+
+    /// LTV above which no more can be borrowed or withdrawn.
+    pub fn max_loan_to_value(&self) -> Fraction {
+        ratio(self.allowed_borrow_value, self.deposited_value)
+    }
+
+    /// LTV at or above which the obligation can be liquidated.
+    pub fn unhealthy_loan_to_value(&self) -> Fraction {
+        ratio(self.unhealthy_borrow_value, self.deposited_value)
+    }
+
+    /// Borrow-factor-adjusted value that can still be borrowed.
+    pub fn remaining_borrow_value(&self) -> Fraction {
+        self.allowed_borrow_value
+            .saturating_sub(self.borrow_factor_adjusted_debt_value)
+    }
+
+    /// Deposited value minus borrowed value, zero if the obligation is underwater.
+    pub fn net_value(&self) -> Fraction {
+        self.deposited_value.saturating_sub(self.borrowed_value)
+    }
+
+    pub fn has_debt(&self) -> bool {
+        !self.borrowed_value.is_zero() || self.borrows.iter().any(|b| !b.borrowed_amount.is_zero())
+    }
+
+    pub fn is_liquidatable(&self) -> bool {
+        self.has_debt() && self.borrow_factor_adjusted_debt_value >= self.unhealthy_borrow_value
+    }
+
+    /// Value of collateral with `ltv_pct` that can be withdrawn without exceeding the allowed
+    /// borrow value; the whole deposited value for zero-LTV collateral.
+    pub fn max_withdraw_value(&self, ltv_pct: u8) -> Fraction {
+        if self.allowed_borrow_value <= self.borrow_factor_adjusted_debt_value {
+            return Fraction::ZERO;
+        }
+        if ltv_pct == 0 {
+            return self.deposited_value;
+        }
+        ratio(
+            self.remaining_borrow_value(),
+            Fraction::from_percent(ltv_pct),
+        )
+    }
+
+    pub fn deposit(&self, reserve: &Pubkey) -> Option<&DepositPosition> {
+        self.deposits
+            .iter()
+            .find(|deposit| deposit.reserve == *reserve)
+    }
+
+    pub fn borrow(&self, reserve: &Pubkey) -> Option<&BorrowPosition> {
+        self.borrows
+            .iter()
+            .find(|borrow| borrow.reserve == *reserve)
+    }
+}
+
+impl Obligation {
+    /// Deposits in use, with their index in `deposits`.
+    pub fn active_deposits(&self) -> impl Iterator<Item = (usize, &ObligationCollateral)> {
+        self.deposits
+            .iter()
+            .enumerate()
+            .filter(|(_, deposit)| deposit.deposit_reserve != Pubkey::default())
+    }
+
+    /// Borrows in use, with their index in `borrows`.
+    pub fn active_borrows(&self) -> impl Iterator<Item = (usize, &ObligationLiquidity)> {
+        self.borrows
+            .iter()
+            .enumerate()
+            .filter(|(_, borrow)| borrow.borrow_reserve != Pubkey::default())
+    }
+
+    pub fn find_deposit(&self, reserve: &Pubkey) -> Option<&ObligationCollateral> {
+        self.active_deposits()
+            .map(|(_, deposit)| deposit)
+            .find(|deposit| deposit.deposit_reserve == *reserve)
+    }
+
+    pub fn find_borrow(&self, reserve: &Pubkey) -> Option<&ObligationLiquidity> {
+        self.active_borrows()
+            .map(|(_, borrow)| borrow)
+            .find(|borrow| borrow.borrow_reserve == *reserve)
+    }
+
+    pub fn is_in_elevation_group(&self) -> bool {
+        self.elevation_group != ELEVATION_GROUP_NONE
+    }
+
+    pub fn deposited_value(&self) -> Fraction {
+        Fraction::from_bits(self.deposited_value_sf)
+    }
+
+    pub fn borrow_factor_adjusted_debt_value(&self) -> Fraction {
+        Fraction::from_bits(self.borrow_factor_adjusted_debt_value_sf)
+    }
+
+    pub fn allowed_borrow_value(&self) -> Fraction {
+        Fraction::from_bits(self.allowed_borrow_value_sf)
+    }
+
+    pub fn unhealthy_borrow_value(&self) -> Fraction {
+        Fraction::from_bits(self.unhealthy_borrow_value_sf)
+    }
+
+    /// LTV as of the last `refresh_obligation`.
+    pub fn loan_to_value(&self) -> Fraction {
+        ratio(
+            self.borrow_factor_adjusted_debt_value(),
+            self.deposited_value(),
+        )
+    }
+
+    /// Max LTV as of the last `refresh_obligation`.
+    pub fn max_loan_to_value(&self) -> Fraction {
+        ratio(self.allowed_borrow_value(), self.deposited_value())
+    }
+
+    /// Unhealthy LTV as of the last `refresh_obligation`.
+    pub fn unhealthy_loan_to_value(&self) -> Fraction {
+        ratio(self.unhealthy_borrow_value(), self.deposited_value())
+    }
+
+    /// Values at the reserves' current state, in the obligation's own elevation group.
+    pub fn compute_values(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+    ) -> Result<ObligationValues, KaminoLendingError> {
+        self.compute_values_in_elevation_group(lending_market, reserves, self.elevation_group)
+    }
+
+    /// Values at the reserves' current state as if the obligation were in `elevation_group`.
+    ///
+    /// Fails with [`KaminoLendingError::InvalidObligationCollateral`] or
+    /// [`KaminoLendingError::InvalidObligationLiquidity`] when a reserve is missing from
+    /// `reserves`, and with [`KaminoLendingError::MathOverflow`] when the values do not fit in a
+    /// [`Fraction`], e.g. because of a corrupt price.
+    pub fn compute_values_in_elevation_group(
+        &self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+        elevation_group: u8,
+    ) -> Result<ObligationValues, KaminoLendingError> {
+        let group = lending_market.elevation_group(elevation_group);
+        let mut values = ObligationValues {
+            elevation_group,
+            deposits: Vec::new(),
+            borrows: Vec::new(),
+            deposited_value: Fraction::ZERO,
+            borrowed_value: Fraction::ZERO,
+            borrow_factor_adjusted_debt_value: Fraction::ZERO,
+            allowed_borrow_value: Fraction::ZERO,
+            unhealthy_borrow_value: Fraction::ZERO,
+        };
+        for (index, deposit) in self.active_deposits() {
+            let reserve = reserves
+                .reserve(&deposit.deposit_reserve)
+                .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+            let liquidity_amount = reserve.collateral_to_liquidity(deposit.deposited_amount);
+            let market_value = reserve.market_value(liquidity_amount);
+            let (max_ltv_pct, liquidation_threshold_pct) =
+                reserve.ltv_and_liquidation_threshold(group);
+            values.deposited_value =
+                add_weighted(values.deposited_value, market_value, Fraction::ONE)?;
+            values.allowed_borrow_value = add_weighted(
+                values.allowed_borrow_value,
+                market_value,
+                Fraction::from_percent(max_ltv_pct),
+            )?;
+            values.unhealthy_borrow_value = add_weighted(
+                values.unhealthy_borrow_value,
+                market_value,
+                Fraction::from_percent(liquidation_threshold_pct),
+            )?;
+            values.deposits.push(DepositPosition {
+                index,
+                reserve: deposit.deposit_reserve,
+                collateral_amount: deposit.deposited_amount,
+                liquidity_amount,
+                market_value,
+                max_ltv_pct,
+                liquidation_threshold_pct,
+            });
+        }
+        for (index, borrow) in self.active_borrows() {
+            let reserve = reserves
+                .reserve(&borrow.borrow_reserve)
+                .ok_or(KaminoLendingError::InvalidObligationLiquidity)?;
+            let borrowed_amount = borrow.accrued_borrowed_amount(reserve);
+            let market_value = reserve.market_value(borrowed_amount);
+            let borrow_factor = reserve.borrow_factor(elevation_group != ELEVATION_GROUP_NONE);
+            let borrow_factor_adjusted_value =
+                add_weighted(Fraction::ZERO, market_value, borrow_factor)?;
+            values.borrowed_value =
+                add_weighted(values.borrowed_value, market_value, Fraction::ONE)?;
+            values.borrow_factor_adjusted_debt_value = add_weighted(
+                values.borrow_factor_adjusted_debt_value,
+                borrow_factor_adjusted_value,
+                Fraction::ONE,
+            )?;
+            values.borrows.push(BorrowPosition {
+                index,
+                reserve: borrow.borrow_reserve,
+                borrowed_amount,
+                market_value,
+                borrow_factor,
+                borrow_factor_adjusted_value,
+            });
+        }
+        Ok(values)
+    }
+
+    /// Recomputes the obligation's values from `reserves` and caches them in its account fields,
+    /// as `refresh_obligation` does. Interest is not accrued on the reserves.
+    pub fn refresh(
+        &mut self,
+        lending_market: &LendingMarket,
+        reserves: &impl ReserveLookup,
+    ) -> Result<ObligationValues, KaminoLendingError> {
+        let values = self.compute_values(lending_market, reserves)?;
+        self.deposited_value_sf = values.deposited_value.to_bits();
+        self.borrowed_assets_market_value_sf = values.borrowed_value.to_bits();
+        self.borrow_factor_adjusted_debt_value_sf =
+            values.borrow_factor_adjusted_debt_value.to_bits();
+        self.allowed_borrow_value_sf = values.allowed_borrow_value.to_bits();
+        self.unhealthy_borrow_value_sf = values.unhealthy_borrow_value.to_bits();
+        self.has_debt = u8::from(values.has_debt());
+        self.lowest_reserve_deposit_max_ltv_pct = values
+            .deposits
+            .iter()
+            .map(|deposit| deposit.max_ltv_pct)
+            .min()
+            .unwrap_or(u8::MAX);
+        self.lowest_reserve_deposit_liquidation_ltv = values
+            .deposits
+            .iter()
+            .map(|deposit| u64::from(deposit.liquidation_threshold_pct))
+            .min()
+            .unwrap_or(u64::MAX);
+        for deposit in &values.deposits {
+            self.deposits[deposit.index].market_value_sf = deposit.market_value.to_bits();
+        }
+        for borrow in &values.borrows {
+            let slot = &mut self.borrows[borrow.index];
+            slot.market_value_sf = borrow.market_value.to_bits();
+            slot.borrow_factor_adjusted_market_value_sf =
+                borrow.borrow_factor_adjusted_value.to_bits();
+        }
+        Ok(values)
+    }
+}
+
+impl ObligationLiquidity {
+    pub fn borrowed_amount(&self) -> Fraction {
+        Fraction::from_bits(self.borrowed_amount_sf)
+    }
+
+    /// Borrowed amount compounded from the obligation's cumulative borrow rate snapshot to the
+    /// reserve's current one.
+    pub fn accrued_borrowed_amount(&self, reserve: &Reserve) -> Fraction {
+        let borrowed = self.borrowed_amount();
+        let snapshot_rate = self.cumulative_borrow_rate_bsf.to_fraction();
+        let reserve_rate = reserve.liquidity.cumulative_borrow_rate();
+        if snapshot_rate.is_zero() || reserve_rate <= snapshot_rate {
+            return borrowed;
+        }
+        crate::math::mul_div_ceil(
+            borrowed.to_bits(),
+            reserve_rate.to_bits(),
+            snapshot_rate.to_bits(),
+        )
+        .map_or(Fraction::MAX, Fraction::from_bits)
+    }
+}
+
+impl Reserve {
+    /// Whether the reserve's config lists elevation group `id`.
+    pub fn is_in_elevation_group(&self, id: u8) -> bool {
+        id != ELEVATION_GROUP_NONE && self.config.elevation_groups.contains(&id)
+    }
+
+    /// Max LTV and liquidation threshold, in percent, of this reserve used as collateral in
+    /// `group`: the group's values if the reserve belongs to it, the reserve's own otherwise.
+    /// Reserves with `disable_usage_as_coll_outside_emode` have no borrowing power outside an
+    /// elevation group.
+    pub fn ltv_and_liquidation_threshold(&self, group: Option<&ElevationGroup>) -> (u8, u8) {
+        match group {
+            Some(group) if self.is_in_elevation_group(group.id) => {
+                (group.ltv_pct, group.liquidation_threshold_pct)
+            }
+            None if self.config.disable_usage_as_coll_outside_emode != 0 => {
+                (0, self.config.liquidation_threshold_pct)
+            }
+            _ => (
+                self.config.loan_to_value_pct,
+                self.config.liquidation_threshold_pct,
+            ),
+        }
+    }
+}
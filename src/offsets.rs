@@ -0,0 +1,206 @@
+//! Byte offsets of account fields, discriminator included, as used by `memcmp` filters.
+//!
+//! Generated from the layouts in `accounts.rs` and `typedefs.rs`; fields of nested structs are
+//! listed under their parent's name. `ShortUrl` stops at its variable-length `short_url`.
+
+pub const USER_STATE_USER_ID_OFFSET: usize = 8;
+pub const USER_STATE_FARM_STATE_OFFSET: usize = 16;
+pub const USER_STATE_OWNER_OFFSET: usize = 48;
+pub const USER_STATE_IS_FARM_DELEGATED_OFFSET: usize = 80;
+pub const USER_STATE_PADDING0_OFFSET: usize = 81;
+pub const USER_STATE_REWARDS_TALLY_SCALED_OFFSET: usize = 88;
+pub const USER_STATE_REWARDS_ISSUED_UNCLAIMED_OFFSET: usize = 248;
+pub const USER_STATE_LAST_CLAIM_TS_OFFSET: usize = 328;
+pub const USER_STATE_ACTIVE_STAKE_SCALED_OFFSET: usize = 408;
+pub const USER_STATE_PENDING_DEPOSIT_STAKE_SCALED_OFFSET: usize = 424;
+pub const USER_STATE_PENDING_DEPOSIT_STAKE_TS_OFFSET: usize = 440;
+pub const USER_STATE_PENDING_WITHDRAWAL_UNSTAKE_SCALED_OFFSET: usize = 448;
+pub const USER_STATE_PENDING_WITHDRAWAL_UNSTAKE_TS_OFFSET: usize = 464;
+pub const USER_STATE_BUMP_OFFSET: usize = 472;
+pub const USER_STATE_DELEGATEE_OFFSET: usize = 480;
+pub const USER_STATE_LAST_STAKE_TS_OFFSET: usize = 512;
+pub const USER_STATE_PADDING1_OFFSET: usize = 520;
+
+pub const LENDING_MARKET_VERSION_OFFSET: usize = 8;
+pub const LENDING_MARKET_BUMP_SEED_OFFSET: usize = 16;
+pub const LENDING_MARKET_LENDING_MARKET_OWNER_OFFSET: usize = 24;
+pub const LENDING_MARKET_LENDING_MARKET_OWNER_CACHED_OFFSET: usize = 56;
+pub const LENDING_MARKET_QUOTE_CURRENCY_OFFSET: usize = 88;
+pub const LENDING_MARKET_REFERRAL_FEE_BPS_OFFSET: usize = 120;
+pub const LENDING_MARKET_EMERGENCY_MODE_OFFSET: usize = 122;
+pub const LENDING_MARKET_AUTODELEVERAGE_ENABLED_OFFSET: usize = 123;
+pub const LENDING_MARKET_BORROW_DISABLED_OFFSET: usize = 124;
+pub const LENDING_MARKET_PRICE_REFRESH_TRIGGER_TO_MAX_AGE_PCT_OFFSET: usize = 125;
+pub const LENDING_MARKET_LIQUIDATION_MAX_DEBT_CLOSE_FACTOR_PCT_OFFSET: usize = 126;
+pub const LENDING_MARKET_INSOLVENCY_RISK_UNHEALTHY_LTV_PCT_OFFSET: usize = 127;
+pub const LENDING_MARKET_MIN_FULL_LIQUIDATION_VALUE_THRESHOLD_OFFSET: usize = 128;
+pub const LENDING_MARKET_MAX_LIQUIDATABLE_DEBT_MARKET_VALUE_AT_ONCE_OFFSET: usize = 136;
+pub const LENDING_MARKET_RESERVED0_OFFSET: usize = 144;
+pub const LENDING_MARKET_GLOBAL_ALLOWED_BORROW_VALUE_OFFSET: usize = 152;
+pub const LENDING_MARKET_RISK_COUNCIL_OFFSET: usize = 160;
+pub const LENDING_MARKET_RESERVED1_OFFSET: usize = 192;
+pub const LENDING_MARKET_ELEVATION_GROUPS_OFFSET: usize = 200;
+pub const LENDING_MARKET_ELEVATION_GROUP_PADDING_OFFSET: usize = 2504;
+pub const LENDING_MARKET_MIN_NET_VALUE_IN_OBLIGATION_SF_OFFSET: usize = 3224;
+pub const LENDING_MARKET_MIN_VALUE_SKIP_LIQUIDATION_LTV_CHECKS_OFFSET: usize = 3240;
+pub const LENDING_MARKET_NAME_OFFSET: usize = 3248;
+pub const LENDING_MARKET_MIN_VALUE_SKIP_LIQUIDATION_BF_CHECKS_OFFSET: usize = 3280;
+pub const LENDING_MARKET_INDIVIDUAL_AUTODELEVERAGE_MARGIN_CALL_PERIOD_SECS_OFFSET: usize = 3288;
+pub const LENDING_MARKET_PADDING1_OFFSET: usize = 3296;
+
+pub const OBLIGATION_TAG_OFFSET: usize = 8;
+pub const OBLIGATION_LAST_UPDATE_OFFSET: usize = 16;
+pub const OBLIGATION_LAST_UPDATE_SLOT_OFFSET: usize = 16;
+pub const OBLIGATION_LAST_UPDATE_STALE_OFFSET: usize = 24;
+pub const OBLIGATION_LAST_UPDATE_PRICE_STATUS_OFFSET: usize = 25;
+pub const OBLIGATION_LAST_UPDATE_PLACEHOLDER_OFFSET: usize = 26;
+pub const OBLIGATION_LENDING_MARKET_OFFSET: usize = 32;
+pub const OBLIGATION_OWNER_OFFSET: usize = 64;
+pub const OBLIGATION_DEPOSITS_OFFSET: usize = 96;
+pub const OBLIGATION_LOWEST_RESERVE_DEPOSIT_LIQUIDATION_LTV_OFFSET: usize = 1184;
+pub const OBLIGATION_DEPOSITED_VALUE_SF_OFFSET: usize = 1192;
+pub const OBLIGATION_BORROWS_OFFSET: usize = 1208;
+pub const OBLIGATION_BORROW_FACTOR_ADJUSTED_DEBT_VALUE_SF_OFFSET: usize = 2208;
+pub const OBLIGATION_BORROWED_ASSETS_MARKET_VALUE_SF_OFFSET: usize = 2224;
+pub const OBLIGATION_ALLOWED_BORROW_VALUE_SF_OFFSET: usize = 2240;
+pub const OBLIGATION_UNHEALTHY_BORROW_VALUE_SF_OFFSET: usize = 2256;
+pub const OBLIGATION_DEPOSITS_ASSET_TIERS_OFFSET: usize = 2272;
+pub const OBLIGATION_BORROWS_ASSET_TIERS_OFFSET: usize = 2280;
+pub const OBLIGATION_ELEVATION_GROUP_OFFSET: usize = 2285;
+pub const OBLIGATION_NUM_OF_OBSOLETE_RESERVES_OFFSET: usize = 2286;
+pub const OBLIGATION_HAS_DEBT_OFFSET: usize = 2287;
+pub const OBLIGATION_REFERRER_OFFSET: usize = 2288;
+pub const OBLIGATION_BORROWING_DISABLED_OFFSET: usize = 2320;
+pub const OBLIGATION_AUTODELEVERAGE_TARGET_LTV_PCT_OFFSET: usize = 2321;
+pub const OBLIGATION_LOWEST_RESERVE_DEPOSIT_MAX_LTV_PCT_OFFSET: usize = 2322;
+pub const OBLIGATION_RESERVED_OFFSET: usize = 2323;
+pub const OBLIGATION_HIGHEST_BORROW_FACTOR_PCT_OFFSET: usize = 2328;
+pub const OBLIGATION_AUTODELEVERAGE_MARGIN_CALL_STARTED_TIMESTAMP_OFFSET: usize = 2336;
+pub const OBLIGATION_PADDING3_OFFSET: usize = 2344;
+
+pub const REFERRER_STATE_SHORT_URL_OFFSET: usize = 8;
+pub const REFERRER_STATE_OWNER_OFFSET: usize = 40;
+
+pub const REFERRER_TOKEN_STATE_REFERRER_OFFSET: usize = 8;
+pub const REFERRER_TOKEN_STATE_MINT_OFFSET: usize = 40;
+pub const REFERRER_TOKEN_STATE_AMOUNT_UNCLAIMED_SF_OFFSET: usize = 72;
+pub const REFERRER_TOKEN_STATE_AMOUNT_CUMULATIVE_SF_OFFSET: usize = 88;
+pub const REFERRER_TOKEN_STATE_BUMP_OFFSET: usize = 104;
+pub const REFERRER_TOKEN_STATE_PADDING_OFFSET: usize = 112;
+
+pub const SHORT_URL_REFERRER_OFFSET: usize = 8;
+pub const SHORT_URL_SHORT_URL_OFFSET: usize = 40;
+
+pub const USER_METADATA_REFERRER_OFFSET: usize = 8;
+pub const USER_METADATA_BUMP_OFFSET: usize = 40;
+pub const USER_METADATA_USER_LOOKUP_TABLE_OFFSET: usize = 48;
+pub const USER_METADATA_OWNER_OFFSET: usize = 80;
+pub const USER_METADATA_PADDING1_OFFSET: usize = 112;
+pub const USER_METADATA_PADDING2_OFFSET: usize = 520;
+
+pub const RESERVE_VERSION_OFFSET: usize = 8;
+pub const RESERVE_LAST_UPDATE_OFFSET: usize = 16;
+pub const RESERVE_LAST_UPDATE_SLOT_OFFSET: usize = 16;
+pub const RESERVE_LAST_UPDATE_STALE_OFFSET: usize = 24;
+pub const RESERVE_LAST_UPDATE_PRICE_STATUS_OFFSET: usize = 25;
+pub const RESERVE_LAST_UPDATE_PLACEHOLDER_OFFSET: usize = 26;
+pub const RESERVE_LENDING_MARKET_OFFSET: usize = 32;
+pub const RESERVE_FARM_COLLATERAL_OFFSET: usize = 64;
+pub const RESERVE_FARM_DEBT_OFFSET: usize = 96;
+pub const RESERVE_LIQUIDITY_OFFSET: usize = 128;
+pub const RESERVE_LIQUIDITY_MINT_PUBKEY_OFFSET: usize = 128;
+pub const RESERVE_LIQUIDITY_SUPPLY_VAULT_OFFSET: usize = 160;
+pub const RESERVE_LIQUIDITY_FEE_VAULT_OFFSET: usize = 192;
+pub const RESERVE_LIQUIDITY_AVAILABLE_AMOUNT_OFFSET: usize = 224;
+pub const RESERVE_LIQUIDITY_BORROWED_AMOUNT_SF_OFFSET: usize = 232;
+pub const RESERVE_LIQUIDITY_MARKET_PRICE_SF_OFFSET: usize = 248;
+pub const RESERVE_LIQUIDITY_MARKET_PRICE_LAST_UPDATED_TS_OFFSET: usize = 264;
+pub const RESERVE_LIQUIDITY_MINT_DECIMALS_OFFSET: usize = 272;
+pub const RESERVE_LIQUIDITY_DEPOSIT_LIMIT_CROSSED_TIMESTAMP_OFFSET: usize = 280;
+pub const RESERVE_LIQUIDITY_BORROW_LIMIT_CROSSED_TIMESTAMP_OFFSET: usize = 288;
+pub const RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_OFFSET: usize = 296;
+pub const RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_VALUE_OFFSET: usize = 296;
+pub const RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_PADDING_OFFSET: usize = 328;
+pub const RESERVE_LIQUIDITY_ACCUMULATED_PROTOCOL_FEES_SF_OFFSET: usize = 344;
+pub const RESERVE_LIQUIDITY_ACCUMULATED_REFERRER_FEES_SF_OFFSET: usize = 360;
+pub const RESERVE_LIQUIDITY_PENDING_REFERRER_FEES_SF_OFFSET: usize = 376;
+pub const RESERVE_LIQUIDITY_ABSOLUTE_REFERRAL_RATE_SF_OFFSET: usize = 392;
+pub const RESERVE_LIQUIDITY_TOKEN_PROGRAM_OFFSET: usize = 408;
+pub const RESERVE_LIQUIDITY_PADDING2_OFFSET: usize = 440;
+pub const RESERVE_LIQUIDITY_PADDING3_OFFSET: usize = 848;
+pub const RESERVE_RESERVE_LIQUIDITY_PADDING_OFFSET: usize = 1360;
+pub const RESERVE_COLLATERAL_OFFSET: usize = 2560;
+pub const RESERVE_COLLATERAL_MINT_PUBKEY_OFFSET: usize = 2560;
+pub const RESERVE_COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET: usize = 2592;
+pub const RESERVE_COLLATERAL_SUPPLY_VAULT_OFFSET: usize = 2600;
+pub const RESERVE_COLLATERAL_PADDING1_OFFSET: usize = 2632;
+pub const RESERVE_COLLATERAL_PADDING2_OFFSET: usize = 3144;
+pub const RESERVE_RESERVE_COLLATERAL_PADDING_OFFSET: usize = 3656;
+pub const RESERVE_CONFIG_OFFSET: usize = 4856;
+pub const RESERVE_CONFIG_STATUS_OFFSET: usize = 4856;
+pub const RESERVE_CONFIG_ASSET_TIER_OFFSET: usize = 4857;
+pub const RESERVE_CONFIG_HOST_FIXED_INTEREST_RATE_BPS_OFFSET: usize = 4858;
+pub const RESERVE_CONFIG_RESERVED2_OFFSET: usize = 4860;
+pub const RESERVE_CONFIG_RESERVED3_OFFSET: usize = 4862;
+pub const RESERVE_CONFIG_PROTOCOL_TAKE_RATE_PCT_OFFSET: usize = 4870;
+pub const RESERVE_CONFIG_PROTOCOL_LIQUIDATION_FEE_PCT_OFFSET: usize = 4871;
+pub const RESERVE_CONFIG_LOAN_TO_VALUE_PCT_OFFSET: usize = 4872;
+pub const RESERVE_CONFIG_LIQUIDATION_THRESHOLD_PCT_OFFSET: usize = 4873;
+pub const RESERVE_CONFIG_MIN_LIQUIDATION_BONUS_BPS_OFFSET: usize = 4874;
+pub const RESERVE_CONFIG_MAX_LIQUIDATION_BONUS_BPS_OFFSET: usize = 4876;
+pub const RESERVE_CONFIG_BAD_DEBT_LIQUIDATION_BONUS_BPS_OFFSET: usize = 4878;
+pub const RESERVE_CONFIG_DELEVERAGING_MARGIN_CALL_PERIOD_SECS_OFFSET: usize = 4880;
+pub const RESERVE_CONFIG_DELEVERAGING_THRESHOLD_DECREASE_BPS_PER_DAY_OFFSET: usize = 4888;
+pub const RESERVE_CONFIG_FEES_OFFSET: usize = 4896;
+pub const RESERVE_CONFIG_FEES_BORROW_FEE_SF_OFFSET: usize = 4896;
+pub const RESERVE_CONFIG_FEES_FLASH_LOAN_FEE_SF_OFFSET: usize = 4904;
+pub const RESERVE_CONFIG_FEES_PADDING_OFFSET: usize = 4912;
+pub const RESERVE_CONFIG_BORROW_RATE_CURVE_OFFSET: usize = 4920;
+pub const RESERVE_CONFIG_BORROW_RATE_CURVE_POINTS_OFFSET: usize = 4920;
+pub const RESERVE_CONFIG_BORROW_FACTOR_PCT_OFFSET: usize = 5008;
+pub const RESERVE_CONFIG_DEPOSIT_LIMIT_OFFSET: usize = 5016;
+pub const RESERVE_CONFIG_BORROW_LIMIT_OFFSET: usize = 5024;
+pub const RESERVE_CONFIG_TOKEN_INFO_OFFSET: usize = 5032;
+pub const RESERVE_CONFIG_TOKEN_INFO_NAME_OFFSET: usize = 5032;
+pub const RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_OFFSET: usize = 5064;
+pub const RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_LOWER_OFFSET: usize = 5064;
+pub const RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_UPPER_OFFSET: usize = 5072;
+pub const RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_EXP_OFFSET: usize = 5080;
+pub const RESERVE_CONFIG_TOKEN_INFO_MAX_TWAP_DIVERGENCE_BPS_OFFSET: usize = 5088;
+pub const RESERVE_CONFIG_TOKEN_INFO_MAX_AGE_PRICE_SECONDS_OFFSET: usize = 5096;
+pub const RESERVE_CONFIG_TOKEN_INFO_MAX_AGE_TWAP_SECONDS_OFFSET: usize = 5104;
+pub const RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_OFFSET: usize = 5112;
+pub const RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_PRICE_FEED_OFFSET: usize = 5112;
+pub const RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_PRICE_CHAIN_OFFSET: usize = 5144;
+pub const RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_TWAP_CHAIN_OFFSET: usize = 5152;
+pub const RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_OFFSET: usize = 5160;
+pub const RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_PRICE_AGGREGATOR_OFFSET: usize = 5160;
+pub const RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_TWAP_AGGREGATOR_OFFSET: usize = 5192;
+pub const RESERVE_CONFIG_TOKEN_INFO_PYTH_CONFIGURATION_OFFSET: usize = 5224;
+pub const RESERVE_CONFIG_TOKEN_INFO_PYTH_CONFIGURATION_PRICE_OFFSET: usize = 5224;
+pub const RESERVE_CONFIG_TOKEN_INFO_BLOCK_PRICE_USAGE_OFFSET: usize = 5256;
+pub const RESERVE_CONFIG_TOKEN_INFO_RESERVED_OFFSET: usize = 5257;
+pub const RESERVE_CONFIG_TOKEN_INFO_PADDING_OFFSET: usize = 5264;
+pub const RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_OFFSET: usize = 5416;
+pub const RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CONFIG_CAPACITY_OFFSET: usize = 5416;
+pub const RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CURRENT_TOTAL_OFFSET: usize = 5424;
+pub const RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_LAST_INTERVAL_START_TIMESTAMP_OFFSET: usize = 5432;
+pub const RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CONFIG_INTERVAL_LENGTH_SECONDS_OFFSET: usize = 5440;
+pub const RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_OFFSET: usize = 5448;
+pub const RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CONFIG_CAPACITY_OFFSET: usize = 5448;
+pub const RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CURRENT_TOTAL_OFFSET: usize = 5456;
+pub const RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_LAST_INTERVAL_START_TIMESTAMP_OFFSET: usize = 5464;
+pub const RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CONFIG_INTERVAL_LENGTH_SECONDS_OFFSET: usize = 5472;
+pub const RESERVE_CONFIG_ELEVATION_GROUPS_OFFSET: usize = 5480;
+pub const RESERVE_CONFIG_DISABLE_USAGE_AS_COLL_OUTSIDE_EMODE_OFFSET: usize = 5500;
+pub const RESERVE_CONFIG_UTILIZATION_LIMIT_BLOCK_BORROWING_ABOVE_PCT_OFFSET: usize = 5501;
+pub const RESERVE_CONFIG_AUTODELEVERAGE_ENABLED_OFFSET: usize = 5502;
+pub const RESERVE_CONFIG_RESERVED1_OFFSET: usize = 5503;
+pub const RESERVE_CONFIG_BORROW_LIMIT_OUTSIDE_ELEVATION_GROUP_OFFSET: usize = 5504;
+pub const RESERVE_CONFIG_BORROW_LIMIT_AGAINST_THIS_COLLATERAL_IN_ELEVATION_GROUP_OFFSET: usize =
+    5512;
+pub const RESERVE_CONFIG_DELEVERAGING_BONUS_INCREASE_BPS_PER_DAY_OFFSET: usize = 5768;
+pub const RESERVE_CONFIG_PADDING_OFFSET: usize = 5776;
+pub const RESERVE_BORROWED_AMOUNT_OUTSIDE_ELEVATION_GROUP_OFFSET: usize = 6704;
+pub const RESERVE_BORROWED_AMOUNTS_AGAINST_THIS_RESERVE_IN_ELEVATION_GROUPS_OFFSET: usize = 6712;
+pub const RESERVE_PADDING_OFFSET: usize = 6968;
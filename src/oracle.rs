@@ -0,0 +1,374 @@
+//! Raw readers for the oracle accounts configured in [`TokenInfo`], and the selection of the price
+//! `refresh_reserve` would use among them.
+//!
+//! Accounts are decoded straight from their bytes so that no oracle SDK (and its `solana-program`
+//! version) has to be pulled in.
+use crate::fraction::Fraction;
+use crate::scope::{OraclePrices, TimestampedPrice, TimestampedPriceWithTwap};
+use crate::*;
+use solana_program::pubkey::Pubkey;
+
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+pub const PYTH_VERSION_2: u32 = 2;
+pub const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+pub const PYTH_PRICE_STATUS_TRADING: u32 = 1;
+pub const PYTH_PRICE_UPDATE_V2_ACCOUNT_DISCM: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+pub const SWITCHBOARD_AGGREGATOR_ACCOUNT_DISCM: [u8; 8] = [217, 230, 65, 101, 201, 162, 27, 125];
+/// A price is rejected when `confidence * ORACLE_CONFIDENCE_FACTOR > price`, i.e. when the
+/// confidence interval is wider than 2% of the price.
+pub const ORACLE_CONFIDENCE_FACTOR: u64 = 50;
+
+const PYTH_EXPO_OFFSET: usize = 20;
+const PYTH_EMA_PRICE_OFFSET: usize = 48;
+const PYTH_EMA_CONF_OFFSET: usize = 72;
+const PYTH_TIMESTAMP_OFFSET: usize = 96;
+const PYTH_AGG_PRICE_OFFSET: usize = 208;
+const PYTH_AGG_CONF_OFFSET: usize = 216;
+const PYTH_AGG_STATUS_OFFSET: usize = 224;
+const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+const SWITCHBOARD_ROUND_OPEN_TIMESTAMP_OFFSET: usize = 358;
+const SWITCHBOARD_RESULT_OFFSET: usize = 366;
+const SWITCHBOARD_STD_DEVIATION_OFFSET: usize = 386;
+const SWITCHBOARD_DECIMAL_LEN: usize = 20;
+
+/// Oracle-agnostic price: `price * 10^exponent`, with a confidence interval in the same units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OraclePrice {
+    pub price: i64,
+    pub exponent: i32,
+    pub timestamp: i64,
+    pub confidence: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OracleSource {
+    Pyth,
+    Switchboard,
+    Scope,
+}
+
+/// A Pyth price and its exponential moving average, which the program uses as TWAP.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PythPrice {
+    pub price: OraclePrice,
+    pub ema: OraclePrice,
+}
+
+/// Raw data of the oracle accounts referenced by a [`TokenInfo`]; `None` for accounts that were
+/// not fetched.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OracleAccounts<'a> {
+    pub pyth_price: Option<&'a [u8]>,
+    pub switchboard_price: Option<&'a [u8]>,
+    pub switchboard_twap: Option<&'a [u8]>,
+    pub scope_prices: Option<&'a OraclePrices>,
+}
+
+/// The price selected among the configured oracles and where it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourcedPrice {
+    pub source: OracleSource,
+    pub price: TimestampedPriceWithTwap,
+}
+
+impl OraclePrice {
+    /// Converts to a [`Fraction`]; `None` for negative prices or out-of-range exponents.
+    pub fn to_fraction(&self) -> Option<Fraction> {
+        let value = u64::try_from(self.price).ok()?;
+        if self.exponent <= 0 {
+            Fraction::from_decimal(value, self.exponent.unsigned_abs())
+        } else {
+            let multiplier = 10u64.checked_pow(self.exponent.unsigned_abs())?;
+            Fraction::from_num(value).checked_mul_int(multiplier)
+        }
+    }
+
+    pub fn to_timestamped(&self) -> Option<TimestampedPrice> {
+        Some(TimestampedPrice {
+            price: self.to_fraction()?,
+            timestamp: u64::try_from(self.timestamp).ok()?,
+        })
+    }
+
+    /// Rejects non-positive prices and confidence intervals wider than
+    /// `1 / ORACLE_CONFIDENCE_FACTOR` of the price.
+    pub fn validate_confidence(&self) -> Result<(), KaminoLendingError> {
+        let price = u64::try_from(self.price)
+            .ok()
+            .filter(|price| *price > 0)
+            .ok_or(KaminoLendingError::PriceIsZero)?;
+        let scaled_confidence = u128::from(self.confidence) * u128::from(ORACLE_CONFIDENCE_FACTOR);
+        if scaled_confidence > u128::from(price) {
+            return Err(KaminoLendingError::PriceConfidenceTooWide);
+        }
+        Ok(())
+    }
+}
+
+fn read_array<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    read_array(data, offset).map(u32::from_le_bytes)
+}
+
+fn read_i32(data: &[u8], offset: usize) -> Option<i32> {
+    read_array(data, offset).map(i32::from_le_bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    read_array(data, offset).map(u64::from_le_bytes)
+}
+
+fn read_i64(data: &[u8], offset: usize) -> Option<i64> {
+    read_array(data, offset).map(i64::from_le_bytes)
+}
+
+fn read_i128(data: &[u8], offset: usize) -> Option<i128> {
+    read_array(data, offset).map(i128::from_le_bytes)
+}
+
+/// Reads a Pyth price account, either a legacy push-oracle price account or a pull-oracle
+/// `PriceUpdateV2` account. Pull updates must be fully verified.
+pub fn read_pyth_price(data: &[u8]) -> Result<PythPrice, KaminoLendingError> {
+    if data.starts_with(&PYTH_PRICE_UPDATE_V2_ACCOUNT_DISCM) {
+        read_pyth_price_update_v2(data)
+    } else {
+        read_pyth_legacy_price(data)
+    }
+}
+
+fn read_pyth_legacy_price(data: &[u8]) -> Result<PythPrice, KaminoLendingError> {
+    let invalid = KaminoLendingError::InvalidPythPriceAccount;
+    if data.len() < PYTH_PRICE_ACCOUNT_MIN_LEN
+        || read_u32(data, 0) != Some(PYTH_MAGIC)
+        || read_u32(data, 4) != Some(PYTH_VERSION_2)
+        || read_u32(data, 8) != Some(PYTH_ACCOUNT_TYPE_PRICE)
+    {
+        return Err(invalid);
+    }
+    if read_u32(data, PYTH_AGG_STATUS_OFFSET) != Some(PYTH_PRICE_STATUS_TRADING) {
+        return Err(KaminoLendingError::PriceNotValid);
+    }
+    let exponent = read_i32(data, PYTH_EXPO_OFFSET).ok_or(invalid)?;
+    let timestamp = read_i64(data, PYTH_TIMESTAMP_OFFSET).ok_or(invalid)?;
+    let price = OraclePrice {
+        price: read_i64(data, PYTH_AGG_PRICE_OFFSET).ok_or(invalid)?,
+        exponent,
+        timestamp,
+        confidence: read_u64(data, PYTH_AGG_CONF_OFFSET).ok_or(invalid)?,
+    };
+    let ema = OraclePrice {
+        price: read_i64(data, PYTH_EMA_PRICE_OFFSET).ok_or(invalid)?,
+        exponent,
+        timestamp,
+        confidence: read_i64(data, PYTH_EMA_CONF_OFFSET)
+            .and_then(|conf| u64::try_from(conf).ok())
+            .ok_or(invalid)?,
+    };
+    Ok(PythPrice { price, ema })
+}
+
+fn read_pyth_price_update_v2(data: &[u8]) -> Result<PythPrice, KaminoLendingError> {
+    let invalid = KaminoLendingError::InvalidPythPriceAccount;
+    // discriminator, then write_authority, then the borsh-encoded VerificationLevel enum:
+    // `Partial { num_signatures: u8 }` (tag 0) or `Full` (tag 1).
+    let message = match data.get(40) {
+        Some(1) => 41,
+        Some(0) => return Err(KaminoLendingError::PriceNotValid),
+        _ => return Err(invalid),
+    };
+    // PriceFeedMessage { feed_id: [u8; 32], price: i64, conf: u64, exponent: i32,
+    // publish_time: i64, prev_publish_time: i64, ema_price: i64, ema_conf: u64 }
+    let price = read_i64(data, message + 32).ok_or(invalid)?;
+    let confidence = read_u64(data, message + 40).ok_or(invalid)?;
+    let exponent = read_i32(data, message + 48).ok_or(invalid)?;
+    let timestamp = read_i64(data, message + 52).ok_or(invalid)?;
+    let ema_price = read_i64(data, message + 68).ok_or(invalid)?;
+    let ema_confidence = read_u64(data, message + 76).ok_or(invalid)?;
+    Ok(PythPrice {
+        price: OraclePrice {
+            price,
+            exponent,
+            timestamp,
+            confidence,
+        },
+        ema: OraclePrice {
+            price: ema_price,
+            exponent,
+            timestamp,
+            confidence: ema_confidence,
+        },
+    })
+}
+
+/// Reads the latest confirmed round of a Switchboard v2 `AggregatorAccountData`, using the round's
+/// standard deviation as confidence.
+pub fn read_switchboard_price(data: &[u8]) -> Result<OraclePrice, KaminoLendingError> {
+    let invalid = KaminoLendingError::InvalidSwitchboardAccount;
+    if !data.starts_with(&SWITCHBOARD_AGGREGATOR_ACCOUNT_DISCM) {
+        return Err(invalid);
+    }
+    let timestamp = read_i64(data, SWITCHBOARD_ROUND_OPEN_TIMESTAMP_OFFSET).ok_or(invalid)?;
+    let (mantissa, scale) =
+        read_switchboard_decimal(data, SWITCHBOARD_RESULT_OFFSET).ok_or(invalid)?;
+    let (std_mantissa, std_scale) =
+        read_switchboard_decimal(data, SWITCHBOARD_STD_DEVIATION_OFFSET).ok_or(invalid)?;
+    let (price, exponent) = switchboard_decimal_to_i64(mantissa, scale)
+        .ok_or(KaminoLendingError::SwitchboardV2Error)?;
+    let confidence = rescale_switchboard_decimal(std_mantissa.unsigned_abs(), std_scale, exponent)
+        .ok_or(KaminoLendingError::SwitchboardV2Error)?;
+    Ok(OraclePrice {
+        price,
+        exponent,
+        timestamp,
+        confidence,
+    })
+}
+
+fn read_switchboard_decimal(data: &[u8], offset: usize) -> Option<(i128, u32)> {
+    data.get(offset..offset + SWITCHBOARD_DECIMAL_LEN)?;
+    Some((read_i128(data, offset)?, read_u32(data, offset + 16)?))
+}
+
+/// Drops trailing precision until `mantissa * 10^-scale` fits in an `i64` mantissa.
+fn switchboard_decimal_to_i64(mut mantissa: i128, mut scale: u32) -> Option<(i64, i32)> {
+    while i64::try_from(mantissa).is_err() {
+        scale = scale.checked_sub(1)?;
+        mantissa /= 10;
+    }
+    Some((i64::try_from(mantissa).ok()?, -i32::try_from(scale).ok()?))
+}
+
+/// Expresses `mantissa * 10^-scale` in units of `10^exponent`, rounding up.
+fn rescale_switchboard_decimal(mantissa: u128, scale: u32, exponent: i32) -> Option<u64> {
+    let target_scale = u32::try_from(-exponent).ok()?;
+    let value = if target_scale >= scale {
+        mantissa.checked_mul(10u128.checked_pow(target_scale - scale)?)?
+    } else {
+        let divisor = 10u128.checked_pow(scale - target_scale)?;
+        mantissa.div_ceil(divisor)
+    };
+    u64::try_from(value).ok()
+}
+
+impl PythConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.price != Pubkey::default()
+    }
+}
+
+impl SwitchboardConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.price_aggregator != Pubkey::default()
+    }
+}
+
+impl TokenInfo {
+    fn pyth_price_and_twap(
+        &self,
+        accounts: &OracleAccounts,
+    ) -> Result<TimestampedPriceWithTwap, KaminoLendingError> {
+        let data = accounts
+            .pyth_price
+            .ok_or(KaminoLendingError::InvalidPythPriceAccount)?;
+        let PythPrice { price, ema } = read_pyth_price(data)?;
+        price.validate_confidence()?;
+        Ok(TimestampedPriceWithTwap {
+            price: price
+                .to_timestamped()
+                .ok_or(KaminoLendingError::PriceNotValid)?,
+            twap: ema.to_timestamped(),
+        })
+    }
+
+    fn switchboard_price_and_twap(
+        &self,
+        accounts: &OracleAccounts,
+    ) -> Result<TimestampedPriceWithTwap, KaminoLendingError> {
+        let data = accounts
+            .switchboard_price
+            .ok_or(KaminoLendingError::InvalidSwitchboardAccount)?;
+        let price = read_switchboard_price(data)?;
+        price.validate_confidence()?;
+        let twap = accounts
+            .switchboard_twap
+            .and_then(|data| read_switchboard_price(data).ok())
+            .and_then(|twap| twap.to_timestamped());
+        Ok(TimestampedPriceWithTwap {
+            price: price
+                .to_timestamped()
+                .ok_or(KaminoLendingError::PriceNotValid)?,
+            twap,
+        })
+    }
+
+    fn scope_price_and_twap(
+        &self,
+        accounts: &OracleAccounts,
+    ) -> Result<TimestampedPriceWithTwap, KaminoLendingError> {
+        let prices = accounts
+            .scope_prices
+            .ok_or(KaminoLendingError::InvalidScopePriceAccount)?;
+        let price = prices.chain_price(&self.scope_configuration.price_chain)?;
+        let twap = if self.scope_configuration.has_twap() {
+            prices
+                .chain_price(&self.scope_configuration.twap_chain)
+                .ok()
+        } else {
+            None
+        };
+        Ok(TimestampedPriceWithTwap { price, twap })
+    }
+
+    /// Reads every enabled oracle and keeps the most recently updated price, like
+    /// `refresh_reserve`. On equal timestamps Pyth wins over Switchboard, which wins over Scope.
+    ///
+    /// Oracles that fail to read are skipped; [`KaminoLendingError::NoPriceFound`] is returned
+    /// when none of the enabled oracles yields a price. Price age and TWAP divergence are not
+    /// checked here.
+    pub fn most_recent_price(
+        &self,
+        accounts: &OracleAccounts,
+    ) -> Result<SourcedPrice, KaminoLendingError> {
+        let candidates = [
+            (
+                OracleSource::Pyth,
+                self.pyth_configuration.is_enabled(),
+                Self::pyth_price_and_twap as fn(&Self, &OracleAccounts) -> _,
+            ),
+            (
+                OracleSource::Switchboard,
+                self.switchboard_configuration.is_enabled(),
+                Self::switchboard_price_and_twap,
+            ),
+            (
+                OracleSource::Scope,
+                self.scope_configuration.is_enabled(),
+                Self::scope_price_and_twap,
+            ),
+        ];
+        candidates
+            .into_iter()
+            .filter(|(_, enabled, _)| *enabled)
+            .filter_map(|(source, _, read)| {
+                read(self, accounts)
+                    .ok()
+                    .map(|price| SourcedPrice { source, price })
+            })
+            .reduce(|current, next| {
+                if current.price.price.timestamp >= next.price.price.timestamp {
+                    current
+                } else {
+                    next
+                }
+            })
+            .ok_or(KaminoLendingError::NoPriceFound)
+    }
+}
@@ -0,0 +1,188 @@
+//! Program derived addresses of the lending program.
+use solana_program::pubkey::Pubkey;
+pub const LENDING_MARKET_AUTHORITY_SEED: &[u8] = b"lma";
+pub const RESERVE_LIQUIDITY_SUPPLY_SEED: &[u8] = b"reserve_liq_supply";
+pub const RESERVE_FEE_RECEIVER_SEED: &[u8] = b"fee_receiver";
+pub const RESERVE_COLLATERAL_MINT_SEED: &[u8] = b"reserve_coll_mint";
+pub const RESERVE_COLLATERAL_SUPPLY_SEED: &[u8] = b"reserve_coll_supply";
+pub const REFERRER_TOKEN_STATE_SEED: &[u8] = b"referrer_acc";
+pub const USER_METADATA_SEED: &[u8] = b"user_meta";
+pub const REFERRER_STATE_SEED: &[u8] = b"ref_state";
+pub const SHORT_URL_SEED: &[u8] = b"short_url";
+/// Authority owning the vaults and collateral mints of every reserve of `lending_market`.
+pub fn find_lending_market_authority_address_with_program_id(
+    program_id: Pubkey,
+    lending_market: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LENDING_MARKET_AUTHORITY_SEED, lending_market.as_ref()],
+        &program_id,
+    )
+}
+pub fn find_lending_market_authority_address(lending_market: &Pubkey) -> (Pubkey, u8) {
+    find_lending_market_authority_address_with_program_id(crate::ID, lending_market)
+}
+fn find_reserve_address_with_program_id(
+    program_id: Pubkey,
+    seed: &[u8],
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed, lending_market.as_ref(), mint.as_ref()], &program_id)
+}
+/// Liquidity supply vault created by `init_reserve` for `mint`.
+pub fn find_reserve_liquidity_supply_address_with_program_id(
+    program_id: Pubkey,
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_address_with_program_id(
+        program_id,
+        RESERVE_LIQUIDITY_SUPPLY_SEED,
+        lending_market,
+        mint,
+    )
+}
+pub fn find_reserve_liquidity_supply_address(
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_liquidity_supply_address_with_program_id(crate::ID, lending_market, mint)
+}
+/// Fee vault created by `init_reserve` for `mint`.
+pub fn find_reserve_fee_receiver_address_with_program_id(
+    program_id: Pubkey,
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_address_with_program_id(
+        program_id,
+        RESERVE_FEE_RECEIVER_SEED,
+        lending_market,
+        mint,
+    )
+}
+pub fn find_reserve_fee_receiver_address(lending_market: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_reserve_fee_receiver_address_with_program_id(crate::ID, lending_market, mint)
+}
+/// Collateral (cToken) mint created by `init_reserve` for `mint`.
+pub fn find_reserve_collateral_mint_address_with_program_id(
+    program_id: Pubkey,
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_address_with_program_id(
+        program_id,
+        RESERVE_COLLATERAL_MINT_SEED,
+        lending_market,
+        mint,
+    )
+}
+pub fn find_reserve_collateral_mint_address(
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_collateral_mint_address_with_program_id(crate::ID, lending_market, mint)
+}
+/// Vault holding the collateral deposited into obligations, created by `init_reserve` for `mint`.
+pub fn find_reserve_collateral_supply_address_with_program_id(
+    program_id: Pubkey,
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_address_with_program_id(
+        program_id,
+        RESERVE_COLLATERAL_SUPPLY_SEED,
+        lending_market,
+        mint,
+    )
+}
+pub fn find_reserve_collateral_supply_address(
+    lending_market: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    find_reserve_collateral_supply_address_with_program_id(crate::ID, lending_market, mint)
+}
+/// Obligation of `owner` in `lending_market` with the given `InitObligationArgs` tag and id.
+/// Plain obligations (tag 0) use the default pubkey for both seed accounts.
+pub fn find_obligation_address_with_program_id(
+    program_id: Pubkey,
+    tag: u8,
+    id: u8,
+    owner: &Pubkey,
+    lending_market: &Pubkey,
+    seed1_account: &Pubkey,
+    seed2_account: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            &[tag],
+            &[id],
+            owner.as_ref(),
+            lending_market.as_ref(),
+            seed1_account.as_ref(),
+            seed2_account.as_ref(),
+        ],
+        &program_id,
+    )
+}
+pub fn find_obligation_address(
+    tag: u8,
+    id: u8,
+    owner: &Pubkey,
+    lending_market: &Pubkey,
+    seed1_account: &Pubkey,
+    seed2_account: &Pubkey,
+) -> (Pubkey, u8) {
+    find_obligation_address_with_program_id(
+        crate::ID,
+        tag,
+        id,
+        owner,
+        lending_market,
+        seed1_account,
+        seed2_account,
+    )
+}
+pub fn find_user_metadata_address_with_program_id(
+    program_id: Pubkey,
+    owner: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_METADATA_SEED, owner.as_ref()], &program_id)
+}
+pub fn find_user_metadata_address(owner: &Pubkey) -> (Pubkey, u8) {
+    find_user_metadata_address_with_program_id(crate::ID, owner)
+}
+/// Account accumulating the referral fees `referrer` earns on `reserve`.
+pub fn find_referrer_token_state_address_with_program_id(
+    program_id: Pubkey,
+    referrer: &Pubkey,
+    reserve: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REFERRER_TOKEN_STATE_SEED,
+            referrer.as_ref(),
+            reserve.as_ref(),
+        ],
+        &program_id,
+    )
+}
+pub fn find_referrer_token_state_address(referrer: &Pubkey, reserve: &Pubkey) -> (Pubkey, u8) {
+    find_referrer_token_state_address_with_program_id(crate::ID, referrer, reserve)
+}
+pub fn find_referrer_state_address_with_program_id(
+    program_id: Pubkey,
+    referrer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRER_STATE_SEED, referrer.as_ref()], &program_id)
+}
+pub fn find_referrer_state_address(referrer: &Pubkey) -> (Pubkey, u8) {
+    find_referrer_state_address_with_program_id(crate::ID, referrer)
+}
+pub fn find_short_url_address_with_program_id(program_id: Pubkey, short_url: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SHORT_URL_SEED, short_url.as_bytes()], &program_id)
+}
+pub fn find_short_url_address(short_url: &str) -> (Pubkey, u8) {
+    find_short_url_address_with_program_id(crate::ID, short_url)
+}
@@ -0,0 +1,767 @@
+//! Plain-language previews of Kamino instructions, for wallets and signing services that show
+//! users what a transaction will do before they sign it.
+//!
+//! Accounts are picked out by their [`InstructionMeta`] names. With a [`MarketSnapshot`] holding
+//! the reserves involved, amounts are shown in whole tokens with their names and fees are included;
+//! without one, amounts stay in base units.
+use crate::fraction::Fraction;
+use crate::*;
+use borsh::BorshDeserialize;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+/// One-line description of `ix`, whose accounts are `accounts` in instruction order.
+///
+/// ```ignore
+/// // "Borrow 1,250.00 USDC from reserve <reserve> against obligation <obligation> (fee 0.1%)"
+/// let text = preview(&ix, &keys, Some(&snapshot));
+/// ```
+pub fn preview(
+    ix: &KaminoLendingProgramIx,
+    accounts: &[Pubkey],
+    snapshot: Option<&MarketSnapshot>,
+) -> String {
+    Preview {
+        meta: ix.metadata(),
+        accounts,
+        snapshot,
+    }
+    .render(ix)
+}
+
+/// [`preview`] of an undecoded instruction; fails if its data is not a Kamino instruction.
+pub fn preview_instruction(
+    ix: &Instruction,
+    snapshot: Option<&MarketSnapshot>,
+) -> std::io::Result<String> {
+    let decoded = KaminoLendingProgramIx::deserialize(&ix.data)?;
+    let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+    Ok(preview(&decoded, &accounts, snapshot))
+}
+
+struct Preview<'a> {
+    meta: &'static InstructionMeta,
+    accounts: &'a [Pubkey],
+    snapshot: Option<&'a MarketSnapshot>,
+}
+
+impl<'a> Preview<'a> {
+    fn key(&self, name: &str) -> Option<Pubkey> {
+        let index = self
+            .meta
+            .accounts
+            .iter()
+            .position(|account| account.name == name)?;
+        self.accounts.get(index).copied()
+    }
+
+    /// The account named `name` as text, `unknown` if the instruction is missing it.
+    fn account(&self, name: &str) -> String {
+        self.key(name)
+            .map_or_else(|| "unknown".to_owned(), |key| key.to_string())
+    }
+
+    fn reserve(&self, name: &str) -> ReserveRef<'a> {
+        let address = self.key(name);
+        let reserve = self
+            .snapshot
+            .zip(address)
+            .and_then(|(snapshot, address)| snapshot.reserves().get(&address));
+        ReserveRef { address, reserve }
+    }
+
+    fn render(&self, ix: &KaminoLendingProgramIx) -> String {
+        use KaminoLendingProgramIx as Ix;
+        match ix {
+            Ix::InitLendingMarket(args) => format!(
+                "Create lending market {} quoted in {}",
+                self.account("lending_market"),
+                name_str(&args.quote_currency),
+            ),
+            Ix::UpdateLendingMarket(args) => self.update_lending_market(args),
+            Ix::UpdateLendingMarketOwner => format!(
+                "Transfer ownership of lending market {} to {}",
+                self.account("lending_market"),
+                self.account("lending_market_owner_cached"),
+            ),
+            Ix::InitReserve => format!(
+                "Create reserve {} for mint {} in lending market {}",
+                self.account("reserve"),
+                self.account("reserve_liquidity_mint"),
+                self.account("lending_market"),
+            ),
+            Ix::InitFarmsForReserve(args) => format!(
+                "Create {} farm {} for {}",
+                farm_kind(args.mode),
+                self.account("farm_state"),
+                self.reserve("reserve").named(),
+            ),
+            Ix::UpdateReserveConfig(args) => self.update_reserve_config(args),
+            Ix::RedeemFees => format!(
+                "Move the accrued protocol fees of {} to its fee vault",
+                self.reserve("reserve").named(),
+            ),
+            Ix::WithdrawProtocolFee(args) => {
+                let reserve = self.reserve("reserve");
+                format!(
+                    "Withdraw {} of protocol fees from {} to {}",
+                    reserve.amount(args.amount),
+                    reserve,
+                    self.account("lending_market_owner_ata"),
+                )
+            }
+            Ix::SocializeLoss(args) => self.socialize_loss("", args.liquidity_amount),
+            Ix::SocializeLossV2(args) => {
+                self.socialize_loss("socialize_loss_accounts_", args.liquidity_amount)
+            }
+            Ix::MarkObligationForDeleveraging(args) => format!(
+                "Mark obligation {} for deleveraging down to {}% LTV",
+                self.account("obligation"),
+                args.autodeleverage_target_ltv_pct,
+            ),
+            Ix::RefreshReserve => format!("Refresh {}", self.reserve("reserve").named()),
+            Ix::RefreshReservesBatch(args) => {
+                let mut text = format!("Refresh {} reserve(s)", self.accounts.len() / 2);
+                if args.skip_price_updates {
+                    text.push_str(" without updating prices");
+                }
+                text
+            }
+            Ix::DepositReserveLiquidity(args) => {
+                let reserve = self.reserve("reserve");
+                format!(
+                    "Deposit {} into {} for cTokens",
+                    reserve.amount(args.liquidity_amount),
+                    reserve,
+                )
+            }
+            Ix::RedeemReserveCollateral(args) => {
+                let reserve = self.reserve("reserve");
+                format!(
+                    "Redeem {} from {}",
+                    reserve.collateral(args.collateral_amount),
+                    reserve,
+                )
+            }
+            Ix::InitObligation(args) => format!(
+                "Create obligation {} (tag {}, id {}) for {} in lending market {}",
+                self.account("obligation"),
+                args.args.tag,
+                args.args.id,
+                self.account("obligation_owner"),
+                self.account("lending_market"),
+            ),
+            Ix::InitObligationFarmsForReserve(args) => format!(
+                "Open the {} farm of {} for obligation {}",
+                farm_kind(args.mode),
+                self.reserve("reserve").named(),
+                self.account("obligation"),
+            ),
+            Ix::RefreshObligationFarmsForReserve(args) => format!(
+                "Refresh the {} farm stake of obligation {} in {}",
+                farm_kind(args.mode),
+                self.account("base_accounts_obligation"),
+                self.reserve("base_accounts_reserve").named(),
+            ),
+            Ix::RefreshObligation => {
+                format!("Refresh obligation {}", self.account("obligation"))
+            }
+            Ix::DepositObligationCollateral(args) => {
+                self.deposit_collateral("", args.collateral_amount)
+            }
+            Ix::DepositObligationCollateralV2(args) => {
+                self.deposit_collateral("deposit_accounts_", args.collateral_amount)
+            }
+            Ix::WithdrawObligationCollateral(args) => {
+                self.withdraw_collateral("", args.collateral_amount)
+            }
+            Ix::WithdrawObligationCollateralV2(args) => {
+                self.withdraw_collateral("withdraw_accounts_", args.collateral_amount)
+            }
+            Ix::BorrowObligationLiquidity(args) => self.borrow("", args.liquidity_amount),
+            Ix::BorrowObligationLiquidityV2(args) => {
+                self.borrow("borrow_accounts_", args.liquidity_amount)
+            }
+            Ix::RepayObligationLiquidity(args) => self.repay("", args.liquidity_amount),
+            Ix::RepayObligationLiquidityV2(args) => {
+                self.repay("repay_accounts_", args.liquidity_amount)
+            }
+            Ix::RepayAndWithdrawAndRedeem(args) => {
+                let repay = self.reserve("repay_accounts_repay_reserve");
+                let withdraw = self.reserve("withdraw_accounts_withdraw_reserve");
+                format!(
+                    "Repay {} to {} and withdraw {} from {}, for obligation {}",
+                    repay.amount(args.repay_amount),
+                    repay,
+                    withdraw.collateral(args.withdraw_collateral_amount),
+                    withdraw,
+                    self.account("repay_accounts_obligation"),
+                )
+            }
+            Ix::DepositAndWithdraw(args) => {
+                let deposit = self.reserve("deposit_accounts_reserve");
+                let withdraw = self.reserve("withdraw_accounts_withdraw_reserve");
+                format!(
+                    "Deposit {} into {} and withdraw {} from {}, for obligation {}",
+                    deposit.amount(args.liquidity_amount),
+                    deposit,
+                    withdraw.collateral(args.withdraw_collateral_amount),
+                    withdraw,
+                    self.account("deposit_accounts_obligation"),
+                )
+            }
+            Ix::DepositReserveLiquidityAndObligationCollateral(args) => {
+                self.deposit_liquidity_as_collateral("", args.liquidity_amount)
+            }
+            Ix::DepositReserveLiquidityAndObligationCollateralV2(args) => {
+                self.deposit_liquidity_as_collateral("deposit_accounts_", args.liquidity_amount)
+            }
+            Ix::WithdrawObligationCollateralAndRedeemReserveCollateral(args) => {
+                self.withdraw_and_redeem("", args.collateral_amount)
+            }
+            Ix::WithdrawObligationCollateralAndRedeemReserveCollateralV2(args) => {
+                self.withdraw_and_redeem("withdraw_accounts_", args.collateral_amount)
+            }
+            Ix::LiquidateObligationAndRedeemReserveCollateral(args) => self.liquidate(
+                "",
+                args.liquidity_amount,
+                args.min_acceptable_received_liquidity_amount,
+                args.max_allowed_ltv_override_percent,
+            ),
+            Ix::LiquidateObligationAndRedeemReserveCollateralV2(args) => self.liquidate(
+                "liquidation_accounts_",
+                args.liquidity_amount,
+                args.min_acceptable_received_liquidity_amount,
+                args.max_allowed_ltv_override_percent,
+            ),
+            Ix::FlashRepayReserveLiquidity(args) => {
+                let reserve = self.reserve("reserve");
+                format!(
+                    "Repay a flash loan of {} to {}{}, borrowed in instruction {}",
+                    reserve.amount(args.liquidity_amount),
+                    reserve,
+                    reserve.fee(ReserveFees::flash_loan_fee_rate),
+                    args.borrow_instruction_index,
+                )
+            }
+            Ix::FlashBorrowReserveLiquidity(args) => {
+                let reserve = self.reserve("reserve");
+                format!(
+                    "Flash borrow {} from {}{}, to be repaid in the same transaction",
+                    reserve.amount(args.liquidity_amount),
+                    reserve,
+                    reserve.fee(ReserveFees::flash_loan_fee_rate),
+                )
+            }
+            Ix::RequestElevationGroup(args) => {
+                let obligation = self.account("obligation");
+                if args.elevation_group == 0 {
+                    format!("Leave the elevation group of obligation {obligation}")
+                } else {
+                    format!(
+                        "Move obligation {obligation} to elevation group {}",
+                        args.elevation_group,
+                    )
+                }
+            }
+            Ix::InitReferrerTokenState => format!(
+                "Create referrer fee account {} of {} for {}",
+                self.account("referrer_token_state"),
+                self.account("referrer"),
+                self.reserve("reserve").named(),
+            ),
+            Ix::InitUserMetadata(_) => {
+                let mut text = format!(
+                    "Create user metadata {} for {}",
+                    self.account("user_metadata"),
+                    self.account("owner"),
+                );
+                if let Some(referrer) = self
+                    .key("referrer_user_metadata")
+                    .filter(|key| *key != crate::ID)
+                {
+                    text.push_str(&format!(", referred by {referrer}"));
+                }
+                text
+            }
+            Ix::WithdrawReferrerFees => format!(
+                "Withdraw the referrer fees of {} from {} to {}",
+                self.account("referrer"),
+                self.reserve("reserve").named(),
+                self.account("referrer_token_account"),
+            ),
+            Ix::InitReferrerStateAndShortUrl(args) => format!(
+                "Register referrer {} with short URL {:?}",
+                self.account("referrer"),
+                args.short_url,
+            ),
+            Ix::DeleteReferrerStateAndShortUrl => format!(
+                "Delete the referrer state and short URL of {}",
+                self.account("referrer"),
+            ),
+            Ix::IdlMissingTypes(_) => "IdlMissingTypes, which does nothing".to_owned(),
+        }
+    }
+
+    fn socialize_loss(&self, prefix: &str, liquidity_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}reserve"));
+        format!(
+            "Write off {} of bad debt of obligation {} in {}, as a loss to its depositors",
+            reserve.amount(liquidity_amount),
+            self.account(&format!("{prefix}obligation")),
+            reserve,
+        )
+    }
+
+    fn deposit_collateral(&self, prefix: &str, collateral_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}deposit_reserve"));
+        format!(
+            "Deposit {} of {} as collateral into obligation {}",
+            reserve.collateral(collateral_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+        )
+    }
+
+    fn withdraw_collateral(&self, prefix: &str, collateral_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}withdraw_reserve"));
+        format!(
+            "Withdraw {} of {} from obligation {}",
+            reserve.collateral(collateral_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+        )
+    }
+
+    fn borrow(&self, prefix: &str, liquidity_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}borrow_reserve"));
+        format!(
+            "Borrow {} from {} against obligation {}{}",
+            reserve.amount(liquidity_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+            reserve.fee(ReserveFees::borrow_fee_rate),
+        )
+    }
+
+    fn repay(&self, prefix: &str, liquidity_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}repay_reserve"));
+        format!(
+            "Repay {} to {} for obligation {}",
+            reserve.amount(liquidity_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+        )
+    }
+
+    fn deposit_liquidity_as_collateral(&self, prefix: &str, liquidity_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}reserve"));
+        format!(
+            "Deposit {} into {} as collateral for obligation {}",
+            reserve.amount(liquidity_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+        )
+    }
+
+    fn withdraw_and_redeem(&self, prefix: &str, collateral_amount: u64) -> String {
+        let reserve = self.reserve(&format!("{prefix}withdraw_reserve"));
+        format!(
+            "Withdraw {} from {} out of obligation {}",
+            reserve.collateral(collateral_amount),
+            reserve,
+            self.account(&format!("{prefix}obligation")),
+        )
+    }
+
+    fn liquidate(
+        &self,
+        prefix: &str,
+        liquidity_amount: u64,
+        min_received: u64,
+        max_allowed_ltv_override_percent: u64,
+    ) -> String {
+        let repay = self.reserve(&format!("{prefix}repay_reserve"));
+        let withdraw = self.reserve(&format!("{prefix}withdraw_reserve"));
+        let mut text = format!(
+            "Liquidate obligation {}: repay {} to {} for collateral from {}, receiving at least {}",
+            self.account(&format!("{prefix}obligation")),
+            repay.amount(liquidity_amount),
+            repay,
+            withdraw,
+            withdraw.amount(min_received),
+        );
+        if max_allowed_ltv_override_percent != 0 {
+            text.push_str(&format!(
+                " (LTV override {max_allowed_ltv_override_percent}%)"
+            ));
+        }
+        text
+    }
+
+    fn update_reserve_config(&self, args: &UpdateReserveConfigIxArgs) -> String {
+        let reserve = self.reserve("reserve");
+        let mode = u8::try_from(args.mode)
+            .ok()
+            .and_then(|tag| UpdateConfigMode::try_from_slice(&[tag]).ok());
+        let mut text = match mode {
+            Some(mode) => {
+                let (field, kind) = reserve_config_field(&mode);
+                match kind.render(&args.value, &reserve) {
+                    Some(value) => format!("Set the {field} of {} to {value}", reserve.named()),
+                    None => format!("Update the {field} of {}", reserve.named()),
+                }
+            }
+            None => format!(
+                "Update the config of {} (unknown mode {})",
+                reserve.named(),
+                args.mode,
+            ),
+        };
+        if args.skip_validation {
+            text.push_str(", skipping validation");
+        }
+        text
+    }
+
+    fn update_lending_market(&self, args: &UpdateLendingMarketIxArgs) -> String {
+        let market = self.account("lending_market");
+        let mode = u8::try_from(args.mode)
+            .ok()
+            .and_then(|tag| UpdateLendingMarketMode::try_from_slice(&[tag]).ok());
+        let Some(mode) = mode else {
+            return format!(
+                "Update lending market {market} (unknown mode {})",
+                args.mode,
+            );
+        };
+        let (field, kind) = lending_market_field(&mode);
+        let reserve = ReserveRef {
+            address: None,
+            reserve: None,
+        };
+        match kind.render(&args.value, &reserve) {
+            Some(value) => format!("Set the {field} of lending market {market} to {value}"),
+            None => format!("Update the {field} of lending market {market}"),
+        }
+    }
+}
+
+/// A reserve account of the instruction, decoded if the snapshot has it.
+struct ReserveRef<'a> {
+    address: Option<Pubkey>,
+    reserve: Option<&'a Reserve>,
+}
+
+impl ReserveRef<'_> {
+    /// Token name, or the liquidity mint if the reserve has no name.
+    fn symbol(&self) -> Option<String> {
+        let reserve = self.reserve?;
+        let name = reserve.config.token_info.name_str();
+        Some(if name.is_empty() {
+            reserve.liquidity.mint_pubkey.to_string()
+        } else {
+            name.into_owned()
+        })
+    }
+
+    fn decimals(&self) -> Option<u32> {
+        let decimals = u32::try_from(self.reserve?.liquidity.mint_decimals).ok()?;
+        10u64.checked_pow(decimals).map(|_| decimals)
+    }
+
+    /// `reserve <address> (USDC)`, for sentences without an amount naming the token.
+    fn named(&self) -> String {
+        match self.symbol() {
+            Some(symbol) => format!("{self} ({symbol})"),
+            None => self.to_string(),
+        }
+    }
+
+    /// `amount` of liquidity in whole tokens; `u64::MAX` means everything, as the program reads
+    /// it for repays and withdrawals.
+    fn amount(&self, amount: u64) -> String {
+        if amount == u64::MAX {
+            return match self.symbol() {
+                Some(symbol) => format!("all {symbol}"),
+                None => "the maximum amount".to_owned(),
+            };
+        }
+        match self.symbol().zip(self.decimals()) {
+            Some((symbol, decimals)) => {
+                format!("{} {symbol}", format_token_amount(amount, decimals))
+            }
+            None => format!("{} base units", group_thousands(u128::from(amount))),
+        }
+    }
+
+    /// `collateral_amount` cTokens, with the liquidity they are worth.
+    fn collateral(&self, collateral_amount: u64) -> String {
+        if collateral_amount == u64::MAX {
+            return match self.symbol() {
+                Some(symbol) => format!("all {symbol} collateral"),
+                None => "all collateral".to_owned(),
+            };
+        }
+        match (self.reserve, self.symbol().zip(self.decimals())) {
+            (Some(reserve), Some((symbol, decimals))) => {
+                let liquidity = reserve
+                    .collateral_to_liquidity(collateral_amount)
+                    .to_floor()
+                    .unwrap_or(u64::MAX);
+                format!(
+                    "{} {symbol} cTokens (about {} {symbol})",
+                    format_token_amount(collateral_amount, decimals),
+                    format_token_amount(liquidity, decimals),
+                )
+            }
+            _ => format!(
+                "{} cToken base units",
+                group_thousands(u128::from(collateral_amount))
+            ),
+        }
+    }
+
+    /// ` (fee 0.1%)` with the rate of `rate`, empty if the reserve is not in the snapshot.
+    fn fee(&self, rate: fn(&ReserveFees) -> Fraction) -> String {
+        match self.reserve {
+            Some(reserve) => format!(" (fee {})", format_percent(rate(&reserve.config.fees))),
+            None => String::new(),
+        }
+    }
+}
+
+impl core::fmt::Display for ReserveRef<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.address {
+            Some(address) => write!(f, "reserve {address}"),
+            None => f.write_str("an unknown reserve"),
+        }
+    }
+}
+
+/// How a config update's value is encoded and shown.
+#[derive(Clone, Copy)]
+enum ValueKind {
+    /// Integer percentage.
+    Pct,
+    Bps,
+    /// `u64` scaled fraction, such as a fee rate.
+    FeeRate,
+    /// `u128` scaled fraction, such as a market value.
+    Sf,
+    /// Liquidity amount in base units of the reserve's token, `u64::MAX` meaning no limit.
+    TokenAmount,
+    Seconds,
+    Flag,
+    Integer,
+    Pubkey,
+    Name,
+    AssetTier,
+    ReserveStatus,
+    ElevationGroup,
+    /// Structured value not shown.
+    Opaque,
+}
+
+impl ValueKind {
+    fn render(self, value: &[u8], reserve: &ReserveRef) -> Option<String> {
+        let int = || read_le(value);
+        Some(match self {
+            Self::Pct => format!("{}%", int()?),
+            Self::Bps => format!("{} bps", int()?),
+            Self::FeeRate => format_percent(Fraction::from_bits(int()?)),
+            Self::Sf => format!("{:.2}", Fraction::from_bits(int()?)),
+            Self::TokenAmount => {
+                let amount = u64::try_from(int()?).ok()?;
+                if amount == u64::MAX {
+                    "unlimited".to_owned()
+                } else {
+                    reserve.amount(amount)
+                }
+            }
+            Self::Seconds => format!("{} seconds", group_thousands(int()?)),
+            Self::Flag => if int()? == 0 { "disabled" } else { "enabled" }.to_owned(),
+            Self::Integer => group_thousands(int()?),
+            Self::Pubkey => Pubkey::try_from(value.get(..32)?).ok()?.to_string(),
+            Self::Name => format!("{:?}", name_str(value.get(..32)?)),
+            Self::AssetTier => {
+                format!("{:?}", AssetTier::from_u8(u8::try_from(int()?).ok()?)?)
+            }
+            Self::ReserveStatus => {
+                format!("{:?}", ReserveStatus::from_u8(u8::try_from(int()?).ok()?)?)
+            }
+            Self::ElevationGroup => {
+                let group = ElevationGroup::deserialize(&mut &value[..]).ok()?;
+                format!("{group}")
+            }
+            Self::Opaque => return None,
+        })
+    }
+}
+
+/// Little-endian integer of at most 16 bytes; longer values, such as the zero-padded 72-byte
+/// lending market value, are read from their first 16 bytes.
+fn read_le(value: &[u8]) -> Option<u128> {
+    if value.is_empty() {
+        return None;
+    }
+    let mut bytes = [0; 16];
+    let len = value.len().min(16);
+    bytes[..len].copy_from_slice(&value[..len]);
+    Some(u128::from_le_bytes(bytes))
+}
+
+fn reserve_config_field(mode: &UpdateConfigMode) -> (&'static str, ValueKind) {
+    use UpdateConfigMode as M;
+    use ValueKind as V;
+    match mode {
+        M::UpdateLoanToValuePct => ("LTV", V::Pct),
+        M::UpdateMaxLiquidationBonusBps => ("max liquidation bonus", V::Bps),
+        M::UpdateLiquidationThresholdPct => ("liquidation threshold", V::Pct),
+        M::UpdateProtocolLiquidationFee => ("protocol liquidation fee", V::Pct),
+        M::UpdateProtocolTakeRate => ("protocol take rate", V::Pct),
+        M::UpdateFeesBorrowFee => ("borrow fee", V::FeeRate),
+        M::UpdateFeesFlashLoanFee => ("flash loan fee", V::FeeRate),
+        M::UpdateFeesReferralFeeBps => ("referral fee", V::Bps),
+        M::UpdateDepositLimit => ("deposit limit", V::TokenAmount),
+        M::UpdateBorrowLimit => ("borrow limit", V::TokenAmount),
+        M::UpdateTokenInfoLowerHeuristic => ("lower price heuristic", V::Opaque),
+        M::UpdateTokenInfoUpperHeuristic => ("upper price heuristic", V::Opaque),
+        M::UpdateTokenInfoExpHeuristic => ("price heuristic exponent", V::Integer),
+        M::UpdateTokenInfoTwapDivergence => ("max TWAP divergence", V::Bps),
+        M::UpdateTokenInfoScopeTwap => ("Scope TWAP chain", V::Opaque),
+        M::UpdateTokenInfoScopeChain => ("Scope price chain", V::Opaque),
+        M::UpdateTokenInfoName => ("token name", V::Name),
+        M::UpdateTokenInfoPriceMaxAge => ("max price age", V::Seconds),
+        M::UpdateTokenInfoTwapMaxAge => ("max TWAP age", V::Seconds),
+        M::UpdateScopePriceFeed => ("Scope price feed", V::Pubkey),
+        M::UpdatePythPrice => ("Pyth price account", V::Pubkey),
+        M::UpdateSwitchboardFeed => ("Switchboard price feed", V::Pubkey),
+        M::UpdateSwitchboardTwapFeed => ("Switchboard TWAP feed", V::Pubkey),
+        M::UpdateBorrowRateCurve => ("borrow rate curve", V::Opaque),
+        M::UpdateEntireReserveConfig => ("entire config", V::Opaque),
+        M::UpdateDebtWithdrawalCap => ("debt withdrawal cap", V::Opaque),
+        M::UpdateDepositWithdrawalCap => ("deposit withdrawal cap", V::Opaque),
+        M::UpdateDebtWithdrawalCapCurrentTotal => ("current debt withdrawal total", V::Opaque),
+        M::UpdateDepositWithdrawalCapCurrentTotal => {
+            ("current deposit withdrawal total", V::Opaque)
+        }
+        M::UpdateBadDebtLiquidationBonusBps => ("bad debt liquidation bonus", V::Bps),
+        M::UpdateMinLiquidationBonusBps => ("min liquidation bonus", V::Bps),
+        M::UpdateDeleveragingMarginCallPeriod => ("deleveraging margin call period", V::Seconds),
+        M::UpdateBorrowFactor => ("borrow factor", V::Pct),
+        M::UpdateAssetTier => ("asset tier", V::AssetTier),
+        M::UpdateElevationGroup => ("elevation groups", V::Opaque),
+        M::UpdateDeleveragingThresholdDecreaseBpsPerDay => {
+            ("daily deleveraging threshold decrease", V::Bps)
+        }
+        M::DeprecatedUpdateMultiplierSideBoost => ("multiplier side boost", V::Opaque),
+        M::DeprecatedUpdateMultiplierTagBoost => ("multiplier tag boost", V::Opaque),
+        M::UpdateReserveStatus => ("status", V::ReserveStatus),
+        M::UpdateFarmCollateral => ("collateral farm", V::Pubkey),
+        M::UpdateFarmDebt => ("debt farm", V::Pubkey),
+        M::UpdateDisableUsageAsCollateralOutsideEmode => {
+            ("collateral use restriction to elevation groups", V::Flag)
+        }
+        M::UpdateBlockBorrowingAboveUtilizationPct => {
+            ("utilization above which borrowing is blocked", V::Pct)
+        }
+        M::UpdateBlockPriceUsage => ("price usage block", V::Flag),
+        M::UpdateBorrowLimitOutsideElevationGroup => {
+            ("borrow limit outside elevation groups", V::TokenAmount)
+        }
+        M::UpdateBorrowLimitsInElevationGroupAgainstThisReserve => (
+            "elevation group borrow limits against this reserve",
+            V::Opaque,
+        ),
+        M::UpdateHostFixedInterestRateBps => ("host fixed interest rate", V::Bps),
+        M::UpdateAutodeleverageEnabled => ("auto-deleveraging", V::Flag),
+        M::UpdateDeleveragingBonusIncreaseBpsPerDay => {
+            ("daily deleveraging bonus increase", V::Bps)
+        }
+    }
+}
+
+fn lending_market_field(mode: &UpdateLendingMarketMode) -> (&'static str, ValueKind) {
+    use UpdateLendingMarketMode as M;
+    use ValueKind as V;
+    match mode {
+        M::UpdateOwner => ("owner", V::Pubkey),
+        M::UpdateEmergencyMode => ("emergency mode", V::Flag),
+        M::UpdateLiquidationCloseFactor => ("liquidation close factor", V::Pct),
+        M::UpdateLiquidationMaxValue => ("max debt value liquidated at once", V::Integer),
+        M::DeprecatedUpdateGlobalUnhealthyBorrow => ("global unhealthy borrow value", V::Integer),
+        M::UpdateGlobalAllowedBorrow => ("global allowed borrow value", V::Integer),
+        M::UpdateRiskCouncil => ("risk council", V::Pubkey),
+        M::UpdateMinFullLiquidationThreshold => ("min full liquidation value", V::Integer),
+        M::UpdateInsolvencyRiskLtv => ("insolvency risk LTV", V::Pct),
+        M::UpdateElevationGroup => ("elevation group", V::ElevationGroup),
+        M::UpdateReferralFeeBps => ("referral fee", V::Bps),
+        M::DeprecatedUpdateMultiplierPoints => ("multiplier points", V::Opaque),
+        M::UpdatePriceRefreshTriggerToMaxAgePct => ("price refresh trigger", V::Pct),
+        M::UpdateAutodeleverageEnabled => ("auto-deleveraging", V::Flag),
+        M::UpdateBorrowingDisabled => ("borrowing block", V::Flag),
+        M::UpdateMinNetValueObligationPostAction => ("min obligation net value", V::Sf),
+        M::UpdateMinValueLtvSkipPriorityLiqCheck => {
+            ("min value for LTV liquidation priority checks", V::Integer)
+        }
+        M::UpdateMinValueBfSkipPriorityLiqCheck => (
+            "min value for borrow factor liquidation priority checks",
+            V::Integer,
+        ),
+        M::UpdatePaddingFields => ("padding", V::Opaque),
+        M::UpdateName => ("name", V::Name),
+        M::UpdateIndividualAutodeleverageMarginCallPeriodSecs => {
+            ("auto-deleveraging margin call period", V::Seconds)
+        }
+    }
+}
+
+/// `mode` argument of the farm instructions, a [`ReserveFarmKind`] index.
+fn farm_kind(mode: u8) -> &'static str {
+    match mode {
+        0 => "collateral",
+        1 => "debt",
+        _ => "unknown",
+    }
+}
+
+/// `1250000000` base units with 6 decimals as `1,250.00`: all significant decimals, at least two.
+fn format_token_amount(amount: u64, decimals: u32) -> String {
+    let factor = 10u64.pow(decimals);
+    let whole = group_thousands(u128::from(amount / factor));
+    if decimals == 0 {
+        return whole;
+    }
+    let fraction = format!("{:0width$}", amount % factor, width = decimals as usize);
+    let significant = fraction
+        .trim_end_matches('0')
+        .len()
+        .max(2)
+        .min(fraction.len());
+    format!("{whole}.{}", &fraction[..significant])
+}
+
+/// `1234567` as `1,234,567`.
+fn group_thousands(value: u128) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// `rate` as a percentage with up to four decimals, e.g. `0.1%`.
+fn format_percent(rate: Fraction) -> String {
+    let Some(percent) = rate.checked_mul_int(100) else {
+        return "overflow".to_owned();
+    };
+    let text = format!("{percent:.4}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    format!("{text}%")
+}
@@ -0,0 +1,241 @@
+//! Price validation performed by `refresh_reserve` and the resulting `LastUpdate::price_status`.
+//!
+//! A refreshed reserve records which checks its new price passed; borrowing and withdrawing
+//! require [`PriceStatusFlags::ALL_CHECKS`] while liquidations only require
+//! [`PriceStatusFlags::LIQUIDATION_CHECKS`].
+use crate::fraction::Fraction;
+use crate::scope::{TimestampedPrice, TimestampedPriceWithTwap};
+use crate::*;
+
+/// Bit set stored in `LastUpdate::price_status`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceStatusFlags(pub u8);
+
+impl PriceStatusFlags {
+    pub const NONE: Self = Self(0);
+    pub const PRICE_LOADED: Self = Self(1 << 0);
+    pub const PRICE_AGE_CHECKED: Self = Self(1 << 1);
+    pub const TWAP_CHECKED: Self = Self(1 << 2);
+    pub const TWAP_AGE_CHECKED: Self = Self(1 << 3);
+    pub const HEURISTIC_CHECKED: Self = Self(1 << 4);
+    pub const PRICE_USAGE_ALLOWED: Self = Self(1 << 5);
+    pub const ALL_CHECKS: Self = Self(0b0011_1111);
+    pub const LIQUIDATION_CHECKS: Self =
+        Self(Self::PRICE_LOADED.0 | Self::PRICE_AGE_CHECKED.0 | Self::PRICE_USAGE_ALLOWED.0);
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Flags of `required` that are not set in `self`.
+    pub const fn missing(self, required: Self) -> Self {
+        Self(required.0 & !self.0)
+    }
+}
+
+impl LastUpdate {
+    pub fn price_status_flags(&self) -> PriceStatusFlags {
+        PriceStatusFlags(self.price_status)
+    }
+
+    /// Whether the account must be refreshed before use at `current_slot`.
+    pub fn is_stale(&self, current_slot: u64) -> bool {
+        self.stale != 0 || self.slot != current_slot
+    }
+}
+
+/// The individual checks behind each [`PriceStatusFlags`] bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PriceCheck {
+    UsageAllowed,
+    PriceAge,
+    TwapAge,
+    TwapDivergence,
+    Heuristic,
+}
+
+impl PriceCheck {
+    pub const ALL: [Self; 5] = [
+        Self::UsageAllowed,
+        Self::PriceAge,
+        Self::TwapAge,
+        Self::TwapDivergence,
+        Self::Heuristic,
+    ];
+
+    pub fn flag(self) -> PriceStatusFlags {
+        match self {
+            Self::UsageAllowed => PriceStatusFlags::PRICE_USAGE_ALLOWED,
+            Self::PriceAge => PriceStatusFlags::PRICE_AGE_CHECKED,
+            Self::TwapAge => PriceStatusFlags::TWAP_AGE_CHECKED,
+            Self::TwapDivergence => PriceStatusFlags::TWAP_CHECKED,
+            Self::Heuristic => PriceStatusFlags::HEURISTIC_CHECKED,
+        }
+    }
+}
+
+/// Outcome of every check `refresh_reserve` runs on a loaded price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceValidation {
+    pub usage_allowed: Result<(), KaminoLendingError>,
+    pub price_age: Result<(), KaminoLendingError>,
+    pub twap_age: Result<(), KaminoLendingError>,
+    pub twap_divergence: Result<(), KaminoLendingError>,
+    pub heuristic: Result<(), KaminoLendingError>,
+    /// Flags the reserve would store in `LastUpdate::price_status`.
+    pub status: PriceStatusFlags,
+}
+
+impl PriceValidation {
+    pub fn result(&self, check: PriceCheck) -> Result<(), KaminoLendingError> {
+        match check {
+            PriceCheck::UsageAllowed => self.usage_allowed,
+            PriceCheck::PriceAge => self.price_age,
+            PriceCheck::TwapAge => self.twap_age,
+            PriceCheck::TwapDivergence => self.twap_divergence,
+            PriceCheck::Heuristic => self.heuristic,
+        }
+    }
+
+    /// Failed checks and the error each one maps to.
+    pub fn failures(&self) -> impl Iterator<Item = (PriceCheck, KaminoLendingError)> + '_ {
+        PriceCheck::ALL
+            .into_iter()
+            .filter_map(|check| self.result(check).err().map(|err| (check, err)))
+    }
+
+    /// Whether borrows, withdrawals and other price-dependent user actions will accept the price.
+    pub fn is_usable(&self) -> bool {
+        self.status.contains(PriceStatusFlags::ALL_CHECKS)
+    }
+
+    pub fn is_usable_for_liquidation(&self) -> bool {
+        self.status.contains(PriceStatusFlags::LIQUIDATION_CHECKS)
+    }
+}
+
+fn check_age(price: &TimestampedPrice, max_age: u64, now: i64) -> Result<(), KaminoLendingError> {
+    let now = u64::try_from(now).unwrap_or(0);
+    if price.timestamp > now {
+        return Err(KaminoLendingError::LastTimestampGreaterThanCurrent);
+    }
+    if now - price.timestamp > max_age {
+        return Err(KaminoLendingError::PriceTooOld);
+    }
+    Ok(())
+}
+
+impl PriceHeuristic {
+    pub fn is_enabled(&self) -> bool {
+        self.lower > 0 || self.upper > 0
+    }
+
+    /// Checks `price` against `lower / 10^exp` and `upper / 10^exp`; a zero bound is disabled.
+    pub fn check(&self, price: Fraction) -> Result<(), KaminoLendingError> {
+        let exp = u32::try_from(self.exp).map_err(|_| KaminoLendingError::InvalidOracleConfig)?;
+        if self.lower > 0 {
+            let lower = Fraction::from_decimal(self.lower, exp)
+                .ok_or(KaminoLendingError::InvalidOracleConfig)?;
+            if price < lower {
+                return Err(KaminoLendingError::PriceIsLowerThanHeuristic);
+            }
+        }
+        if self.upper > 0 {
+            let upper = Fraction::from_decimal(self.upper, exp)
+                .ok_or(KaminoLendingError::InvalidOracleConfig)?;
+            if price > upper {
+                return Err(KaminoLendingError::PriceIsBiggerThanHeuristic);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `price` lies within `max_divergence_bps` of `twap`.
+pub fn check_twap_divergence(
+    price: Fraction,
+    twap: Fraction,
+    max_divergence_bps: u64,
+) -> Result<(), KaminoLendingError> {
+    let tolerance = twap
+        .checked_mul(Fraction::from_bps(max_divergence_bps))
+        .ok_or(KaminoLendingError::MathOverflow)?;
+    let lower = twap.saturating_sub(tolerance);
+    let upper = twap
+        .checked_add(tolerance)
+        .ok_or(KaminoLendingError::MathOverflow)?;
+    if price < lower || price > upper {
+        return Err(KaminoLendingError::PriceTooDivergentFromTwap);
+    }
+    Ok(())
+}
+
+impl TokenInfo {
+    /// Runs the checks `refresh_reserve` applies to a freshly loaded price at unix timestamp
+    /// `now` and reports each outcome along with the resulting price status.
+    ///
+    /// When TWAP checks are disabled (`max_twap_divergence_bps == 0`) both TWAP checks pass.
+    pub fn validate_price(&self, price: &TimestampedPriceWithTwap, now: i64) -> PriceValidation {
+        let usage_allowed = if self.block_price_usage == 0 {
+            Ok(())
+        } else {
+            Err(KaminoLendingError::PriceNotValid)
+        };
+        let price_age = check_age(&price.price, self.max_age_price_seconds, now);
+        let (twap_age, twap_divergence) = if !self.is_twap_enabled() {
+            (Ok(()), Ok(()))
+        } else if let Some(twap) = &price.twap {
+            (
+                check_age(twap, self.max_age_twap_seconds, now),
+                check_twap_divergence(price.price.price, twap.price, self.max_twap_divergence_bps),
+            )
+        } else {
+            (
+                Err(KaminoLendingError::InvalidTwapPrice),
+                Err(KaminoLendingError::InvalidTwapPrice),
+            )
+        };
+        let heuristic = self.heuristic.check(price.price.price);
+
+        let mut validation = PriceValidation {
+            usage_allowed,
+            price_age,
+            twap_age,
+            twap_divergence,
+            heuristic,
+            status: PriceStatusFlags::PRICE_LOADED,
+        };
+        for check in PriceCheck::ALL {
+            if validation.result(check).is_ok() {
+                validation.status.insert(check.flag());
+            }
+        }
+        validation
+    }
+}
+
+impl Reserve {
+    /// Whether instructions at `current_slot` fail with [`KaminoLendingError::ReserveStale`]
+    /// unless preceded by `refresh_reserve`.
+    pub fn is_stale(&self, current_slot: u64) -> bool {
+        self.last_update.is_stale(current_slot)
+    }
+
+    /// Price status recorded by the last refresh.
+    pub fn price_status(&self) -> PriceStatusFlags {
+        self.last_update.price_status_flags()
+    }
+
+    /// Validates `price` against this reserve's `TokenInfo`, predicting the price status the next
+    /// `refresh_reserve` would record.
+    pub fn validate_price(&self, price: &TimestampedPriceWithTwap, now: i64) -> PriceValidation {
+        self.config.token_info.validate_price(price, now)
+    }
+}
@@ -0,0 +1,251 @@
+//! Reserve-level accounting: supply, utilization, cToken exchange rate, pricing and fees.
+use crate::fraction::Fraction;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// Read access to reserves by address, so calculators can work over any collection of decoded
+/// reserves.
+pub trait ReserveLookup {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve>;
+}
+
+impl<S: BuildHasher> ReserveLookup for HashMap<Pubkey, Reserve, S> {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        self.get(address)
+    }
+}
+
+impl ReserveLookup for BTreeMap<Pubkey, Reserve> {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        self.get(address)
+    }
+}
+
+impl ReserveLookup for [(Pubkey, Reserve)] {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        self.iter()
+            .find(|(key, _)| key == address)
+            .map(|(_, reserve)| reserve)
+    }
+}
+
+impl ReserveLookup for Vec<(Pubkey, Reserve)> {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        self.as_slice().reserve(address)
+    }
+}
+
+impl<T: ReserveLookup + ?Sized> ReserveLookup for &T {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        (**self).reserve(address)
+    }
+}
+
+impl ReserveLiquidity {
+    pub fn borrowed_amount(&self) -> Fraction {
+        Fraction::from_bits(self.borrowed_amount_sf)
+    }
+
+    pub fn market_price(&self) -> Fraction {
+        Fraction::from_bits(self.market_price_sf)
+    }
+
+    /// `10^mint_decimals`, the number of base units in one token.
+    pub fn mint_factor(&self) -> u64 {
+        u32::try_from(self.mint_decimals)
+            .ok()
+            .and_then(|decimals| 10u64.checked_pow(decimals))
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Protocol and referrer fees accrued in the reserve but not yet withdrawn, saturating at
+    /// [`Fraction::MAX`].
+    pub fn accumulated_fees(&self) -> Fraction {
+        Fraction::from_bits(self.accumulated_protocol_fees_sf)
+            .saturating_add(Fraction::from_bits(self.accumulated_referrer_fees_sf))
+            .saturating_add(Fraction::from_bits(self.pending_referrer_fees_sf))
+    }
+
+    /// Liquidity owed to depositors: available plus borrowed, minus accrued fees, saturating at
+    /// [`Fraction::MAX`].
+    pub fn total_supply(&self) -> Fraction {
+        Fraction::from_num(self.available_amount)
+            .saturating_add(self.borrowed_amount())
+            .saturating_sub(self.accumulated_fees())
+    }
+
+    /// Share of the total supply currently borrowed, between zero and one.
+    pub fn utilization_rate(&self) -> Fraction {
+        let total_supply = self.total_supply();
+        if total_supply.is_zero() {
+            return Fraction::ZERO;
+        }
+        self.borrowed_amount()
+            .checked_div(total_supply)
+            .unwrap_or(Fraction::ZERO)
+            .min(Fraction::ONE)
+    }
+
+    /// Cumulative borrow rate, using the low 128 bits of the big fraction.
+    pub fn cumulative_borrow_rate(&self) -> Fraction {
+        self.cumulative_borrow_rate_bsf.to_fraction()
+    }
+}
+
+impl BigFractionBytes {
+    /// The value as a [`Fraction`]; saturates if the upper 128 bits are in use, which no real
+    /// cumulative borrow rate reaches.
+    pub fn to_fraction(&self) -> Fraction {
+        if self.value[2] != 0 || self.value[3] != 0 {
+            return Fraction::MAX;
+        }
+        Fraction::from_bits(u128::from(self.value[0]) | (u128::from(self.value[1]) << 64))
+    }
+}
+
+impl ReserveStatus {
+    pub fn from_u8(status: u8) -> Option<Self> {
+        match status {
+            0 => Some(Self::Active),
+            1 => Some(Self::Obsolete),
+            2 => Some(Self::Hidden),
+            _ => None,
+        }
+    }
+}
+
+impl AssetTier {
+    pub fn from_u8(tier: u8) -> Option<Self> {
+        match tier {
+            0 => Some(Self::Regular),
+            1 => Some(Self::IsolatedCollateral),
+            2 => Some(Self::IsolatedDebt),
+            _ => None,
+        }
+    }
+}
+
+impl ReserveFees {
+    pub fn borrow_fee_rate(&self) -> Fraction {
+        Fraction::from_bits(u128::from(self.borrow_fee_sf))
+    }
+
+    pub fn flash_loan_fee_rate(&self) -> Fraction {
+        Fraction::from_bits(u128::from(self.flash_loan_fee_sf))
+    }
+}
+
+impl Reserve {
+    pub fn status(&self) -> Option<ReserveStatus> {
+        ReserveStatus::from_u8(self.config.status)
+    }
+
+    pub fn asset_tier(&self) -> Option<AssetTier> {
+        AssetTier::from_u8(self.config.asset_tier)
+    }
+
+    pub fn total_supply(&self) -> Fraction {
+        self.liquidity.total_supply()
+    }
+
+    pub fn utilization_rate(&self) -> Fraction {
+        self.liquidity.utilization_rate()
+    }
+
+    fn collateral_supply_and_liquidity(&self) -> Option<(u128, Fraction)> {
+        let total_supply = self.total_supply();
+        (self.collateral.mint_total_supply != 0 && !total_supply.is_zero())
+            .then(|| (u128::from(self.collateral.mint_total_supply), total_supply))
+    }
+
+    /// Liquidity redeemable for `collateral_amount` cTokens, at the current exchange rate.
+    pub fn collateral_to_liquidity(&self, collateral_amount: u64) -> Fraction {
+        match self.collateral_supply_and_liquidity() {
+            Some((collateral_supply, total_supply)) => Fraction::from_bits(
+                crate::math::mul_div_floor(
+                    total_supply.to_bits(),
+                    u128::from(collateral_amount),
+                    collateral_supply,
+                )
+                .unwrap_or(u128::MAX),
+            ),
+            None => Fraction::from_num(collateral_amount),
+        }
+    }
+
+    /// cTokens minted for depositing `liquidity_amount`, rounded down.
+    pub fn liquidity_to_collateral(&self, liquidity_amount: Fraction) -> u64 {
+        match self.collateral_supply_and_liquidity() {
+            // Both amounts are scaled by 2^60, so the quotient is a whole number of cTokens.
+            Some((collateral_supply, total_supply)) => crate::math::mul_div_floor(
+                liquidity_amount.to_bits(),
+                collateral_supply,
+                total_supply.to_bits(),
+            )
+            .and_then(|collateral| u64::try_from(collateral).ok())
+            .unwrap_or(u64::MAX),
+            None => liquidity_amount.to_floor().unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Market value of `liquidity_amount` base units at the reserve's last refreshed price.
+    pub fn market_value(&self, liquidity_amount: Fraction) -> Fraction {
+        liquidity_amount
+            .checked_mul(self.liquidity.market_price())
+            .and_then(|value| value.checked_div_int(self.liquidity.mint_factor()))
+            .unwrap_or(Fraction::MAX)
+    }
+
+    /// Liquidity amount, in base units, worth `value` at the reserve's last refreshed price.
+    pub fn liquidity_amount_for_value(&self, value: Fraction) -> Fraction {
+        let price = self.liquidity.market_price();
+        if price.is_zero() {
+            return Fraction::MAX;
+        }
+        value
+            .checked_mul_int(self.liquidity.mint_factor())
+            .and_then(|value| value.checked_div(price))
+            .unwrap_or(Fraction::MAX)
+    }
+
+    /// Multiplier applied to this reserve's debt when weighing it against collateral; debt in an
+    /// elevation group is not penalized.
+    pub fn borrow_factor(&self, in_elevation_group: bool) -> Fraction {
+        if in_elevation_group {
+            Fraction::ONE
+        } else {
+            Fraction::from_percent(self.config.borrow_factor_pct).max(Fraction::ONE)
+        }
+    }
+
+    /// Origination fee charged on top of a borrow of `amount`, or included in it with
+    /// [`FeeCalculation::Inclusive`]. A non-zero fee rate charges at least one base unit, two
+    /// when a referrer is paid.
+    pub fn borrow_fee(
+        &self,
+        amount: u64,
+        fee_calculation: FeeCalculation,
+        with_referrer: bool,
+    ) -> Result<u64, KaminoLendingError> {
+        let rate = self.config.fees.borrow_fee_rate();
+        if rate.is_zero() || amount == 0 {
+            return Ok(0);
+        }
+        let amount_f = Fraction::from_num(amount);
+        let fee = match fee_calculation {
+            FeeCalculation::Exclusive => amount_f.checked_mul(rate),
+            FeeCalculation::Inclusive => rate
+                .checked_div(rate + Fraction::ONE)
+                .and_then(|rate| amount_f.checked_mul(rate)),
+        }
+        .ok_or(KaminoLendingError::MathOverflow)?;
+        let minimum_fee = Fraction::from_num(if with_referrer { 2 } else { 1 });
+        let fee = fee.max(minimum_fee);
+        if fee >= amount_f {
+            return Err(KaminoLendingError::BorrowTooSmall);
+        }
+        fee.to_round().ok_or(KaminoLendingError::MathOverflow)
+    }
+}
@@ -0,0 +1,932 @@
+//! `resolve` constructors filling the keys of the user-facing instructions from decoded reserves
+//! and obligations.
+//!
+//! Vaults, mints and token programs come from the reserve itself, so Token-2022 reserves get
+//! their own token program. Optional accounts that do not apply (no farm on the reserve, no
+//! referrer on the obligation) are set to the lending program id, which is how anchor encodes
+//! a missing optional account. Collateral mints are always classic SPL Token mints.
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+fn optional(key: Pubkey) -> Pubkey {
+    if key == Pubkey::default() {
+        crate::ID
+    } else {
+        key
+    }
+}
+fn lending_market_authority(lending_market: &Pubkey) -> Pubkey {
+    find_lending_market_authority_address(lending_market).0
+}
+/// `(obligation_farm_user_state, reserve_farm_state)` of the reserve's `kind` farm.
+fn farm_accounts(
+    reserve: &Reserve,
+    kind: ReserveFarmKind,
+    obligation_pubkey: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    match reserve.farm(kind) {
+        Some(farm) => (
+            find_obligation_farm_user_state_address(&farm, obligation_pubkey).0,
+            farm,
+        ),
+        None => (crate::ID, crate::ID),
+    }
+}
+fn referrer_token_state(obligation: &Obligation, reserve_pubkey: &Pubkey) -> Pubkey {
+    if obligation.referrer == Pubkey::default() {
+        crate::ID
+    } else {
+        find_referrer_token_state_address(&obligation.referrer, reserve_pubkey).0
+    }
+}
+impl RefreshReserveKeys {
+    pub fn resolve(reserve: &Reserve, reserve_pubkey: &Pubkey) -> Self {
+        let token_info = &reserve.config.token_info;
+        Self {
+            reserve: *reserve_pubkey,
+            lending_market: reserve.lending_market,
+            pyth_oracle: optional(token_info.pyth_configuration.price),
+            switchboard_price_oracle: optional(
+                token_info.switchboard_configuration.price_aggregator,
+            ),
+            switchboard_twap_oracle: optional(token_info.switchboard_configuration.twap_aggregator),
+            scope_prices: optional(token_info.scope_configuration.price_feed),
+        }
+    }
+}
+impl RefreshObligationKeys {
+    /// The deposit and borrow reserves of the obligation still have to be appended as remaining
+    /// accounts, deposits first.
+    pub fn resolve(obligation: &Obligation, obligation_pubkey: &Pubkey) -> Self {
+        Self {
+            lending_market: obligation.lending_market,
+            obligation: *obligation_pubkey,
+        }
+    }
+}
+impl RequestElevationGroupKeys {
+    pub fn resolve(obligation: &Obligation, obligation_pubkey: &Pubkey) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: obligation.lending_market,
+        }
+    }
+}
+impl DepositReserveLiquidityKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        owner: &Pubkey,
+        user_source_liquidity: &Pubkey,
+        user_destination_collateral: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: *owner,
+            reserve: *reserve_pubkey,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_liquidity_supply: reserve.liquidity.supply_vault,
+            reserve_collateral_mint: reserve.collateral.mint_pubkey,
+            user_source_liquidity: *user_source_liquidity,
+            user_destination_collateral: *user_destination_collateral,
+            collateral_token_program: TOKEN_PROGRAM_ID,
+            liquidity_token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl RedeemReserveCollateralKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        owner: &Pubkey,
+        user_source_collateral: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: *owner,
+            lending_market: reserve.lending_market,
+            reserve: *reserve_pubkey,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_collateral_mint: reserve.collateral.mint_pubkey,
+            reserve_liquidity_supply: reserve.liquidity.supply_vault,
+            user_source_collateral: *user_source_collateral,
+            user_destination_liquidity: *user_destination_liquidity,
+            collateral_token_program: TOKEN_PROGRAM_ID,
+            liquidity_token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl InitUserMetadataKeys {
+    /// `owner` pays for the account; `referrer` is the owner of the referring user metadata.
+    pub fn resolve(owner: &Pubkey, referrer: Option<&Pubkey>) -> Self {
+        Self {
+            owner: *owner,
+            fee_payer: *owner,
+            user_metadata: find_user_metadata_address(owner).0,
+            referrer_user_metadata: referrer
+                .map_or(crate::ID, |referrer| find_user_metadata_address(referrer).0),
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+impl InitObligationKeys {
+    /// Plain obligations use `InitObligationArgs { tag: 0, id: 0 }` and the default pubkey for
+    /// both seed accounts.
+    pub fn resolve(
+        owner: &Pubkey,
+        lending_market: &Pubkey,
+        args: &InitObligationArgs,
+        seed1_account: &Pubkey,
+        seed2_account: &Pubkey,
+    ) -> Self {
+        Self {
+            obligation_owner: *owner,
+            fee_payer: *owner,
+            obligation: find_obligation_address(
+                args.tag,
+                args.id,
+                owner,
+                lending_market,
+                seed1_account,
+                seed2_account,
+            )
+            .0,
+            lending_market: *lending_market,
+            seed1_account: *seed1_account,
+            seed2_account: *seed2_account,
+            owner_user_metadata: find_user_metadata_address(owner).0,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+impl InitObligationFarmsForReserveKeys {
+    /// `None` if the reserve has no `kind` farm.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        kind: ReserveFarmKind,
+    ) -> Option<Self> {
+        let farm = reserve.farm(kind)?;
+        Some(Self {
+            payer: obligation.owner,
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            reserve: *reserve_pubkey,
+            reserve_farm_state: farm,
+            obligation_farm: find_obligation_farm_user_state_address(&farm, obligation_pubkey).0,
+            lending_market: reserve.lending_market,
+            farms_program: FARMS_PROGRAM_ID,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        })
+    }
+}
+impl RefreshObligationFarmsForReserveKeys {
+    /// `None` if the reserve has no `kind` farm.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation_pubkey: &Pubkey,
+        kind: ReserveFarmKind,
+        crank: &Pubkey,
+    ) -> Option<Self> {
+        let farm = reserve.farm(kind)?;
+        Some(Self {
+            crank: *crank,
+            base_accounts_obligation: *obligation_pubkey,
+            base_accounts_lending_market_authority: lending_market_authority(
+                &reserve.lending_market,
+            ),
+            base_accounts_reserve: *reserve_pubkey,
+            base_accounts_reserve_farm_state: farm,
+            base_accounts_obligation_farm_user_state: find_obligation_farm_user_state_address(
+                &farm,
+                obligation_pubkey,
+            )
+            .0,
+            base_accounts_lending_market: reserve.lending_market,
+            farms_program: FARMS_PROGRAM_ID,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        })
+    }
+}
+impl DepositObligationCollateralKeys {
+    /// `user_source_collateral` holds the reserve's collateral tokens.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_collateral: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            deposit_reserve: *reserve_pubkey,
+            reserve_destination_collateral: reserve.collateral.supply_vault,
+            user_source_collateral: *user_source_collateral,
+            token_program: TOKEN_PROGRAM_ID,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl DepositObligationCollateralV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_collateral: &Pubkey,
+    ) -> Self {
+        let keys = DepositObligationCollateralKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_source_collateral,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Collateral, obligation_pubkey);
+        Self {
+            deposit_accounts_owner: keys.owner,
+            deposit_accounts_obligation: keys.obligation,
+            deposit_accounts_lending_market: keys.lending_market,
+            deposit_accounts_deposit_reserve: keys.deposit_reserve,
+            deposit_accounts_reserve_destination_collateral: keys.reserve_destination_collateral,
+            deposit_accounts_user_source_collateral: keys.user_source_collateral,
+            deposit_accounts_token_program: keys.token_program,
+            deposit_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl WithdrawObligationCollateralKeys {
+    /// `user_destination_collateral` receives the reserve's collateral tokens.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_collateral: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            withdraw_reserve: *reserve_pubkey,
+            reserve_source_collateral: reserve.collateral.supply_vault,
+            user_destination_collateral: *user_destination_collateral,
+            token_program: TOKEN_PROGRAM_ID,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl WithdrawObligationCollateralV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_collateral: &Pubkey,
+    ) -> Self {
+        let keys = WithdrawObligationCollateralKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_destination_collateral,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Collateral, obligation_pubkey);
+        Self {
+            withdraw_accounts_owner: keys.owner,
+            withdraw_accounts_obligation: keys.obligation,
+            withdraw_accounts_lending_market: keys.lending_market,
+            withdraw_accounts_lending_market_authority: keys.lending_market_authority,
+            withdraw_accounts_withdraw_reserve: keys.withdraw_reserve,
+            withdraw_accounts_reserve_source_collateral: keys.reserve_source_collateral,
+            withdraw_accounts_user_destination_collateral: keys.user_destination_collateral,
+            withdraw_accounts_token_program: keys.token_program,
+            withdraw_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl BorrowObligationLiquidityKeys {
+    /// The referrer token state is the obligation referrer's one for this reserve, if any.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            borrow_reserve: *reserve_pubkey,
+            borrow_reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_source_liquidity: reserve.liquidity.supply_vault,
+            borrow_reserve_liquidity_fee_receiver: reserve.liquidity.fee_vault,
+            user_destination_liquidity: *user_destination_liquidity,
+            referrer_token_state: referrer_token_state(obligation, reserve_pubkey),
+            token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl BorrowObligationLiquidityV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        let keys = BorrowObligationLiquidityKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_destination_liquidity,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Debt, obligation_pubkey);
+        Self {
+            borrow_accounts_owner: keys.owner,
+            borrow_accounts_obligation: keys.obligation,
+            borrow_accounts_lending_market: keys.lending_market,
+            borrow_accounts_lending_market_authority: keys.lending_market_authority,
+            borrow_accounts_borrow_reserve: keys.borrow_reserve,
+            borrow_accounts_borrow_reserve_liquidity_mint: keys.borrow_reserve_liquidity_mint,
+            borrow_accounts_reserve_source_liquidity: keys.reserve_source_liquidity,
+            borrow_accounts_borrow_reserve_liquidity_fee_receiver: keys
+                .borrow_reserve_liquidity_fee_receiver,
+            borrow_accounts_user_destination_liquidity: keys.user_destination_liquidity,
+            borrow_accounts_referrer_token_state: keys.referrer_token_state,
+            borrow_accounts_token_program: keys.token_program,
+            borrow_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl RepayObligationLiquidityKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            repay_reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_destination_liquidity: reserve.liquidity.supply_vault,
+            user_source_liquidity: *user_source_liquidity,
+            token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl RepayObligationLiquidityV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+    ) -> Self {
+        let keys = RepayObligationLiquidityKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_source_liquidity,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Debt, obligation_pubkey);
+        Self {
+            repay_accounts_owner: keys.owner,
+            repay_accounts_obligation: keys.obligation,
+            repay_accounts_lending_market: keys.lending_market,
+            repay_accounts_repay_reserve: keys.repay_reserve,
+            repay_accounts_reserve_liquidity_mint: keys.reserve_liquidity_mint,
+            repay_accounts_reserve_destination_liquidity: keys.reserve_destination_liquidity,
+            repay_accounts_user_source_liquidity: keys.user_source_liquidity,
+            repay_accounts_token_program: keys.token_program,
+            repay_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl DepositReserveLiquidityAndObligationCollateralKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_liquidity_supply: reserve.liquidity.supply_vault,
+            reserve_collateral_mint: reserve.collateral.mint_pubkey,
+            reserve_destination_deposit_collateral: reserve.collateral.supply_vault,
+            user_source_liquidity: *user_source_liquidity,
+            placeholder_user_destination_collateral: crate::ID,
+            collateral_token_program: TOKEN_PROGRAM_ID,
+            liquidity_token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl DepositReserveLiquidityAndObligationCollateralV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+    ) -> Self {
+        let keys = DepositReserveLiquidityAndObligationCollateralKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_source_liquidity,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Collateral, obligation_pubkey);
+        Self {
+            deposit_accounts_owner: keys.owner,
+            deposit_accounts_obligation: keys.obligation,
+            deposit_accounts_lending_market: keys.lending_market,
+            deposit_accounts_lending_market_authority: keys.lending_market_authority,
+            deposit_accounts_reserve: keys.reserve,
+            deposit_accounts_reserve_liquidity_mint: keys.reserve_liquidity_mint,
+            deposit_accounts_reserve_liquidity_supply: keys.reserve_liquidity_supply,
+            deposit_accounts_reserve_collateral_mint: keys.reserve_collateral_mint,
+            deposit_accounts_reserve_destination_deposit_collateral: keys
+                .reserve_destination_deposit_collateral,
+            deposit_accounts_user_source_liquidity: keys.user_source_liquidity,
+            deposit_accounts_placeholder_user_destination_collateral: keys
+                .placeholder_user_destination_collateral,
+            deposit_accounts_collateral_token_program: keys.collateral_token_program,
+            deposit_accounts_liquidity_token_program: keys.liquidity_token_program,
+            deposit_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl WithdrawObligationCollateralAndRedeemReserveCollateralKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            owner: obligation.owner,
+            obligation: *obligation_pubkey,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            withdraw_reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_source_collateral: reserve.collateral.supply_vault,
+            reserve_collateral_mint: reserve.collateral.mint_pubkey,
+            reserve_liquidity_supply: reserve.liquidity.supply_vault,
+            user_destination_liquidity: *user_destination_liquidity,
+            placeholder_user_destination_collateral: crate::ID,
+            collateral_token_program: TOKEN_PROGRAM_ID,
+            liquidity_token_program: reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl WithdrawObligationCollateralAndRedeemReserveCollateralV2Keys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        let keys = WithdrawObligationCollateralAndRedeemReserveCollateralKeys::resolve(
+            reserve,
+            reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_destination_liquidity,
+        );
+        let (obligation_farm_user_state, reserve_farm_state) =
+            farm_accounts(reserve, ReserveFarmKind::Collateral, obligation_pubkey);
+        Self {
+            withdraw_accounts_owner: keys.owner,
+            withdraw_accounts_obligation: keys.obligation,
+            withdraw_accounts_lending_market: keys.lending_market,
+            withdraw_accounts_lending_market_authority: keys.lending_market_authority,
+            withdraw_accounts_withdraw_reserve: keys.withdraw_reserve,
+            withdraw_accounts_reserve_liquidity_mint: keys.reserve_liquidity_mint,
+            withdraw_accounts_reserve_source_collateral: keys.reserve_source_collateral,
+            withdraw_accounts_reserve_collateral_mint: keys.reserve_collateral_mint,
+            withdraw_accounts_reserve_liquidity_supply: keys.reserve_liquidity_supply,
+            withdraw_accounts_user_destination_liquidity: keys.user_destination_liquidity,
+            withdraw_accounts_placeholder_user_destination_collateral: keys
+                .placeholder_user_destination_collateral,
+            withdraw_accounts_collateral_token_program: keys.collateral_token_program,
+            withdraw_accounts_liquidity_token_program: keys.liquidity_token_program,
+            withdraw_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            farms_accounts_obligation_farm_user_state: obligation_farm_user_state,
+            farms_accounts_reserve_farm_state: reserve_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl RepayAndWithdrawAndRedeemKeys {
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve(
+        repay_reserve: &Reserve,
+        repay_reserve_pubkey: &Pubkey,
+        withdraw_reserve: &Reserve,
+        withdraw_reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        let repay = RepayObligationLiquidityKeys::resolve(
+            repay_reserve,
+            repay_reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_source_liquidity,
+        );
+        let withdraw = WithdrawObligationCollateralAndRedeemReserveCollateralKeys::resolve(
+            withdraw_reserve,
+            withdraw_reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_destination_liquidity,
+        );
+        let (collateral_farm_user_state, collateral_farm_state) = farm_accounts(
+            withdraw_reserve,
+            ReserveFarmKind::Collateral,
+            obligation_pubkey,
+        );
+        let (debt_farm_user_state, debt_farm_state) =
+            farm_accounts(repay_reserve, ReserveFarmKind::Debt, obligation_pubkey);
+        Self {
+            repay_accounts_owner: repay.owner,
+            repay_accounts_obligation: repay.obligation,
+            repay_accounts_lending_market: repay.lending_market,
+            repay_accounts_repay_reserve: repay.repay_reserve,
+            repay_accounts_reserve_liquidity_mint: repay.reserve_liquidity_mint,
+            repay_accounts_reserve_destination_liquidity: repay.reserve_destination_liquidity,
+            repay_accounts_user_source_liquidity: repay.user_source_liquidity,
+            repay_accounts_token_program: repay.token_program,
+            repay_accounts_instruction_sysvar_account: repay.instruction_sysvar_account,
+            withdraw_accounts_owner: withdraw.owner,
+            withdraw_accounts_obligation: withdraw.obligation,
+            withdraw_accounts_lending_market: withdraw.lending_market,
+            withdraw_accounts_lending_market_authority: withdraw.lending_market_authority,
+            withdraw_accounts_withdraw_reserve: withdraw.withdraw_reserve,
+            withdraw_accounts_reserve_liquidity_mint: withdraw.reserve_liquidity_mint,
+            withdraw_accounts_reserve_source_collateral: withdraw.reserve_source_collateral,
+            withdraw_accounts_reserve_collateral_mint: withdraw.reserve_collateral_mint,
+            withdraw_accounts_reserve_liquidity_supply: withdraw.reserve_liquidity_supply,
+            withdraw_accounts_user_destination_liquidity: withdraw.user_destination_liquidity,
+            withdraw_accounts_placeholder_user_destination_collateral: withdraw
+                .placeholder_user_destination_collateral,
+            withdraw_accounts_collateral_token_program: withdraw.collateral_token_program,
+            withdraw_accounts_liquidity_token_program: withdraw.liquidity_token_program,
+            withdraw_accounts_instruction_sysvar_account: withdraw.instruction_sysvar_account,
+            collateral_farms_accounts_obligation_farm_user_state: collateral_farm_user_state,
+            collateral_farms_accounts_reserve_farm_state: collateral_farm_state,
+            debt_farms_accounts_obligation_farm_user_state: debt_farm_user_state,
+            debt_farms_accounts_reserve_farm_state: debt_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl DepositAndWithdrawKeys {
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve(
+        deposit_reserve: &Reserve,
+        deposit_reserve_pubkey: &Pubkey,
+        withdraw_reserve: &Reserve,
+        withdraw_reserve_pubkey: &Pubkey,
+        obligation: &Obligation,
+        obligation_pubkey: &Pubkey,
+        user_source_liquidity: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        let deposit = DepositReserveLiquidityAndObligationCollateralKeys::resolve(
+            deposit_reserve,
+            deposit_reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_source_liquidity,
+        );
+        let withdraw = WithdrawObligationCollateralAndRedeemReserveCollateralKeys::resolve(
+            withdraw_reserve,
+            withdraw_reserve_pubkey,
+            obligation,
+            obligation_pubkey,
+            user_destination_liquidity,
+        );
+        let (deposit_farm_user_state, deposit_farm_state) = farm_accounts(
+            deposit_reserve,
+            ReserveFarmKind::Collateral,
+            obligation_pubkey,
+        );
+        let (withdraw_farm_user_state, withdraw_farm_state) = farm_accounts(
+            withdraw_reserve,
+            ReserveFarmKind::Collateral,
+            obligation_pubkey,
+        );
+        Self {
+            deposit_accounts_owner: deposit.owner,
+            deposit_accounts_obligation: deposit.obligation,
+            deposit_accounts_lending_market: deposit.lending_market,
+            deposit_accounts_lending_market_authority: deposit.lending_market_authority,
+            deposit_accounts_reserve: deposit.reserve,
+            deposit_accounts_reserve_liquidity_mint: deposit.reserve_liquidity_mint,
+            deposit_accounts_reserve_liquidity_supply: deposit.reserve_liquidity_supply,
+            deposit_accounts_reserve_collateral_mint: deposit.reserve_collateral_mint,
+            deposit_accounts_reserve_destination_deposit_collateral: deposit
+                .reserve_destination_deposit_collateral,
+            deposit_accounts_user_source_liquidity: deposit.user_source_liquidity,
+            deposit_accounts_placeholder_user_destination_collateral: deposit
+                .placeholder_user_destination_collateral,
+            deposit_accounts_collateral_token_program: deposit.collateral_token_program,
+            deposit_accounts_liquidity_token_program: deposit.liquidity_token_program,
+            deposit_accounts_instruction_sysvar_account: deposit.instruction_sysvar_account,
+            withdraw_accounts_owner: withdraw.owner,
+            withdraw_accounts_obligation: withdraw.obligation,
+            withdraw_accounts_lending_market: withdraw.lending_market,
+            withdraw_accounts_lending_market_authority: withdraw.lending_market_authority,
+            withdraw_accounts_withdraw_reserve: withdraw.withdraw_reserve,
+            withdraw_accounts_reserve_liquidity_mint: withdraw.reserve_liquidity_mint,
+            withdraw_accounts_reserve_source_collateral: withdraw.reserve_source_collateral,
+            withdraw_accounts_reserve_collateral_mint: withdraw.reserve_collateral_mint,
+            withdraw_accounts_reserve_liquidity_supply: withdraw.reserve_liquidity_supply,
+            withdraw_accounts_user_destination_liquidity: withdraw.user_destination_liquidity,
+            withdraw_accounts_placeholder_user_destination_collateral: withdraw
+                .placeholder_user_destination_collateral,
+            withdraw_accounts_collateral_token_program: withdraw.collateral_token_program,
+            withdraw_accounts_liquidity_token_program: withdraw.liquidity_token_program,
+            withdraw_accounts_instruction_sysvar_account: withdraw.instruction_sysvar_account,
+            deposit_farms_accounts_obligation_farm_user_state: deposit_farm_user_state,
+            deposit_farms_accounts_reserve_farm_state: deposit_farm_state,
+            withdraw_farms_accounts_obligation_farm_user_state: withdraw_farm_user_state,
+            withdraw_farms_accounts_reserve_farm_state: withdraw_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+/// Token accounts of the liquidator: the repaid liquidity comes from `source_liquidity`, the
+/// seized collateral is redeemed into `destination_liquidity`, and whatever cannot be redeemed
+/// lands in `destination_collateral`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LiquidatorTokenAccounts {
+    pub liquidator: Pubkey,
+    pub source_liquidity: Pubkey,
+    pub destination_collateral: Pubkey,
+    pub destination_liquidity: Pubkey,
+}
+impl LiquidateObligationAndRedeemReserveCollateralKeys {
+    pub fn resolve(
+        repay_reserve: &Reserve,
+        repay_reserve_pubkey: &Pubkey,
+        withdraw_reserve: &Reserve,
+        withdraw_reserve_pubkey: &Pubkey,
+        obligation_pubkey: &Pubkey,
+        liquidator: &LiquidatorTokenAccounts,
+    ) -> Self {
+        Self {
+            liquidator: liquidator.liquidator,
+            obligation: *obligation_pubkey,
+            lending_market: withdraw_reserve.lending_market,
+            lending_market_authority: lending_market_authority(&withdraw_reserve.lending_market),
+            repay_reserve: *repay_reserve_pubkey,
+            repay_reserve_liquidity_mint: repay_reserve.liquidity.mint_pubkey,
+            repay_reserve_liquidity_supply: repay_reserve.liquidity.supply_vault,
+            withdraw_reserve: *withdraw_reserve_pubkey,
+            withdraw_reserve_liquidity_mint: withdraw_reserve.liquidity.mint_pubkey,
+            withdraw_reserve_collateral_mint: withdraw_reserve.collateral.mint_pubkey,
+            withdraw_reserve_collateral_supply: withdraw_reserve.collateral.supply_vault,
+            withdraw_reserve_liquidity_supply: withdraw_reserve.liquidity.supply_vault,
+            withdraw_reserve_liquidity_fee_receiver: withdraw_reserve.liquidity.fee_vault,
+            user_source_liquidity: liquidator.source_liquidity,
+            user_destination_collateral: liquidator.destination_collateral,
+            user_destination_liquidity: liquidator.destination_liquidity,
+            collateral_token_program: TOKEN_PROGRAM_ID,
+            repay_liquidity_token_program: repay_reserve.liquidity.token_program,
+            withdraw_liquidity_token_program: withdraw_reserve.liquidity.token_program,
+            instruction_sysvar_account: sysvar::instructions::ID,
+        }
+    }
+}
+impl LiquidateObligationAndRedeemReserveCollateralV2Keys {
+    pub fn resolve(
+        repay_reserve: &Reserve,
+        repay_reserve_pubkey: &Pubkey,
+        withdraw_reserve: &Reserve,
+        withdraw_reserve_pubkey: &Pubkey,
+        obligation_pubkey: &Pubkey,
+        liquidator: &LiquidatorTokenAccounts,
+    ) -> Self {
+        let keys = LiquidateObligationAndRedeemReserveCollateralKeys::resolve(
+            repay_reserve,
+            repay_reserve_pubkey,
+            withdraw_reserve,
+            withdraw_reserve_pubkey,
+            obligation_pubkey,
+            liquidator,
+        );
+        let (collateral_farm_user_state, collateral_farm_state) = farm_accounts(
+            withdraw_reserve,
+            ReserveFarmKind::Collateral,
+            obligation_pubkey,
+        );
+        let (debt_farm_user_state, debt_farm_state) =
+            farm_accounts(repay_reserve, ReserveFarmKind::Debt, obligation_pubkey);
+        Self {
+            liquidation_accounts_liquidator: keys.liquidator,
+            liquidation_accounts_obligation: keys.obligation,
+            liquidation_accounts_lending_market: keys.lending_market,
+            liquidation_accounts_lending_market_authority: keys.lending_market_authority,
+            liquidation_accounts_repay_reserve: keys.repay_reserve,
+            liquidation_accounts_repay_reserve_liquidity_mint: keys.repay_reserve_liquidity_mint,
+            liquidation_accounts_repay_reserve_liquidity_supply: keys
+                .repay_reserve_liquidity_supply,
+            liquidation_accounts_withdraw_reserve: keys.withdraw_reserve,
+            liquidation_accounts_withdraw_reserve_liquidity_mint: keys
+                .withdraw_reserve_liquidity_mint,
+            liquidation_accounts_withdraw_reserve_collateral_mint: keys
+                .withdraw_reserve_collateral_mint,
+            liquidation_accounts_withdraw_reserve_collateral_supply: keys
+                .withdraw_reserve_collateral_supply,
+            liquidation_accounts_withdraw_reserve_liquidity_supply: keys
+                .withdraw_reserve_liquidity_supply,
+            liquidation_accounts_withdraw_reserve_liquidity_fee_receiver: keys
+                .withdraw_reserve_liquidity_fee_receiver,
+            liquidation_accounts_user_source_liquidity: keys.user_source_liquidity,
+            liquidation_accounts_user_destination_collateral: keys.user_destination_collateral,
+            liquidation_accounts_user_destination_liquidity: keys.user_destination_liquidity,
+            liquidation_accounts_collateral_token_program: keys.collateral_token_program,
+            liquidation_accounts_repay_liquidity_token_program: keys.repay_liquidity_token_program,
+            liquidation_accounts_withdraw_liquidity_token_program: keys
+                .withdraw_liquidity_token_program,
+            liquidation_accounts_instruction_sysvar_account: keys.instruction_sysvar_account,
+            collateral_farms_accounts_obligation_farm_user_state: collateral_farm_user_state,
+            collateral_farms_accounts_reserve_farm_state: collateral_farm_state,
+            debt_farms_accounts_obligation_farm_user_state: debt_farm_user_state,
+            debt_farms_accounts_reserve_farm_state: debt_farm_state,
+            farms_program: FARMS_PROGRAM_ID,
+        }
+    }
+}
+impl FlashBorrowReserveLiquidityKeys {
+    /// Flash loans made without a referrer.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        user_transfer_authority: &Pubkey,
+        user_destination_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            user_transfer_authority: *user_transfer_authority,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            lending_market: reserve.lending_market,
+            reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_source_liquidity: reserve.liquidity.supply_vault,
+            user_destination_liquidity: *user_destination_liquidity,
+            reserve_liquidity_fee_receiver: reserve.liquidity.fee_vault,
+            referrer_token_state: crate::ID,
+            referrer_account: crate::ID,
+            sysvar_info: sysvar::instructions::ID,
+            token_program: reserve.liquidity.token_program,
+        }
+    }
+}
+impl FlashRepayReserveLiquidityKeys {
+    /// Flash loans made without a referrer.
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        user_transfer_authority: &Pubkey,
+        user_source_liquidity: &Pubkey,
+    ) -> Self {
+        Self {
+            user_transfer_authority: *user_transfer_authority,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            lending_market: reserve.lending_market,
+            reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_destination_liquidity: reserve.liquidity.supply_vault,
+            user_source_liquidity: *user_source_liquidity,
+            reserve_liquidity_fee_receiver: reserve.liquidity.fee_vault,
+            referrer_token_state: crate::ID,
+            referrer_account: crate::ID,
+            sysvar_info: sysvar::instructions::ID,
+            token_program: reserve.liquidity.token_program,
+        }
+    }
+}
+impl InitReferrerTokenStateKeys {
+    /// `referrer` pays for the account.
+    pub fn resolve(reserve: &Reserve, reserve_pubkey: &Pubkey, referrer: &Pubkey) -> Self {
+        Self {
+            payer: *referrer,
+            lending_market: reserve.lending_market,
+            reserve: *reserve_pubkey,
+            referrer: *referrer,
+            referrer_token_state: find_referrer_token_state_address(referrer, reserve_pubkey).0,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+impl WithdrawReferrerFeesKeys {
+    pub fn resolve(
+        reserve: &Reserve,
+        reserve_pubkey: &Pubkey,
+        referrer: &Pubkey,
+        referrer_token_account: &Pubkey,
+    ) -> Self {
+        Self {
+            referrer: *referrer,
+            referrer_token_state: find_referrer_token_state_address(referrer, reserve_pubkey).0,
+            reserve: *reserve_pubkey,
+            reserve_liquidity_mint: reserve.liquidity.mint_pubkey,
+            reserve_supply_liquidity: reserve.liquidity.supply_vault,
+            referrer_token_account: *referrer_token_account,
+            lending_market: reserve.lending_market,
+            lending_market_authority: lending_market_authority(&reserve.lending_market),
+            token_program: reserve.liquidity.token_program,
+        }
+    }
+}
+impl InitReferrerStateAndShortUrlKeys {
+    pub fn resolve(referrer: &Pubkey, short_url: &str) -> Self {
+        Self {
+            referrer: *referrer,
+            referrer_state: find_referrer_state_address(referrer).0,
+            referrer_short_url: find_short_url_address(short_url).0,
+            referrer_user_metadata: find_user_metadata_address(referrer).0,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
+impl DeleteReferrerStateAndShortUrlKeys {
+    pub fn resolve(referrer_state: &ReferrerState) -> Self {
+        Self {
+            referrer: referrer_state.owner,
+            referrer_state: find_referrer_state_address(&referrer_state.owner).0,
+            short_url: referrer_state.short_url,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+        }
+    }
+}
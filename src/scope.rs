@@ -0,0 +1,178 @@
+//! Scope oracle prices account and the reserve price chains resolved against it.
+//!
+//! `TokenInfo::scope_configuration` names an `OraclePrices` account and up to four price indices
+//! per chain; the reserve price is the product of the prices along the chain (e.g. `mSOL/SOL *
+//! SOL/USD`), unused slots being set to `u16::MAX`.
+use crate::fraction::Fraction;
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+pub const SCOPE_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("HFn8GnPADiny6XqUoWE8uRPPxb29ikn4yTuPa9MF2fWJ");
+pub const SCOPE_MAX_ENTRIES: usize = 512;
+pub const SCOPE_CHAIN_SENTINEL: u16 = u16::MAX;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScopePrice {
+    pub value: u64,
+    pub exp: u64,
+}
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatedPrice {
+    pub price: ScopePrice,
+    pub last_updated_slot: u64,
+    pub unix_timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub reserved: [u64; 2],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
+    pub reserved2: [u16; 3],
+    pub index: u16,
+}
+pub const ORACLE_PRICES_ACCOUNT_DISCM: [u8; 8] = [89, 128, 118, 221, 6, 72, 180, 146];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OraclePrices {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub oracle_mappings: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::big_array"))]
+    pub prices: [DatedPrice; 512],
+}
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OraclePricesAccount(pub OraclePrices);
+impl OraclePricesAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ORACLE_PRICES_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORACLE_PRICES_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(OraclePrices::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ORACLE_PRICES_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// A resolved price and the unix timestamp of its oldest component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedPrice {
+    pub price: Fraction,
+    pub timestamp: u64,
+}
+impl TimestampedPrice {
+    /// Seconds elapsed between the price's timestamp and `now`, zero if the price is from the
+    /// future.
+    pub fn age(&self, now: i64) -> u64 {
+        u64::try_from(now)
+            .unwrap_or(0)
+            .saturating_sub(self.timestamp)
+    }
+}
+/// Scope price and, when a TWAP chain is configured, TWAP of a reserve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedPriceWithTwap {
+    pub price: TimestampedPrice,
+    pub twap: Option<TimestampedPrice>,
+}
+impl ScopePrice {
+    pub fn to_fraction(self) -> Option<Fraction> {
+        Fraction::from_decimal(self.value, u32::try_from(self.exp).ok()?)
+    }
+}
+impl OraclePrices {
+    /// Multiplies the prices along `chain`, stopping at the first [`SCOPE_CHAIN_SENTINEL`].
+    pub fn chain_price(&self, chain: &[u16; 4]) -> Result<TimestampedPrice, KaminoLendingError> {
+        let mut links = chain
+            .iter()
+            .take_while(|id| **id != SCOPE_CHAIN_SENTINEL)
+            .map(|id| {
+                self.prices
+                    .get(usize::from(*id))
+                    .ok_or(KaminoLendingError::InvalidOracleConfig)
+            })
+            .peekable();
+        if links.peek().is_none() {
+            return Err(KaminoLendingError::NoPriceFound);
+        }
+        let mut price = Fraction::ONE;
+        let mut timestamp = u64::MAX;
+        for link in links {
+            let link = link?;
+            if link.price.value == 0 {
+                return Err(KaminoLendingError::PriceIsZero);
+            }
+            let link_price = link
+                .price
+                .to_fraction()
+                .ok_or(KaminoLendingError::MathOverflow)?;
+            price = price
+                .checked_mul(link_price)
+                .ok_or(KaminoLendingError::MathOverflow)?;
+            timestamp = timestamp.min(link.unix_timestamp);
+        }
+        if price.is_zero() {
+            return Err(KaminoLendingError::PriceIsZero);
+        }
+        Ok(TimestampedPrice { price, timestamp })
+    }
+}
+impl ScopeConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.price_feed != Pubkey::default() && self.price_chain[0] != SCOPE_CHAIN_SENTINEL
+    }
+    pub fn has_twap(&self) -> bool {
+        self.twap_chain[0] != SCOPE_CHAIN_SENTINEL
+    }
+}
+impl TokenInfo {
+    pub fn is_twap_enabled(&self) -> bool {
+        self.max_twap_divergence_bps > 0
+    }
+    /// Resolves the reserve price and TWAP from the `OraclePrices` account at
+    /// `scope_configuration.price_feed`, as `refresh_reserve` does, rejecting prices older than
+    /// `max_age_price_seconds` (resp. `max_age_twap_seconds`) at unix timestamp `now`.
+    ///
+    /// The TWAP is only resolved when TWAP checks are enabled and a TWAP chain is configured.
+    pub fn scope_price(
+        &self,
+        prices: &OraclePrices,
+        now: i64,
+    ) -> Result<TimestampedPriceWithTwap, KaminoLendingError> {
+        if !self.scope_configuration.is_enabled() {
+            return Err(KaminoLendingError::InvalidOracleConfig);
+        }
+        let price = prices.chain_price(&self.scope_configuration.price_chain)?;
+        if price.age(now) > self.max_age_price_seconds {
+            return Err(KaminoLendingError::PriceTooOld);
+        }
+        let twap = if self.is_twap_enabled() && self.scope_configuration.has_twap() {
+            let twap = prices
+                .chain_price(&self.scope_configuration.twap_chain)
+                .map_err(|_| KaminoLendingError::InvalidTwapPrice)?;
+            if twap.age(now) > self.max_age_twap_seconds {
+                return Err(KaminoLendingError::PriceTooOld);
+            }
+            Some(twap)
+        } else {
+            None
+        };
+        Ok(TimestampedPriceWithTwap { price, twap })
+    }
+}
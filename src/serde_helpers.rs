@@ -0,0 +1,499 @@
+//! `#[serde(with = "...")]` adapters used by the `serde` feature: base58 pubkeys, UTF-8 names,
+//! decimal `_sf` values and arrays longer than the 32 elements serde supports natively.
+//!
+//! Human-readable formats (JSON, YAML, ...) get the friendly encodings; binary formats keep the
+//! raw values so they stay compact and lossless. Padding and reserved fields are serialized unless
+//! the value is wrapped in [`WithoutPadding`], and deserialize as zeroes when missing.
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use serde::Serialize;
+
+/// Serializes the wrapped value, and everything it contains, without padding and reserved
+/// fields: struct fields whose name contains `padding` or starts with `reserved`.
+///
+/// ```ignore
+/// let json = serde_json::to_string(&WithoutPadding(&reserve))?;
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WithoutPadding<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for WithoutPadding<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(PaddingFilter(serializer))
+    }
+}
+
+fn is_padding(field: &str) -> bool {
+    field.contains("padding") || field.starts_with("reserved")
+}
+
+/// Serializer, and compound serializer, forwarding to the wrapped one with padding fields
+/// skipped and every nested value wrapped in [`WithoutPadding`].
+struct PaddingFilter<S>(S);
+
+macro_rules! forward_primitives {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<S::Ok, S::Error> {
+                self.0.$method(value)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = PaddingFilter<S::SerializeSeq>;
+    type SerializeTuple = PaddingFilter<S::SerializeTuple>;
+    type SerializeTupleStruct = PaddingFilter<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = PaddingFilter<S::SerializeTupleVariant>;
+    type SerializeMap = PaddingFilter<S::SerializeMap>;
+    type SerializeStruct = PaddingFilter<S::SerializeStruct>;
+    type SerializeStructVariant = PaddingFilter<S::SerializeStructVariant>;
+
+    forward_primitives!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.0.serialize_some(&WithoutPadding(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0
+            .serialize_newtype_struct(name, &WithoutPadding(value))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0
+            .serialize_newtype_variant(name, variant_index, variant, &WithoutPadding(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.0.serialize_seq(len).map(PaddingFilter)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.0.serialize_tuple(len).map(PaddingFilter)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.0.serialize_tuple_struct(name, len).map(PaddingFilter)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(PaddingFilter)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        self.0.serialize_map(len).map(PaddingFilter)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        self.0.serialize_struct(name, len).map(PaddingFilter)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(PaddingFilter)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<S: SerializeSeq> SerializeSeq for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_element(&WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTuple> SerializeTuple for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_element(&WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTupleStruct> SerializeTupleStruct for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_field(&WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTupleVariant> SerializeTupleVariant for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_field(&WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeMap> SerializeMap for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), S::Error> {
+        self.0.serialize_key(&WithoutPadding(key))
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.0.serialize_value(&WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeStruct> SerializeStruct for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        if is_padding(key) {
+            return self.0.skip_field(key);
+        }
+        self.0.serialize_field(key, &WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeStructVariant> SerializeStructVariant for PaddingFilter<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        if is_padding(key) {
+            return self.0.skip_field(key);
+        }
+        self.0.serialize_field(key, &WithoutPadding(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+/// Value of a padding field left out of the input.
+#[doc(hidden)]
+pub fn zeroed<T: Zeroed>() -> T {
+    T::zeroed()
+}
+
+#[doc(hidden)]
+pub trait Zeroed {
+    fn zeroed() -> Self;
+}
+
+macro_rules! impl_zeroed {
+    ($($int:ty),*) => {
+        $(
+            impl Zeroed for $int {
+                fn zeroed() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_zeroed!(u8, u16, u32, u64, u128);
+
+impl<T: Zeroed, const N: usize> Zeroed for [T; N] {
+    fn zeroed() -> Self {
+        core::array::from_fn(|_| T::zeroed())
+    }
+}
+
+/// `Pubkey` as its base58 string.
+pub mod pubkey {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(key: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(key)
+        } else {
+            key.to_bytes().serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Pubkey::from_str(&s).map_err(de::Error::custom)
+        } else {
+            <[u8; 32]>::deserialize(deserializer).map(Pubkey::new_from_array)
+        }
+    }
+}
+
+/// `BTreeMap` keyed by `Pubkey`, as a map keyed by base58 strings so it is valid JSON.
+pub mod pubkey_map {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer, V: Serialize>(
+        map: &BTreeMap<Pubkey, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(map.iter().map(|(key, value)| (key.to_string(), value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, V: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Pubkey, V>, D::Error> {
+        BTreeMap::<String, V>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(key, value)| {
+                Pubkey::from_str(&key)
+                    .map(|key| (key, value))
+                    .map_err(de::Error::custom)
+            })
+            .collect()
+    }
+}
+
+/// Zero-padded byte string as UTF-8 text without the trailing zeroes. Names that are not valid
+/// UTF-8 fall back to the byte array, which is also accepted on input.
+pub mod name {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Borrowed only when the name is valid UTF-8; anything else keeps its raw bytes.
+        match crate::display::name_str(bytes) {
+            Cow::Borrowed(name) if serializer.is_human_readable() => serializer.serialize_str(name),
+            _ => big_array::serialize(bytes, serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NameVisitor::<N>)
+        } else {
+            deserializer.deserialize_tuple(N, NameVisitor::<N>)
+        }
+    }
+
+    struct NameVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for NameVisitor<N> {
+        type Value = [u8; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a string of at most {N} bytes or an array of {N} bytes")
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+            if name.len() > N {
+                return Err(E::invalid_length(name.len(), &self));
+            }
+            let mut bytes = [0; N];
+            bytes[..name.len()].copy_from_slice(name.as_bytes());
+            Ok(bytes)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            ArrayVisitor::<u8, N>(PhantomData).visit_seq(seq)
+        }
+    }
+}
+
+/// `u128` `_sf` field as the decimal string of the [`Fraction`](crate::fraction::Fraction) it
+/// holds.
+pub mod sf {
+    use super::*;
+    use crate::fraction::Fraction;
+
+    pub fn serialize<S: Serializer>(bits: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        Fraction::from_bits(*bits).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        Fraction::deserialize(deserializer).map(Fraction::to_bits)
+    }
+}
+
+/// `u64` `_sf` field (the reserve fee rates) as a decimal string.
+pub mod sf_u64 {
+    use super::*;
+    use crate::fraction::Fraction;
+
+    pub fn serialize<S: Serializer>(bits: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        sf::serialize(&u128::from(*bits), serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let value = Fraction::deserialize(deserializer)?;
+        u64::try_from(value.to_bits())
+            .map_err(|_| de::Error::custom(format!("fee rate {value} out of range")))
+    }
+}
+
+/// Arrays of any length, as sequences.
+pub mod big_array {
+    use super::*;
+
+    pub fn serialize<S: Serializer, T: Serialize, const N: usize>(
+        array: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for item in array {
+            tuple.serialize_element(item)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[T; N], D::Error> {
+        deserializer.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))
+    }
+}
+
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of length {N}")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(N);
+        while items.len() < N {
+            match seq.next_element()? {
+                Some(item) => items.push(item),
+                None => return Err(de::Error::invalid_length(items.len(), &self)),
+            }
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        items
+            .try_into()
+            .map_err(|_| de::Error::invalid_length(N, &self))
+    }
+}
@@ -0,0 +1,700 @@
+//! What-if simulation of obligation actions: hypothetical deposits, withdrawals, borrows, repays,
+//! price moves and elevation group switches applied one after another, with the obligation's
+//! values and liquidation prices after each step or the error the program would raise.
+//!
+//! Checks follow the ones [`max_amounts`](crate::max_amounts) models for the corresponding
+//! instructions. Interest does not accrue between steps.
+use crate::fraction::Fraction;
+use crate::max_amounts::{borrow_blocker, borrow_debt_bounds, withdraw_all_amount};
+use crate::obligation::ObligationValues;
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use std::collections::BTreeMap;
+
+/// A hypothetical change to an obligation or to the prices it is valued at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObligationAction {
+    /// `deposit_reserve_liquidity_and_obligation_collateral` of `liquidity_amount`.
+    Deposit {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        reserve: Pubkey,
+        liquidity_amount: u64,
+    },
+    /// `withdraw_obligation_collateral` of `collateral_amount` cTokens; `u64::MAX` withdraws the
+    /// whole deposit without debt, and as much of it as the LTV allows with debt.
+    Withdraw {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        reserve: Pubkey,
+        collateral_amount: u64,
+    },
+    /// `borrow_obligation_liquidity` of `liquidity_amount`; the origination fee is added to the
+    /// debt.
+    Borrow {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        reserve: Pubkey,
+        liquidity_amount: u64,
+    },
+    /// `repay_obligation_liquidity` of `liquidity_amount`, `u64::MAX` for the whole debt.
+    Repay {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        reserve: Pubkey,
+        liquidity_amount: u64,
+    },
+    /// Sets the market price of the simulated reserves lending `mint`.
+    SetPrice {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        mint: Pubkey,
+        price: Fraction,
+    },
+    /// `request_elevation_group`, [`ELEVATION_GROUP_NONE`] to leave the current group.
+    RequestElevationGroup { elevation_group: u8 },
+}
+
+impl ObligationAction {
+    /// Reserve the action is on, `None` for price changes and elevation group switches.
+    pub fn reserve(&self) -> Option<&Pubkey> {
+        match self {
+            Self::Deposit { reserve, .. }
+            | Self::Withdraw { reserve, .. }
+            | Self::Borrow { reserve, .. }
+            | Self::Repay { reserve, .. } => Some(reserve),
+            Self::SetPrice { .. } | Self::RequestElevationGroup { .. } => None,
+        }
+    }
+}
+
+/// Price of `mint` at which the obligation becomes liquidatable, other prices unchanged:
+/// reached from above for a mint mostly held as collateral, from below for one mostly owed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidationPrice {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub mint: Pubkey,
+    pub current_price: Fraction,
+    pub liquidation_price: Fraction,
+    /// Whether the obligation is liquidated by this price falling rather than rising.
+    pub falling: bool,
+}
+
+/// State of the obligation after one action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationStep {
+    pub action: ObligationAction,
+    pub values: ObligationValues,
+    /// Mints whose price alone can make the obligation liquidatable; empty without debt.
+    pub liquidation_prices: Vec<LiquidationPrice>,
+}
+
+/// The action at index `step` would fail with `error`; `completed` holds the steps before it.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("action {step} would fail: {error}")]
+pub struct SimulationError {
+    pub step: usize,
+    pub error: KaminoLendingError,
+    pub completed: Vec<SimulationStep>,
+}
+
+/// An obligation with copies of its lending market and reserves, changed by each applied action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObligationSimulator {
+    lending_market: LendingMarket,
+    obligation: Obligation,
+    reserves: BTreeMap<Pubkey, Reserve>,
+    now: i64,
+}
+
+impl ObligationSimulator {
+    /// Simulator at unix timestamp `now`, which withdrawal caps are evaluated at. `reserves` must
+    /// hold the obligation's reserves and those the actions are on.
+    pub fn new(
+        lending_market: LendingMarket,
+        obligation: Obligation,
+        reserves: impl IntoIterator<Item = (Pubkey, Reserve)>,
+        now: i64,
+    ) -> Self {
+        Self {
+            lending_market,
+            obligation,
+            reserves: reserves.into_iter().collect(),
+            now,
+        }
+    }
+
+    pub fn obligation(&self) -> &Obligation {
+        &self.obligation
+    }
+
+    pub fn reserves(&self) -> &BTreeMap<Pubkey, Reserve> {
+        &self.reserves
+    }
+
+    pub fn values(&self) -> Result<ObligationValues, KaminoLendingError> {
+        self.obligation
+            .compute_values(&self.lending_market, &self.reserves)
+    }
+
+    /// Applies `action`, leaving the simulator unchanged if it fails.
+    pub fn apply(
+        &mut self,
+        action: &ObligationAction,
+    ) -> Result<SimulationStep, KaminoLendingError> {
+        let mut next = self.clone();
+        let before = next.values()?;
+        match *action {
+            ObligationAction::Deposit {
+                reserve,
+                liquidity_amount,
+            } => next.deposit(&reserve, liquidity_amount)?,
+            ObligationAction::Withdraw {
+                reserve,
+                collateral_amount,
+            } => next.withdraw(&reserve, collateral_amount, &before)?,
+            ObligationAction::Borrow {
+                reserve,
+                liquidity_amount,
+            } => next.borrow(&reserve, liquidity_amount, &before)?,
+            ObligationAction::Repay {
+                reserve,
+                liquidity_amount,
+            } => next.repay(&reserve, liquidity_amount)?,
+            ObligationAction::SetPrice { mint, price } => next.set_price(&mint, price),
+            ObligationAction::RequestElevationGroup { elevation_group } => {
+                next.request_elevation_group(elevation_group)?
+            }
+        }
+        let values = next.values()?;
+        if action.reserve().is_some()
+            && values.loan_to_value() > before.loan_to_value()
+            && values.is_liquidatable()
+        {
+            return Err(KaminoLendingError::WorseLtvThanUnhealthyLtv);
+        }
+        next.store_values(&values);
+        let liquidation_prices = next.liquidation_prices(&values);
+        *self = next;
+        Ok(SimulationStep {
+            action: action.clone(),
+            values,
+            liquidation_prices,
+        })
+    }
+
+    /// Applies `actions` in order, stopping at the first that fails.
+    pub fn run<'a>(
+        &mut self,
+        actions: impl IntoIterator<Item = &'a ObligationAction>,
+    ) -> Result<Vec<SimulationStep>, SimulationError> {
+        let mut steps = Vec::new();
+        for (step, action) in actions.into_iter().enumerate() {
+            match self.apply(action) {
+                Ok(result) => steps.push(result),
+                Err(error) => {
+                    return Err(SimulationError {
+                        step,
+                        error,
+                        completed: steps,
+                    })
+                }
+            }
+        }
+        Ok(steps)
+    }
+
+    fn reserve_mut(&mut self, address: &Pubkey) -> Result<&mut Reserve, KaminoLendingError> {
+        self.reserves
+            .get_mut(address)
+            .ok_or(KaminoLendingError::InvalidAccountInput)
+    }
+
+    fn deposit(
+        &mut self,
+        address: &Pubkey,
+        liquidity_amount: u64,
+    ) -> Result<(), KaminoLendingError> {
+        if liquidity_amount == 0 {
+            return Err(KaminoLendingError::InvalidAmount);
+        }
+        if self.lending_market.is_emergency_mode() {
+            return Err(KaminoLendingError::GlobalEmergencyMode);
+        }
+        let group = self
+            .lending_market
+            .elevation_group(self.obligation.elevation_group)
+            .cloned();
+        let now = self.now;
+        let lending_market_address = self.obligation.lending_market;
+        let reserve = self.reserve_mut(address)?;
+        if reserve.lending_market != lending_market_address {
+            return Err(KaminoLendingError::InvalidAccountInput);
+        }
+        if reserve.status() == Some(ReserveStatus::Obsolete) {
+            return Err(KaminoLendingError::ReserveObsolete);
+        }
+        let total_supply = reserve
+            .total_supply()
+            .checked_add(Fraction::from_num(liquidity_amount))
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        if total_supply > reserve.config.deposit_limit {
+            return Err(KaminoLendingError::DepositLimitExceeded);
+        }
+        if let Some(group) = &group {
+            if !reserve.is_in_elevation_group(group.id) {
+                return Err(KaminoLendingError::InconsistentElevationGroup);
+            }
+            if group.debt_reserve == *address {
+                return Err(KaminoLendingError::ElevationGroupDebtReserveAsCollateral);
+            }
+        }
+        let collateral_amount =
+            reserve.liquidity_to_collateral(Fraction::from_num(liquidity_amount));
+        if collateral_amount == 0 {
+            return Err(KaminoLendingError::InvalidAmount);
+        }
+        reserve.liquidity.available_amount = reserve
+            .liquidity
+            .available_amount
+            .checked_add(liquidity_amount)
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        reserve.collateral.mint_total_supply = reserve
+            .collateral
+            .mint_total_supply
+            .checked_add(collateral_amount)
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        reserve.config.deposit_withdrawal_cap = reserve
+            .config
+            .deposit_withdrawal_cap
+            .after_deposit(liquidity_amount, now);
+        let asset_tier = reserve.config.asset_tier;
+
+        let obligation = &mut self.obligation;
+        let isolated_collateral = AssetTier::IsolatedCollateral as u8;
+        let has_other_deposits = obligation
+            .active_deposits()
+            .any(|(_, deposit)| deposit.deposit_reserve != *address);
+        let other_is_isolated = obligation.active_deposits().any(|(index, deposit)| {
+            deposit.deposit_reserve != *address
+                && obligation.deposits_asset_tiers[index] == isolated_collateral
+        });
+        if has_other_deposits && (asset_tier == isolated_collateral || other_is_isolated) {
+            return Err(KaminoLendingError::IsolatedAssetTierViolation);
+        }
+        let existing = obligation
+            .active_deposits()
+            .find(|(_, deposit)| deposit.deposit_reserve == *address)
+            .map(|(index, _)| index);
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                let index = obligation
+                    .deposits
+                    .iter()
+                    .position(|deposit| deposit.deposit_reserve == Pubkey::default())
+                    .ok_or(KaminoLendingError::ObligationReserveLimit)?;
+                if let Some(group) = &group {
+                    let count = obligation.active_deposits().count() + 1;
+                    if count > usize::from(group.max_reserves_as_collateral) {
+                        return Err(
+                            KaminoLendingError::ObligationCollateralExceedsElevationGroupLimit,
+                        );
+                    }
+                }
+                obligation.deposits[index].deposit_reserve = *address;
+                obligation.deposits_asset_tiers[index] = asset_tier;
+                index
+            }
+        };
+        let deposit = &mut obligation.deposits[index];
+        deposit.deposited_amount = deposit
+            .deposited_amount
+            .checked_add(collateral_amount)
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        Ok(())
+    }
+
+    fn withdraw(
+        &mut self,
+        address: &Pubkey,
+        collateral_amount: u64,
+        values: &ObligationValues,
+    ) -> Result<(), KaminoLendingError> {
+        if collateral_amount == 0 {
+            return Err(KaminoLendingError::InvalidAmount);
+        }
+        if self.lending_market.is_emergency_mode() {
+            return Err(KaminoLendingError::GlobalEmergencyMode);
+        }
+        let reserve = self
+            .reserves
+            .get(address)
+            .ok_or(KaminoLendingError::InvalidAccountInput)?;
+        let (index, deposit) = self
+            .obligation
+            .active_deposits()
+            .find(|(_, deposit)| deposit.deposit_reserve == *address)
+            .ok_or(KaminoLendingError::ObligationCollateralEmpty)?;
+        let mut amount = collateral_amount.min(deposit.deposited_amount);
+        if values.has_debt() {
+            let position = values
+                .deposit(address)
+                .ok_or(KaminoLendingError::InvalidObligationCollateral)?;
+            let max_withdraw_value = values.max_withdraw_value(position.max_ltv_pct);
+            if collateral_amount == u64::MAX {
+                if max_withdraw_value.is_zero() {
+                    return Err(KaminoLendingError::WithdrawTooLarge);
+                }
+                amount = withdraw_all_amount(amount, position.market_value, max_withdraw_value);
+            }
+            let withdraw_value = reserve.market_value(reserve.collateral_to_liquidity(amount));
+            if collateral_amount != u64::MAX && withdraw_value > max_withdraw_value {
+                return Err(KaminoLendingError::WithdrawTooLarge);
+            }
+            let min_net_value = self.lending_market.min_net_value_in_obligation();
+            if values.net_value().saturating_sub(withdraw_value) < min_net_value {
+                return Err(KaminoLendingError::NetValueRemainingTooSmall);
+            }
+        }
+        let deposit = &mut self.obligation.deposits[index];
+        deposit.deposited_amount -= amount;
+        if deposit.deposited_amount == 0 {
+            deposit.deposit_reserve = Pubkey::default();
+            deposit.market_value_sf = 0;
+            deposit.borrowed_amount_against_this_collateral_in_elevation_group = 0;
+        }
+        Ok(())
+    }
+
+    fn borrow(
+        &mut self,
+        address: &Pubkey,
+        liquidity_amount: u64,
+        values: &ObligationValues,
+    ) -> Result<(), KaminoLendingError> {
+        if liquidity_amount == 0 {
+            return Err(KaminoLendingError::InvalidAmount);
+        }
+        let reserve = self
+            .reserves
+            .get(address)
+            .ok_or(KaminoLendingError::InvalidAccountInput)?;
+        if let Some(err) = borrow_blocker(&self.obligation, &self.lending_market, reserve, address)
+        {
+            return Err(err);
+        }
+        if self.obligation.active_deposits().next().is_none() {
+            return Err(KaminoLendingError::ObligationDepositsEmpty);
+        }
+        let with_referrer = self.obligation.referrer != Pubkey::default()
+            && self.lending_market.referral_fee_bps > 0;
+        let fee = reserve.borrow_fee(liquidity_amount, FeeCalculation::Exclusive, with_referrer)?;
+        let debt_increase = liquidity_amount
+            .checked_add(fee)
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        let bounds = borrow_debt_bounds(
+            &self.obligation,
+            &self.lending_market,
+            &self.reserves,
+            values,
+            reserve,
+            self.now,
+        )?;
+        if let Some((_, constraint)) = bounds.iter().find(|(bound, _)| debt_increase > *bound) {
+            return Err(borrow_limit_error(*constraint));
+        }
+
+        let now = self.now;
+        let group = self
+            .lending_market
+            .elevation_group(self.obligation.elevation_group)
+            .map(|group| usize::from(group.id - 1));
+        let reserve = self.reserve_mut(address)?;
+        reserve.liquidity.available_amount -= debt_increase;
+        reserve.liquidity.borrowed_amount_sf = reserve
+            .liquidity
+            .borrowed_amount()
+            .checked_add(Fraction::from_num(debt_increase))
+            .ok_or(KaminoLendingError::MathOverflow)?
+            .to_bits();
+        reserve.config.debt_withdrawal_cap = reserve
+            .config
+            .debt_withdrawal_cap
+            .after_withdrawal(debt_increase, now)?;
+        if group.is_none() {
+            reserve.borrowed_amount_outside_elevation_group = reserve
+                .borrowed_amount_outside_elevation_group
+                .saturating_add(debt_increase);
+        }
+        let cumulative_borrow_rate_bsf = reserve.liquidity.cumulative_borrow_rate_bsf.clone();
+        let asset_tier = reserve.config.asset_tier;
+        let accrued = self
+            .obligation
+            .find_borrow(address)
+            .map_or(Fraction::ZERO, |borrow| {
+                borrow.accrued_borrowed_amount(&self.reserves[address])
+            });
+        if let Some(group_index) = group {
+            self.update_elevation_group_borrows(group_index, |borrowed| {
+                borrowed.saturating_add(debt_increase)
+            });
+        }
+
+        let obligation = &mut self.obligation;
+        let existing = obligation
+            .active_borrows()
+            .find(|(_, borrow)| borrow.borrow_reserve == *address)
+            .map(|(index, _)| index);
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                let index = obligation
+                    .borrows
+                    .iter()
+                    .position(|borrow| borrow.borrow_reserve == Pubkey::default())
+                    .ok_or(KaminoLendingError::ObligationReserveLimit)?;
+                obligation.borrows[index].borrow_reserve = *address;
+                obligation.borrows_asset_tiers[index] = asset_tier;
+                index
+            }
+        };
+        let borrow = &mut obligation.borrows[index];
+        borrow.borrowed_amount_sf = (accrued + Fraction::from_num(debt_increase)).to_bits();
+        borrow.cumulative_borrow_rate_bsf = cumulative_borrow_rate_bsf;
+        if group.is_none() {
+            borrow.borrowed_amount_outside_elevation_groups = borrow
+                .borrowed_amount_outside_elevation_groups
+                .saturating_add(debt_increase);
+        }
+        Ok(())
+    }
+
+    fn repay(&mut self, address: &Pubkey, liquidity_amount: u64) -> Result<(), KaminoLendingError> {
+        if liquidity_amount == 0 {
+            return Err(KaminoLendingError::InvalidAmount);
+        }
+        let reserve = self
+            .reserves
+            .get(address)
+            .ok_or(KaminoLendingError::InvalidAccountInput)?;
+        let (index, borrow) = self
+            .obligation
+            .active_borrows()
+            .find(|(_, borrow)| borrow.borrow_reserve == *address)
+            .ok_or(KaminoLendingError::ObligationLiquidityEmpty)?;
+        let accrued = borrow.accrued_borrowed_amount(reserve);
+        let debt = accrued.to_ceil().unwrap_or(u64::MAX);
+        let amount = liquidity_amount.min(debt);
+        let outside_repaid = amount.min(borrow.borrowed_amount_outside_elevation_groups);
+        let cumulative_borrow_rate_bsf = reserve.liquidity.cumulative_borrow_rate_bsf.clone();
+
+        let now = self.now;
+        let group = self
+            .lending_market
+            .elevation_group(self.obligation.elevation_group)
+            .map(|group| usize::from(group.id - 1));
+        let in_group = group.is_some();
+        let reserve = self.reserve_mut(address)?;
+        reserve.liquidity.available_amount = reserve
+            .liquidity
+            .available_amount
+            .checked_add(amount)
+            .ok_or(KaminoLendingError::MathOverflow)?;
+        reserve.liquidity.borrowed_amount_sf = reserve
+            .liquidity
+            .borrowed_amount()
+            .saturating_sub(Fraction::from_num(amount))
+            .to_bits();
+        reserve.config.debt_withdrawal_cap = reserve
+            .config
+            .debt_withdrawal_cap
+            .after_deposit(amount, now);
+        if !in_group {
+            reserve.borrowed_amount_outside_elevation_group = reserve
+                .borrowed_amount_outside_elevation_group
+                .saturating_sub(outside_repaid);
+        }
+        if let Some(group_index) = group {
+            self.update_elevation_group_borrows(group_index, |borrowed| {
+                borrowed.saturating_sub(amount)
+            });
+        }
+
+        let borrow = &mut self.obligation.borrows[index];
+        let remaining = accrued.saturating_sub(Fraction::from_num(amount));
+        borrow.borrowed_amount_outside_elevation_groups -= outside_repaid;
+        if remaining.is_zero() || amount == debt {
+            borrow.borrow_reserve = Pubkey::default();
+            borrow.borrowed_amount_sf = 0;
+            borrow.market_value_sf = 0;
+            borrow.borrow_factor_adjusted_market_value_sf = 0;
+            borrow.borrowed_amount_outside_elevation_groups = 0;
+        } else {
+            borrow.borrowed_amount_sf = remaining.to_bits();
+            borrow.cumulative_borrow_rate_bsf = cumulative_borrow_rate_bsf;
+        }
+        Ok(())
+    }
+
+    /// Applies `update` to the amount borrowed in the elevation group at `group_index` against
+    /// each of the obligation's deposit reserves.
+    fn update_elevation_group_borrows(&mut self, group_index: usize, update: impl Fn(u64) -> u64) {
+        let deposit_reserves: Vec<Pubkey> = self
+            .obligation
+            .active_deposits()
+            .map(|(_, deposit)| deposit.deposit_reserve)
+            .collect();
+        for deposit_reserve in deposit_reserves {
+            if let Some(borrowed) = self.reserves.get_mut(&deposit_reserve).and_then(|reserve| {
+                reserve
+                    .borrowed_amounts_against_this_reserve_in_elevation_groups
+                    .get_mut(group_index)
+            }) {
+                *borrowed = update(*borrowed);
+            }
+        }
+    }
+
+    fn set_price(&mut self, mint: &Pubkey, price: Fraction) {
+        for reserve in self.reserves.values_mut() {
+            if reserve.liquidity.mint_pubkey == *mint {
+                reserve.liquidity.market_price_sf = price.to_bits();
+            }
+        }
+    }
+
+    fn request_elevation_group(&mut self, elevation_group: u8) -> Result<(), KaminoLendingError> {
+        self.obligation.check_elevation_group(
+            &self.lending_market,
+            &self.reserves,
+            elevation_group,
+        )?;
+        self.obligation.elevation_group = elevation_group;
+        Ok(())
+    }
+
+    /// Caches `values` in the obligation the way `refresh_obligation` does.
+    fn store_values(&mut self, values: &ObligationValues) {
+        let obligation = &mut self.obligation;
+        obligation.deposited_value_sf = values.deposited_value.to_bits();
+        obligation.borrowed_assets_market_value_sf = values.borrowed_value.to_bits();
+        obligation.borrow_factor_adjusted_debt_value_sf =
+            values.borrow_factor_adjusted_debt_value.to_bits();
+        obligation.allowed_borrow_value_sf = values.allowed_borrow_value.to_bits();
+        obligation.unhealthy_borrow_value_sf = values.unhealthy_borrow_value.to_bits();
+        obligation.has_debt = u8::from(values.has_debt());
+        for deposit in &values.deposits {
+            obligation.deposits[deposit.index].market_value_sf = deposit.market_value.to_bits();
+        }
+        for borrow in &values.borrows {
+            let slot = &mut obligation.borrows[borrow.index];
+            slot.market_value_sf = borrow.market_value.to_bits();
+            slot.borrow_factor_adjusted_market_value_sf =
+                borrow.borrow_factor_adjusted_value.to_bits();
+        }
+    }
+
+    fn liquidation_prices(&self, values: &ObligationValues) -> Vec<LiquidationPrice> {
+        if !values.has_debt() {
+            return Vec::new();
+        }
+        // Weight of each mint in the unhealthy borrow value (collateral) and in the
+        // borrow-factor-adjusted debt (debt), both linear in its price.
+        let mut weights: BTreeMap<Pubkey, (Fraction, Fraction, Fraction)> = BTreeMap::new();
+        for deposit in &values.deposits {
+            let reserve = &self.reserves[&deposit.reserve];
+            let entry = weights.entry(reserve.liquidity.mint_pubkey).or_insert((
+                reserve.liquidity.market_price(),
+                Fraction::ZERO,
+                Fraction::ZERO,
+            ));
+            entry.1 +=
+                deposit.market_value * Fraction::from_percent(deposit.liquidation_threshold_pct);
+        }
+        for borrow in &values.borrows {
+            let reserve = &self.reserves[&borrow.reserve];
+            let entry = weights.entry(reserve.liquidity.mint_pubkey).or_insert((
+                reserve.liquidity.market_price(),
+                Fraction::ZERO,
+                Fraction::ZERO,
+            ));
+            entry.2 += borrow.borrow_factor_adjusted_value;
+        }
+        let unhealthy = values.unhealthy_borrow_value;
+        let debt = values.borrow_factor_adjusted_debt_value;
+        weights
+            .into_iter()
+            .filter_map(|(mint, (price, collateral, owed))| {
+                let other_collateral = unhealthy.saturating_sub(collateral);
+                let other_debt = debt.saturating_sub(owed);
+                // Liquidatable once other_debt + k * owed >= other_collateral + k * collateral,
+                // k being the price multiplier.
+                let multiplier = if collateral > owed {
+                    (other_debt > other_collateral)
+                        .then(|| (other_debt - other_collateral).checked_div(collateral - owed))?
+                } else if owed > collateral {
+                    (other_collateral > other_debt)
+                        .then(|| (other_collateral - other_debt).checked_div(owed - collateral))?
+                } else {
+                    None
+                }?;
+                Some(LiquidationPrice {
+                    mint,
+                    current_price: price,
+                    liquidation_price: price.checked_mul(multiplier).unwrap_or(Fraction::MAX),
+                    falling: collateral > owed,
+                })
+            })
+            .collect()
+    }
+}
+
+fn borrow_limit_error(constraint: AmountConstraint) -> KaminoLendingError {
+    match constraint {
+        AmountConstraint::AvailableLiquidity => KaminoLendingError::InsufficientLiquidity,
+        AmountConstraint::WithdrawalCap => KaminoLendingError::WithdrawalCapReached,
+        AmountConstraint::BorrowLimit => KaminoLendingError::BorrowLimitExceeded,
+        AmountConstraint::UtilizationLimit => {
+            KaminoLendingError::BorrowingAboveUtilizationRateDisabled
+        }
+        AmountConstraint::ElevationGroupBorrowLimit
+        | AmountConstraint::BorrowLimitOutsideElevationGroup => {
+            KaminoLendingError::ElevationGroupBorrowLimitExceeded
+        }
+        AmountConstraint::MinNetValue => KaminoLendingError::NetValueRemainingTooSmall,
+        AmountConstraint::Blocked(err) => err,
+        AmountConstraint::WalletBalance
+        | AmountConstraint::Position
+        | AmountConstraint::DepositLimit
+        | AmountConstraint::ObligationLtv => KaminoLendingError::BorrowTooLarge,
+    }
+}
+
+/// Runs `actions` on copies of `obligation`, `lending_market` and the reserves the obligation
+/// and actions refer to, at unix timestamp `now`.
+pub fn simulate_obligation_actions(
+    obligation: &Obligation,
+    lending_market: &LendingMarket,
+    reserves: &impl ReserveLookup,
+    actions: &[ObligationAction],
+    now: i64,
+) -> Result<Vec<SimulationStep>, SimulationError> {
+    let addresses = obligation
+        .active_deposits()
+        .map(|(_, deposit)| deposit.deposit_reserve)
+        .chain(
+            obligation
+                .active_borrows()
+                .map(|(_, borrow)| borrow.borrow_reserve),
+        )
+        .chain(
+            actions
+                .iter()
+                .filter_map(ObligationAction::reserve)
+                .copied(),
+        );
+    let involved: BTreeMap<Pubkey, Reserve> = addresses
+        .filter_map(|address| Some((address, reserves.reserve(&address)?.clone())))
+        .collect();
+    ObligationSimulator::new(lending_market.clone(), obligation.clone(), involved, now).run(actions)
+}
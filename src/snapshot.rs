@@ -0,0 +1,485 @@
+//! A consistent view of one lending market, its reserves and some of its obligations, loaded from
+//! any [`AccountSource`].
+use crate::filters;
+use crate::reserve::ReserveLookup;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
+use thiserror::Error;
+
+/// An on-chain account as returned by an [`AccountSource`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawAccount {
+    pub lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// `getProgramAccounts` filter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccountFilter {
+    DataSize(u64),
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl AccountFilter {
+    pub fn memcmp(offset: usize, bytes: impl Into<Vec<u8>>) -> Self {
+        Self::Memcmp {
+            offset,
+            bytes: bytes.into(),
+        }
+    }
+
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Self::DataSize(size) => data.len() as u64 == *size,
+            Self::Memcmp { offset, bytes } => offset
+                .checked_add(bytes.len())
+                .and_then(|end| data.get(*offset..end))
+                .is_some_and(|window| window == bytes.as_slice()),
+        }
+    }
+}
+
+/// Where accounts come from: an RPC client, a validator snapshot or fixtures.
+pub trait AccountSource {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn get_account(&self, address: &Pubkey) -> Result<Option<RawAccount>, Self::Error>;
+
+    /// Accounts in the order of `addresses`.
+    fn get_multiple_accounts(
+        &self,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<Option<RawAccount>>, Self::Error> {
+        addresses
+            .iter()
+            .map(|address| self.get_account(address))
+            .collect()
+    }
+
+    /// Accounts owned by `program_id` matching every filter.
+    fn get_program_accounts_with_filters(
+        &self,
+        program_id: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Result<Vec<(Pubkey, RawAccount)>, Self::Error>;
+}
+
+impl<T: AccountSource + ?Sized> AccountSource for &T {
+    type Error = T::Error;
+
+    fn get_account(&self, address: &Pubkey) -> Result<Option<RawAccount>, Self::Error> {
+        (**self).get_account(address)
+    }
+
+    fn get_multiple_accounts(
+        &self,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<Option<RawAccount>>, Self::Error> {
+        (**self).get_multiple_accounts(addresses)
+    }
+
+    fn get_program_accounts_with_filters(
+        &self,
+        program_id: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Result<Vec<(Pubkey, RawAccount)>, Self::Error> {
+        (**self).get_program_accounts_with_filters(program_id, filters)
+    }
+}
+
+/// Accounts held in memory, for tests and offline tools.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct InMemoryAccountSource {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey_map"))]
+    accounts: BTreeMap<Pubkey, RawAccount>,
+}
+
+impl InMemoryAccountSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, address: Pubkey, account: RawAccount) -> Option<RawAccount> {
+        self.accounts.insert(address, account)
+    }
+
+    /// Inserts `data` owned by the lending program.
+    pub fn insert_program_account(&mut self, address: Pubkey, data: Vec<u8>) -> Option<RawAccount> {
+        self.insert(
+            address,
+            RawAccount {
+                lamports: 0,
+                owner: crate::ID,
+                data,
+            },
+        )
+    }
+
+    pub fn remove(&mut self, address: &Pubkey) -> Option<RawAccount> {
+        self.accounts.remove(address)
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+impl FromIterator<(Pubkey, RawAccount)> for InMemoryAccountSource {
+    fn from_iter<I: IntoIterator<Item = (Pubkey, RawAccount)>>(iter: I) -> Self {
+        Self {
+            accounts: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl AccountSource for InMemoryAccountSource {
+    type Error = std::convert::Infallible;
+
+    fn get_account(&self, address: &Pubkey) -> Result<Option<RawAccount>, Self::Error> {
+        Ok(self.accounts.get(address).cloned())
+    }
+
+    fn get_program_accounts_with_filters(
+        &self,
+        program_id: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Result<Vec<(Pubkey, RawAccount)>, Self::Error> {
+        Ok(self
+            .accounts
+            .iter()
+            .filter(|(_, account)| account.owner == *program_id)
+            .filter(|(_, account)| filters.iter().all(|filter| filter.matches(&account.data)))
+            .map(|(address, account)| (*address, account.clone()))
+            .collect())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("account source error: {0}")]
+    Source(Box<dyn std::error::Error + Send + Sync>),
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("account {address} is owned by {owner}, not the lending program")]
+    InvalidAccountOwner { address: Pubkey, owner: Pubkey },
+    #[error("account {address} could not be decoded: {source}")]
+    InvalidAccountData {
+        address: Pubkey,
+        source: std::io::Error,
+    },
+    #[error("account {address} belongs to lending market {lending_market}")]
+    ForeignAccount {
+        address: Pubkey,
+        lending_market: Pubkey,
+    },
+}
+
+impl SnapshotError {
+    fn from_source(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Source(Box::new(err))
+    }
+}
+
+fn decode<T>(
+    address: &Pubkey,
+    account: &RawAccount,
+    deserialize: impl Fn(&[u8]) -> std::io::Result<T>,
+) -> Result<T, SnapshotError> {
+    if account.owner != crate::ID {
+        return Err(SnapshotError::InvalidAccountOwner {
+            address: *address,
+            owner: account.owner,
+        });
+    }
+    deserialize(&account.data).map_err(|source| SnapshotError::InvalidAccountData {
+        address: *address,
+        source,
+    })
+}
+
+/// A lending market with its reserves and a set of its obligations, indexed by address, reserve
+/// mint and obligation owner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketSnapshot {
+    pub lending_market_address: Pubkey,
+    pub lending_market: LendingMarket,
+    reserves: BTreeMap<Pubkey, Reserve>,
+    obligations: BTreeMap<Pubkey, Obligation>,
+    reserves_by_mint: HashMap<Pubkey, Pubkey>,
+    obligations_by_owner: HashMap<Pubkey, Vec<Pubkey>>,
+}
+
+impl MarketSnapshot {
+    /// Snapshot of already decoded accounts; fails if a reserve or obligation belongs to another
+    /// market.
+    pub fn from_accounts(
+        lending_market_address: Pubkey,
+        lending_market: LendingMarket,
+        reserves: impl IntoIterator<Item = (Pubkey, Reserve)>,
+        obligations: impl IntoIterator<Item = (Pubkey, Obligation)>,
+    ) -> Result<Self, SnapshotError> {
+        let mut snapshot = Self {
+            lending_market_address,
+            lending_market,
+            reserves: BTreeMap::new(),
+            obligations: BTreeMap::new(),
+            reserves_by_mint: HashMap::new(),
+            obligations_by_owner: HashMap::new(),
+        };
+        for (address, reserve) in reserves {
+            snapshot.insert_reserve(address, reserve)?;
+        }
+        for (address, obligation) in obligations {
+            snapshot.insert_obligation(address, obligation)?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Loads the lending market at `lending_market_address` and all its reserves.
+    pub fn load<S: AccountSource>(
+        source: &S,
+        lending_market_address: &Pubkey,
+    ) -> Result<Self, SnapshotError> {
+        let account = source
+            .get_account(lending_market_address)
+            .map_err(SnapshotError::from_source)?
+            .ok_or(SnapshotError::AccountNotFound(*lending_market_address))?;
+        let lending_market = decode(
+            lending_market_address,
+            &account,
+            LendingMarketAccount::deserialize,
+        )?
+        .0;
+        let reserves = source
+            .get_program_accounts_with_filters(
+                &crate::ID,
+                &filters::reserves_in_market(lending_market_address),
+            )
+            .map_err(SnapshotError::from_source)?
+            .into_iter()
+            .map(|(address, account)| {
+                decode(&address, &account, ReserveAccount::deserialize)
+                    .map(|reserve| (address, reserve.0))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_accounts(*lending_market_address, lending_market, reserves, [])
+    }
+
+    /// Loads the lending market, its reserves and all its obligations.
+    pub fn load_with_obligations<S: AccountSource>(
+        source: &S,
+        lending_market_address: &Pubkey,
+    ) -> Result<Self, SnapshotError> {
+        let mut snapshot = Self::load(source, lending_market_address)?;
+        snapshot.load_obligations_matching(
+            source,
+            &filters::obligations_in_market(lending_market_address),
+        )?;
+        Ok(snapshot)
+    }
+
+    /// Adds the obligations at `addresses`, failing if one is missing.
+    pub fn load_obligations<S: AccountSource>(
+        &mut self,
+        source: &S,
+        addresses: &[Pubkey],
+    ) -> Result<(), SnapshotError> {
+        let accounts = source
+            .get_multiple_accounts(addresses)
+            .map_err(SnapshotError::from_source)?;
+        for (address, account) in addresses.iter().zip(accounts) {
+            let account = account.ok_or(SnapshotError::AccountNotFound(*address))?;
+            let obligation = decode(address, &account, ObligationAccount::deserialize)?.0;
+            self.insert_obligation(*address, obligation)?;
+        }
+        Ok(())
+    }
+
+    /// Adds every obligation of `owner` in this market.
+    pub fn load_obligations_by_owner<S: AccountSource>(
+        &mut self,
+        source: &S,
+        owner: &Pubkey,
+    ) -> Result<(), SnapshotError> {
+        let filters = filters::obligations_in_market_by_owner(&self.lending_market_address, owner);
+        self.load_obligations_matching(source, &filters)
+    }
+
+    fn load_obligations_matching<S: AccountSource>(
+        &mut self,
+        source: &S,
+        filters: &[AccountFilter],
+    ) -> Result<(), SnapshotError> {
+        let accounts = source
+            .get_program_accounts_with_filters(&crate::ID, filters)
+            .map_err(SnapshotError::from_source)?;
+        for (address, account) in accounts {
+            let obligation = decode(&address, &account, ObligationAccount::deserialize)?.0;
+            self.insert_obligation(address, obligation)?;
+        }
+        Ok(())
+    }
+
+    /// Adds or replaces a reserve.
+    pub fn insert_reserve(
+        &mut self,
+        address: Pubkey,
+        reserve: Reserve,
+    ) -> Result<(), SnapshotError> {
+        if reserve.lending_market != self.lending_market_address {
+            return Err(SnapshotError::ForeignAccount {
+                address,
+                lending_market: reserve.lending_market,
+            });
+        }
+        let mint = reserve.liquidity.mint_pubkey;
+        if let Some(previous) = self.reserves.insert(address, reserve) {
+            let previous_mint = previous.liquidity.mint_pubkey;
+            if self.reserves_by_mint.get(&previous_mint) == Some(&address) {
+                self.reserves_by_mint.remove(&previous_mint);
+            }
+        }
+        self.reserves_by_mint.insert(mint, address);
+        Ok(())
+    }
+
+    /// Adds or replaces an obligation.
+    pub fn insert_obligation(
+        &mut self,
+        address: Pubkey,
+        obligation: Obligation,
+    ) -> Result<(), SnapshotError> {
+        if obligation.lending_market != self.lending_market_address {
+            return Err(SnapshotError::ForeignAccount {
+                address,
+                lending_market: obligation.lending_market,
+            });
+        }
+        if let Some(previous) = self.obligations.insert(address, obligation) {
+            if let Some(addresses) = self.obligations_by_owner.get_mut(&previous.owner) {
+                addresses.retain(|a| *a != address);
+            }
+        }
+        let owner = self.obligations[&address].owner;
+        self.obligations_by_owner
+            .entry(owner)
+            .or_default()
+            .push(address);
+        Ok(())
+    }
+
+    pub fn reserves(&self) -> &BTreeMap<Pubkey, Reserve> {
+        &self.reserves
+    }
+
+    pub fn obligations(&self) -> &BTreeMap<Pubkey, Obligation> {
+        &self.obligations
+    }
+
+    pub fn obligation(&self, address: &Pubkey) -> Option<&Obligation> {
+        self.obligations.get(address)
+    }
+
+    /// The reserve lending `mint`, with its address.
+    pub fn reserve_by_mint(&self, mint: &Pubkey) -> Option<(&Pubkey, &Reserve)> {
+        let address = self.reserves_by_mint.get(mint)?;
+        self.reserves.get_key_value(address)
+    }
+
+    /// Loaded obligations of `owner`.
+    pub fn obligations_by_owner<'a>(
+        &'a self,
+        owner: &Pubkey,
+    ) -> impl Iterator<Item = (&'a Pubkey, &'a Obligation)> + 'a {
+        self.obligations_by_owner
+            .get(owner)
+            .into_iter()
+            .flatten()
+            .filter_map(|address| self.obligations.get_key_value(address))
+    }
+
+    /// Reserves referenced by `obligation` that are not in the snapshot.
+    pub fn missing_reserves(&self, obligation: &Obligation) -> Vec<Pubkey> {
+        let deposits = obligation
+            .active_deposits()
+            .map(|(_, deposit)| deposit.deposit_reserve);
+        let borrows = obligation
+            .active_borrows()
+            .map(|(_, borrow)| borrow.borrow_reserve);
+        deposits
+            .chain(borrows)
+            .filter(|reserve| !self.reserves.contains_key(reserve))
+            .collect()
+    }
+}
+
+impl ReserveLookup for MarketSnapshot {
+    fn reserve(&self, address: &Pubkey) -> Option<&Reserve> {
+        self.reserves.get(address)
+    }
+}
+
+/// Serialized as the market and its reserves and obligations keyed by address; the indexes are
+/// rebuilt, and foreign accounts rejected, on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "MarketSnapshot")]
+struct MarketSnapshotRepr<'a> {
+    #[serde(with = "crate::serde_helpers::pubkey")]
+    lending_market_address: Pubkey,
+    lending_market: std::borrow::Cow<'a, LendingMarket>,
+    #[serde(with = "crate::serde_helpers::pubkey_map")]
+    reserves: BTreeMap<Pubkey, std::borrow::Cow<'a, Reserve>>,
+    #[serde(with = "crate::serde_helpers::pubkey_map")]
+    obligations: BTreeMap<Pubkey, std::borrow::Cow<'a, Obligation>>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MarketSnapshot {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MarketSnapshotRepr {
+            lending_market_address: self.lending_market_address,
+            lending_market: std::borrow::Cow::Borrowed(&self.lending_market),
+            reserves: self
+                .reserves
+                .iter()
+                .map(|(address, reserve)| (*address, std::borrow::Cow::Borrowed(reserve)))
+                .collect(),
+            obligations: self
+                .obligations
+                .iter()
+                .map(|(address, obligation)| (*address, std::borrow::Cow::Borrowed(obligation)))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MarketSnapshot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MarketSnapshotRepr::deserialize(deserializer)?;
+        Self::from_accounts(
+            repr.lending_market_address,
+            repr.lending_market.into_owned(),
+            repr.reserves
+                .into_iter()
+                .map(|(address, reserve)| (address, reserve.into_owned())),
+            repr.obligations
+                .into_iter()
+                .map(|(address, obligation)| (address, obligation.into_owned())),
+        )
+        .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,372 @@
+//! Market-wide stress tests: per-mint price shocks applied to a [`MarketSnapshot`], every obligation
+//! revalued, the debt liquidators could repay and the bad debt left behind.
+//!
+//! Each liquidatable obligation is liquidated against its largest deposit and borrow, repaying at
+//! most that borrow's value and that deposit's value over one plus the [`liquidation_bonus`].
+//! All collateral covers at most its value over one plus the bonus; any debt beyond that is bad
+//! debt, split across the obligation's borrows by market value. Per reserve, it is the amount
+//! `socialize_loss_v2` would be called with. Reports serialize to JSON with the `serde` feature
+//! and to CSV with [`StressTestReport::obligations_csv`] and [`StressTestReport::reserves_csv`].
+use crate::fraction::Fraction;
+use crate::liquidation::liquidation_bonus;
+use crate::obligation::ObligationValues;
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Price of every reserve lending `mint` multiplied by `multiplier`: `0.7` for a 30% drop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceShock {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub mint: Pubkey,
+    pub multiplier: Fraction,
+}
+
+impl PriceShock {
+    pub fn new(mint: Pubkey, multiplier: Fraction) -> Self {
+        Self { mint, multiplier }
+    }
+
+    /// Price drop of `pct` percent, capped at 100.
+    pub fn drop_pct(mint: Pubkey, pct: u8) -> Self {
+        Self::new(mint, Fraction::from_percent(100 - pct.min(100)))
+    }
+}
+
+/// One obligation after the shocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObligationStress {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub address: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub owner: Pubkey,
+    pub loan_to_value_before: Fraction,
+    pub loan_to_value: Fraction,
+    pub unhealthy_loan_to_value: Fraction,
+    pub deposited_value: Fraction,
+    pub borrowed_value: Fraction,
+    pub liquidatable: bool,
+    /// Bonus of a liquidation against the largest deposit and borrow, zero if healthy.
+    pub liquidation_bonus: Fraction,
+    /// Debt value the next liquidation may repay: capped by the largest borrow's value and by the
+    /// largest deposit's value over one plus the bonus.
+    pub liquidatable_debt_value: Fraction,
+    /// Debt value left once all collateral is seized.
+    pub bad_debt_value: Fraction,
+}
+
+/// One reserve after the shocks, summed over the snapshot's obligations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReserveStress {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub address: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub mint: Pubkey,
+    pub name: String,
+    pub price_before: Fraction,
+    pub price: Fraction,
+    pub deposited_value: Fraction,
+    pub borrowed_value: Fraction,
+    pub liquidatable_debt_value: Fraction,
+    pub liquidatable_debt_amount: Fraction,
+    /// Collateral value paid to liquidators for `liquidatable_debt_value` of other reserves.
+    pub seized_collateral_value: Fraction,
+    pub bad_debt_value: Fraction,
+    /// Bad debt in liquidity base units, rounded up.
+    pub bad_debt_amount: u64,
+}
+
+/// An obligation left out of the report because its values could not be computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkippedObligation {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+    pub address: Pubkey,
+    pub error: KaminoLendingError,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StressTotals {
+    pub obligations: usize,
+    pub liquidatable_obligations: usize,
+    pub obligations_with_bad_debt: usize,
+    pub deposited_value: Fraction,
+    pub borrowed_value: Fraction,
+    pub liquidatable_debt_value: Fraction,
+    pub bad_debt_value: Fraction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StressTestReport {
+    pub shocks: Vec<PriceShock>,
+    pub obligations: Vec<ObligationStress>,
+    pub reserves: Vec<ReserveStress>,
+    pub totals: StressTotals,
+    pub skipped: Vec<SkippedObligation>,
+}
+
+impl StressTestReport {
+    /// Reserves with bad debt and the liquidity amount to socialize for each.
+    pub fn bad_debt(&self) -> impl Iterator<Item = (&Pubkey, u64)> {
+        self.reserves
+            .iter()
+            .filter(|reserve| reserve.bad_debt_amount != 0)
+            .map(|reserve| (&reserve.address, reserve.bad_debt_amount))
+    }
+
+    pub fn obligations_csv(&self) -> String {
+        let mut csv = String::from(
+            "address,owner,loan_to_value_before,loan_to_value,unhealthy_loan_to_value,\
+             deposited_value,borrowed_value,liquidatable,liquidation_bonus,\
+             liquidatable_debt_value,bad_debt_value\n",
+        );
+        for row in &self.obligations {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                row.address,
+                row.owner,
+                row.loan_to_value_before,
+                row.loan_to_value,
+                row.unhealthy_loan_to_value,
+                row.deposited_value,
+                row.borrowed_value,
+                row.liquidatable,
+                row.liquidation_bonus,
+                row.liquidatable_debt_value,
+                row.bad_debt_value,
+            );
+        }
+        csv
+    }
+
+    pub fn reserves_csv(&self) -> String {
+        let mut csv = String::from(
+            "address,mint,name,price_before,price,deposited_value,borrowed_value,\
+             liquidatable_debt_value,liquidatable_debt_amount,seized_collateral_value,\
+             bad_debt_value,bad_debt_amount\n",
+        );
+        for row in &self.reserves {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                row.address,
+                row.mint,
+                csv_field(&row.name),
+                row.price_before,
+                row.price,
+                row.deposited_value,
+                row.borrowed_value,
+                row.liquidatable_debt_value,
+                row.liquidatable_debt_amount,
+                row.seized_collateral_value,
+                row.bad_debt_value,
+                row.bad_debt_amount,
+            );
+        }
+        csv
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// `part` of `whole`, zero for an empty `whole`.
+fn share(part: Fraction, whole: Fraction) -> Fraction {
+    if whole.is_zero() {
+        return Fraction::ZERO;
+    }
+    part.checked_div(whole).unwrap_or(Fraction::ONE)
+}
+
+/// Obligation with `values` liquidated against its largest deposit and borrow.
+fn stress_obligation(
+    lending_market: &LendingMarket,
+    reserves: &BTreeMap<Pubkey, Reserve>,
+    values: &ObligationValues,
+) -> (Fraction, Fraction, Fraction) {
+    if !values.is_liquidatable() {
+        return (Fraction::ZERO, Fraction::ZERO, Fraction::ZERO);
+    }
+    let largest_deposit = values
+        .deposits
+        .iter()
+        .max_by_key(|deposit| deposit.market_value);
+    let largest_borrow = values
+        .borrows
+        .iter()
+        .max_by_key(|borrow| borrow.market_value);
+    let (bonus, position_cap) = match (largest_deposit, largest_borrow) {
+        (Some(deposit), Some(borrow)) => {
+            let bonus = liquidation_bonus(
+                lending_market,
+                values,
+                &reserves[&deposit.reserve],
+                &reserves[&borrow.reserve],
+            );
+            let seizable = deposit
+                .market_value
+                .checked_div(Fraction::ONE.saturating_add(bonus))
+                .unwrap_or(Fraction::ZERO);
+            (bonus, borrow.market_value.min(seizable))
+        }
+        _ => (Fraction::ZERO, Fraction::ZERO),
+    };
+    let repayable = values
+        .deposited_value
+        .checked_div(Fraction::ONE.saturating_add(bonus))
+        .unwrap_or(Fraction::ZERO)
+        .min(values.borrowed_value);
+    let liquidatable_debt_value = lending_market
+        .max_liquidatable_debt_value(values)
+        .min(repayable)
+        .min(position_cap);
+    let bad_debt_value = values.borrowed_value.saturating_sub(repayable);
+    (bonus, liquidatable_debt_value, bad_debt_value)
+}
+
+fn reserve_row(address: Pubkey, before: &Reserve, after: &Reserve) -> ReserveStress {
+    ReserveStress {
+        address,
+        mint: after.liquidity.mint_pubkey,
+        name: after.config.token_info.name_str().into_owned(),
+        price_before: before.liquidity.market_price(),
+        price: after.liquidity.market_price(),
+        deposited_value: Fraction::ZERO,
+        borrowed_value: Fraction::ZERO,
+        liquidatable_debt_value: Fraction::ZERO,
+        liquidatable_debt_amount: Fraction::ZERO,
+        seized_collateral_value: Fraction::ZERO,
+        bad_debt_value: Fraction::ZERO,
+        bad_debt_amount: 0,
+    }
+}
+
+/// Applies `shocks` to the reserves of `snapshot` and reports every obligation's health,
+/// liquidatable debt and bad debt, per obligation and per reserve. Values too large for a
+/// [`Fraction`] saturate at [`Fraction::MAX`].
+pub fn stress_test(snapshot: &MarketSnapshot, shocks: &[PriceShock]) -> StressTestReport {
+    let lending_market = &snapshot.lending_market;
+    let mut reserves = snapshot.reserves().clone();
+    for shock in shocks {
+        for reserve in reserves.values_mut() {
+            if reserve.liquidity.mint_pubkey == shock.mint {
+                let price = reserve.liquidity.market_price();
+                reserve.liquidity.market_price_sf = price
+                    .checked_mul(shock.multiplier)
+                    .unwrap_or(Fraction::MAX)
+                    .to_bits();
+            }
+        }
+    }
+    let mut rows: BTreeMap<Pubkey, ReserveStress> = snapshot
+        .reserves()
+        .iter()
+        .map(|(address, before)| (*address, reserve_row(*address, before, &reserves[address])))
+        .collect();
+    let mut bad_debt_amounts: BTreeMap<Pubkey, Fraction> = BTreeMap::new();
+    let mut report = StressTestReport {
+        shocks: shocks.to_vec(),
+        obligations: Vec::new(),
+        reserves: Vec::new(),
+        totals: StressTotals::default(),
+        skipped: Vec::new(),
+    };
+
+    for (address, obligation) in snapshot.obligations() {
+        let values = match obligation.compute_values(lending_market, &reserves) {
+            Ok(values) => values,
+            Err(error) => {
+                report.skipped.push(SkippedObligation {
+                    address: *address,
+                    error,
+                });
+                continue;
+            }
+        };
+        let loan_to_value_before = obligation
+            .compute_values(lending_market, snapshot)
+            .map_or(Fraction::ZERO, |before| before.loan_to_value());
+        let (bonus, liquidatable_debt_value, bad_debt_value) =
+            stress_obligation(lending_market, &reserves, &values);
+        let seized_value =
+            liquidatable_debt_value.saturating_mul(Fraction::ONE.saturating_add(bonus));
+
+        for deposit in &values.deposits {
+            let row = rows.get_mut(&deposit.reserve).expect("valued reserve");
+            row.deposited_value = row.deposited_value.saturating_add(deposit.market_value);
+            row.seized_collateral_value = row.seized_collateral_value.saturating_add(
+                seized_value.saturating_mul(share(deposit.market_value, values.deposited_value)),
+            );
+        }
+        for borrow in &values.borrows {
+            let row = rows.get_mut(&borrow.reserve).expect("valued reserve");
+            let borrow_share = share(borrow.market_value, values.borrowed_value);
+            row.borrowed_value = row.borrowed_value.saturating_add(borrow.market_value);
+            row.liquidatable_debt_value = row
+                .liquidatable_debt_value
+                .saturating_add(liquidatable_debt_value.saturating_mul(borrow_share));
+            row.liquidatable_debt_amount = row.liquidatable_debt_amount.saturating_add(
+                borrow
+                    .borrowed_amount
+                    .saturating_mul(share(liquidatable_debt_value, values.borrowed_value)),
+            );
+            row.bad_debt_value = row
+                .bad_debt_value
+                .saturating_add(bad_debt_value.saturating_mul(borrow_share));
+            let bad_debt_amount = bad_debt_amounts
+                .entry(borrow.reserve)
+                .or_insert(Fraction::ZERO);
+            *bad_debt_amount = bad_debt_amount.saturating_add(
+                borrow
+                    .borrowed_amount
+                    .saturating_mul(share(bad_debt_value, values.borrowed_value)),
+            );
+        }
+
+        let totals = &mut report.totals;
+        totals.obligations += 1;
+        totals.liquidatable_obligations += usize::from(values.is_liquidatable());
+        totals.obligations_with_bad_debt += usize::from(!bad_debt_value.is_zero());
+        totals.deposited_value = totals
+            .deposited_value
+            .saturating_add(values.deposited_value);
+        totals.borrowed_value = totals.borrowed_value.saturating_add(values.borrowed_value);
+        totals.liquidatable_debt_value = totals
+            .liquidatable_debt_value
+            .saturating_add(liquidatable_debt_value);
+        totals.bad_debt_value = totals.bad_debt_value.saturating_add(bad_debt_value);
+        report.obligations.push(ObligationStress {
+            address: *address,
+            owner: obligation.owner,
+            loan_to_value_before,
+            loan_to_value: values.loan_to_value(),
+            unhealthy_loan_to_value: values.unhealthy_loan_to_value(),
+            deposited_value: values.deposited_value,
+            borrowed_value: values.borrowed_value,
+            liquidatable: values.is_liquidatable(),
+            liquidation_bonus: bonus,
+            liquidatable_debt_value,
+            bad_debt_value,
+        });
+    }
+
+    for (address, amount) in bad_debt_amounts {
+        if let Some(row) = rows.get_mut(&address) {
+            row.bad_debt_amount = amount.to_ceil().unwrap_or(u64::MAX);
+        }
+    }
+    report.reserves = rows.into_values().collect();
+    report
+}
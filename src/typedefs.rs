@@ -62,9 +62,15 @@ pub enum UpdateLendingMarketConfigValue {
     U16(u16),
     U64(u64),
     U128(u128),
-    Pubkey(Pubkey),
+    Pubkey(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
+        Pubkey,
+    ),
     ElevationGroup(ElevationGroup),
-    Name([u8; 32]),
+    Name(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::name"))]
+        [u8; 32],
+    ),
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -108,8 +114,11 @@ pub struct ElevationGroup {
     pub liquidation_threshold_pct: u8,
     pub allow_new_loans: u8,
     pub max_reserves_as_collateral: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding0: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub debt_reserve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding1: [u64; 4],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -121,22 +130,31 @@ pub struct InitObligationArgs {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObligationCollateral {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub deposit_reserve: Pubkey,
     pub deposited_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub market_value_sf: u128,
     pub borrowed_amount_against_this_collateral_in_elevation_group: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: [u64; 9],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObligationLiquidity {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub borrow_reserve: Pubkey,
     pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub borrowed_amount_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub market_value_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub borrow_factor_adjusted_market_value_sf: u128,
     pub borrowed_amount_outside_elevation_groups: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding2: [u64; 7],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -150,6 +168,7 @@ pub enum AssetTier {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BigFractionBytes {
     pub value: [u64; 4],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: [u64; 2],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -161,10 +180,14 @@ pub enum FeeCalculation {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReserveCollateral {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub mint_pubkey: Pubkey,
     pub mint_total_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub supply_vault: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding1: [u128; 32],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding2: [u128; 32],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -173,7 +196,9 @@ pub struct ReserveConfig {
     pub status: u8,
     pub asset_tier: u8,
     pub host_fixed_interest_rate_bps: u16,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved2: [u8; 2],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved3: [u8; 8],
     pub protocol_take_rate_pct: u8,
     pub protocol_liquidation_fee_pct: u8,
@@ -196,6 +221,7 @@ pub struct ReserveConfig {
     pub disable_usage_as_coll_outside_emode: u8,
     pub utilization_limit_block_borrowing_above_pct: u8,
     pub autodeleverage_enabled: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved1: [u8; 1],
     pub borrow_limit_outside_elevation_group: u64,
     pub borrow_limit_against_this_collateral_in_elevation_group: [u64; 32],
@@ -210,30 +236,45 @@ pub enum ReserveFarmKind {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReserveFees {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf_u64"))]
     pub borrow_fee_sf: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf_u64"))]
     pub flash_loan_fee_sf: u64,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: [u8; 8],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReserveLiquidity {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub mint_pubkey: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub supply_vault: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub fee_vault: Pubkey,
     pub available_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub borrowed_amount_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub market_price_sf: u128,
     pub market_price_last_updated_ts: u64,
     pub mint_decimals: u64,
     pub deposit_limit_crossed_timestamp: u64,
     pub borrow_limit_crossed_timestamp: u64,
     pub cumulative_borrow_rate_bsf: BigFractionBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub accumulated_protocol_fees_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub accumulated_referrer_fees_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub pending_referrer_fees_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::sf"))]
     pub absolute_referral_rate_sf: u128,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub token_program: Pubkey,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed", with = "crate::serde_helpers::big_array"))]
     pub padding2: [u64; 51],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding3: [u128; 32],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -261,11 +302,13 @@ pub struct PriceHeuristic {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PythConfiguration {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub price: Pubkey,
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScopeConfiguration {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub price_feed: Pubkey,
     pub price_chain: [u16; 4],
     pub twap_chain: [u16; 4],
@@ -273,12 +316,15 @@ pub struct ScopeConfiguration {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchboardConfiguration {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub price_aggregator: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::pubkey"))]
     pub twap_aggregator: Pubkey,
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenInfo {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::name"))]
     pub name: [u8; 32],
     pub heuristic: PriceHeuristic,
     pub max_twap_divergence_bps: u64,
@@ -288,7 +334,9 @@ pub struct TokenInfo {
     pub switchboard_configuration: SwitchboardConfiguration,
     pub pyth_configuration: PythConfiguration,
     pub block_price_usage: u8,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub reserved: [u8; 7],
+    #[cfg_attr(feature = "serde", serde(default = "crate::serde_helpers::zeroed"))]
     pub padding: [u64; 19],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -0,0 +1,1131 @@
+//! Semantic checks of instruction accounts for programs calling into the lending program.
+//!
+//! Where `*_verify_account_keys` compares the accounts against keys the caller already trusts,
+//! the `*_verify_account_semantics` functions derive the expected keys from the accounts
+//! themselves: the reserve and obligation belong to the lending market, the vaults and mints are
+//! the reserve's, the authority is the market's PDA, the owner is the obligation's and signed,
+//! and the token program owns the mint. Fields are read in place at their [`offsets`](crate::offsets),
+//! so no account larger than a few pubkeys is copied onto the stack.
+use crate::offsets::*;
+use crate::*;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+use std::cell::Ref;
+use thiserror::Error;
+
+/// First failed check, naming the offending field of the instruction's accounts struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum AccountVerificationError {
+    #[error("{field}: expected {expected}, got {actual}")]
+    WrongAccount {
+        field: &'static str,
+        expected: Pubkey,
+        actual: Pubkey,
+    },
+    #[error("{field}: belongs to lending market {lending_market}, not {expected_lending_market}")]
+    ForeignAccount {
+        field: &'static str,
+        expected_lending_market: Pubkey,
+        lending_market: Pubkey,
+    },
+    #[error("{field}: owned by {owner}, expected {expected_owner}")]
+    InvalidOwner {
+        field: &'static str,
+        expected_owner: Pubkey,
+        owner: Pubkey,
+    },
+    #[error("{field}: not a valid account of the expected type")]
+    InvalidAccountData { field: &'static str },
+    #[error("{field}: missing required signature")]
+    MissingSignature { field: &'static str },
+}
+
+impl AccountVerificationError {
+    /// Name of the offending field.
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::WrongAccount { field, .. }
+            | Self::ForeignAccount { field, .. }
+            | Self::InvalidOwner { field, .. }
+            | Self::InvalidAccountData { field }
+            | Self::MissingSignature { field } => field,
+        }
+    }
+}
+
+impl From<AccountVerificationError> for ProgramError {
+    fn from(e: AccountVerificationError) -> Self {
+        match e {
+            AccountVerificationError::WrongAccount { .. }
+            | AccountVerificationError::ForeignAccount { .. } => ProgramError::InvalidArgument,
+            AccountVerificationError::InvalidOwner { .. } => ProgramError::IllegalOwner,
+            AccountVerificationError::InvalidAccountData { .. } => ProgramError::InvalidAccountData,
+            AccountVerificationError::MissingSignature { .. } => {
+                ProgramError::MissingRequiredSignature
+            }
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, AccountVerificationError>;
+
+fn check_key(field: &'static str, info: &AccountInfo, expected: &Pubkey) -> Result<()> {
+    if info.key != expected {
+        return Err(AccountVerificationError::WrongAccount {
+            field,
+            expected: *expected,
+            actual: *info.key,
+        });
+    }
+    Ok(())
+}
+
+fn check_signer(field: &'static str, info: &AccountInfo) -> Result<()> {
+    if !info.is_signer {
+        return Err(AccountVerificationError::MissingSignature { field });
+    }
+    Ok(())
+}
+
+fn check_lending_market(
+    field: &'static str,
+    lending_market_of_account: &Pubkey,
+    lending_market: &AccountInfo,
+) -> Result<()> {
+    if lending_market_of_account != lending_market.key {
+        return Err(AccountVerificationError::ForeignAccount {
+            field,
+            expected_lending_market: *lending_market.key,
+            lending_market: *lending_market_of_account,
+        });
+    }
+    Ok(())
+}
+
+/// `token_program` must be `expected`, the reserve's token program, and own `mint`.
+fn check_token_program(
+    mint_field: &'static str,
+    mint: &AccountInfo,
+    token_program_field: &'static str,
+    token_program: &AccountInfo,
+    expected: &Pubkey,
+) -> Result<()> {
+    check_key(token_program_field, token_program, expected)?;
+    if mint.owner != token_program.key {
+        return Err(AccountVerificationError::InvalidOwner {
+            field: mint_field,
+            expected_owner: *token_program.key,
+            owner: *mint.owner,
+        });
+    }
+    Ok(())
+}
+
+/// Data of a `discm` account owned by `owner`, at least `len` bytes long.
+fn load<'a>(
+    field: &'static str,
+    info: &'a AccountInfo,
+    owner: &Pubkey,
+    discm: [u8; 8],
+    len: usize,
+) -> Result<Ref<'a, &'a mut [u8]>> {
+    if info.owner != owner {
+        return Err(AccountVerificationError::InvalidOwner {
+            field,
+            expected_owner: *owner,
+            owner: *info.owner,
+        });
+    }
+    let data = info
+        .try_borrow_data()
+        .map_err(|_| AccountVerificationError::InvalidAccountData { field })?;
+    if data.len() < len || data[..8] != discm {
+        return Err(AccountVerificationError::InvalidAccountData { field });
+    }
+    Ok(data)
+}
+
+fn pubkey_at(data: &[u8], offset: usize) -> Pubkey {
+    let mut key = [0; 32];
+    key.copy_from_slice(&data[offset..offset + 32]);
+    Pubkey::new_from_array(key)
+}
+
+/// The market authority must be the PDA of `lending_market` with its stored bump seed.
+fn check_lending_market_authority(
+    field: &'static str,
+    lending_market_authority: &AccountInfo,
+    lending_market: &AccountInfo,
+) -> Result<()> {
+    let data = load(
+        "lending_market",
+        lending_market,
+        &crate::ID,
+        LENDING_MARKET_ACCOUNT_DISCM,
+        LENDING_MARKET_ACCOUNT_LEN,
+    )?;
+    let bump = data[LENDING_MARKET_BUMP_SEED_OFFSET];
+    let expected = Pubkey::create_program_address(
+        &[
+            LENDING_MARKET_AUTHORITY_SEED,
+            lending_market.key.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| AccountVerificationError::InvalidAccountData {
+        field: "lending_market",
+    })?;
+    check_key(field, lending_market_authority, &expected)
+}
+
+/// Keys a reserve links to.
+struct ReserveView {
+    lending_market: Pubkey,
+    farm_collateral: Pubkey,
+    farm_debt: Pubkey,
+    liquidity_mint: Pubkey,
+    liquidity_supply_vault: Pubkey,
+    liquidity_fee_vault: Pubkey,
+    liquidity_token_program: Pubkey,
+    collateral_mint: Pubkey,
+    collateral_supply_vault: Pubkey,
+}
+
+impl ReserveView {
+    fn load(field: &'static str, info: &AccountInfo) -> Result<Self> {
+        let data = load(
+            field,
+            info,
+            &crate::ID,
+            RESERVE_ACCOUNT_DISCM,
+            RESERVE_ACCOUNT_LEN,
+        )?;
+        Ok(Self {
+            lending_market: pubkey_at(&data, RESERVE_LENDING_MARKET_OFFSET),
+            farm_collateral: pubkey_at(&data, RESERVE_FARM_COLLATERAL_OFFSET),
+            farm_debt: pubkey_at(&data, RESERVE_FARM_DEBT_OFFSET),
+            liquidity_mint: pubkey_at(&data, RESERVE_LIQUIDITY_MINT_PUBKEY_OFFSET),
+            liquidity_supply_vault: pubkey_at(&data, RESERVE_LIQUIDITY_SUPPLY_VAULT_OFFSET),
+            liquidity_fee_vault: pubkey_at(&data, RESERVE_LIQUIDITY_FEE_VAULT_OFFSET),
+            liquidity_token_program: pubkey_at(&data, RESERVE_LIQUIDITY_TOKEN_PROGRAM_OFFSET),
+            collateral_mint: pubkey_at(&data, RESERVE_COLLATERAL_MINT_PUBKEY_OFFSET),
+            collateral_supply_vault: pubkey_at(&data, RESERVE_COLLATERAL_SUPPLY_VAULT_OFFSET),
+        })
+    }
+
+    fn farm(&self, kind: ReserveFarmKind) -> Pubkey {
+        match kind {
+            ReserveFarmKind::Collateral => self.farm_collateral,
+            ReserveFarmKind::Debt => self.farm_debt,
+        }
+    }
+}
+
+/// Keys an obligation links to.
+struct ObligationView {
+    lending_market: Pubkey,
+    owner: Pubkey,
+    referrer: Pubkey,
+}
+
+impl ObligationView {
+    fn load(field: &'static str, info: &AccountInfo) -> Result<Self> {
+        let data = load(
+            field,
+            info,
+            &crate::ID,
+            OBLIGATION_ACCOUNT_DISCM,
+            OBLIGATION_ACCOUNT_LEN,
+        )?;
+        Ok(Self {
+            lending_market: pubkey_at(&data, OBLIGATION_LENDING_MARKET_OFFSET),
+            owner: pubkey_at(&data, OBLIGATION_OWNER_OFFSET),
+            referrer: pubkey_at(&data, OBLIGATION_REFERRER_OFFSET),
+        })
+    }
+}
+
+/// The obligation's farm accounts for the reserve's `kind` farm: the program id for both if the
+/// reserve has no such farm, otherwise the farm and the obligation's `UserState` in it.
+fn check_farm_accounts(
+    reserve: &ReserveView,
+    kind: ReserveFarmKind,
+    obligation: &AccountInfo,
+    user_state_field: &'static str,
+    user_state: &AccountInfo,
+    farm_state_field: &'static str,
+    farm_state: &AccountInfo,
+) -> Result<()> {
+    let farm = reserve.farm(kind);
+    if farm == Pubkey::default() {
+        check_key(farm_state_field, farm_state, &crate::ID)?;
+        return check_key(user_state_field, user_state, &crate::ID);
+    }
+    check_key(farm_state_field, farm_state, &farm)?;
+    let data = load(
+        user_state_field,
+        user_state,
+        &FARMS_PROGRAM_ID,
+        USER_STATE_ACCOUNT_DISCM,
+        USER_STATE_ACCOUNT_LEN,
+    )?;
+    if pubkey_at(&data, USER_STATE_FARM_STATE_OFFSET) != farm
+        || pubkey_at(&data, USER_STATE_OWNER_OFFSET) != *obligation.key
+    {
+        return Err(AccountVerificationError::InvalidAccountData {
+            field: user_state_field,
+        });
+    }
+    Ok(())
+}
+
+/// The program id if the obligation has no referrer, otherwise the referrer's token state for
+/// the reserve's mint.
+fn check_referrer_token_state(
+    field: &'static str,
+    referrer_token_state: &AccountInfo,
+    obligation: &ObligationView,
+    reserve: &ReserveView,
+) -> Result<()> {
+    if obligation.referrer == Pubkey::default() {
+        return check_key(field, referrer_token_state, &crate::ID);
+    }
+    let data = load(
+        field,
+        referrer_token_state,
+        &crate::ID,
+        REFERRER_TOKEN_STATE_ACCOUNT_DISCM,
+        REFERRER_TOKEN_STATE_ACCOUNT_LEN,
+    )?;
+    if pubkey_at(&data, REFERRER_TOKEN_STATE_REFERRER_OFFSET) != obligation.referrer
+        || pubkey_at(&data, REFERRER_TOKEN_STATE_MINT_OFFSET) != reserve.liquidity_mint
+    {
+        return Err(AccountVerificationError::InvalidAccountData { field });
+    }
+    Ok(())
+}
+
+macro_rules! key {
+    ($accounts:ident . $field:ident, $expected:expr) => {
+        check_key(stringify!($field), $accounts.$field, &$expected)?
+    };
+}
+
+macro_rules! signer {
+    ($accounts:ident . $field:ident) => {
+        check_signer(stringify!($field), $accounts.$field)?
+    };
+}
+
+/// Loads the reserve and checks it belongs to the lending market.
+macro_rules! reserve {
+    ($accounts:ident . $reserve:ident, $lending_market:ident) => {{
+        let reserve = ReserveView::load(stringify!($reserve), $accounts.$reserve)?;
+        check_lending_market(
+            stringify!($reserve),
+            &reserve.lending_market,
+            $accounts.$lending_market,
+        )?;
+        reserve
+    }};
+}
+
+/// Loads the obligation, checks its market and that `owner` is its signing owner.
+macro_rules! obligation {
+    ($accounts:ident . $obligation:ident, $lending_market:ident, $owner:ident) => {{
+        let obligation = ObligationView::load(stringify!($obligation), $accounts.$obligation)?;
+        check_lending_market(
+            stringify!($obligation),
+            &obligation.lending_market,
+            $accounts.$lending_market,
+        )?;
+        check_key(stringify!($owner), $accounts.$owner, &obligation.owner)?;
+        check_signer(stringify!($owner), $accounts.$owner)?;
+        obligation
+    }};
+}
+
+macro_rules! authority {
+    ($accounts:ident . $authority:ident, $lending_market:ident) => {
+        check_lending_market_authority(
+            stringify!($authority),
+            $accounts.$authority,
+            $accounts.$lending_market,
+        )?
+    };
+}
+
+macro_rules! token_program {
+    ($accounts:ident . $mint:ident, $token_program:ident, $expected:expr) => {
+        check_token_program(
+            stringify!($mint),
+            $accounts.$mint,
+            stringify!($token_program),
+            $accounts.$token_program,
+            &$expected,
+        )?
+    };
+}
+
+macro_rules! farm {
+    (
+        $accounts:ident,
+        $reserve:expr,
+        $kind:ident,
+        $obligation:ident,
+        $user_state:ident,
+        $farm_state:ident
+    ) => {
+        check_farm_accounts(
+            &$reserve,
+            ReserveFarmKind::$kind,
+            $accounts.$obligation,
+            stringify!($user_state),
+            $accounts.$user_state,
+            stringify!($farm_state),
+            $accounts.$farm_state,
+        )?
+    };
+}
+
+pub fn refresh_reserve_verify_account_semantics(
+    accounts: RefreshReserveAccounts<'_, '_>,
+) -> Result<()> {
+    let data = load(
+        "reserve",
+        accounts.reserve,
+        &crate::ID,
+        RESERVE_ACCOUNT_DISCM,
+        RESERVE_ACCOUNT_LEN,
+    )?;
+    check_lending_market(
+        "reserve",
+        &pubkey_at(&data, RESERVE_LENDING_MARKET_OFFSET),
+        accounts.lending_market,
+    )
+}
+
+pub fn deposit_reserve_liquidity_verify_account_semantics(
+    accounts: DepositReserveLiquidityAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.owner);
+    let reserve = reserve!(accounts.reserve, lending_market);
+    authority!(accounts.lending_market_authority, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    key!(accounts.reserve_collateral_mint, reserve.collateral_mint);
+    token_program!(
+        accounts.reserve_collateral_mint,
+        collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn redeem_reserve_collateral_verify_account_semantics(
+    accounts: RedeemReserveCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.owner);
+    let reserve = reserve!(accounts.reserve, lending_market);
+    authority!(accounts.lending_market_authority, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(accounts.reserve_collateral_mint, reserve.collateral_mint);
+    key!(
+        accounts.reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    token_program!(
+        accounts.reserve_collateral_mint,
+        collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn deposit_obligation_collateral_verify_account_semantics(
+    accounts: DepositObligationCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    obligation!(accounts.obligation, lending_market, owner);
+    let reserve = reserve!(accounts.deposit_reserve, lending_market);
+    key!(
+        accounts.reserve_destination_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(accounts.token_program, TOKEN_PROGRAM_ID);
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn deposit_obligation_collateral_v2_verify_account_semantics(
+    accounts: DepositObligationCollateralV2Accounts<'_, '_>,
+) -> Result<()> {
+    obligation!(
+        accounts.deposit_accounts_obligation,
+        deposit_accounts_lending_market,
+        deposit_accounts_owner
+    );
+    let reserve = reserve!(
+        accounts.deposit_accounts_deposit_reserve,
+        deposit_accounts_lending_market
+    );
+    key!(
+        accounts.deposit_accounts_reserve_destination_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(accounts.deposit_accounts_token_program, TOKEN_PROGRAM_ID);
+    key!(
+        accounts.deposit_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    authority!(
+        accounts.lending_market_authority,
+        deposit_accounts_lending_market
+    );
+    farm!(
+        accounts,
+        reserve,
+        Collateral,
+        deposit_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn withdraw_obligation_collateral_verify_account_semantics(
+    accounts: WithdrawObligationCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    obligation!(accounts.obligation, lending_market, owner);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.withdraw_reserve, lending_market);
+    key!(
+        accounts.reserve_source_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(accounts.token_program, TOKEN_PROGRAM_ID);
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn withdraw_obligation_collateral_v2_verify_account_semantics(
+    accounts: WithdrawObligationCollateralV2Accounts<'_, '_>,
+) -> Result<()> {
+    obligation!(
+        accounts.withdraw_accounts_obligation,
+        withdraw_accounts_lending_market,
+        withdraw_accounts_owner
+    );
+    authority!(
+        accounts.withdraw_accounts_lending_market_authority,
+        withdraw_accounts_lending_market
+    );
+    let reserve = reserve!(
+        accounts.withdraw_accounts_withdraw_reserve,
+        withdraw_accounts_lending_market
+    );
+    key!(
+        accounts.withdraw_accounts_reserve_source_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(accounts.withdraw_accounts_token_program, TOKEN_PROGRAM_ID);
+    key!(
+        accounts.withdraw_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        reserve,
+        Collateral,
+        withdraw_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn borrow_obligation_liquidity_verify_account_semantics(
+    accounts: BorrowObligationLiquidityAccounts<'_, '_>,
+) -> Result<()> {
+    let obligation = obligation!(accounts.obligation, lending_market, owner);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.borrow_reserve, lending_market);
+    key!(
+        accounts.borrow_reserve_liquidity_mint,
+        reserve.liquidity_mint
+    );
+    key!(
+        accounts.reserve_source_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.borrow_reserve_liquidity_fee_receiver,
+        reserve.liquidity_fee_vault
+    );
+    check_referrer_token_state(
+        "referrer_token_state",
+        accounts.referrer_token_state,
+        &obligation,
+        &reserve,
+    )?;
+    token_program!(
+        accounts.borrow_reserve_liquidity_mint,
+        token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn borrow_obligation_liquidity_v2_verify_account_semantics(
+    accounts: BorrowObligationLiquidityV2Accounts<'_, '_>,
+) -> Result<()> {
+    let obligation = obligation!(
+        accounts.borrow_accounts_obligation,
+        borrow_accounts_lending_market,
+        borrow_accounts_owner
+    );
+    authority!(
+        accounts.borrow_accounts_lending_market_authority,
+        borrow_accounts_lending_market
+    );
+    let reserve = reserve!(
+        accounts.borrow_accounts_borrow_reserve,
+        borrow_accounts_lending_market
+    );
+    key!(
+        accounts.borrow_accounts_borrow_reserve_liquidity_mint,
+        reserve.liquidity_mint
+    );
+    key!(
+        accounts.borrow_accounts_reserve_source_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.borrow_accounts_borrow_reserve_liquidity_fee_receiver,
+        reserve.liquidity_fee_vault
+    );
+    check_referrer_token_state(
+        "borrow_accounts_referrer_token_state",
+        accounts.borrow_accounts_referrer_token_state,
+        &obligation,
+        &reserve,
+    )?;
+    token_program!(
+        accounts.borrow_accounts_borrow_reserve_liquidity_mint,
+        borrow_accounts_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.borrow_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        reserve,
+        Debt,
+        borrow_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+/// Anyone may repay an obligation, so `owner` is only required to sign, not to own it.
+pub fn repay_obligation_liquidity_verify_account_semantics(
+    accounts: RepayObligationLiquidityAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.owner);
+    let obligation = ObligationView::load("obligation", accounts.obligation)?;
+    check_lending_market(
+        "obligation",
+        &obligation.lending_market,
+        accounts.lending_market,
+    )?;
+    let reserve = reserve!(accounts.repay_reserve, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_destination_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn repay_obligation_liquidity_v2_verify_account_semantics(
+    accounts: RepayObligationLiquidityV2Accounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.repay_accounts_owner);
+    let obligation = ObligationView::load(
+        "repay_accounts_obligation",
+        accounts.repay_accounts_obligation,
+    )?;
+    check_lending_market(
+        "repay_accounts_obligation",
+        &obligation.lending_market,
+        accounts.repay_accounts_lending_market,
+    )?;
+    let reserve = reserve!(
+        accounts.repay_accounts_repay_reserve,
+        repay_accounts_lending_market
+    );
+    key!(
+        accounts.repay_accounts_reserve_liquidity_mint,
+        reserve.liquidity_mint
+    );
+    key!(
+        accounts.repay_accounts_reserve_destination_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    token_program!(
+        accounts.repay_accounts_reserve_liquidity_mint,
+        repay_accounts_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.repay_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        reserve,
+        Debt,
+        repay_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    authority!(
+        accounts.lending_market_authority,
+        repay_accounts_lending_market
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn deposit_reserve_liquidity_and_obligation_collateral_verify_account_semantics(
+    accounts: DepositReserveLiquidityAndObligationCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    obligation!(accounts.obligation, lending_market, owner);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.reserve, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    key!(accounts.reserve_collateral_mint, reserve.collateral_mint);
+    key!(
+        accounts.reserve_destination_deposit_collateral,
+        reserve.collateral_supply_vault
+    );
+    token_program!(
+        accounts.reserve_collateral_mint,
+        collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn deposit_reserve_liquidity_and_obligation_collateral_v2_verify_account_semantics(
+    accounts: DepositReserveLiquidityAndObligationCollateralV2Accounts<'_, '_>,
+) -> Result<()> {
+    obligation!(
+        accounts.deposit_accounts_obligation,
+        deposit_accounts_lending_market,
+        deposit_accounts_owner
+    );
+    authority!(
+        accounts.deposit_accounts_lending_market_authority,
+        deposit_accounts_lending_market
+    );
+    let reserve = reserve!(
+        accounts.deposit_accounts_reserve,
+        deposit_accounts_lending_market
+    );
+    key!(
+        accounts.deposit_accounts_reserve_liquidity_mint,
+        reserve.liquidity_mint
+    );
+    key!(
+        accounts.deposit_accounts_reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.deposit_accounts_reserve_collateral_mint,
+        reserve.collateral_mint
+    );
+    key!(
+        accounts.deposit_accounts_reserve_destination_deposit_collateral,
+        reserve.collateral_supply_vault
+    );
+    token_program!(
+        accounts.deposit_accounts_reserve_collateral_mint,
+        deposit_accounts_collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.deposit_accounts_reserve_liquidity_mint,
+        deposit_accounts_liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.deposit_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        reserve,
+        Collateral,
+        deposit_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn withdraw_obligation_collateral_and_redeem_reserve_collateral_verify_account_semantics(
+    accounts: WithdrawObligationCollateralAndRedeemReserveCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    obligation!(accounts.obligation, lending_market, owner);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.withdraw_reserve, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_source_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(accounts.reserve_collateral_mint, reserve.collateral_mint);
+    key!(
+        accounts.reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    token_program!(
+        accounts.reserve_collateral_mint,
+        collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn withdraw_obligation_collateral_and_redeem_reserve_collateral_v2_verify_account_semantics(
+    accounts: WithdrawObligationCollateralAndRedeemReserveCollateralV2Accounts<'_, '_>,
+) -> Result<()> {
+    obligation!(
+        accounts.withdraw_accounts_obligation,
+        withdraw_accounts_lending_market,
+        withdraw_accounts_owner
+    );
+    authority!(
+        accounts.withdraw_accounts_lending_market_authority,
+        withdraw_accounts_lending_market
+    );
+    let reserve = reserve!(
+        accounts.withdraw_accounts_withdraw_reserve,
+        withdraw_accounts_lending_market
+    );
+    key!(
+        accounts.withdraw_accounts_reserve_liquidity_mint,
+        reserve.liquidity_mint
+    );
+    key!(
+        accounts.withdraw_accounts_reserve_source_collateral,
+        reserve.collateral_supply_vault
+    );
+    key!(
+        accounts.withdraw_accounts_reserve_collateral_mint,
+        reserve.collateral_mint
+    );
+    key!(
+        accounts.withdraw_accounts_reserve_liquidity_supply,
+        reserve.liquidity_supply_vault
+    );
+    token_program!(
+        accounts.withdraw_accounts_reserve_collateral_mint,
+        withdraw_accounts_collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.withdraw_accounts_reserve_liquidity_mint,
+        withdraw_accounts_liquidity_token_program,
+        reserve.liquidity_token_program
+    );
+    key!(
+        accounts.withdraw_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        reserve,
+        Collateral,
+        withdraw_accounts_obligation,
+        farms_accounts_obligation_farm_user_state,
+        farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn liquidate_obligation_and_redeem_reserve_collateral_verify_account_semantics(
+    accounts: LiquidateObligationAndRedeemReserveCollateralAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.liquidator);
+    let obligation = ObligationView::load("obligation", accounts.obligation)?;
+    check_lending_market(
+        "obligation",
+        &obligation.lending_market,
+        accounts.lending_market,
+    )?;
+    authority!(accounts.lending_market_authority, lending_market);
+    let repay_reserve = reserve!(accounts.repay_reserve, lending_market);
+    key!(
+        accounts.repay_reserve_liquidity_mint,
+        repay_reserve.liquidity_mint
+    );
+    key!(
+        accounts.repay_reserve_liquidity_supply,
+        repay_reserve.liquidity_supply_vault
+    );
+    let withdraw_reserve = reserve!(accounts.withdraw_reserve, lending_market);
+    key!(
+        accounts.withdraw_reserve_liquidity_mint,
+        withdraw_reserve.liquidity_mint
+    );
+    key!(
+        accounts.withdraw_reserve_collateral_mint,
+        withdraw_reserve.collateral_mint
+    );
+    key!(
+        accounts.withdraw_reserve_collateral_supply,
+        withdraw_reserve.collateral_supply_vault
+    );
+    key!(
+        accounts.withdraw_reserve_liquidity_supply,
+        withdraw_reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.withdraw_reserve_liquidity_fee_receiver,
+        withdraw_reserve.liquidity_fee_vault
+    );
+    token_program!(
+        accounts.withdraw_reserve_collateral_mint,
+        collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.repay_reserve_liquidity_mint,
+        repay_liquidity_token_program,
+        repay_reserve.liquidity_token_program
+    );
+    token_program!(
+        accounts.withdraw_reserve_liquidity_mint,
+        withdraw_liquidity_token_program,
+        withdraw_reserve.liquidity_token_program
+    );
+    key!(
+        accounts.instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    Ok(())
+}
+
+pub fn liquidate_obligation_and_redeem_reserve_collateral_v2_verify_account_semantics(
+    accounts: LiquidateObligationAndRedeemReserveCollateralV2Accounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.liquidation_accounts_liquidator);
+    let obligation = ObligationView::load(
+        "liquidation_accounts_obligation",
+        accounts.liquidation_accounts_obligation,
+    )?;
+    check_lending_market(
+        "liquidation_accounts_obligation",
+        &obligation.lending_market,
+        accounts.liquidation_accounts_lending_market,
+    )?;
+    authority!(
+        accounts.liquidation_accounts_lending_market_authority,
+        liquidation_accounts_lending_market
+    );
+    let repay_reserve = reserve!(
+        accounts.liquidation_accounts_repay_reserve,
+        liquidation_accounts_lending_market
+    );
+    key!(
+        accounts.liquidation_accounts_repay_reserve_liquidity_mint,
+        repay_reserve.liquidity_mint
+    );
+    key!(
+        accounts.liquidation_accounts_repay_reserve_liquidity_supply,
+        repay_reserve.liquidity_supply_vault
+    );
+    let withdraw_reserve = reserve!(
+        accounts.liquidation_accounts_withdraw_reserve,
+        liquidation_accounts_lending_market
+    );
+    key!(
+        accounts.liquidation_accounts_withdraw_reserve_liquidity_mint,
+        withdraw_reserve.liquidity_mint
+    );
+    key!(
+        accounts.liquidation_accounts_withdraw_reserve_collateral_mint,
+        withdraw_reserve.collateral_mint
+    );
+    key!(
+        accounts.liquidation_accounts_withdraw_reserve_collateral_supply,
+        withdraw_reserve.collateral_supply_vault
+    );
+    key!(
+        accounts.liquidation_accounts_withdraw_reserve_liquidity_supply,
+        withdraw_reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.liquidation_accounts_withdraw_reserve_liquidity_fee_receiver,
+        withdraw_reserve.liquidity_fee_vault
+    );
+    token_program!(
+        accounts.liquidation_accounts_withdraw_reserve_collateral_mint,
+        liquidation_accounts_collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    token_program!(
+        accounts.liquidation_accounts_repay_reserve_liquidity_mint,
+        liquidation_accounts_repay_liquidity_token_program,
+        repay_reserve.liquidity_token_program
+    );
+    token_program!(
+        accounts.liquidation_accounts_withdraw_reserve_liquidity_mint,
+        liquidation_accounts_withdraw_liquidity_token_program,
+        withdraw_reserve.liquidity_token_program
+    );
+    key!(
+        accounts.liquidation_accounts_instruction_sysvar_account,
+        sysvar::instructions::ID
+    );
+    farm!(
+        accounts,
+        withdraw_reserve,
+        Collateral,
+        liquidation_accounts_obligation,
+        collateral_farms_accounts_obligation_farm_user_state,
+        collateral_farms_accounts_reserve_farm_state
+    );
+    farm!(
+        accounts,
+        repay_reserve,
+        Debt,
+        liquidation_accounts_obligation,
+        debt_farms_accounts_obligation_farm_user_state,
+        debt_farms_accounts_reserve_farm_state
+    );
+    key!(accounts.farms_program, FARMS_PROGRAM_ID);
+    Ok(())
+}
+
+pub fn flash_borrow_reserve_liquidity_verify_account_semantics(
+    accounts: FlashBorrowReserveLiquidityAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.user_transfer_authority);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.reserve, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_source_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.reserve_liquidity_fee_receiver,
+        reserve.liquidity_fee_vault
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        token_program,
+        reserve.liquidity_token_program
+    );
+    key!(accounts.sysvar_info, sysvar::instructions::ID);
+    Ok(())
+}
+
+pub fn flash_repay_reserve_liquidity_verify_account_semantics(
+    accounts: FlashRepayReserveLiquidityAccounts<'_, '_>,
+) -> Result<()> {
+    signer!(accounts.user_transfer_authority);
+    authority!(accounts.lending_market_authority, lending_market);
+    let reserve = reserve!(accounts.reserve, lending_market);
+    key!(accounts.reserve_liquidity_mint, reserve.liquidity_mint);
+    key!(
+        accounts.reserve_destination_liquidity,
+        reserve.liquidity_supply_vault
+    );
+    key!(
+        accounts.reserve_liquidity_fee_receiver,
+        reserve.liquidity_fee_vault
+    );
+    token_program!(
+        accounts.reserve_liquidity_mint,
+        token_program,
+        reserve.liquidity_token_program
+    );
+    key!(accounts.sysvar_info, sysvar::instructions::ID);
+    Ok(())
+}
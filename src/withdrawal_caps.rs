@@ -0,0 +1,112 @@
+//! Rolling-interval withdrawal caps of `ReserveConfig::deposit_withdrawal_cap` and
+//! `ReserveConfig::debt_withdrawal_cap`.
+//!
+//! Withdrawals (resp. borrows) add to `current_total` and deposits (resp. repays) subtract from it;
+//! the first action after `config_interval_length_seconds` have elapsed since
+//! `last_interval_start_timestamp` starts a new interval from zero. Amounts are in liquidity tokens.
+use crate::*;
+
+fn to_timestamp(now: i64) -> u64 {
+    u64::try_from(now).unwrap_or(0)
+}
+
+impl WithdrawalCaps {
+    /// Caps with a non-positive capacity or a zero interval do not limit anything.
+    pub fn is_enabled(&self) -> bool {
+        self.config_capacity > 0 && self.config_interval_length_seconds > 0
+    }
+
+    fn interval_elapsed(&self, now: u64) -> bool {
+        now.saturating_sub(self.last_interval_start_timestamp)
+            >= self.config_interval_length_seconds
+    }
+
+    /// `current_total` as seen by an action at unix timestamp `now`, i.e. zero once the interval
+    /// has elapsed.
+    pub fn current_total_at(&self, now: i64) -> i64 {
+        if self.is_enabled() && self.interval_elapsed(to_timestamp(now)) {
+            0
+        } else {
+            self.current_total
+        }
+    }
+
+    /// Amount that can still be withdrawn at `now`; `u64::MAX` when the cap is disabled.
+    pub fn remaining_capacity(&self, now: i64) -> u64 {
+        if !self.is_enabled() {
+            return u64::MAX;
+        }
+        let remaining = i128::from(self.config_capacity) - i128::from(self.current_total_at(now));
+        u64::try_from(remaining.max(0)).unwrap_or(u64::MAX)
+    }
+
+    /// Seconds until the current interval ends and the capacity is restored, `None` when the cap
+    /// is disabled. Zero means the next action starts a new interval.
+    pub fn time_until_reset(&self, now: i64) -> Option<u64> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let interval_end = self
+            .last_interval_start_timestamp
+            .saturating_add(self.config_interval_length_seconds);
+        Some(interval_end.saturating_sub(to_timestamp(now)))
+    }
+
+    fn start_interval_if_elapsed(&mut self, now: u64) {
+        if self.interval_elapsed(now) {
+            self.current_total = 0;
+            self.last_interval_start_timestamp = now;
+        }
+    }
+
+    /// State after withdrawing (or borrowing) `amount` at `now`, failing with
+    /// [`KaminoLendingError::WithdrawalCapReached`] if it would exceed the capacity.
+    pub fn after_withdrawal(&self, amount: u64, now: i64) -> Result<Self, KaminoLendingError> {
+        let mut caps = self.clone();
+        if !caps.is_enabled() {
+            return Ok(caps);
+        }
+        caps.start_interval_if_elapsed(to_timestamp(now));
+        let amount = i64::try_from(amount).map_err(|_| KaminoLendingError::IntegerOverflow)?;
+        let current_total = caps
+            .current_total
+            .checked_add(amount)
+            .ok_or(KaminoLendingError::IntegerOverflow)?;
+        if current_total > caps.config_capacity {
+            return Err(KaminoLendingError::WithdrawalCapReached);
+        }
+        caps.current_total = current_total;
+        Ok(caps)
+    }
+
+    /// State after depositing (or repaying) `amount` at `now`, which frees up capacity.
+    pub fn after_deposit(&self, amount: u64, now: i64) -> Self {
+        let mut caps = self.clone();
+        if !caps.is_enabled() {
+            return caps;
+        }
+        caps.start_interval_if_elapsed(to_timestamp(now));
+        let amount = i64::try_from(amount).unwrap_or(i64::MAX);
+        caps.current_total = caps.current_total.saturating_sub(amount);
+        caps
+    }
+}
+
+impl Reserve {
+    /// Largest amount of liquidity that can be redeemed from the reserve at `now`, bounded by the
+    /// available liquidity and the deposit withdrawal cap.
+    pub fn max_withdrawable_liquidity(&self, now: i64) -> u64 {
+        self.liquidity
+            .available_amount
+            .min(self.config.deposit_withdrawal_cap.remaining_capacity(now))
+    }
+
+    /// Largest amount of liquidity that can be borrowed from the reserve at `now`, bounded by the
+    /// available liquidity and the debt withdrawal cap. Borrow limits and obligation health are
+    /// not taken into account.
+    pub fn max_borrowable_liquidity(&self, now: i64) -> u64 {
+        self.liquidity
+            .available_amount
+            .min(self.config.debt_withdrawal_cap.remaining_capacity(now))
+    }
+}
@@ -0,0 +1,97 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::*;
+use solana_program::rent::Rent;
+
+fn zeroed<T: BorshDeserialize>() -> T {
+    T::deserialize(&mut &[0u8; 10_000][..]).unwrap()
+}
+
+macro_rules! assert_account_len {
+    ($account:ident, $inner:ty, $discm:expr, $len:expr, $onchain:expr) => {{
+        let data = $account(zeroed::<$inner>()).try_to_vec().unwrap();
+        assert_eq!(data.len(), $len, stringify!($account));
+        assert_eq!($len, $onchain, stringify!($account));
+        assert_eq!($account::LEN, $len);
+
+        let mut buf = vec![0u8; $len];
+        buf[..8].copy_from_slice(&$discm);
+        let mut reader = &buf[8..];
+        <$inner>::deserialize(&mut reader).unwrap();
+        assert!(
+            reader.is_empty(),
+            "{} leaves trailing bytes",
+            stringify!($account)
+        );
+        assert!($account::deserialize(&buf[..$len - 1]).is_err());
+    }};
+}
+
+#[test]
+fn account_lens_match_borsh_layout() {
+    assert_account_len!(
+        UserStateAccount,
+        UserState,
+        USER_STATE_ACCOUNT_DISCM,
+        USER_STATE_ACCOUNT_LEN,
+        920
+    );
+    assert_account_len!(
+        LendingMarketAccount,
+        LendingMarket,
+        LENDING_MARKET_ACCOUNT_DISCM,
+        LENDING_MARKET_ACCOUNT_LEN,
+        4664
+    );
+    assert_account_len!(
+        ObligationAccount,
+        Obligation,
+        OBLIGATION_ACCOUNT_DISCM,
+        OBLIGATION_ACCOUNT_LEN,
+        3344
+    );
+    assert_account_len!(
+        ReferrerStateAccount,
+        ReferrerState,
+        REFERRER_STATE_ACCOUNT_DISCM,
+        REFERRER_STATE_ACCOUNT_LEN,
+        72
+    );
+    assert_account_len!(
+        ReferrerTokenStateAccount,
+        ReferrerTokenState,
+        REFERRER_TOKEN_STATE_ACCOUNT_DISCM,
+        REFERRER_TOKEN_STATE_ACCOUNT_LEN,
+        360
+    );
+    assert_account_len!(
+        UserMetadataAccount,
+        UserMetadata,
+        USER_METADATA_ACCOUNT_DISCM,
+        USER_METADATA_ACCOUNT_LEN,
+        1032
+    );
+    assert_account_len!(
+        ReserveAccount,
+        Reserve,
+        RESERVE_ACCOUNT_DISCM,
+        RESERVE_ACCOUNT_LEN,
+        8624
+    );
+}
+
+#[test]
+fn rent_exempt_minimum_uses_account_len() {
+    let rent = Rent::default();
+    assert_eq!(
+        ReserveAccount::rent_exempt_minimum(&rent),
+        rent.minimum_balance(RESERVE_ACCOUNT_LEN)
+    );
+    assert_eq!(
+        ObligationAccount::rent_exempt_minimum(&rent),
+        rent.minimum_balance(OBLIGATION_ACCOUNT_LEN)
+    );
+    assert!(
+        LendingMarketAccount::rent_exempt_minimum(&rent)
+            > UserMetadataAccount::rent_exempt_minimum(&rent)
+    );
+}
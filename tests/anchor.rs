@@ -0,0 +1,117 @@
+#![cfg(feature = "anchor")]
+use anchor_lang::error::{Error, ErrorCode};
+use anchor_lang::prelude::AccountInfo;
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, Discriminator, Owner, ToAccountInfos, ToAccountMetas,
+};
+use kamino_lending_interface::*;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::fixture;
+
+fn error_code(error: Error) -> u32 {
+    match error {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => panic!("unexpected program error {error}"),
+    }
+}
+
+#[test]
+fn accounts_round_trip_through_anchor() {
+    let data = fixture("reserve.bin");
+    let reserve = Reserve::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(reserve, ReserveAccount::deserialize(&data).unwrap().0);
+    let mut serialized = Vec::new();
+    reserve.try_serialize(&mut serialized).unwrap();
+    assert_eq!(serialized, data);
+
+    let data = fixture("user_state.bin");
+    let user_state = UserState::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(user_state, UserStateAccount::deserialize(&data).unwrap().0);
+
+    assert_eq!(Obligation::DISCRIMINATOR, OBLIGATION_ACCOUNT_DISCM);
+    assert_eq!(Obligation::owner(), kamino_lending_interface::ID);
+    assert_eq!(UserState::owner(), FARMS_PROGRAM_ID);
+    assert_eq!(OraclePrices::owner(), SCOPE_PROGRAM_ID);
+}
+
+#[test]
+fn deserialize_checks_discriminator() {
+    let data = fixture("obligation.bin");
+    let err = Reserve::try_deserialize(&mut data.as_slice()).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        ErrorCode::AccountDiscriminatorMismatch as u32
+    );
+    let err = Reserve::try_deserialize(&mut &data[..4]).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        ErrorCode::AccountDiscriminatorNotFound as u32
+    );
+    let err = Obligation::try_deserialize(&mut &data[..100]).unwrap_err();
+    assert_eq!(error_code(err), ErrorCode::AccountDidNotDeserialize as u32);
+}
+
+#[test]
+fn instruction_accounts_to_metas_and_infos() {
+    let keys = RefreshReserveKeys {
+        reserve: Pubkey::new_unique(),
+        lending_market: Pubkey::new_unique(),
+        pyth_oracle: Pubkey::new_unique(),
+        switchboard_price_oracle: Pubkey::new_unique(),
+        switchboard_twap_oracle: Pubkey::new_unique(),
+        scope_prices: Pubkey::new_unique(),
+    };
+    let metas: [AccountMeta; REFRESH_RESERVE_IX_ACCOUNTS_LEN] = keys.into();
+    assert_eq!(keys.to_account_metas(None), metas.to_vec());
+    assert_eq!(keys.to_account_metas(Some(false)), metas.to_vec());
+    assert!(keys
+        .to_account_metas(Some(true))
+        .iter()
+        .all(|meta| meta.is_signer));
+
+    let pubkeys: Vec<Pubkey> = metas.iter().map(|meta| meta.pubkey).collect();
+    let mut lamports = vec![0u64; pubkeys.len()];
+    let mut data: Vec<Vec<u8>> = vec![Vec::new(); pubkeys.len()];
+    let owner = kamino_lending_interface::ID;
+    let infos: Vec<AccountInfo> = pubkeys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+        })
+        .collect();
+    let accounts = RefreshReserveAccounts {
+        reserve: &infos[0],
+        lending_market: &infos[1],
+        pyth_oracle: &infos[2],
+        switchboard_price_oracle: &infos[3],
+        switchboard_twap_oracle: &infos[4],
+        scope_prices: &infos[5],
+    };
+    assert_eq!(accounts.to_account_metas(None), metas.to_vec());
+    let account_infos = accounts.to_account_infos();
+    assert_eq!(
+        account_infos
+            .iter()
+            .map(|info| *info.key)
+            .collect::<Vec<_>>(),
+        pubkeys
+    );
+}
+
+#[test]
+fn errors_convert_to_anchor_errors() {
+    match Error::from(KaminoLendingError::InvalidMarketOwner) {
+        Error::AnchorError(error) => {
+            assert_eq!(error.error_name, "InvalidMarketOwner");
+            assert_eq!(error.error_code_number, 6001);
+            assert_eq!(error.error_msg, "Market owner is invalid");
+        }
+        Error::ProgramError(error) => panic!("unexpected program error {error}"),
+    }
+}
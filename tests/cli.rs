@@ -0,0 +1,96 @@
+#![cfg(feature = "cli")]
+use std::process::{Command, Output};
+
+use base64::Engine;
+use common::fixture_path;
+use serde_json::{json, Value};
+
+mod common;
+
+/// Data of the instruction named `name` in the instructions fixture.
+fn instruction(name: &str) -> Vec<u8> {
+    let fixtures = std::fs::read_to_string(fixture_path("instructions.txt")).unwrap();
+    let hex = fixtures
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))
+        .unwrap();
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn base64(data: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(data)
+}
+
+fn inspect(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_kamino-inspect"))
+        .args(args)
+        .args(["--now", "1700000000"])
+        .output()
+        .unwrap()
+}
+
+fn inspect_json(input: &str) -> Value {
+    let output = inspect(&[input, "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn accounts_from_files() {
+    let reserve = inspect_json(&fixture_path("reserve.bin"));
+    assert_eq!(reserve["account"], "Reserve");
+    assert_eq!(reserve["data"]["config"]["loan_to_value_pct"], 80);
+    assert_eq!(
+        reserve["data"]["collateral"]["exchange_rate"],
+        "1.169525660115789473"
+    );
+
+    let output = inspect(&[&fixture_path("obligation.bin")]);
+    assert!(output.status.success());
+    let table = String::from_utf8(output.stdout).unwrap();
+    let row = table
+        .lines()
+        .find(|line| line.starts_with("data.borrows[0].reserve "))
+        .unwrap();
+    assert!(row.ends_with(" D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59"));
+}
+
+#[test]
+fn instruction_args_use_their_serde_representation() {
+    let init = inspect_json(&bs58::encode(instruction("InitLendingMarket")).into_string());
+    assert_eq!(init["instruction"], "InitLendingMarket");
+    assert_eq!(init["args"], json!({ "quote_currency": "USD" }));
+    assert_eq!(init["accounts"][1], "lending_market");
+
+    let deposit = inspect_json(&base64(&instruction("DepositReserveLiquidity")));
+    assert_eq!(
+        deposit["args"],
+        json!({ "liquidity_amount": 1_000_000_000u64 })
+    );
+
+    // "AtqK60/JGWY=" is only valid base64.
+    let refresh = inspect_json(&base64(&instruction("RefreshReserve")));
+    assert_eq!(refresh["instruction"], "RefreshReserve");
+    assert_eq!(refresh["args"], Value::Null);
+}
+
+#[test]
+fn ambiguous_text_prefers_the_decoding_that_is_kamino_data() {
+    // Valid base58 too, but only the base64 decoding is an instruction.
+    let text = base64(&instruction("RequestElevationGroup"));
+    assert_eq!(text, "JHf7gSLwB5MB");
+    let request = inspect_json(&text);
+    assert_eq!(request["instruction"], "RequestElevationGroup");
+    assert_eq!(request["args"], json!({ "elevation_group": 1 }));
+
+    let output = inspect(&[&text, "--encoding", "base58"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: "));
+}
@@ -0,0 +1,111 @@
+//! Fixtures and markets shared by the integration tests.
+#![allow(dead_code)]
+use std::str::FromStr;
+
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+pub const NOW: i64 = 1_700_000_000;
+
+// Addresses in the account fixtures.
+pub const LENDING_MARKET: &str = "7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF";
+pub const OWNER: &str = "5hXu2jbwpPa86ZY5NkC1R33LtTgA98DRKwfhdNUW1qJy";
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+pub const USDC_RESERVE: &str = "D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59";
+pub const SOL_RESERVE: &str = "d4A2prbA2whesmvHaL88BH6Ewn5N4bTSU2Ze8P6Bc4Q";
+
+pub fn pubkey(address: &str) -> Pubkey {
+    Pubkey::from_str(address).unwrap()
+}
+
+pub fn fixture_path(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn fixture(name: &str) -> Vec<u8> {
+    std::fs::read(fixture_path(name)).unwrap()
+}
+
+pub fn reserve() -> Reserve {
+    ReserveAccount::deserialize(&fixture("reserve.bin"))
+        .unwrap()
+        .0
+}
+
+pub fn obligation() -> Obligation {
+    ObligationAccount::deserialize(&fixture("obligation.bin"))
+        .unwrap()
+        .0
+}
+
+pub fn lending_market() -> LendingMarket {
+    LendingMarketAccount::deserialize(&fixture("lending_market.bin"))
+        .unwrap()
+        .0
+}
+
+/// The fixture obligation, outside elevation groups, with the USDC reserve fixture and a SOL
+/// reserve derived from it at `sol_price` USDC.
+pub fn market(sol_price: u64) -> (LendingMarket, Obligation, Vec<(Pubkey, Reserve)>) {
+    let mut obligation = obligation();
+    obligation.elevation_group = 0;
+    let usdc = reserve();
+    let mut sol = usdc.clone();
+    sol.liquidity.mint_pubkey = Pubkey::new_unique();
+    sol.liquidity.mint_decimals = 9;
+    sol.liquidity.market_price_sf = Fraction::from_num(sol_price).to_bits();
+    sol.config.loan_to_value_pct = 65;
+    sol.config.liquidation_threshold_pct = 75;
+    sol.config.token_info.name = [0; 32];
+    sol.config.token_info.name[..3].copy_from_slice(b"SOL");
+    let reserves = vec![(pubkey(USDC_RESERVE), usdc), (pubkey(SOL_RESERVE), sol)];
+    (lending_market(), obligation, reserves)
+}
+
+/// Accounts built from zeroes, with round prices and amounts for exact expectations.
+pub mod synthetic {
+    use borsh::BorshDeserialize;
+
+    use super::*;
+
+    pub const LENDING_MARKET: Pubkey = Pubkey::new_from_array([1; 32]);
+    pub const COLLATERAL: Pubkey = Pubkey::new_from_array([2; 32]);
+    pub const DEBT: Pubkey = Pubkey::new_from_array([3; 32]);
+
+    /// An active reserve of a 6-decimal token at `price`, exchanging one cToken per base unit,
+    /// with 50% LTV, a 60% liquidation threshold and no limits.
+    pub fn reserve(price: u64, available_amount: u64, borrowed_amount: u64) -> Reserve {
+        let mut reserve = Reserve::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+        reserve.lending_market = LENDING_MARKET;
+        reserve.liquidity.mint_decimals = 6;
+        reserve.liquidity.market_price_sf = Fraction::from_num(price).to_bits();
+        reserve.liquidity.available_amount = available_amount;
+        reserve.liquidity.borrowed_amount_sf = Fraction::from_num(borrowed_amount).to_bits();
+        reserve.collateral.mint_total_supply = available_amount + borrowed_amount;
+        reserve.config.loan_to_value_pct = 50;
+        reserve.config.liquidation_threshold_pct = 60;
+        reserve.config.borrow_factor_pct = 100;
+        reserve.config.deposit_limit = u64::MAX;
+        reserve.config.borrow_limit = u64::MAX;
+        reserve.config.borrow_limit_outside_elevation_group = u64::MAX;
+        reserve
+    }
+
+    /// An obligation with 10 tokens of collateral at 100 (value 1_000, allowed borrow value 500)
+    /// against a debt of 200 tokens at 1, both reserves holding 1_000 tokens of liquidity.
+    pub fn market() -> (LendingMarket, Obligation, Vec<(Pubkey, Reserve)>) {
+        let lending_market = LendingMarket::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+        let mut obligation = Obligation::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+        obligation.lending_market = LENDING_MARKET;
+        obligation.deposits[0].deposit_reserve = COLLATERAL;
+        obligation.deposits[0].deposited_amount = 10_000_000;
+        obligation.borrows[0].borrow_reserve = DEBT;
+        obligation.borrows[0].borrowed_amount_sf = Fraction::from_num(200_000_000).to_bits();
+        let reserves = vec![
+            (COLLATERAL, reserve(100, 1_000_000_000, 0)),
+            (DEBT, reserve(1, 1_000_000_000, 200_000_000)),
+        ];
+        (lending_market, obligation, reserves)
+    }
+}
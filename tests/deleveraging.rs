@@ -0,0 +1,122 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+
+const STARTED: u64 = 1_700_000_000;
+const EXPIRES: u64 = STARTED + 3_600;
+
+/// An obligation marked at `STARTED` with a 75% target LTV, against a reserve with a one hour
+/// margin call, a threshold decreasing by 625 bps and a bonus growing by 50 bps per day from 2%
+/// up to 5%.
+fn marked() -> (LendingMarket, Reserve, Obligation) {
+    let lending_market = LendingMarket::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    let mut reserve = Reserve::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    reserve.config.deleveraging_margin_call_period_secs = 3_600;
+    reserve.config.deleveraging_threshold_decrease_bps_per_day = 625;
+    reserve.config.deleveraging_bonus_increase_bps_per_day = 50;
+    reserve.config.min_liquidation_bonus_bps = 200;
+    reserve.config.max_liquidation_bonus_bps = 500;
+    let mut obligation = Obligation::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    obligation.autodeleverage_target_ltv_pct = 75;
+    obligation.autodeleverage_margin_call_started_timestamp = STARTED;
+    (lending_market, reserve, obligation)
+}
+
+#[test]
+fn unmarked_obligation_has_no_state() {
+    let (lending_market, reserve, mut obligation) = marked();
+    obligation.autodeleverage_margin_call_started_timestamp = 0;
+    assert!(!obligation.is_marked_for_deleveraging());
+    assert_eq!(
+        obligation.deleveraging_state(&lending_market, &reserve, STARTED as i64),
+        None
+    );
+    assert_eq!(
+        obligation.deleveraging_liquidatable_at(&lending_market, &reserve, Fraction::ONE),
+        None
+    );
+}
+
+#[test]
+fn margin_call_expires_after_the_market_or_reserve_period() {
+    let (mut lending_market, reserve, obligation) = marked();
+    let running = obligation
+        .deleveraging_state(&lending_market, &reserve, EXPIRES as i64 - 1)
+        .unwrap();
+    assert_eq!(running.margin_call_started_at, STARTED);
+    assert_eq!(running.margin_call_expires_at, EXPIRES);
+    assert_eq!(running.seconds_since_expiry, 0);
+    assert_eq!(running.liquidation_threshold, Fraction::from_percent(75u8));
+    assert_eq!(running.liquidation_bonus_bps, 200);
+    assert!(!running.is_margin_call_expired(EXPIRES as i64 - 1));
+    assert!(!running.is_liquidatable(Fraction::ONE, EXPIRES as i64 - 1));
+    assert!(running.is_margin_call_expired(EXPIRES as i64));
+    assert!(running.is_liquidatable(Fraction::from_percent(75u8), EXPIRES as i64));
+    assert!(!running.is_liquidatable(Fraction::from_percent(74u8), EXPIRES as i64));
+
+    lending_market.individual_autodeleverage_margin_call_period_secs = 7_200;
+    let state = obligation
+        .deleveraging_state(&lending_market, &reserve, EXPIRES as i64)
+        .unwrap();
+    assert_eq!(state.margin_call_expires_at, STARTED + 7_200);
+    assert!(!state.is_margin_call_expired(EXPIRES as i64));
+}
+
+#[test]
+fn threshold_decreases_and_bonus_grows_per_second_after_expiry() {
+    let (lending_market, reserve, obligation) = marked();
+    let state = |seconds_since_expiry: u64| {
+        obligation
+            .deleveraging_state(
+                &lending_market,
+                &reserve,
+                (EXPIRES + seconds_since_expiry) as i64,
+            )
+            .unwrap()
+    };
+
+    let half_day = state(SECONDS_PER_DAY / 2);
+    assert_eq!(half_day.seconds_since_expiry, SECONDS_PER_DAY / 2);
+    assert_eq!(half_day.liquidation_threshold, Fraction::from_ratio(23, 32));
+    assert_eq!(half_day.liquidation_bonus_bps, 225);
+
+    let one_day = state(SECONDS_PER_DAY);
+    assert_eq!(one_day.liquidation_threshold, Fraction::from_ratio(11, 16));
+    assert_eq!(one_day.liquidation_bonus_bps, 250);
+
+    // The bonus stops at the maximum, the threshold at zero.
+    let long_after = state(20 * SECONDS_PER_DAY);
+    assert_eq!(long_after.liquidation_threshold, Fraction::ZERO);
+    assert_eq!(long_after.liquidation_bonus_bps, 500);
+}
+
+#[test]
+fn liquidatable_at_is_when_the_threshold_reaches_the_ltv() {
+    let (lending_market, mut reserve, obligation) = marked();
+    let liquidatable_at = |reserve: &Reserve, loan_to_value: Fraction| {
+        obligation.deleveraging_liquidatable_at(&lending_market, reserve, loan_to_value)
+    };
+    assert_eq!(
+        liquidatable_at(&reserve, Fraction::from_percent(75u8)),
+        Some(EXPIRES)
+    );
+    assert_eq!(liquidatable_at(&reserve, Fraction::ONE), Some(EXPIRES));
+    assert_eq!(
+        liquidatable_at(&reserve, Fraction::from_ratio(23, 32)),
+        Some(EXPIRES + SECONDS_PER_DAY / 2)
+    );
+
+    // A threshold landing between two seconds is reached on the later one.
+    let loan_to_value = Fraction::from_percent(70u8);
+    let at = liquidatable_at(&reserve, loan_to_value).unwrap();
+    let state_at = |now: u64| {
+        obligation
+            .deleveraging_state(&lending_market, &reserve, now as i64)
+            .unwrap()
+    };
+    assert!(state_at(at).is_liquidatable(loan_to_value, at as i64));
+    assert!(!state_at(at - 1).is_liquidatable(loan_to_value, at as i64 - 1));
+
+    reserve.config.deleveraging_threshold_decrease_bps_per_day = 0;
+    assert_eq!(liquidatable_at(&reserve, loan_to_value), None);
+}
@@ -0,0 +1,131 @@
+use std::fmt::Debug;
+
+use borsh::BorshDeserialize;
+use kamino_lending_interface::*;
+
+mod common;
+
+use common::{fixture, lending_market, obligation, reserve};
+
+#[test]
+fn names() {
+    let market = lending_market();
+    assert_eq!(market.name_str(), "Main Market");
+    assert_eq!(market.quote_currency_str(), "USD");
+    assert_eq!(reserve().config.token_info.name_str(), "USDC");
+    assert_eq!(name_str(&[0; 32]), "");
+    assert_eq!(name_str(b"a\0b\0\0"), "a\0b");
+    assert_eq!(name_str(&[0xff, b'x', 0]), "\u{fffd}x");
+}
+
+#[test]
+fn display_summaries() {
+    assert_eq!(
+        reserve().to_string(),
+        "USDC reserve EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v: price 0.999980, supply \
+         222209.8754, borrowed 98765.4321, utilization 44.45%, LTV 80%, liquidation threshold 85%"
+    );
+    assert_eq!(
+        obligation().to_string(),
+        "Obligation of 5hXu2jbwpPa86ZY5NkC1R33LtTgA98DRKwfhdNUW1qJy: 1 deposit(s) worth 6543.21, 1 \
+         borrow(s) worth 2999.94, LTV 45.85% (max 65.00%, unhealthy 75.00%)"
+    );
+    let mut market = lending_market();
+    assert_eq!(
+        market.to_string(),
+        "Lending market \"Main Market\" (USD) owned by A9rQoT9EwjwUJ7bUcrnHuHcXWALqdbXZ2JJTFqNsQmHD, \
+         1 elevation group(s)"
+    );
+    market.emergency_mode = 1;
+    assert!(market.to_string().ends_with(", emergency mode"));
+    assert_eq!(
+        market.elevation_groups[0].to_string(),
+        "Elevation group 1: LTV 90%, liquidation threshold 92%, max bonus 100 bps, debt reserve \
+         d4A2prbA2whesmvHaL88BH6Ewn5N4bTSU2Ze8P6Bc4Q, up to 4 collateral reserves"
+    );
+}
+
+#[test]
+fn compact_debug_hides_padding() {
+    let reserve = reserve();
+    let full = format!("{reserve:#?}");
+    let compact = format!("{:#?}", reserve.compact());
+    assert!(full.contains("padding"));
+    assert!(!compact.contains("padding") && !compact.contains("reserved"));
+    assert!(compact.lines().count() * 4 < full.lines().count());
+    assert!(compact.contains("name: \"USDC\""));
+    assert!(compact.contains("market_price_sf: Fraction(0.999979999999999999)"));
+    assert!(compact.contains(&format!(
+        "cumulative_borrow_rate_bsf: {:?}",
+        reserve.liquidity.cumulative_borrow_rate()
+    )));
+
+    let account = KaminoLendingAccount::deserialize(&fixture("lending_market.bin")).unwrap();
+    let compact = format!("{:#?}", account.compact());
+    assert!(compact.starts_with("LendingMarket {"));
+    assert!(compact.contains("name: \"Main Market\""));
+    assert!(!compact.contains("elevation_group_padding"));
+}
+
+/// Names of the top-level fields in a `{:#?}` dump.
+fn top_level_fields(pretty: &str) -> Vec<&str> {
+    pretty
+        .lines()
+        .filter_map(|line| line.strip_prefix("    "))
+        .filter(|line| !line.starts_with(' '))
+        .filter_map(|line| Some(line.split_once(": ")?.0))
+        .collect()
+}
+
+fn assert_compact_lists_every_field<T: BorshDeserialize + Debug + DebugCompact>() {
+    let value = T::deserialize(&mut vec![0u8; 20_000].as_slice()).unwrap();
+    let full = format!("{value:#?}");
+    let expected: Vec<_> = top_level_fields(&full)
+        .into_iter()
+        .filter(|field| !field.contains("padding") && !field.starts_with("reserved"))
+        .collect();
+    let compact = format!("{:#?}", value.compact());
+    assert_eq!(
+        top_level_fields(&compact),
+        expected,
+        "{}",
+        std::any::type_name::<T>()
+    );
+}
+
+#[test]
+fn compact_debug_lists_every_field_but_padding() {
+    macro_rules! check {
+        ($($ty:ty),* $(,)?) => {
+            $(assert_compact_lists_every_field::<$ty>();)*
+        };
+    }
+    check!(
+        UserState,
+        LendingMarket,
+        Obligation,
+        ReferrerState,
+        ReferrerTokenState,
+        ShortUrl,
+        UserMetadata,
+        Reserve,
+        LastUpdate,
+        ElevationGroup,
+        InitObligationArgs,
+        ObligationCollateral,
+        ObligationLiquidity,
+        BigFractionBytes,
+        ReserveCollateral,
+        ReserveConfig,
+        ReserveFees,
+        ReserveLiquidity,
+        WithdrawalCaps,
+        PriceHeuristic,
+        PythConfiguration,
+        ScopeConfiguration,
+        SwitchboardConfiguration,
+        TokenInfo,
+        BorrowRateCurve,
+        CurvePoint,
+    );
+}
@@ -0,0 +1,212 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::synthetic::{self, reserve, DEBT};
+
+const OTHER_DEBT: Pubkey = Pubkey::new_from_array([4; 32]);
+
+fn group(id: u8, ltv_pct: u8, debt_reserve: Pubkey) -> ElevationGroup {
+    ElevationGroup {
+        max_liquidation_bonus_bps: 0,
+        id,
+        ltv_pct,
+        liquidation_threshold_pct: ltv_pct + 5,
+        allow_new_loans: 1,
+        max_reserves_as_collateral: 1,
+        padding0: 0,
+        debt_reserve,
+        padding1: [0; 4],
+    }
+}
+
+/// The synthetic market, plus an `OTHER_DEBT` reserve, with every reserve in elevation groups 1
+/// and 2 without limits. Group 1 lends `DEBT` at 75% LTV, group 2 lends `OTHER_DEBT`; the
+/// obligation is outside both.
+fn market() -> (LendingMarket, Obligation, Vec<(Pubkey, Reserve)>) {
+    let (mut lending_market, obligation, mut reserves) = synthetic::market();
+    lending_market.elevation_groups[0] = group(1, 75, DEBT);
+    lending_market.elevation_groups[1] = group(2, 90, OTHER_DEBT);
+    reserves.push((OTHER_DEBT, reserve(1, 1_000_000_000, 0)));
+    for (_, reserve) in &mut reserves {
+        reserve.config.elevation_groups[..2].copy_from_slice(&[1, 2]);
+        reserve
+            .config
+            .borrow_limit_against_this_collateral_in_elevation_group[..2]
+            .copy_from_slice(&[u64::MAX, u64::MAX]);
+    }
+    (lending_market, obligation, reserves)
+}
+
+#[test]
+fn eligibility_lists_every_other_group() {
+    let (lending_market, obligation, reserves) = market();
+    let eligibility = obligation
+        .elevation_group_eligibility(&lending_market, &reserves)
+        .unwrap();
+    assert_eq!(eligibility.len(), 2);
+    assert_eq!(eligibility[1].elevation_group, 2);
+    assert_eq!(
+        eligibility[1].result,
+        Err(KaminoLendingError::ElevationGroupHasAnotherDebtReserve)
+    );
+
+    let switch = eligibility[0].result.clone().unwrap();
+    assert_eq!(switch.elevation_group, 1);
+    assert_eq!(switch.current.allowed_borrow_value, Fraction::from_num(500));
+    assert_eq!(switch.target.allowed_borrow_value, Fraction::from_num(750));
+    assert_eq!(switch.max_ltv_increase(), Fraction::from_percent(25u8));
+    assert_eq!(
+        switch.unhealthy_ltv_increase(),
+        Fraction::from_percent(20u8)
+    );
+    assert_eq!(switch.additional_borrow_value(), Fraction::from_num(250));
+    assert!(switch.is_improvement());
+    assert_eq!(
+        obligation.recommended_elevation_group(&lending_market, &reserves),
+        Ok(Some(switch))
+    );
+
+    let missing = &reserves[1..];
+    assert_eq!(
+        obligation.elevation_group_eligibility(&lending_market, &missing),
+        Err(KaminoLendingError::InvalidObligationCollateral)
+    );
+    assert_eq!(
+        obligation.check_elevation_group(&lending_market, &missing, 1),
+        Err(KaminoLendingError::InvalidObligationCollateral)
+    );
+}
+
+#[test]
+fn leaving_a_group_is_bound_by_the_limit_outside_groups() {
+    let (lending_market, mut obligation, mut reserves) = market();
+    obligation.elevation_group = 1;
+    let eligibility = obligation
+        .elevation_group_eligibility(&lending_market, &reserves)
+        .unwrap();
+    assert_eq!(
+        eligibility
+            .iter()
+            .map(|eligibility| eligibility.elevation_group)
+            .collect::<Vec<_>>(),
+        vec![ELEVATION_GROUP_NONE, 2]
+    );
+    let switch = eligibility[0].result.clone().unwrap();
+    assert_eq!(switch.target.allowed_borrow_value, Fraction::from_num(500));
+    assert!(!switch.is_improvement());
+    assert_eq!(
+        obligation.recommended_elevation_group(&lending_market, &reserves),
+        Ok(None)
+    );
+
+    let debt = &mut reserves[1].1;
+    debt.config.borrow_limit_outside_elevation_group = 300_000_000;
+    debt.borrowed_amount_outside_elevation_group = 100_000_001;
+    assert_eq!(
+        obligation.check_elevation_group(&lending_market, &reserves, ELEVATION_GROUP_NONE),
+        Err(KaminoLendingError::ElevationGroupBorrowLimitExceeded)
+    );
+}
+
+#[test]
+fn program_rejections() {
+    let (lending_market, obligation, reserves) = market();
+    let check = |lending_market: &LendingMarket, obligation: &Obligation, reserves: &Vec<_>| {
+        obligation.check_elevation_group(lending_market, reserves, 1)
+    };
+    let with_market = |change: fn(&mut LendingMarket)| {
+        let mut lending_market = lending_market.clone();
+        change(&mut lending_market);
+        check(&lending_market, &obligation, &reserves)
+    };
+    let with_obligation = |change: fn(&mut Obligation)| {
+        let mut obligation = obligation.clone();
+        change(&mut obligation);
+        check(&lending_market, &obligation, &reserves)
+    };
+    let with_reserves = |change: fn(&mut Vec<(Pubkey, Reserve)>)| {
+        let mut reserves = reserves.clone();
+        change(&mut reserves);
+        check(&lending_market, &obligation, &reserves)
+    };
+
+    assert_eq!(
+        with_market(|market| market.emergency_mode = 1),
+        Err(KaminoLendingError::GlobalEmergencyMode)
+    );
+    assert_eq!(
+        with_obligation(|obligation| obligation.elevation_group = 1),
+        Err(KaminoLendingError::ElevationGroupAlreadyActivated)
+    );
+    assert_eq!(
+        obligation.check_elevation_group(&lending_market, &reserves, 5),
+        Err(KaminoLendingError::InvalidElevationGroup)
+    );
+    assert_eq!(
+        with_market(|market| market.elevation_groups[0].debt_reserve = Pubkey::default()),
+        Err(KaminoLendingError::ElevationGroupWithoutDebtReserve)
+    );
+    assert_eq!(
+        with_market(|market| market.elevation_groups[0].max_reserves_as_collateral = 0),
+        Err(KaminoLendingError::ElevationGroupMaxCollateralReserveZero)
+    );
+    assert_eq!(
+        with_market(|market| market.elevation_groups[0].allow_new_loans = 0),
+        Err(KaminoLendingError::ElevationGroupNewLoansDisabled)
+    );
+    assert_eq!(
+        with_obligation(|obligation| {
+            obligation.borrows[1] = obligation.borrows[0].clone();
+            obligation.borrows[1].borrow_reserve = OTHER_DEBT;
+        }),
+        Err(KaminoLendingError::ObligationElevationGroupMultipleDebtReserve)
+    );
+    assert_eq!(
+        with_reserves(|reserves| reserves[1].1.config.elevation_groups = [0; 20]),
+        Err(KaminoLendingError::InconsistentElevationGroup)
+    );
+    assert_eq!(
+        with_reserves(|reserves| reserves[0].1.config.elevation_groups = [0; 20]),
+        Err(KaminoLendingError::InconsistentElevationGroup)
+    );
+    assert_eq!(
+        with_obligation(|obligation| {
+            obligation.deposits[1] = obligation.deposits[0].clone();
+            obligation.deposits[1].deposit_reserve = OTHER_DEBT;
+        }),
+        Err(KaminoLendingError::ObligationCollateralExceedsElevationGroupLimit)
+    );
+
+    let mut lending_market = lending_market.clone();
+    lending_market.elevation_groups[0].max_reserves_as_collateral = 2;
+    let mut with_debt_collateral = obligation.clone();
+    with_debt_collateral.deposits[1] = obligation.deposits[0].clone();
+    with_debt_collateral.deposits[1].deposit_reserve = DEBT;
+    assert_eq!(
+        check(&lending_market, &with_debt_collateral, &reserves),
+        Err(KaminoLendingError::ElevationGroupDebtReserveAsCollateral)
+    );
+
+    // The collateral's limit in the group leaves room for just under the 200 of debt.
+    let mut limited = reserves.clone();
+    limited[0]
+        .1
+        .config
+        .borrow_limit_against_this_collateral_in_elevation_group[0] = 350_000_000;
+    limited[0]
+        .1
+        .borrowed_amounts_against_this_reserve_in_elevation_groups[0] = 150_000_001;
+    assert_eq!(
+        check(&lending_market, &obligation, &limited),
+        Err(KaminoLendingError::ElevationGroupBorrowLimitExceeded)
+    );
+
+    lending_market.elevation_groups[0].ltv_pct = 15;
+    assert_eq!(
+        check(&lending_market, &obligation, &reserves),
+        Err(KaminoLendingError::UnhealthyElevationGroupLtv)
+    );
+}
@@ -0,0 +1,105 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::math::WAD;
+use kamino_lending_interface::*;
+
+/// A reward paying `rate` per time unit since `ts_start`, last issued at `last_issuance_ts`.
+/// Unused curve points start at `u64::MAX`, as the farms program initializes them.
+fn reward(reward_type: RewardType, rate: u64, last_issuance_ts: u64) -> RewardInfo {
+    let mut reward = RewardInfo::deserialize(&mut [0u8; 1_000].as_slice()).unwrap();
+    reward.reward_type = reward_type as u8;
+    reward.rewards_available = u64::MAX;
+    reward.last_issuance_ts = last_issuance_ts;
+    for point in &mut reward.reward_schedule_curve.points {
+        point.ts_start = u64::MAX;
+    }
+    reward.reward_schedule_curve.points[0] = RewardPerTimeUnitPoint {
+        ts_start: 0,
+        reward_per_time_unit: rate,
+    };
+    reward
+}
+
+fn tokens(amount: u128) -> u128 {
+    amount * WAD
+}
+
+#[test]
+fn proportional_rewards_split_issuance_across_stake() {
+    let mut info = reward(RewardType::Proportional, 1_000, 100);
+    assert_eq!(
+        info.accrue(tokens(3_000), 110),
+        Some((10_000, 3_333_333_333_333_333_333))
+    );
+
+    info.rewards_available = 4_000;
+    assert_eq!(
+        info.accrue(tokens(3_000), 110),
+        Some((4_000, 1_333_333_333_333_333_333))
+    );
+
+    // 150 hundredths per second issue 4.5 tokens over 3 seconds, rounded down.
+    let mut info = reward(RewardType::Proportional, 150, 100);
+    info.rewards_per_second_decimals = 2;
+    assert_eq!(info.accrue(tokens(4), 103), Some((4, WAD)));
+}
+
+#[test]
+fn constant_rewards_pay_each_staked_token() {
+    let mut info = reward(RewardType::Constant, 2, 100);
+    assert_eq!(info.accrue(tokens(3_000), 110), Some((60_000, 20 * WAD)));
+
+    info.rewards_available = 30_000;
+    assert_eq!(info.accrue(tokens(3_000), 110), Some((30_000, 10 * WAD)));
+}
+
+#[test]
+fn accrual_follows_the_schedule_curve() {
+    let mut info = reward(RewardType::Proportional, 10, 100);
+    info.reward_schedule_curve.points[1] = RewardPerTimeUnitPoint {
+        ts_start: 105,
+        reward_per_time_unit: 20,
+    };
+    assert_eq!(info.accrue(tokens(1), 110), Some((150, 150 * WAD)));
+    assert_eq!(info.accrue(tokens(1), 104), Some((40, 40 * WAD)));
+}
+
+#[test]
+fn nothing_accrues_without_stake_or_elapsed_time() {
+    let info = reward(RewardType::Proportional, 1_000, 100);
+    assert_eq!(info.accrue(0, 110), Some((0, 0)));
+    assert_eq!(info.accrue(tokens(1), 100), Some((0, 0)));
+    assert_eq!(info.accrue(tokens(1), 90), Some((0, 0)));
+
+    let mut unknown = info.clone();
+    unknown.reward_type = 2;
+    assert_eq!(unknown.accrue(tokens(1), 110), None);
+}
+
+#[test]
+fn pending_reward_adds_projected_accrual_to_unclaimed() {
+    let mut farm_state = FarmState::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    farm_state.num_reward_tokens = 1;
+    farm_state.total_active_stake_scaled = tokens(3_000);
+    farm_state.reward_infos[0] = reward(RewardType::Proportional, 1_000, 100);
+    farm_state.reward_infos[0].reward_per_share_scaled = 2 * WAD;
+
+    let mut user_state = UserState::deserialize(&mut [0u8; 1_000].as_slice()).unwrap();
+    user_state.active_stake_scaled = tokens(1_000);
+    user_state.rewards_tally_scaled[0] = tokens(2_000);
+    user_state.rewards_issued_unclaimed[0] = 7;
+
+    assert_eq!(user_state.pending_reward(&farm_state, 0, 100), Some(7));
+    // The 7 unclaimed plus a third of the 10_000 issued since, rounded down.
+    assert_eq!(user_state.pending_reward(&farm_state, 0, 110), Some(3_340));
+    assert_eq!(
+        user_state.pending_reward(&farm_state, FARMS_MAX_REWARDS_TOKENS, 110),
+        None
+    );
+
+    let pending = user_state.pending_rewards(&farm_state, 110);
+    assert_eq!(pending.len(), 1);
+    assert_eq!((pending[0].reward_index, pending[0].amount), (0, 3_340));
+
+    user_state.rewards_issued_unclaimed[0] = 0;
+    assert!(user_state.pending_rewards(&farm_state, 100).is_empty());
+}
@@ -0,0 +1,30 @@
+use kamino_lending_interface::fraction::Fraction;
+use proptest::prelude::*;
+
+#[test]
+fn fraction_parses_decimals() {
+    assert_eq!(
+        "1.25".parse::<Fraction>().unwrap(),
+        Fraction::from_ratio(5, 4)
+    );
+    assert_eq!(
+        "0.5".parse::<Fraction>().unwrap(),
+        Fraction::from_percent(50u8)
+    );
+    assert_eq!(
+        ".5".parse::<Fraction>().unwrap(),
+        Fraction::from_percent(50u8)
+    );
+    assert_eq!("42".parse::<Fraction>().unwrap(), Fraction::from_num(42));
+    for invalid in ["", ".", "-1", "1e5", "1.2.3", " 1", "295147905179352825856"] {
+        assert!(invalid.parse::<Fraction>().is_err(), "{invalid}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn fraction_exact_string_round_trips(bits in any::<u128>()) {
+        let fraction = Fraction::from_bits(bits);
+        prop_assert_eq!(fraction.to_exact_string().parse::<Fraction>().unwrap(), fraction);
+    }
+}
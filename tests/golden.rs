@@ -0,0 +1,328 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::hash::hashv;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::*;
+
+/// Anchor discriminator: first 8 bytes of `sha256("<namespace>:<name>")`.
+fn anchor_discm(namespace: &str, name: &str) -> [u8; 8] {
+    hashv(&[namespace.as_bytes(), b":", name.as_bytes()]).to_bytes()[..8]
+        .try_into()
+        .unwrap()
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// `(variant name, instruction data)` pairs of `instructions.txt`, one hex-encoded instruction per line.
+fn load_instruction_fixtures() -> Vec<(String, Vec<u8>)> {
+    String::from_utf8(fixture("instructions.txt"))
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let (name, hex) = line.split_once(' ').unwrap();
+            let data = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect();
+            (name.to_string(), data)
+        })
+        .collect()
+}
+
+fn name_str(name: &[u8]) -> &str {
+    std::str::from_utf8(name).unwrap().trim_end_matches('\0')
+}
+
+macro_rules! round_trip {
+    ($wrapper:ident, $file:expr, $discm:expr, $name:expr) => {{
+        let data = fixture($file);
+        assert_eq!(data[..8], $discm, "{} discriminator", $name);
+        assert_eq!($discm, anchor_discm("account", $name));
+        let account = $wrapper::deserialize(&data).unwrap();
+        assert_eq!(account.try_to_vec().unwrap(), data, "{} round trip", $name);
+        account.0
+    }};
+}
+
+#[test]
+fn reserve_fixture() {
+    let reserve = round_trip!(
+        ReserveAccount,
+        "reserve.bin",
+        RESERVE_ACCOUNT_DISCM,
+        "Reserve"
+    );
+    assert_eq!(reserve.version, 1);
+    assert_eq!(reserve.last_update.slot, 301_234_567);
+    assert_eq!(reserve.lending_market, pubkey(LENDING_MARKET));
+    assert_eq!(reserve.liquidity.mint_pubkey, pubkey(USDC_MINT));
+    assert_eq!(reserve.liquidity.mint_decimals, 6);
+    assert_eq!(reserve.liquidity.available_amount, 123_456_789_000);
+    assert_eq!(
+        reserve.liquidity.borrowed_amount(),
+        Fraction::from_num(98_765_432_100)
+    );
+    assert_eq!(
+        reserve.liquidity.market_price(),
+        Fraction::from_decimal(99_998_000, 8).unwrap()
+    );
+    assert_eq!(
+        reserve.liquidity.cumulative_borrow_rate(),
+        Fraction::from_decimal(1_254_321, 6).unwrap()
+    );
+    assert_eq!(reserve.collateral.mint_total_supply, 190_000_000_000);
+    assert_eq!(reserve.config.loan_to_value_pct, 80);
+    assert_eq!(reserve.config.liquidation_threshold_pct, 85);
+    assert_eq!(reserve.config.max_liquidation_bonus_bps, 500);
+    assert_eq!(reserve.config.deposit_limit, 500_000_000_000_000);
+    assert_eq!(
+        reserve.config.utilization_limit_block_borrowing_above_pct,
+        95
+    );
+    assert_eq!(reserve.config.elevation_groups[0], 1);
+    assert_eq!(name_str(&reserve.config.token_info.name), "USDC");
+    assert_eq!(
+        reserve.config.token_info.scope_configuration.price_chain[0],
+        20
+    );
+    assert_eq!(
+        reserve.config.borrow_rate_curve.points[2].borrow_rate_bps,
+        1500
+    );
+    assert_eq!(
+        reserve
+            .config
+            .deposit_withdrawal_cap
+            .config_interval_length_seconds,
+        86_400
+    );
+    assert_eq!(
+        reserve.borrowed_amounts_against_this_reserve_in_elevation_groups[0],
+        8_765_432_100
+    );
+}
+
+#[test]
+fn obligation_fixture() {
+    let obligation = round_trip!(
+        ObligationAccount,
+        "obligation.bin",
+        OBLIGATION_ACCOUNT_DISCM,
+        "Obligation"
+    );
+    assert_eq!(obligation.lending_market, pubkey(LENDING_MARKET));
+    assert_eq!(obligation.owner, pubkey(OWNER));
+    assert_eq!(obligation.deposits[0].deposit_reserve, pubkey(SOL_RESERVE));
+    assert_eq!(obligation.deposits[0].deposited_amount, 41_250_000_000);
+    assert_eq!(obligation.borrows[0].borrow_reserve, pubkey(USDC_RESERVE));
+    assert_eq!(
+        obligation.borrows[0].borrowed_amount(),
+        Fraction::from_num(3_000_000_000)
+    );
+    assert_eq!(obligation.active_deposits().count(), 1);
+    assert_eq!(obligation.active_borrows().count(), 1);
+    assert_eq!(obligation.has_debt, 1);
+    assert_eq!(obligation.lowest_reserve_deposit_max_ltv_pct, 65);
+    assert_eq!(
+        obligation.allowed_borrow_value(),
+        Fraction::from_decimal(42_530_865, 4).unwrap()
+    );
+}
+
+#[test]
+fn lending_market_fixture() {
+    let market = round_trip!(
+        LendingMarketAccount,
+        "lending_market.bin",
+        LENDING_MARKET_ACCOUNT_DISCM,
+        "LendingMarket"
+    );
+    assert_eq!(name_str(&market.name), "Main Market");
+    assert_eq!(name_str(&market.quote_currency), "USD");
+    assert_eq!(market.referral_fee_bps, 2000);
+    assert_eq!(market.liquidation_max_debt_close_factor_pct, 20);
+    assert_eq!(
+        market.individual_autodeleverage_margin_call_period_secs,
+        172_800
+    );
+    let group = market.elevation_group(1).unwrap();
+    assert_eq!(
+        (group.id, group.ltv_pct, group.liquidation_threshold_pct),
+        (1, 90, 92)
+    );
+    assert_eq!(group.debt_reserve, pubkey(SOL_RESERVE));
+}
+
+#[test]
+fn user_metadata_fixture() {
+    let metadata = round_trip!(
+        UserMetadataAccount,
+        "user_metadata.bin",
+        USER_METADATA_ACCOUNT_DISCM,
+        "UserMetadata"
+    );
+    assert_eq!(metadata.owner, pubkey(OWNER));
+    assert_eq!(metadata.referrer, Pubkey::default());
+    assert_eq!(metadata.bump, 255);
+}
+
+#[test]
+fn referrer_fixtures() {
+    let state = round_trip!(
+        ReferrerStateAccount,
+        "referrer_state.bin",
+        REFERRER_STATE_ACCOUNT_DISCM,
+        "ReferrerState"
+    );
+    assert_eq!(state.owner, pubkey(OWNER));
+
+    let token_state = round_trip!(
+        ReferrerTokenStateAccount,
+        "referrer_token_state.bin",
+        REFERRER_TOKEN_STATE_ACCOUNT_DISCM,
+        "ReferrerTokenState"
+    );
+    assert_eq!(token_state.referrer, pubkey(OWNER));
+    assert_eq!(token_state.mint, pubkey(USDC_MINT));
+    assert_eq!(
+        token_state.amount_cumulative_sf,
+        Fraction::from_num(98_765).to_bits()
+    );
+    assert_eq!(token_state.bump, 253);
+
+    let short_url = round_trip!(
+        ShortUrlAccount,
+        "short_url.bin",
+        SHORT_URL_ACCOUNT_DISCM,
+        "ShortUrl"
+    );
+    assert_eq!(short_url.referrer, pubkey(OWNER));
+    assert_eq!(short_url.short_url, "kamino-friend");
+}
+
+#[test]
+fn user_state_fixture() {
+    let user_state = round_trip!(
+        UserStateAccount,
+        "user_state.bin",
+        USER_STATE_ACCOUNT_DISCM,
+        "UserState"
+    );
+    assert_eq!(user_state.user_id, 7);
+    assert_eq!(
+        user_state.active_stake_scaled,
+        3_000_000_000 * 10u128.pow(18)
+    );
+    assert_eq!(user_state.rewards_issued_unclaimed[0], 1_234);
+}
+
+#[test]
+fn instruction_fixtures_round_trip() {
+    let fixtures = load_instruction_fixtures();
+    for (name, data) in &fixtures {
+        assert_eq!(
+            data[..8],
+            anchor_discm("global", &snake_case(name)),
+            "{name} discriminator"
+        );
+        let ix = KaminoLendingProgramIx::deserialize(data).unwrap();
+        let debug = format!("{ix:?}");
+        assert!(
+            debug == *name || debug.starts_with(&format!("{name}(")),
+            "{name} decoded as {debug}"
+        );
+        assert_eq!(&ix.try_to_vec().unwrap(), data, "{name} round trip");
+    }
+    assert_eq!(fixtures.len(), 44);
+}
+
+#[test]
+fn instruction_fixtures_args() {
+    let fixtures = load_instruction_fixtures();
+    let decode = |name: &str| {
+        let (_, data) = fixtures
+            .iter()
+            .find(|(fixture, _)| fixture == name)
+            .unwrap();
+        KaminoLendingProgramIx::deserialize(data).unwrap()
+    };
+    assert_eq!(
+        decode("BorrowObligationLiquidityV2"),
+        KaminoLendingProgramIx::BorrowObligationLiquidityV2(BorrowObligationLiquidityV2IxArgs {
+            liquidity_amount: 3_000_000_000,
+        })
+    );
+    assert_eq!(
+        decode("FlashRepayReserveLiquidity"),
+        KaminoLendingProgramIx::FlashRepayReserveLiquidity(FlashRepayReserveLiquidityIxArgs {
+            liquidity_amount: 25_000_000_000,
+            borrow_instruction_index: 2,
+        })
+    );
+    assert_eq!(
+        decode("LiquidateObligationAndRedeemReserveCollateral"),
+        KaminoLendingProgramIx::LiquidateObligationAndRedeemReserveCollateral(
+            LiquidateObligationAndRedeemReserveCollateralIxArgs {
+                liquidity_amount: 600_000_000,
+                min_acceptable_received_liquidity_amount: 3_100_000_000,
+                max_allowed_ltv_override_percent: 0,
+            }
+        )
+    );
+    assert_eq!(
+        decode("InitReferrerStateAndShortUrl"),
+        KaminoLendingProgramIx::InitReferrerStateAndShortUrl(InitReferrerStateAndShortUrlIxArgs {
+            short_url: "kamino-friend".to_string(),
+        })
+    );
+    match decode("IdlMissingTypes") {
+        KaminoLendingProgramIx::IdlMissingTypes(args) => {
+            assert_eq!(args.reserve_status, ReserveStatus::Hidden);
+            assert_eq!(
+                args.update_lending_market_config_value,
+                UpdateLendingMarketConfigValue::U64(172_800)
+            );
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+}
+
+#[test]
+fn account_fixtures_decode_by_discriminator() {
+    for (file, name) in [
+        ("reserve.bin", "Reserve"),
+        ("obligation.bin", "Obligation"),
+        ("lending_market.bin", "LendingMarket"),
+        ("user_metadata.bin", "UserMetadata"),
+        ("referrer_state.bin", "ReferrerState"),
+        ("referrer_token_state.bin", "ReferrerTokenState"),
+        ("short_url.bin", "ShortUrl"),
+        ("user_state.bin", "UserState"),
+    ] {
+        let data = fixture(file);
+        assert!(KaminoLendingAccount::is_account_data(&data), "{file}");
+        assert_eq!(
+            KaminoLendingAccount::deserialize(&data).unwrap().name(),
+            name
+        );
+    }
+    let (_, ix) = &load_instruction_fixtures()[0];
+    assert!(!KaminoLendingAccount::is_account_data(ix));
+    assert!(KaminoLendingAccount::deserialize(&[0; 4]).is_err());
+}
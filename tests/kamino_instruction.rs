@@ -0,0 +1,114 @@
+use kamino_lending_interface::*;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+
+fn check<I: KaminoInstruction>(ix: &I, generated: Instruction) {
+    let program_id = Pubkey::new_unique();
+    let built = ix.to_instruction(program_id).unwrap();
+    assert_eq!(built.accounts, generated.accounts, "{}", I::NAME);
+    assert_eq!(built.data, generated.data, "{}", I::NAME);
+    assert_eq!(built.program_id, program_id);
+    assert_eq!(built.accounts.len(), I::ACCOUNTS_LEN);
+    assert_eq!(built.data[..8], I::DISCM);
+    let decoded = KaminoLendingProgramIx::deserialize(&built.data).unwrap();
+    assert!(format!("{decoded:?}").starts_with(I::NAME), "{}", I::NAME);
+}
+
+fn refresh_obligation_keys() -> RefreshObligationKeys {
+    RefreshObligationKeys {
+        lending_market: Pubkey::new_unique(),
+        obligation: Pubkey::new_unique(),
+    }
+}
+
+fn borrow_keys() -> BorrowObligationLiquidityKeys {
+    BorrowObligationLiquidityKeys {
+        owner: Pubkey::new_unique(),
+        obligation: Pubkey::new_unique(),
+        lending_market: Pubkey::new_unique(),
+        lending_market_authority: Pubkey::new_unique(),
+        borrow_reserve: Pubkey::new_unique(),
+        borrow_reserve_liquidity_mint: Pubkey::new_unique(),
+        reserve_source_liquidity: Pubkey::new_unique(),
+        borrow_reserve_liquidity_fee_receiver: Pubkey::new_unique(),
+        user_destination_liquidity: Pubkey::new_unique(),
+        referrer_token_state: Pubkey::new_unique(),
+        token_program: Pubkey::new_unique(),
+        instruction_sysvar_account: Pubkey::new_unique(),
+    }
+}
+
+#[test]
+fn matches_generated_instruction_builders() {
+    let keys = refresh_obligation_keys();
+    check(&keys, refresh_obligation_ix(keys).unwrap());
+
+    let keys = borrow_keys();
+    let args = BorrowObligationLiquidityIxArgs {
+        liquidity_amount: 1_000_000,
+    };
+    check(
+        &(keys, args.clone()),
+        borrow_obligation_liquidity_ix(keys, args).unwrap(),
+    );
+
+    let keys = InitObligationKeys::resolve(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &InitObligationArgs { tag: 0, id: 0 },
+        &Pubkey::default(),
+        &Pubkey::default(),
+    );
+    let args = InitObligationIxArgs {
+        args: InitObligationArgs { tag: 0, id: 0 },
+    };
+    check(
+        &(keys, args.clone()),
+        init_obligation_ix(keys, args).unwrap(),
+    );
+}
+
+/// Generic code sees every instruction through the trait.
+fn summary<I: KaminoInstruction>(ix: &I) -> (&'static str, usize, usize) {
+    (I::NAME, I::ACCOUNTS_LEN, ix.data().unwrap().len())
+}
+
+#[test]
+fn generic_over_instructions() {
+    assert_eq!(
+        summary(&refresh_obligation_keys()),
+        ("RefreshObligation", 2, 8)
+    );
+    let borrow = (
+        borrow_keys(),
+        BorrowObligationLiquidityIxArgs {
+            liquidity_amount: 5,
+        },
+    );
+    assert_eq!(summary(&borrow), ("BorrowObligationLiquidity", 12, 16));
+    assert_eq!(
+        <(
+            FlashBorrowReserveLiquidityKeys,
+            FlashBorrowReserveLiquidityIxArgs
+        )>::DISCM,
+        FLASH_BORROW_RESERVE_LIQUIDITY_IX_DISCM
+    );
+}
+
+#[test]
+fn refresh_reserves_batch_takes_remaining_accounts() {
+    let args = RefreshReservesBatchIxArgs {
+        skip_price_updates: false,
+    };
+    let mut generated = refresh_reserves_batch_ix(args.clone()).unwrap();
+    check(&(Vec::new(), args.clone()), generated.clone());
+
+    let accounts = vec![
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+    ];
+    generated.accounts = accounts.clone();
+    let batch = (accounts, args);
+    assert_eq!(batch.to_instruction(ID).unwrap(), generated);
+    assert_eq!(summary(&batch), ("RefreshReservesBatch", 0, 9));
+}
@@ -0,0 +1,403 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::{pubkey, synthetic, NOW, SOL_RESERVE, USDC_RESERVE};
+
+/// The common market with SOL at `sol_price` USDC and no limits on USDC, the obligation refreshed.
+fn market(sol_price: u64) -> (LendingMarket, Obligation, Reserve, Reserve) {
+    let (lending_market, mut obligation, mut reserves) = common::market(sol_price);
+    let usdc = &mut reserves[0].1;
+    usdc.config.deposit_limit = u64::MAX;
+    usdc.config.borrow_limit = u64::MAX;
+    usdc.config.borrow_limit_outside_elevation_group = u64::MAX;
+    obligation.refresh(&lending_market, &reserves).unwrap();
+    let [(_, usdc), (_, sol)] = <[_; 2]>::try_from(reserves).unwrap();
+    (lending_market, obligation, usdc, sol)
+}
+
+fn usdc_address() -> Pubkey {
+    pubkey(USDC_RESERVE)
+}
+
+fn sol_address() -> Pubkey {
+    pubkey(SOL_RESERVE)
+}
+
+fn reserves(usdc: &Reserve, sol: &Reserve) -> Vec<(Pubkey, Reserve)> {
+    vec![(usdc_address(), usdc.clone()), (sol_address(), sol.clone())]
+}
+
+#[test]
+fn deposit_and_redeem_reserve_liquidity() {
+    let (mut lending_market, _, mut usdc, _) = market(150);
+    let before = usdc.clone();
+
+    let minted = deposit_reserve_liquidity(&lending_market, &mut usdc, 1_000_000, NOW).unwrap();
+    assert_eq!(minted.liquidity_paid, 1_000_000);
+    // floor(1_000_000 * 190_000_000_000 cTokens / 222_209_875_422 supplied), where the supply is
+    // 123_456_789_000 available + 98_765_432_100 borrowed - 12_345_678 protocol fees.
+    assert_eq!(minted.collateral_received, 855_047);
+    assert_eq!(
+        usdc.liquidity.available_amount,
+        before.liquidity.available_amount + 1_000_000
+    );
+    assert_eq!(
+        usdc.collateral.mint_total_supply,
+        before.collateral.mint_total_supply + minted.collateral_received
+    );
+
+    let redeemed =
+        redeem_reserve_collateral(&lending_market, &mut usdc, minted.collateral_received, NOW)
+            .unwrap();
+    assert_eq!(redeemed.collateral_paid, minted.collateral_received);
+    assert!(redeemed.liquidity_received <= 1_000_000);
+    assert!(redeemed.liquidity_received >= 999_998);
+    assert_eq!(
+        usdc.collateral.mint_total_supply,
+        before.collateral.mint_total_supply
+    );
+
+    let after = usdc.clone();
+    assert_eq!(
+        deposit_reserve_liquidity(&lending_market, &mut usdc, 0, NOW),
+        Err(KaminoLendingError::InvalidAmount)
+    );
+    lending_market.emergency_mode = 1;
+    assert_eq!(
+        redeem_reserve_collateral(&lending_market, &mut usdc, 1_000, NOW),
+        Err(KaminoLendingError::GlobalEmergencyMode)
+    );
+    assert_eq!(usdc, after);
+}
+
+#[test]
+fn deposit_overflowing_the_total_supply_is_rejected() {
+    let (lending_market, _, mut usdc, _) = market(150);
+    usdc.liquidity.borrowed_amount_sf =
+        Fraction::MAX.to_bits() - Fraction::from_num(u64::MAX).to_bits();
+    let before = usdc.clone();
+
+    assert_eq!(
+        deposit_reserve_liquidity(&lending_market, &mut usdc, u64::MAX, NOW),
+        Err(KaminoLendingError::MathOverflow)
+    );
+    assert_eq!(usdc, before);
+}
+
+#[test]
+fn obligation_positions_round_trip() {
+    let (lending_market, mut obligation, mut usdc, sol) = market(150);
+    let (usdc_address, sol_address) = (usdc_address(), sol_address());
+    let refresh = |obligation: &mut Obligation, usdc: &Reserve| {
+        obligation
+            .refresh(&lending_market, &reserves(usdc, &sol))
+            .unwrap()
+    };
+    let deposited = obligation
+        .find_deposit(&sol_address)
+        .unwrap()
+        .deposited_amount;
+
+    let locked =
+        deposit_obligation_collateral(&lending_market, &mut obligation, &sol_address, &sol, 1_000)
+            .unwrap();
+    assert_eq!(locked.collateral_paid, 1_000);
+    assert_eq!(
+        obligation
+            .find_deposit(&sol_address)
+            .unwrap()
+            .deposited_amount,
+        deposited + 1_000
+    );
+    refresh(&mut obligation, &usdc);
+
+    let unchanged = (obligation.clone(), usdc.clone());
+    assert_eq!(
+        borrow_obligation_liquidity(
+            &lending_market,
+            &mut obligation,
+            &usdc_address,
+            &mut usdc,
+            &mut [],
+            5_000_000_000,
+            NOW
+        ),
+        Err(KaminoLendingError::BorrowTooLarge)
+    );
+    assert_eq!((obligation.clone(), usdc.clone()), unchanged);
+
+    let debt_before = obligation
+        .find_borrow(&usdc_address)
+        .unwrap()
+        .borrowed_amount();
+    let borrowed = borrow_obligation_liquidity(
+        &lending_market,
+        &mut obligation,
+        &usdc_address,
+        &mut usdc,
+        &mut [],
+        10_000_000,
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(borrowed.liquidity_received, 10_000_000);
+    assert_eq!(
+        borrowed.fees,
+        unchanged
+            .1
+            .borrow_fee(10_000_000, FeeCalculation::Exclusive, false)
+            .unwrap()
+    );
+    assert_eq!(
+        usdc.liquidity.available_amount,
+        unchanged.1.liquidity.available_amount - 10_000_000 - borrowed.fees
+    );
+    assert!(
+        obligation
+            .find_borrow(&usdc_address)
+            .unwrap()
+            .borrowed_amount()
+            > debt_before
+    );
+    refresh(&mut obligation, &usdc);
+
+    let repaid = repay_obligation_liquidity(
+        &lending_market,
+        &mut obligation,
+        &usdc_address,
+        &mut usdc,
+        &mut [],
+        u64::MAX,
+        NOW,
+    )
+    .unwrap();
+    assert!(repaid.liquidity_paid > 10_000_000);
+    assert_eq!(obligation.find_borrow(&usdc_address), None);
+    assert_eq!(
+        repay_obligation_liquidity(
+            &lending_market,
+            &mut obligation,
+            &usdc_address,
+            &mut usdc,
+            &mut [],
+            1,
+            NOW
+        ),
+        Err(KaminoLendingError::ObligationLiquidityEmpty)
+    );
+    refresh(&mut obligation, &usdc);
+
+    let released = withdraw_obligation_collateral(
+        &lending_market,
+        &mut obligation,
+        &sol_address,
+        &sol,
+        u64::MAX,
+    )
+    .unwrap();
+    assert_eq!(released.collateral_received, deposited + 1_000);
+    assert_eq!(obligation.find_deposit(&sol_address), None);
+}
+
+#[test]
+fn withdraw_checks_refreshed_values() {
+    let (lending_market, mut obligation, usdc, sol) = market(150);
+    let sol_address = sol_address();
+    let deposited = obligation
+        .find_deposit(&sol_address)
+        .unwrap()
+        .deposited_amount;
+    let released = withdraw_obligation_collateral(
+        &lending_market,
+        &mut obligation,
+        &sol_address,
+        &sol,
+        u64::MAX,
+    )
+    .unwrap();
+    assert!(released.collateral_received > 0);
+    assert!(released.collateral_received < deposited);
+    assert_eq!(
+        obligation
+            .find_deposit(&sol_address)
+            .unwrap()
+            .deposited_amount,
+        deposited - released.collateral_received
+    );
+
+    obligation
+        .refresh(&lending_market, &reserves(&usdc, &sol))
+        .unwrap();
+    let unchanged = obligation.clone();
+    assert_eq!(
+        withdraw_obligation_collateral(
+            &lending_market,
+            &mut obligation,
+            &sol_address,
+            &sol,
+            deposited - released.collateral_received
+        ),
+        Err(KaminoLendingError::WithdrawTooLarge)
+    );
+    assert_eq!(obligation, unchanged);
+
+    let (lending_market, mut obligation, _, sol) = market(80);
+    let unchanged = obligation.clone();
+    assert_eq!(
+        withdraw_obligation_collateral(
+            &lending_market,
+            &mut obligation,
+            &sol_address,
+            &sol,
+            u64::MAX
+        ),
+        Err(KaminoLendingError::WithdrawTooLarge)
+    );
+    assert_eq!(obligation, unchanged);
+}
+
+#[test]
+fn borrow_and_repay_restore_elevation_group_borrows() {
+    let (mut lending_market, mut obligation, mut reserves) = synthetic::market();
+    lending_market.elevation_groups[0] = ElevationGroup {
+        max_liquidation_bonus_bps: 0,
+        id: 1,
+        ltv_pct: 75,
+        liquidation_threshold_pct: 80,
+        allow_new_loans: 1,
+        max_reserves_as_collateral: 1,
+        padding0: 0,
+        debt_reserve: synthetic::DEBT,
+        padding1: [0; 4],
+    };
+    obligation.elevation_group = 1;
+    for (_, reserve) in &mut reserves {
+        reserve.config.elevation_groups[0] = 1;
+        reserve
+            .config
+            .borrow_limit_against_this_collateral_in_elevation_group[0] = u64::MAX;
+    }
+    reserves[0]
+        .1
+        .borrowed_amounts_against_this_reserve_in_elevation_groups[0] = 200_000_000;
+    obligation.refresh(&lending_market, &reserves).unwrap();
+    let (mut deposit_reserves, mut debt) = (vec![reserves[0].clone()], reserves[1].1.clone());
+    let borrowed_against = |deposit_reserves: &[(Pubkey, Reserve)]| {
+        deposit_reserves[0]
+            .1
+            .borrowed_amounts_against_this_reserve_in_elevation_groups[0]
+    };
+
+    borrow_obligation_liquidity(
+        &lending_market,
+        &mut obligation,
+        &synthetic::DEBT,
+        &mut debt,
+        &mut deposit_reserves,
+        100_000_000,
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(borrowed_against(&deposit_reserves), 300_000_000);
+    repay_obligation_liquidity(
+        &lending_market,
+        &mut obligation,
+        &synthetic::DEBT,
+        &mut debt,
+        &mut deposit_reserves,
+        100_000_000,
+        NOW,
+    )
+    .unwrap();
+    assert_eq!(borrowed_against(&deposit_reserves), 200_000_000);
+}
+
+#[test]
+fn liquidation_repays_debt_for_collateral_and_bonus() {
+    let (lending_market, mut healthy, mut usdc, mut sol) = market(150);
+    let (usdc_address, sol_address) = (usdc_address(), sol_address());
+    assert_eq!(
+        liquidate_obligation_and_redeem_reserve_collateral(
+            &lending_market,
+            &mut healthy,
+            &usdc_address,
+            &mut usdc,
+            &sol_address,
+            Some(&mut sol),
+            &mut [],
+            u64::MAX,
+            0,
+            NOW,
+        ),
+        Err(KaminoLendingError::ObligationHealthy)
+    );
+
+    let (lending_market, mut obligation, mut usdc, mut sol) = market(80);
+    let values = obligation
+        .compute_values(&lending_market, &reserves(&usdc, &sol))
+        .unwrap();
+    let bonus = liquidation_bonus(&lending_market, &values, &sol, &usdc);
+    let unchanged = (obligation.clone(), usdc.clone(), sol.clone());
+    assert_eq!(
+        liquidate_obligation_and_redeem_reserve_collateral(
+            &lending_market,
+            &mut obligation,
+            &usdc_address,
+            &mut usdc,
+            &sol_address,
+            Some(&mut sol),
+            &mut [],
+            u64::MAX,
+            u64::MAX,
+            NOW,
+        ),
+        Err(KaminoLendingError::LiquidationRewardTooSmall)
+    );
+    assert_eq!((obligation.clone(), usdc.clone(), sol.clone()), unchanged);
+
+    let moved = liquidate_obligation_and_redeem_reserve_collateral(
+        &lending_market,
+        &mut obligation,
+        &usdc_address,
+        &mut usdc,
+        &sol_address,
+        Some(&mut sol),
+        &mut [],
+        u64::MAX,
+        0,
+        NOW,
+    )
+    .unwrap();
+    let repaid_value = usdc.market_value(Fraction::from_num(moved.liquidity_paid));
+    let max_value = lending_market.max_liquidatable_debt_value(&values);
+    assert!(repaid_value <= max_value + Fraction::from_percent(1u8));
+    assert!(repaid_value * Fraction::from_num(100) >= max_value * Fraction::from_num(99));
+    let withdrawn = moved.liquidity_received + moved.fees;
+    let withdrawn_value = sol.market_value(Fraction::from_num(withdrawn));
+    let expected = repaid_value * (Fraction::ONE + bonus);
+    assert!(withdrawn_value <= expected);
+    assert!(withdrawn_value * Fraction::from_num(1_000) >= expected * Fraction::from_num(999));
+    assert!(moved.fees > 0);
+    assert_eq!(
+        sol.liquidity.available_amount,
+        unchanged.2.liquidity.available_amount - withdrawn
+    );
+    assert_eq!(
+        usdc.liquidity.available_amount,
+        unchanged.1.liquidity.available_amount + moved.liquidity_paid
+    );
+    assert!(
+        obligation
+            .find_deposit(&sol_address)
+            .unwrap()
+            .deposited_amount
+            < unchanged
+                .0
+                .find_deposit(&sol_address)
+                .unwrap()
+                .deposited_amount
+    );
+}
@@ -0,0 +1,162 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+mod common;
+
+use common::*;
+
+#[test]
+fn unhealthy_obligations_ranked_by_profit() {
+    let usdc = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let sol = Pubkey::from_str(SOL_RESERVE).unwrap();
+    let (lending_market, obligation, reserves) = market(150);
+    let mut underwater = obligation.clone();
+    underwater.deposits[0].deposited_amount /= 2;
+    let obligations = [
+        (Pubkey::new_unique(), obligation),
+        (Pubkey::new_unique(), underwater),
+    ];
+    let scan = |reserves: &Vec<(Pubkey, Reserve)>| {
+        scan_liquidations(
+            &lending_market,
+            obligations
+                .iter()
+                .map(|(address, obligation)| (address, obligation)),
+            reserves,
+            NOW,
+        )
+    };
+    assert_eq!(scan(&reserves).len(), 1);
+
+    let (_, _, reserves) = market(80);
+    let found = scan(&reserves);
+    assert_eq!(found.len(), 2);
+    assert!(found[0].profit_value >= found[1].profit_value);
+    let partial = found
+        .iter()
+        .find(|found| found.obligation == obligations[0].0)
+        .unwrap();
+    let values = obligations[0]
+        .1
+        .compute_values(&lending_market, &reserves)
+        .unwrap();
+    assert_eq!(partial.reason, LiquidationReason::Unhealthy);
+    assert_eq!(partial.repay_reserve, usdc);
+    assert_eq!(partial.withdraw_reserve, sol);
+    assert_eq!(
+        partial.liquidation_threshold,
+        values.unhealthy_loan_to_value()
+    );
+    assert_eq!(
+        partial.liquidation_bonus,
+        values.loan_to_value() - values.unhealthy_loan_to_value()
+    );
+    assert_eq!(
+        partial.repay_value,
+        values.borrowed_value * Fraction::from_percent(20u8)
+    );
+    let bonus_value = partial.repay_value * partial.liquidation_bonus;
+    assert_eq!(
+        partial.protocol_fee_value,
+        bonus_value * Fraction::from_percent(50u8)
+    );
+    assert_eq!(
+        partial.flash_loan_fee_value,
+        partial.repay_value * reserves[0].1.config.fees.flash_loan_fee_rate()
+    );
+    assert_eq!(
+        partial.profit_value,
+        bonus_value - partial.protocol_fee_value - partial.flash_loan_fee_value
+    );
+    let withdrawn = reserves[1]
+        .1
+        .liquidity_to_collateral(partial.withdraw_liquidity_amount);
+    assert!(withdrawn > 0 && withdrawn < values.deposits[0].collateral_amount);
+
+    let bad_debt = found
+        .iter()
+        .find(|found| found.obligation == obligations[1].0)
+        .unwrap();
+    let values = obligations[1]
+        .1
+        .compute_values(&lending_market, &reserves)
+        .unwrap();
+    assert!(bad_debt.loan_to_value > Fraction::ONE);
+    assert_eq!(bad_debt.liquidation_bonus, Fraction::from_bps(99u16));
+    assert_eq!(
+        bad_debt.repay_value,
+        values.deposited_value / (Fraction::ONE + bad_debt.liquidation_bonus)
+    );
+}
+
+#[test]
+fn withdraws_lowest_ltv_collateral_first() {
+    let usdc = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let sol = Pubkey::from_str(SOL_RESERVE).unwrap();
+    let (lending_market, mut obligation, mut reserves) = market(80);
+    obligation.deposits[1].deposit_reserve = usdc;
+    obligation.deposits[1].deposited_amount = 10_000_000;
+    let address = Pubkey::new_unique();
+
+    for (ltv_pct, error) in [
+        (0, KaminoLendingError::ZeroMaxLtvAssetsInDeposits),
+        (50, KaminoLendingError::MinLtvAssetsPriority),
+    ] {
+        reserves[0].1.config.loan_to_value_pct = ltv_pct;
+        let values = obligation
+            .compute_values(&lending_market, &reserves)
+            .unwrap();
+        assert_eq!(
+            check_liquidation_collateral(&lending_market, &values, &usdc),
+            Ok(())
+        );
+        assert_eq!(
+            check_liquidation_collateral(&lending_market, &values, &sol),
+            Err(error)
+        );
+        let found = obligation
+            .liquidation_opportunity(&address, &lending_market, &reserves, NOW)
+            .unwrap();
+        assert_eq!(found.withdraw_reserve, usdc);
+    }
+
+    let mut lending_market = lending_market;
+    lending_market.min_value_skip_liquidation_ltv_checks = 1_000_000;
+    let values = obligation
+        .compute_values(&lending_market, &reserves)
+        .unwrap();
+    assert_eq!(
+        check_liquidation_collateral(&lending_market, &values, &sol),
+        Ok(())
+    );
+}
+
+#[test]
+fn deleveraging_after_margin_call() {
+    let (lending_market, mut obligation, mut reserves) = market(150);
+    reserves[0].1.config.deleveraging_margin_call_period_secs = 86_400;
+    obligation.autodeleverage_target_ltv_pct = 30;
+    obligation.autodeleverage_margin_call_started_timestamp = NOW as u64 - 3 * 86_400;
+    let address = Pubkey::new_unique();
+
+    let found = obligation
+        .liquidation_opportunity(&address, &lending_market, &reserves, NOW)
+        .unwrap();
+    let state = obligation
+        .deleveraging_state(&lending_market, &reserves[0].1, NOW)
+        .unwrap();
+    assert_eq!(found.reason, LiquidationReason::Deleveraging);
+    assert_eq!(found.liquidation_threshold, state.liquidation_threshold);
+    assert_eq!(
+        found.liquidation_bonus,
+        Fraction::from_bps(state.liquidation_bonus_bps)
+    );
+
+    obligation.autodeleverage_margin_call_started_timestamp = NOW as u64 - 3_600;
+    assert_eq!(
+        obligation.liquidation_opportunity(&address, &lending_market, &reserves, NOW),
+        None
+    );
+}
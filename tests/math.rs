@@ -0,0 +1,58 @@
+use kamino_lending_interface::math::{mul_div_ceil, mul_div_floor, WAD};
+use proptest::prelude::*;
+
+#[test]
+fn mul_div_rounds_towards_its_direction() {
+    assert_eq!(mul_div_floor(6, 4, 3), Some(8));
+    assert_eq!(mul_div_ceil(6, 4, 3), Some(8));
+    assert_eq!(mul_div_floor(7, WAD, 3), Some(2_333_333_333_333_333_333));
+    assert_eq!(mul_div_ceil(7, WAD, 3), Some(2_333_333_333_333_333_334));
+    assert_eq!(mul_div_floor(0, u128::MAX, 1), Some(0));
+    assert_eq!(mul_div_ceil(0, u128::MAX, 1), Some(0));
+}
+
+#[test]
+fn mul_div_carries_products_wider_than_128_bits() {
+    assert_eq!(
+        mul_div_floor(u128::MAX, u128::MAX, u128::MAX),
+        Some(u128::MAX)
+    );
+    assert_eq!(mul_div_floor(u128::MAX, 2, 4), Some(u128::MAX >> 1));
+    assert_eq!(mul_div_ceil(u128::MAX, 2, 4), Some((u128::MAX >> 1) + 1));
+    assert_eq!(
+        mul_div_floor(WAD * WAD, WAD * WAD, WAD * WAD),
+        Some(WAD * WAD)
+    );
+}
+
+#[test]
+fn mul_div_rejects_zero_denominators_and_overflow() {
+    assert_eq!(mul_div_floor(1, 1, 0), None);
+    assert_eq!(mul_div_ceil(1, 1, 0), None);
+    assert_eq!(mul_div_floor(u128::MAX, 2, 1), None);
+    assert_eq!(mul_div_ceil(u128::MAX, 3, 2), None);
+    // (2^43 - 1) * (2^86 + 2^43 + 1) = 2^129 - 1: the floor fits but rounding it up does not.
+    let (a, b) = ((1 << 43) - 1, (1 << 86) + (1 << 43) + 1);
+    assert_eq!(mul_div_floor(a, b, 2), Some(u128::MAX));
+    assert_eq!(mul_div_ceil(a, b, 2), None);
+    assert_eq!(
+        mul_div_floor(u128::MAX, u128::MAX - 1, u128::MAX - 1),
+        Some(u128::MAX)
+    );
+    assert_eq!(
+        mul_div_ceil(u128::MAX, u128::MAX - 1, u128::MAX - 1),
+        Some(u128::MAX)
+    );
+}
+
+proptest! {
+    #[test]
+    fn mul_div_matches_narrow_arithmetic(a in any::<u64>(), b in any::<u64>(), denominator in 1..=u64::MAX) {
+        let (product, denominator) = (u128::from(a) * u128::from(b), u128::from(denominator));
+        prop_assert_eq!(mul_div_floor(a.into(), b.into(), denominator), Some(product / denominator));
+        prop_assert_eq!(
+            mul_div_ceil(a.into(), b.into(), denominator),
+            Some(product.div_ceil(denominator))
+        );
+    }
+}
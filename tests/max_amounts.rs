@@ -0,0 +1,380 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::synthetic::*;
+use common::NOW;
+
+fn max_amount(amount: u64, constraint: AmountConstraint) -> MaxAmount {
+    MaxAmount { amount, constraint }
+}
+
+fn max_borrow(
+    lending_market: &LendingMarket,
+    obligation: &Obligation,
+    reserves: &Vec<(Pubkey, Reserve)>,
+) -> MaxAmount {
+    max_borrow_amount(obligation, lending_market, reserves, &DEBT, NOW).unwrap()
+}
+
+#[test]
+fn borrow_is_bound_by_each_debt_limit() {
+    let (mut lending_market, obligation, reserves) = market();
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(300_000_000, AmountConstraint::ObligationLtv)
+    );
+
+    let limited = |limit: fn(&mut Reserve)| {
+        let mut reserves = reserves.clone();
+        limit(&mut reserves[1].1);
+        max_borrow(&lending_market, &obligation, &reserves)
+    };
+    assert_eq!(
+        limited(|debt| debt.liquidity.available_amount = 100_000_000),
+        max_amount(100_000_000, AmountConstraint::AvailableLiquidity)
+    );
+    assert_eq!(
+        limited(|debt| {
+            debt.config.debt_withdrawal_cap = WithdrawalCaps {
+                config_capacity: 90_000_000,
+                current_total: 10_000_000,
+                last_interval_start_timestamp: NOW as u64,
+                config_interval_length_seconds: 3_600,
+            }
+        }),
+        max_amount(80_000_000, AmountConstraint::WithdrawalCap)
+    );
+    assert_eq!(
+        limited(|debt| debt.config.borrow_limit = 240_000_000),
+        max_amount(40_000_000, AmountConstraint::BorrowLimit)
+    );
+    // A quarter of the 1_200 tokens supplied may be borrowed, 200 already are.
+    assert_eq!(
+        limited(|debt| debt.config.utilization_limit_block_borrowing_above_pct = 25),
+        max_amount(100_000_000, AmountConstraint::UtilizationLimit)
+    );
+    assert_eq!(
+        limited(|debt| {
+            debt.config.borrow_limit_outside_elevation_group = 230_000_000;
+            debt.borrowed_amount_outside_elevation_group = 200_000_000;
+        }),
+        max_amount(
+            30_000_000,
+            AmountConstraint::BorrowLimitOutsideElevationGroup
+        )
+    );
+
+    // A net value of 800 keeps 750 in the obligation.
+    lending_market.min_net_value_in_obligation_sf = Fraction::from_num(750).to_bits();
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(50_000_000, AmountConstraint::MinNetValue)
+    );
+}
+
+#[test]
+fn borrow_in_an_elevation_group_is_bound_by_the_collateral_limit() {
+    let (mut lending_market, mut obligation, mut reserves) = market();
+    lending_market.elevation_groups[0] = ElevationGroup {
+        id: 1,
+        ltv_pct: 75,
+        liquidation_threshold_pct: 80,
+        allow_new_loans: 1,
+        max_reserves_as_collateral: 1,
+        debt_reserve: DEBT,
+        ..lending_market.elevation_groups[0].clone()
+    };
+    obligation.elevation_group = 1;
+    for (_, reserve) in &mut reserves {
+        reserve.config.elevation_groups[0] = 1;
+    }
+    // No borrowing against the collateral in the group until its limit is raised.
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(0, AmountConstraint::ElevationGroupBorrowLimit)
+    );
+    reserves[0]
+        .1
+        .config
+        .borrow_limit_against_this_collateral_in_elevation_group[0] = u64::MAX;
+    // 750 of allowed borrow value, with no borrow factor in the group.
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(550_000_000, AmountConstraint::ObligationLtv)
+    );
+
+    let collateral = &mut reserves[0].1;
+    collateral
+        .config
+        .borrow_limit_against_this_collateral_in_elevation_group[0] = 70_000_000;
+    collateral.borrowed_amounts_against_this_reserve_in_elevation_groups[0] = 20_000_000;
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(50_000_000, AmountConstraint::ElevationGroupBorrowLimit)
+    );
+
+    lending_market.elevation_groups[0].allow_new_loans = 0;
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        MaxAmount::blocked(KaminoLendingError::ElevationGroupNewLoansDisabled)
+    );
+}
+
+#[test]
+fn borrow_blockers() {
+    let (mut lending_market, mut obligation, reserves) = market();
+    obligation.autodeleverage_margin_call_started_timestamp = 1;
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        MaxAmount::blocked(KaminoLendingError::ObligationCurrentlyMarkedForDeleveraging)
+    );
+    lending_market.borrow_disabled = 1;
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        MaxAmount::blocked(KaminoLendingError::BorrowingDisabled)
+    );
+    lending_market.emergency_mode = 1;
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        MaxAmount::blocked(KaminoLendingError::GlobalEmergencyMode)
+    );
+    assert_eq!(
+        max_borrow_amount(
+            &obligation,
+            &lending_market,
+            &reserves,
+            &Pubkey::new_unique(),
+            NOW
+        ),
+        Err(KaminoLendingError::InvalidAccountInput)
+    );
+}
+
+#[test]
+fn borrow_leaves_room_for_the_origination_fee() {
+    let (lending_market, obligation, mut reserves) = market();
+    reserves[1].1.config.fees.borrow_fee_sf = Fraction::from_percent(1u8).to_bits() as u64;
+    let debt = &reserves[1].1;
+    let max = max_borrow(&lending_market, &obligation, &reserves);
+    assert_eq!(max.constraint, AmountConstraint::ObligationLtv);
+    let fee = |amount| {
+        debt.borrow_fee(amount, FeeCalculation::Exclusive, false)
+            .unwrap()
+    };
+    // 300 / 1.01 = 297.0297..., and the rounded fee lets one more unit through.
+    assert_eq!(max.amount, 297_029_703);
+    assert_eq!(max.amount + fee(max.amount), 300_000_000);
+    assert!(max.amount + 1 + fee(max.amount + 1) > 300_000_000);
+}
+
+#[test]
+fn borrow_fee_inclusive_and_exclusive() {
+    let (_, _, reserves) = market();
+    let mut reserve = reserves[1].1.clone();
+    assert_eq!(
+        reserve.borrow_fee(1_000, FeeCalculation::Exclusive, true),
+        Ok(0)
+    );
+
+    reserve.config.fees.borrow_fee_sf = Fraction::from_percent(1u8).to_bits() as u64;
+    assert_eq!(
+        reserve.borrow_fee(100_000_000, FeeCalculation::Exclusive, false),
+        Ok(1_000_000)
+    );
+    // The fee is carved out of the amount: 101 = 100 + 1% of 100.
+    assert_eq!(
+        reserve.borrow_fee(101_000_000, FeeCalculation::Inclusive, false),
+        Ok(1_000_000)
+    );
+    assert_eq!(
+        reserve.borrow_fee(0, FeeCalculation::Inclusive, false),
+        Ok(0)
+    );
+    // Minimum fees of one unit, two with a referrer.
+    assert_eq!(
+        reserve.borrow_fee(10, FeeCalculation::Exclusive, false),
+        Ok(1)
+    );
+    assert_eq!(
+        reserve.borrow_fee(10, FeeCalculation::Inclusive, true),
+        Ok(2)
+    );
+    assert_eq!(
+        reserve.borrow_fee(2, FeeCalculation::Exclusive, true),
+        Err(KaminoLendingError::BorrowTooSmall)
+    );
+}
+
+#[test]
+fn withdraw_is_bound_by_position_ltv_and_net_value() {
+    let (mut lending_market, mut obligation, mut reserves) = market();
+    // 300 of remaining borrow value frees 600 of 50%-LTV collateral, i.e. 6 tokens.
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Ok(max_amount(6_000_000, AmountConstraint::ObligationLtv))
+    );
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &DEBT),
+        Ok(max_amount(0, AmountConstraint::Position))
+    );
+
+    lending_market.min_net_value_in_obligation_sf = Fraction::from_num(750).to_bits();
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Ok(max_amount(500_000, AmountConstraint::MinNetValue))
+    );
+
+    // Redeeming is also bound by the liquidity left in the reserve and its withdrawal cap.
+    lending_market.min_net_value_in_obligation_sf = 0;
+    obligation.borrows[0].borrow_reserve = Pubkey::default();
+    let collateral = &mut reserves[0].1;
+    collateral.liquidity.available_amount = 3_000_000;
+    collateral.liquidity.borrowed_amount_sf = Fraction::from_num(997_000_000).to_bits();
+    let redeem = |lending_market: &LendingMarket, reserves: &Vec<(Pubkey, Reserve)>| {
+        max_withdraw_and_redeem_collateral_amount(
+            &obligation,
+            lending_market,
+            reserves,
+            &COLLATERAL,
+            NOW,
+        )
+    };
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Ok(max_amount(10_000_000, AmountConstraint::Position))
+    );
+    assert_eq!(
+        redeem(&lending_market, &reserves),
+        Ok(max_amount(3_000_000, AmountConstraint::AvailableLiquidity))
+    );
+    reserves[0].1.config.deposit_withdrawal_cap = WithdrawalCaps {
+        config_capacity: 2_000_000,
+        current_total: 0,
+        last_interval_start_timestamp: NOW as u64,
+        config_interval_length_seconds: 3_600,
+    };
+    assert_eq!(
+        redeem(&lending_market, &reserves),
+        Ok(max_amount(2_000_000, AmountConstraint::WithdrawalCap))
+    );
+
+    lending_market.emergency_mode = 1;
+    assert_eq!(
+        redeem(&lending_market, &reserves),
+        Ok(MaxAmount::blocked(KaminoLendingError::GlobalEmergencyMode))
+    );
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Ok(MaxAmount::blocked(KaminoLendingError::GlobalEmergencyMode))
+    );
+}
+
+#[test]
+fn deposit_and_repay_limits() {
+    let (mut lending_market, obligation, mut reserves) = market();
+    let reserve = &mut reserves[1].1;
+    assert_eq!(
+        max_deposit_amount(&lending_market, reserve, 5_000),
+        max_amount(5_000, AmountConstraint::WalletBalance)
+    );
+    reserve.config.deposit_limit = 1_200_001_000;
+    assert_eq!(
+        max_deposit_amount(&lending_market, reserve, 5_000),
+        max_amount(1_000, AmountConstraint::DepositLimit)
+    );
+    reserve.config.status = ReserveStatus::Obsolete as u8;
+    assert_eq!(
+        max_deposit_amount(&lending_market, reserve, 5_000),
+        MaxAmount::blocked(KaminoLendingError::ReserveObsolete)
+    );
+    lending_market.emergency_mode = 1;
+    assert_eq!(
+        max_deposit_amount(&lending_market, reserve, 5_000),
+        MaxAmount::blocked(KaminoLendingError::GlobalEmergencyMode)
+    );
+
+    assert_eq!(
+        max_repay_amount(&obligation, &reserves, &DEBT, u64::MAX),
+        Ok(max_amount(200_000_000, AmountConstraint::Position))
+    );
+    assert_eq!(
+        max_repay_amount(&obligation, &reserves, &DEBT, 5_000),
+        Ok(max_amount(5_000, AmountConstraint::WalletBalance))
+    );
+    assert_eq!(
+        max_repay_amount(&obligation, &reserves, &COLLATERAL, 5_000),
+        Ok(max_amount(0, AmountConstraint::Position))
+    );
+}
+
+#[test]
+fn empty_obligations_and_reserves() {
+    let (lending_market, mut obligation, mut reserves) = market();
+    obligation.deposits[0].deposit_reserve = Pubkey::default();
+    obligation.borrows[0].borrow_reserve = Pubkey::default();
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Ok(max_amount(0, AmountConstraint::Position))
+    );
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(0, AmountConstraint::ObligationLtv)
+    );
+
+    // Nothing supplied: the whole deposit limit is open, nothing can be borrowed or redeemed.
+    reserves[1].1 = reserve(1, 0, 0);
+    reserves[1].1.config.deposit_limit = 1_000_000;
+    reserves[1]
+        .1
+        .config
+        .utilization_limit_block_borrowing_above_pct = 90;
+    assert_eq!(
+        max_deposit_amount(&lending_market, &reserves[1].1, u64::MAX),
+        max_amount(1_000_000, AmountConstraint::DepositLimit)
+    );
+    assert_eq!(
+        max_borrow(&lending_market, &obligation, &reserves),
+        max_amount(0, AmountConstraint::AvailableLiquidity)
+    );
+    obligation.deposits[0].deposit_reserve = DEBT;
+    obligation.deposits[0].deposited_amount = 1_000;
+    assert_eq!(
+        max_withdraw_and_redeem_collateral_amount(
+            &obligation,
+            &lending_market,
+            &reserves,
+            &DEBT,
+            NOW
+        ),
+        Ok(max_amount(0, AmountConstraint::AvailableLiquidity))
+    );
+}
+
+#[test]
+fn overflowing_values_are_reported() {
+    let (lending_market, obligation, mut reserves) = market();
+    // The debt's market value saturates, and its borrow factor pushes it over the maximum.
+    reserves[1].1.liquidity.market_price_sf = u128::MAX;
+    reserves[1].1.config.borrow_factor_pct = 150;
+    assert_eq!(
+        obligation.compute_values(&lending_market, &reserves),
+        Err(KaminoLendingError::MathOverflow)
+    );
+    assert_eq!(
+        max_borrow_amount(&obligation, &lending_market, &reserves, &DEBT, NOW),
+        Err(KaminoLendingError::MathOverflow)
+    );
+
+    let (lending_market, mut obligation, mut reserves) = market();
+    reserves[0].1.liquidity.market_price_sf = u128::MAX;
+    obligation.deposits[1] = obligation.deposits[0].clone();
+    obligation.deposits[1].deposit_reserve = DEBT;
+    reserves[1].1.liquidity.market_price_sf = u128::MAX;
+    assert_eq!(
+        max_withdraw_collateral_amount(&obligation, &lending_market, &reserves, &COLLATERAL),
+        Err(KaminoLendingError::MathOverflow)
+    );
+}
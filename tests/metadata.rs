@@ -0,0 +1,131 @@
+use kamino_lending_interface::*;
+use serde_json::Value;
+use solana_program::hash::hashv;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn flatten(accounts: &Value, prefix: &str, out: &mut Vec<(String, bool, bool, bool)>) {
+    for account in accounts.as_array().unwrap() {
+        let name = format!("{prefix}{}", snake_case(account["name"].as_str().unwrap()));
+        if account.get("accounts").is_some() {
+            flatten(&account["accounts"], &format!("{name}_"), out);
+        } else {
+            out.push((
+                name,
+                account["isMut"].as_bool().unwrap(),
+                account["isSigner"].as_bool().unwrap(),
+                account["isOptional"].as_bool().unwrap_or(false),
+            ));
+        }
+    }
+}
+
+#[test]
+fn registry_matches_idl() {
+    let idl: Value = serde_json::from_str(include_str!("../kamino_lending.json")).unwrap();
+    let instructions = idl["instructions"].as_array().unwrap();
+    assert_eq!(instructions.len(), INSTRUCTION_METAS.len());
+    for (ix, meta) in instructions.iter().zip(INSTRUCTION_METAS) {
+        let idl_name = ix["name"].as_str().unwrap();
+        let snake = snake_case(idl_name);
+        assert_eq!(snake_case(meta.name).trim_start_matches('_'), snake);
+        let discm = hashv(&[b"global:", snake.as_bytes()]).to_bytes();
+        assert_eq!(meta.discm, discm[..8], "{idl_name}");
+
+        let mut accounts = Vec::new();
+        flatten(&ix["accounts"], "", &mut accounts);
+        let registered: Vec<_> = meta
+            .accounts
+            .iter()
+            .map(|a| {
+                (
+                    a.name.to_string(),
+                    a.is_writable,
+                    a.is_signer,
+                    a.is_optional,
+                )
+            })
+            .collect();
+        assert_eq!(registered, accounts, "{idl_name}");
+
+        let args: Vec<_> = ix["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|arg| snake_case(arg["name"].as_str().unwrap()))
+            .collect();
+        let registered: Vec<_> = meta.args.iter().map(|arg| arg.name.to_string()).collect();
+        assert_eq!(registered, args, "{idl_name}");
+    }
+}
+
+#[test]
+fn lookup_and_labelling() {
+    let keys = RefreshObligationKeys {
+        lending_market: Pubkey::new_unique(),
+        obligation: Pubkey::new_unique(),
+    };
+    let mut ix = keys.to_instruction(ID).unwrap();
+    ix.accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+
+    let meta = instruction_meta_by_discm(REFRESH_OBLIGATION_IX_DISCM).unwrap();
+    assert_eq!(
+        meta.name,
+        <RefreshObligationKeys as KaminoInstruction>::NAME
+    );
+    assert_eq!(
+        KaminoLendingProgramIx::deserialize(&ix.data)
+            .unwrap()
+            .metadata(),
+        meta
+    );
+    assert_eq!(instruction_meta_by_name("RefreshObligation"), Some(meta));
+    assert!(instruction_meta_by_discm([0; 8]).is_none());
+
+    let labels: Vec<_> = meta
+        .label_accounts(&ix.accounts)
+        .map(|(name, account)| (name.into_owned(), account.is_writable))
+        .collect();
+    assert_eq!(
+        labels,
+        [
+            ("lending_market".to_string(), false),
+            ("obligation".to_string(), true),
+            ("remaining_account_0".to_string(), false),
+        ]
+    );
+    assert!(meta.account(2).is_none());
+}
+
+#[test]
+fn flags_match_generated_account_metas() {
+    let keys = BorrowObligationLiquidityV2Keys::from(
+        [Pubkey::new_unique(); BORROW_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN],
+    );
+    let metas: [AccountMeta; BORROW_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let registry = instruction_meta_by_discm(BORROW_OBLIGATION_LIQUIDITY_V2_IX_DISCM).unwrap();
+    for (meta, account) in metas.iter().zip(registry.accounts) {
+        assert_eq!(
+            (meta.is_writable, meta.is_signer),
+            (account.is_writable, account.is_signer),
+            "{}",
+            account.name
+        );
+    }
+    let args = registry.args;
+    assert_eq!((args[0].name, args[0].ty), ("liquidity_amount", "u64"));
+}
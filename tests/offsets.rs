@@ -0,0 +1,835 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use kamino_lending_interface::filters;
+use kamino_lending_interface::offsets::*;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+fn zeroed<T: BorshDeserialize>() -> T {
+    T::deserialize(&mut &[0u8; 10_000][..]).unwrap()
+}
+
+/// Offset, discriminator included, of the first non-zero byte of `account`'s serialization.
+fn first_set_byte<T: BorshSerialize>(account: &T) -> Option<usize> {
+    let data = account.try_to_vec().unwrap();
+    data.iter()
+        .position(|byte| *byte != 0)
+        .map(|index| index + 8)
+}
+
+macro_rules! assert_offset {
+    ($account:ty, $offset:ident, |$a:ident| $set:expr) => {{
+        let mut $a: $account = zeroed();
+        $set;
+        assert_eq!(first_set_byte(&$a), Some($offset), stringify!($offset));
+    }};
+}
+
+#[test]
+fn offsets_match_borsh_layout() {
+    assert_offset!(UserState, USER_STATE_USER_ID_OFFSET, |a| a.user_id = 1);
+    assert_offset!(UserState, USER_STATE_FARM_STATE_OFFSET, |a| a.farm_state =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(UserState, USER_STATE_OWNER_OFFSET, |a| a.owner =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(UserState, USER_STATE_IS_FARM_DELEGATED_OFFSET, |a| a
+        .is_farm_delegated =
+        1);
+    assert_offset!(UserState, USER_STATE_PADDING0_OFFSET, |a| a.padding0[0] = 1);
+    assert_offset!(UserState, USER_STATE_REWARDS_TALLY_SCALED_OFFSET, |a| a
+        .rewards_tally_scaled[0] =
+        1);
+    assert_offset!(UserState, USER_STATE_REWARDS_ISSUED_UNCLAIMED_OFFSET, |a| {
+        a.rewards_issued_unclaimed[0] = 1
+    });
+    assert_offset!(UserState, USER_STATE_LAST_CLAIM_TS_OFFSET, |a| a
+        .last_claim_ts[0] =
+        1);
+    assert_offset!(UserState, USER_STATE_ACTIVE_STAKE_SCALED_OFFSET, |a| a
+        .active_stake_scaled =
+        1);
+    assert_offset!(
+        UserState,
+        USER_STATE_PENDING_DEPOSIT_STAKE_SCALED_OFFSET,
+        |a| a.pending_deposit_stake_scaled = 1
+    );
+    assert_offset!(UserState, USER_STATE_PENDING_DEPOSIT_STAKE_TS_OFFSET, |a| {
+        a.pending_deposit_stake_ts = 1
+    });
+    assert_offset!(
+        UserState,
+        USER_STATE_PENDING_WITHDRAWAL_UNSTAKE_SCALED_OFFSET,
+        |a| a.pending_withdrawal_unstake_scaled = 1
+    );
+    assert_offset!(
+        UserState,
+        USER_STATE_PENDING_WITHDRAWAL_UNSTAKE_TS_OFFSET,
+        |a| a.pending_withdrawal_unstake_ts = 1
+    );
+    assert_offset!(UserState, USER_STATE_BUMP_OFFSET, |a| a.bump = 1);
+    assert_offset!(UserState, USER_STATE_DELEGATEE_OFFSET, |a| a.delegatee =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(UserState, USER_STATE_LAST_STAKE_TS_OFFSET, |a| a
+        .last_stake_ts =
+        1);
+    assert_offset!(UserState, USER_STATE_PADDING1_OFFSET, |a| a.padding1[0] = 1);
+    assert_offset!(LendingMarket, LENDING_MARKET_VERSION_OFFSET, |a| a
+        .version =
+        1);
+    assert_offset!(LendingMarket, LENDING_MARKET_BUMP_SEED_OFFSET, |a| a
+        .bump_seed =
+        1);
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_LENDING_MARKET_OWNER_OFFSET,
+        |a| a.lending_market_owner = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_LENDING_MARKET_OWNER_CACHED_OFFSET,
+        |a| a.lending_market_owner_cached = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_QUOTE_CURRENCY_OFFSET, |a| a
+        .quote_currency[0] =
+        1);
+    assert_offset!(LendingMarket, LENDING_MARKET_REFERRAL_FEE_BPS_OFFSET, |a| {
+        a.referral_fee_bps = 1
+    });
+    assert_offset!(LendingMarket, LENDING_MARKET_EMERGENCY_MODE_OFFSET, |a| a
+        .emergency_mode =
+        1);
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_AUTODELEVERAGE_ENABLED_OFFSET,
+        |a| a.autodeleverage_enabled = 1
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_BORROW_DISABLED_OFFSET, |a| {
+        a.borrow_disabled = 1
+    });
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_PRICE_REFRESH_TRIGGER_TO_MAX_AGE_PCT_OFFSET,
+        |a| a.price_refresh_trigger_to_max_age_pct = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_LIQUIDATION_MAX_DEBT_CLOSE_FACTOR_PCT_OFFSET,
+        |a| a.liquidation_max_debt_close_factor_pct = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_INSOLVENCY_RISK_UNHEALTHY_LTV_PCT_OFFSET,
+        |a| a.insolvency_risk_unhealthy_ltv_pct = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_MIN_FULL_LIQUIDATION_VALUE_THRESHOLD_OFFSET,
+        |a| a.min_full_liquidation_value_threshold = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_MAX_LIQUIDATABLE_DEBT_MARKET_VALUE_AT_ONCE_OFFSET,
+        |a| a.max_liquidatable_debt_market_value_at_once = 1
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_RESERVED0_OFFSET, |a| a
+        .reserved0[0] =
+        1);
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_GLOBAL_ALLOWED_BORROW_VALUE_OFFSET,
+        |a| a.global_allowed_borrow_value = 1
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_RISK_COUNCIL_OFFSET, |a| a
+        .risk_council =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(LendingMarket, LENDING_MARKET_RESERVED1_OFFSET, |a| a
+        .reserved1[0] =
+        1);
+    assert_offset!(LendingMarket, LENDING_MARKET_ELEVATION_GROUPS_OFFSET, |a| {
+        a.elevation_groups[0].max_liquidation_bonus_bps = 1
+    });
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_ELEVATION_GROUP_PADDING_OFFSET,
+        |a| a.elevation_group_padding[0] = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_MIN_NET_VALUE_IN_OBLIGATION_SF_OFFSET,
+        |a| a.min_net_value_in_obligation_sf = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_MIN_VALUE_SKIP_LIQUIDATION_LTV_CHECKS_OFFSET,
+        |a| a.min_value_skip_liquidation_ltv_checks = 1
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_NAME_OFFSET, |a| a.name[0] = 1);
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_MIN_VALUE_SKIP_LIQUIDATION_BF_CHECKS_OFFSET,
+        |a| a.min_value_skip_liquidation_bf_checks = 1
+    );
+    assert_offset!(
+        LendingMarket,
+        LENDING_MARKET_INDIVIDUAL_AUTODELEVERAGE_MARGIN_CALL_PERIOD_SECS_OFFSET,
+        |a| a.individual_autodeleverage_margin_call_period_secs = 1
+    );
+    assert_offset!(LendingMarket, LENDING_MARKET_PADDING1_OFFSET, |a| a
+        .padding1[0] =
+        1);
+    assert_offset!(Obligation, OBLIGATION_TAG_OFFSET, |a| a.tag = 1);
+    assert_offset!(Obligation, OBLIGATION_LAST_UPDATE_OFFSET, |a| a
+        .last_update
+        .slot = 1);
+    assert_offset!(Obligation, OBLIGATION_LAST_UPDATE_SLOT_OFFSET, |a| a
+        .last_update
+        .slot =
+        1);
+    assert_offset!(Obligation, OBLIGATION_LAST_UPDATE_STALE_OFFSET, |a| a
+        .last_update
+        .stale =
+        1);
+    assert_offset!(
+        Obligation,
+        OBLIGATION_LAST_UPDATE_PRICE_STATUS_OFFSET,
+        |a| a.last_update.price_status = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_LAST_UPDATE_PLACEHOLDER_OFFSET, |a| {
+        a.last_update.placeholder[0] = 1
+    });
+    assert_offset!(Obligation, OBLIGATION_LENDING_MARKET_OFFSET, |a| a
+        .lending_market =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Obligation, OBLIGATION_OWNER_OFFSET, |a| a.owner =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Obligation, OBLIGATION_DEPOSITS_OFFSET, |a| a.deposits[0]
+        .deposit_reserve =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(
+        Obligation,
+        OBLIGATION_LOWEST_RESERVE_DEPOSIT_LIQUIDATION_LTV_OFFSET,
+        |a| a.lowest_reserve_deposit_liquidation_ltv = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_DEPOSITED_VALUE_SF_OFFSET, |a| a
+        .deposited_value_sf =
+        1);
+    assert_offset!(Obligation, OBLIGATION_BORROWS_OFFSET, |a| a.borrows[0]
+        .borrow_reserve =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(
+        Obligation,
+        OBLIGATION_BORROW_FACTOR_ADJUSTED_DEBT_VALUE_SF_OFFSET,
+        |a| a.borrow_factor_adjusted_debt_value_sf = 1
+    );
+    assert_offset!(
+        Obligation,
+        OBLIGATION_BORROWED_ASSETS_MARKET_VALUE_SF_OFFSET,
+        |a| a.borrowed_assets_market_value_sf = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_ALLOWED_BORROW_VALUE_SF_OFFSET, |a| {
+        a.allowed_borrow_value_sf = 1
+    });
+    assert_offset!(
+        Obligation,
+        OBLIGATION_UNHEALTHY_BORROW_VALUE_SF_OFFSET,
+        |a| a.unhealthy_borrow_value_sf = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_DEPOSITS_ASSET_TIERS_OFFSET, |a| a
+        .deposits_asset_tiers[0] =
+        1);
+    assert_offset!(Obligation, OBLIGATION_BORROWS_ASSET_TIERS_OFFSET, |a| a
+        .borrows_asset_tiers[0] =
+        1);
+    assert_offset!(Obligation, OBLIGATION_ELEVATION_GROUP_OFFSET, |a| a
+        .elevation_group =
+        1);
+    assert_offset!(
+        Obligation,
+        OBLIGATION_NUM_OF_OBSOLETE_RESERVES_OFFSET,
+        |a| a.num_of_obsolete_reserves = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_HAS_DEBT_OFFSET, |a| a.has_debt = 1);
+    assert_offset!(Obligation, OBLIGATION_REFERRER_OFFSET, |a| a.referrer =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Obligation, OBLIGATION_BORROWING_DISABLED_OFFSET, |a| a
+        .borrowing_disabled =
+        1);
+    assert_offset!(
+        Obligation,
+        OBLIGATION_AUTODELEVERAGE_TARGET_LTV_PCT_OFFSET,
+        |a| a.autodeleverage_target_ltv_pct = 1
+    );
+    assert_offset!(
+        Obligation,
+        OBLIGATION_LOWEST_RESERVE_DEPOSIT_MAX_LTV_PCT_OFFSET,
+        |a| a.lowest_reserve_deposit_max_ltv_pct = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_RESERVED_OFFSET, |a| a.reserved[0] =
+        1);
+    assert_offset!(
+        Obligation,
+        OBLIGATION_HIGHEST_BORROW_FACTOR_PCT_OFFSET,
+        |a| a.highest_borrow_factor_pct = 1
+    );
+    assert_offset!(
+        Obligation,
+        OBLIGATION_AUTODELEVERAGE_MARGIN_CALL_STARTED_TIMESTAMP_OFFSET,
+        |a| a.autodeleverage_margin_call_started_timestamp = 1
+    );
+    assert_offset!(Obligation, OBLIGATION_PADDING3_OFFSET, |a| a.padding3[0] =
+        1);
+    assert_offset!(ReferrerState, REFERRER_STATE_SHORT_URL_OFFSET, |a| a
+        .short_url =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(ReferrerState, REFERRER_STATE_OWNER_OFFSET, |a| a.owner =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(
+        ReferrerTokenState,
+        REFERRER_TOKEN_STATE_REFERRER_OFFSET,
+        |a| a.referrer = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(ReferrerTokenState, REFERRER_TOKEN_STATE_MINT_OFFSET, |a| {
+        a.mint = Pubkey::new_from_array([1; 32])
+    });
+    assert_offset!(
+        ReferrerTokenState,
+        REFERRER_TOKEN_STATE_AMOUNT_UNCLAIMED_SF_OFFSET,
+        |a| a.amount_unclaimed_sf = 1
+    );
+    assert_offset!(
+        ReferrerTokenState,
+        REFERRER_TOKEN_STATE_AMOUNT_CUMULATIVE_SF_OFFSET,
+        |a| a.amount_cumulative_sf = 1
+    );
+    assert_offset!(ReferrerTokenState, REFERRER_TOKEN_STATE_BUMP_OFFSET, |a| {
+        a.bump = 1
+    });
+    assert_offset!(
+        ReferrerTokenState,
+        REFERRER_TOKEN_STATE_PADDING_OFFSET,
+        |a| a.padding[0] = 1
+    );
+    assert_offset!(ShortUrl, SHORT_URL_REFERRER_OFFSET, |a| a.referrer =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(ShortUrl, SHORT_URL_SHORT_URL_OFFSET, |a| a.short_url =
+        "x".to_string());
+    assert_offset!(UserMetadata, USER_METADATA_REFERRER_OFFSET, |a| a
+        .referrer =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(UserMetadata, USER_METADATA_BUMP_OFFSET, |a| a.bump = 1);
+    assert_offset!(UserMetadata, USER_METADATA_USER_LOOKUP_TABLE_OFFSET, |a| {
+        a.user_lookup_table = Pubkey::new_from_array([1; 32])
+    });
+    assert_offset!(UserMetadata, USER_METADATA_OWNER_OFFSET, |a| a.owner =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(UserMetadata, USER_METADATA_PADDING1_OFFSET, |a| a
+        .padding1[0] =
+        1);
+    assert_offset!(UserMetadata, USER_METADATA_PADDING2_OFFSET, |a| a
+        .padding2[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_VERSION_OFFSET, |a| a.version = 1);
+    assert_offset!(Reserve, RESERVE_LAST_UPDATE_OFFSET, |a| a
+        .last_update
+        .slot = 1);
+    assert_offset!(Reserve, RESERVE_LAST_UPDATE_SLOT_OFFSET, |a| a
+        .last_update
+        .slot = 1);
+    assert_offset!(Reserve, RESERVE_LAST_UPDATE_STALE_OFFSET, |a| a
+        .last_update
+        .stale =
+        1);
+    assert_offset!(Reserve, RESERVE_LAST_UPDATE_PRICE_STATUS_OFFSET, |a| a
+        .last_update
+        .price_status =
+        1);
+    assert_offset!(Reserve, RESERVE_LAST_UPDATE_PLACEHOLDER_OFFSET, |a| a
+        .last_update
+        .placeholder[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_LENDING_MARKET_OFFSET, |a| a
+        .lending_market =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_FARM_COLLATERAL_OFFSET, |a| a
+        .farm_collateral =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_FARM_DEBT_OFFSET, |a| a.farm_debt =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_OFFSET, |a| a
+        .liquidity
+        .mint_pubkey =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_MINT_PUBKEY_OFFSET, |a| a
+        .liquidity
+        .mint_pubkey =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_SUPPLY_VAULT_OFFSET, |a| a
+        .liquidity
+        .supply_vault =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_FEE_VAULT_OFFSET, |a| a
+        .liquidity
+        .fee_vault =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_AVAILABLE_AMOUNT_OFFSET, |a| a
+        .liquidity
+        .available_amount =
+        1);
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_BORROWED_AMOUNT_SF_OFFSET, |a| {
+        a.liquidity.borrowed_amount_sf = 1
+    });
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_MARKET_PRICE_SF_OFFSET, |a| a
+        .liquidity
+        .market_price_sf =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_MARKET_PRICE_LAST_UPDATED_TS_OFFSET,
+        |a| a.liquidity.market_price_last_updated_ts = 1
+    );
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_MINT_DECIMALS_OFFSET, |a| a
+        .liquidity
+        .mint_decimals =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_DEPOSIT_LIMIT_CROSSED_TIMESTAMP_OFFSET,
+        |a| a.liquidity.deposit_limit_crossed_timestamp = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_BORROW_LIMIT_CROSSED_TIMESTAMP_OFFSET,
+        |a| a.liquidity.borrow_limit_crossed_timestamp = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_OFFSET,
+        |a| a.liquidity.cumulative_borrow_rate_bsf.value[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_VALUE_OFFSET,
+        |a| a.liquidity.cumulative_borrow_rate_bsf.value[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_CUMULATIVE_BORROW_RATE_BSF_PADDING_OFFSET,
+        |a| a.liquidity.cumulative_borrow_rate_bsf.padding[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_ACCUMULATED_PROTOCOL_FEES_SF_OFFSET,
+        |a| a.liquidity.accumulated_protocol_fees_sf = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_ACCUMULATED_REFERRER_FEES_SF_OFFSET,
+        |a| a.liquidity.accumulated_referrer_fees_sf = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_PENDING_REFERRER_FEES_SF_OFFSET,
+        |a| a.liquidity.pending_referrer_fees_sf = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_LIQUIDITY_ABSOLUTE_REFERRAL_RATE_SF_OFFSET,
+        |a| a.liquidity.absolute_referral_rate_sf = 1
+    );
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_TOKEN_PROGRAM_OFFSET, |a| a
+        .liquidity
+        .token_program =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_PADDING2_OFFSET, |a| a
+        .liquidity
+        .padding2[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_LIQUIDITY_PADDING3_OFFSET, |a| a
+        .liquidity
+        .padding3[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_RESERVE_LIQUIDITY_PADDING_OFFSET, |a| a
+        .reserve_liquidity_padding[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_COLLATERAL_OFFSET, |a| a
+        .collateral
+        .mint_pubkey =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_COLLATERAL_MINT_PUBKEY_OFFSET, |a| a
+        .collateral
+        .mint_pubkey =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET, |a| {
+        a.collateral.mint_total_supply = 1
+    });
+    assert_offset!(Reserve, RESERVE_COLLATERAL_SUPPLY_VAULT_OFFSET, |a| a
+        .collateral
+        .supply_vault =
+        Pubkey::new_from_array([1; 32]));
+    assert_offset!(Reserve, RESERVE_COLLATERAL_PADDING1_OFFSET, |a| a
+        .collateral
+        .padding1[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_COLLATERAL_PADDING2_OFFSET, |a| a
+        .collateral
+        .padding2[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_RESERVE_COLLATERAL_PADDING_OFFSET, |a| a
+        .reserve_collateral_padding[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_OFFSET, |a| a.config.status = 1);
+    assert_offset!(Reserve, RESERVE_CONFIG_STATUS_OFFSET, |a| a.config.status =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_ASSET_TIER_OFFSET, |a| a
+        .config
+        .asset_tier =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_HOST_FIXED_INTEREST_RATE_BPS_OFFSET,
+        |a| a.config.host_fixed_interest_rate_bps = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_RESERVED2_OFFSET, |a| a
+        .config
+        .reserved2[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_RESERVED3_OFFSET, |a| a
+        .config
+        .reserved3[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_PROTOCOL_TAKE_RATE_PCT_OFFSET, |a| {
+        a.config.protocol_take_rate_pct = 1
+    });
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_PROTOCOL_LIQUIDATION_FEE_PCT_OFFSET,
+        |a| a.config.protocol_liquidation_fee_pct = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_LOAN_TO_VALUE_PCT_OFFSET, |a| a
+        .config
+        .loan_to_value_pct =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_LIQUIDATION_THRESHOLD_PCT_OFFSET,
+        |a| a.config.liquidation_threshold_pct = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_MIN_LIQUIDATION_BONUS_BPS_OFFSET,
+        |a| a.config.min_liquidation_bonus_bps = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_MAX_LIQUIDATION_BONUS_BPS_OFFSET,
+        |a| a.config.max_liquidation_bonus_bps = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_BAD_DEBT_LIQUIDATION_BONUS_BPS_OFFSET,
+        |a| a.config.bad_debt_liquidation_bonus_bps = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DELEVERAGING_MARGIN_CALL_PERIOD_SECS_OFFSET,
+        |a| a.config.deleveraging_margin_call_period_secs = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DELEVERAGING_THRESHOLD_DECREASE_BPS_PER_DAY_OFFSET,
+        |a| a.config.deleveraging_threshold_decrease_bps_per_day = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_FEES_OFFSET, |a| a
+        .config
+        .fees
+        .borrow_fee_sf =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_FEES_BORROW_FEE_SF_OFFSET, |a| a
+        .config
+        .fees
+        .borrow_fee_sf =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_FEES_FLASH_LOAN_FEE_SF_OFFSET, |a| {
+        a.config.fees.flash_loan_fee_sf = 1
+    });
+    assert_offset!(Reserve, RESERVE_CONFIG_FEES_PADDING_OFFSET, |a| a
+        .config
+        .fees
+        .padding[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_BORROW_RATE_CURVE_OFFSET, |a| a
+        .config
+        .borrow_rate_curve
+        .points[0]
+        .utilization_rate_bps =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_BORROW_RATE_CURVE_POINTS_OFFSET,
+        |a| a.config.borrow_rate_curve.points[0].utilization_rate_bps = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_BORROW_FACTOR_PCT_OFFSET, |a| a
+        .config
+        .borrow_factor_pct =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_DEPOSIT_LIMIT_OFFSET, |a| a
+        .config
+        .deposit_limit =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_BORROW_LIMIT_OFFSET, |a| a
+        .config
+        .borrow_limit =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_TOKEN_INFO_OFFSET, |a| a
+        .config
+        .token_info
+        .name[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_TOKEN_INFO_NAME_OFFSET, |a| a
+        .config
+        .token_info
+        .name[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_OFFSET, |a| a
+        .config
+        .token_info
+        .heuristic
+        .lower =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_LOWER_OFFSET,
+        |a| a.config.token_info.heuristic.lower = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_UPPER_OFFSET,
+        |a| a.config.token_info.heuristic.upper = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_HEURISTIC_EXP_OFFSET,
+        |a| a.config.token_info.heuristic.exp = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_MAX_TWAP_DIVERGENCE_BPS_OFFSET,
+        |a| a.config.token_info.max_twap_divergence_bps = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_MAX_AGE_PRICE_SECONDS_OFFSET,
+        |a| a.config.token_info.max_age_price_seconds = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_MAX_AGE_TWAP_SECONDS_OFFSET,
+        |a| a.config.token_info.max_age_twap_seconds = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_OFFSET,
+        |a| a.config.token_info.scope_configuration.price_feed = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_PRICE_FEED_OFFSET,
+        |a| a.config.token_info.scope_configuration.price_feed = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_PRICE_CHAIN_OFFSET,
+        |a| a.config.token_info.scope_configuration.price_chain[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SCOPE_CONFIGURATION_TWAP_CHAIN_OFFSET,
+        |a| a.config.token_info.scope_configuration.twap_chain[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_OFFSET,
+        |a| a
+            .config
+            .token_info
+            .switchboard_configuration
+            .price_aggregator = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_PRICE_AGGREGATOR_OFFSET,
+        |a| a
+            .config
+            .token_info
+            .switchboard_configuration
+            .price_aggregator = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_SWITCHBOARD_CONFIGURATION_TWAP_AGGREGATOR_OFFSET,
+        |a| a
+            .config
+            .token_info
+            .switchboard_configuration
+            .twap_aggregator = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_PYTH_CONFIGURATION_OFFSET,
+        |a| a.config.token_info.pyth_configuration.price = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_PYTH_CONFIGURATION_PRICE_OFFSET,
+        |a| a.config.token_info.pyth_configuration.price = Pubkey::new_from_array([1; 32])
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_TOKEN_INFO_BLOCK_PRICE_USAGE_OFFSET,
+        |a| a.config.token_info.block_price_usage = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_TOKEN_INFO_RESERVED_OFFSET, |a| a
+        .config
+        .token_info
+        .reserved[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_TOKEN_INFO_PADDING_OFFSET, |a| a
+        .config
+        .token_info
+        .padding[0] =
+        1);
+    assert_offset!(Reserve, RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_OFFSET, |a| {
+        a.config.deposit_withdrawal_cap.config_capacity = 1
+    });
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CONFIG_CAPACITY_OFFSET,
+        |a| a.config.deposit_withdrawal_cap.config_capacity = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CURRENT_TOTAL_OFFSET,
+        |a| a.config.deposit_withdrawal_cap.current_total = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_LAST_INTERVAL_START_TIMESTAMP_OFFSET,
+        |a| a
+            .config
+            .deposit_withdrawal_cap
+            .last_interval_start_timestamp = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEPOSIT_WITHDRAWAL_CAP_CONFIG_INTERVAL_LENGTH_SECONDS_OFFSET,
+        |a| a
+            .config
+            .deposit_withdrawal_cap
+            .config_interval_length_seconds = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_OFFSET, |a| a
+        .config
+        .debt_withdrawal_cap
+        .config_capacity =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CONFIG_CAPACITY_OFFSET,
+        |a| a.config.debt_withdrawal_cap.config_capacity = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CURRENT_TOTAL_OFFSET,
+        |a| a.config.debt_withdrawal_cap.current_total = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_LAST_INTERVAL_START_TIMESTAMP_OFFSET,
+        |a| a.config.debt_withdrawal_cap.last_interval_start_timestamp = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DEBT_WITHDRAWAL_CAP_CONFIG_INTERVAL_LENGTH_SECONDS_OFFSET,
+        |a| a.config.debt_withdrawal_cap.config_interval_length_seconds = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_ELEVATION_GROUPS_OFFSET, |a| a
+        .config
+        .elevation_groups[0] =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DISABLE_USAGE_AS_COLL_OUTSIDE_EMODE_OFFSET,
+        |a| a.config.disable_usage_as_coll_outside_emode = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_UTILIZATION_LIMIT_BLOCK_BORROWING_ABOVE_PCT_OFFSET,
+        |a| a.config.utilization_limit_block_borrowing_above_pct = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_AUTODELEVERAGE_ENABLED_OFFSET, |a| {
+        a.config.autodeleverage_enabled = 1
+    });
+    assert_offset!(Reserve, RESERVE_CONFIG_RESERVED1_OFFSET, |a| a
+        .config
+        .reserved1[0] =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_BORROW_LIMIT_OUTSIDE_ELEVATION_GROUP_OFFSET,
+        |a| a.config.borrow_limit_outside_elevation_group = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_BORROW_LIMIT_AGAINST_THIS_COLLATERAL_IN_ELEVATION_GROUP_OFFSET,
+        |a| a
+            .config
+            .borrow_limit_against_this_collateral_in_elevation_group[0] = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_CONFIG_DELEVERAGING_BONUS_INCREASE_BPS_PER_DAY_OFFSET,
+        |a| a.config.deleveraging_bonus_increase_bps_per_day = 1
+    );
+    assert_offset!(Reserve, RESERVE_CONFIG_PADDING_OFFSET, |a| a
+        .config_padding[0] =
+        1);
+    assert_offset!(
+        Reserve,
+        RESERVE_BORROWED_AMOUNT_OUTSIDE_ELEVATION_GROUP_OFFSET,
+        |a| a.borrowed_amount_outside_elevation_group = 1
+    );
+    assert_offset!(
+        Reserve,
+        RESERVE_BORROWED_AMOUNTS_AGAINST_THIS_RESERVE_IN_ELEVATION_GROUPS_OFFSET,
+        |a| a.borrowed_amounts_against_this_reserve_in_elevation_groups[0] = 1
+    );
+    assert_offset!(Reserve, RESERVE_PADDING_OFFSET, |a| a.padding[0] = 1);
+}
+
+#[test]
+fn filters_select_matching_accounts() {
+    let lending_market = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut obligation: Obligation = zeroed();
+    obligation.lending_market = lending_market;
+    obligation.owner = owner;
+    let data = ObligationAccount(obligation).try_to_vec().unwrap();
+    let matches = |filters: Vec<AccountFilter>| filters.iter().all(|filter| filter.matches(&data));
+
+    assert!(matches(filters::obligations_in_market(&lending_market)));
+    assert!(matches(filters::obligations_by_owner(&owner)));
+    assert!(matches(filters::obligations_in_market_by_owner(
+        &lending_market,
+        &owner
+    )));
+    assert!(!matches(filters::obligations_by_owner(&lending_market)));
+    assert!(!matches(filters::reserves_in_market(&lending_market)));
+
+    let mint = Pubkey::new_unique();
+    let mut reserve: Reserve = zeroed();
+    reserve.lending_market = lending_market;
+    reserve.liquidity.mint_pubkey = mint;
+    let data = ReserveAccount(reserve).try_to_vec().unwrap();
+    assert!(filters::reserve_in_market_by_mint(&lending_market, &mint)
+        .iter()
+        .all(|filter| filter.matches(&data)));
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "oracle")]
+use borsh::BorshDeserialize;
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::oracle::*;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+fn fixture(name: &str) -> Vec<u8> {
+    std::fs::read(format!(
+        "{}/tests/fixtures/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+    .unwrap()
+}
+
+#[test]
+fn legacy_pyth_price_account() {
+    let data = fixture("pyth_price.bin");
+    let PythPrice { price, ema } = read_pyth_price(&data).unwrap();
+    assert_eq!(
+        price,
+        OraclePrice {
+            price: 15_012_345_678,
+            exponent: -8,
+            timestamp: 1_700_000_000,
+            confidence: 7_512_345,
+        }
+    );
+    assert_eq!(
+        ema,
+        OraclePrice {
+            price: 14_998_000_000,
+            exponent: -8,
+            timestamp: 1_700_000_000,
+            confidence: 9_100_000,
+        }
+    );
+    assert_eq!(price.validate_confidence(), Ok(()));
+    assert_eq!(
+        price.to_fraction(),
+        Some(Fraction::from_decimal(15_012_345_678, 8).unwrap())
+    );
+
+    // Aggregate status `Unknown` instead of `Trading`.
+    let mut halted = data.clone();
+    halted[224] = 0;
+    assert_eq!(
+        read_pyth_price(&halted),
+        Err(KaminoLendingError::PriceNotValid)
+    );
+    let mut wrong_magic = data.clone();
+    wrong_magic[0] ^= 1;
+    assert_eq!(
+        read_pyth_price(&wrong_magic),
+        Err(KaminoLendingError::InvalidPythPriceAccount)
+    );
+    assert_eq!(
+        read_pyth_price(&data[..239]),
+        Err(KaminoLendingError::InvalidPythPriceAccount)
+    );
+}
+
+#[test]
+fn pyth_price_update_v2_account() {
+    let data = fixture("pyth_price_update_v2.bin");
+    let PythPrice { price, ema } = read_pyth_price(&data).unwrap();
+    assert_eq!(
+        price,
+        OraclePrice {
+            price: 15_012_345_678,
+            exponent: -8,
+            timestamp: 1_700_000_010,
+            confidence: 7_512_345,
+        }
+    );
+    assert_eq!(
+        ema,
+        OraclePrice {
+            price: 14_998_000_000,
+            exponent: -8,
+            timestamp: 1_700_000_010,
+            confidence: 9_100_000,
+        }
+    );
+}
+
+#[test]
+fn partially_verified_price_update_is_rejected() {
+    let data = fixture("pyth_price_update_v2.bin");
+    // `VerificationLevel::Partial { num_signatures: 3 }` shifts the message by one byte.
+    let mut partial = data[..40].to_vec();
+    partial.extend([0, 3]);
+    partial.extend(&data[41..data.len() - 1]);
+    assert_eq!(partial.len(), data.len());
+    assert_eq!(
+        read_pyth_price(&partial),
+        Err(KaminoLendingError::PriceNotValid)
+    );
+
+    let mut unknown_level = data.clone();
+    unknown_level[40] = 2;
+    assert_eq!(
+        read_pyth_price(&unknown_level),
+        Err(KaminoLendingError::InvalidPythPriceAccount)
+    );
+    assert_eq!(
+        read_pyth_price(&data[..100]),
+        Err(KaminoLendingError::InvalidPythPriceAccount)
+    );
+}
+
+#[test]
+fn switchboard_aggregator_account() {
+    let data = fixture("switchboard_aggregator.bin");
+    // 150.123456789 with a standard deviation of 0.0421.
+    assert_eq!(
+        read_switchboard_price(&data),
+        Ok(OraclePrice {
+            price: 150_123_456_789,
+            exponent: -9,
+            timestamp: 1_700_000_005,
+            confidence: 42_100_000,
+        })
+    );
+
+    // A 24-digit mantissa loses its last five digits to fit in an i64; the confidence is rounded
+    // up to the new exponent.
+    let mut precise = data.clone();
+    precise[366..382].copy_from_slice(&150_123_456_789_012_345_678_901i128.to_le_bytes());
+    precise[382..386].copy_from_slice(&21u32.to_le_bytes());
+    precise[386..402].copy_from_slice(&123_456i128.to_le_bytes());
+    precise[402..406].copy_from_slice(&21u32.to_le_bytes());
+    let price = read_switchboard_price(&precise).unwrap();
+    assert_eq!(
+        (price.price, price.exponent),
+        (1_501_234_567_890_123_456, -16)
+    );
+    assert_eq!(price.confidence, 2);
+
+    let mut wrong_discm = data.clone();
+    wrong_discm[0] ^= 1;
+    assert_eq!(
+        read_switchboard_price(&wrong_discm),
+        Err(KaminoLendingError::InvalidSwitchboardAccount)
+    );
+    assert_eq!(
+        read_switchboard_price(&data[..400]),
+        Err(KaminoLendingError::InvalidSwitchboardAccount)
+    );
+}
+
+#[test]
+fn most_recent_price_prefers_the_latest_oracle() {
+    let mut token_info = TokenInfo::deserialize(&mut [0u8; 1_000].as_slice()).unwrap();
+    token_info.pyth_configuration.price = Pubkey::new_unique();
+    token_info.switchboard_configuration.price_aggregator = Pubkey::new_unique();
+    let (legacy, update, switchboard) = (
+        fixture("pyth_price.bin"),
+        fixture("pyth_price_update_v2.bin"),
+        fixture("switchboard_aggregator.bin"),
+    );
+
+    let accounts = OracleAccounts {
+        pyth_price: Some(&update),
+        switchboard_price: Some(&switchboard),
+        ..OracleAccounts::default()
+    };
+    let selected = token_info.most_recent_price(&accounts).unwrap();
+    assert_eq!(selected.source, OracleSource::Pyth);
+    assert_eq!(selected.price.price.timestamp, 1_700_000_010);
+
+    let accounts = OracleAccounts {
+        pyth_price: Some(&legacy),
+        ..accounts
+    };
+    let selected = token_info.most_recent_price(&accounts).unwrap();
+    assert_eq!(selected.source, OracleSource::Switchboard);
+    assert_eq!(selected.price.price.timestamp, 1_700_000_005);
+    assert_eq!(selected.price.twap, None);
+}
@@ -0,0 +1,182 @@
+use kamino_lending_interface::*;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+mod common;
+
+use common::{lending_market, obligation, pubkey, USDC_RESERVE};
+
+fn reserve() -> Reserve {
+    let mut reserve = common::reserve();
+    reserve.liquidity.mint_decimals = 6;
+    reserve.config.fees.borrow_fee_sf = Fraction::from_str("0.001").unwrap().to_bits() as u64;
+    reserve
+}
+
+fn snapshot(reserve: &Reserve) -> MarketSnapshot {
+    MarketSnapshot::from_accounts(
+        reserve.lending_market,
+        lending_market(),
+        [(pubkey(USDC_RESERVE), reserve.clone())],
+        [],
+    )
+    .unwrap()
+}
+
+#[test]
+fn borrow_in_whole_tokens_with_fee() {
+    let reserve = reserve();
+    let obligation_pubkey = Pubkey::new_unique();
+    let keys = BorrowObligationLiquidityKeys::resolve(
+        &reserve,
+        &Pubkey::from_str(USDC_RESERVE).unwrap(),
+        &obligation(),
+        &obligation_pubkey,
+        &Pubkey::new_unique(),
+    );
+    let ix = borrow_obligation_liquidity_ix(
+        keys,
+        BorrowObligationLiquidityIxArgs {
+            liquidity_amount: 1_250_000_000,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        preview_instruction(&ix, Some(&snapshot(&reserve))).unwrap(),
+        format!(
+            "Borrow 1,250.00 USDC from reserve {USDC_RESERVE} against obligation {obligation_pubkey} (fee 0.1%)"
+        )
+    );
+    assert_eq!(
+        preview_instruction(&ix, None).unwrap(),
+        format!(
+            "Borrow 1,250,000,000 base units from reserve {USDC_RESERVE} against obligation {obligation_pubkey}"
+        )
+    );
+}
+
+#[test]
+fn v2_and_collateral_amounts() {
+    let reserve = reserve();
+    let snapshot = snapshot(&reserve);
+    let reserve_pubkey = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let obligation_pubkey = Pubkey::new_unique();
+    let keys = RepayObligationLiquidityV2Keys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation(),
+        &obligation_pubkey,
+        &Pubkey::new_unique(),
+    );
+    let accounts: Vec<Pubkey> =
+        <[AccountMeta; REPAY_OBLIGATION_LIQUIDITY_V2_IX_ACCOUNTS_LEN]>::from(keys)
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect();
+    let repay_all =
+        KaminoLendingProgramIx::RepayObligationLiquidityV2(RepayObligationLiquidityV2IxArgs {
+            liquidity_amount: u64::MAX,
+        });
+    assert_eq!(
+        preview(&repay_all, &accounts, Some(&snapshot)),
+        format!("Repay all USDC to reserve {reserve_pubkey} for obligation {obligation_pubkey}")
+    );
+
+    let keys = DepositObligationCollateralKeys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation(),
+        &obligation_pubkey,
+        &Pubkey::new_unique(),
+    );
+    let ix = deposit_obligation_collateral_ix(
+        keys,
+        DepositObligationCollateralIxArgs {
+            collateral_amount: 1_500_000,
+        },
+    )
+    .unwrap();
+    let text = preview_instruction(&ix, Some(&snapshot)).unwrap();
+    assert!(
+        text.starts_with("Deposit 1.50 USDC cTokens (about "),
+        "{text}"
+    );
+    assert!(
+        text.ends_with(&format!(
+            " USDC) of reserve {reserve_pubkey} as collateral into obligation {obligation_pubkey}"
+        )),
+        "{text}"
+    );
+}
+
+#[test]
+fn admin_updates() {
+    let reserve = reserve();
+    let snapshot = snapshot(&reserve);
+    let reserve_pubkey = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let keys = UpdateReserveConfigKeys {
+        lending_market_owner: Pubkey::new_unique(),
+        lending_market: reserve.lending_market,
+        reserve: reserve_pubkey,
+    };
+    let update = |mode: u64, value: Vec<u8>| {
+        let ix = update_reserve_config_ix(
+            keys,
+            UpdateReserveConfigIxArgs {
+                mode,
+                value,
+                skip_validation: false,
+            },
+        )
+        .unwrap();
+        preview_instruction(&ix, Some(&snapshot)).unwrap()
+    };
+
+    let fee = Fraction::from_str("0.0025").unwrap().to_bits() as u64;
+    assert_eq!(
+        update(
+            UpdateConfigMode::UpdateFeesBorrowFee as u64,
+            fee.to_le_bytes().to_vec()
+        ),
+        format!("Set the borrow fee of reserve {reserve_pubkey} (USDC) to 0.25%")
+    );
+    assert_eq!(
+        update(UpdateConfigMode::UpdateLoanToValuePct as u64, vec![75]),
+        format!("Set the LTV of reserve {reserve_pubkey} (USDC) to 75%")
+    );
+    assert_eq!(
+        update(
+            UpdateConfigMode::UpdateDepositLimit as u64,
+            2_000_000_000_000u64.to_le_bytes().to_vec()
+        ),
+        format!("Set the deposit limit of reserve {reserve_pubkey} (USDC) to 2,000,000.00 USDC")
+    );
+    assert_eq!(
+        update(UpdateConfigMode::UpdateBorrowRateCurve as u64, vec![0; 88]),
+        format!("Update the borrow rate curve of reserve {reserve_pubkey} (USDC)")
+    );
+    assert_eq!(
+        update(200, vec![]),
+        format!("Update the config of reserve {reserve_pubkey} (USDC) (unknown mode 200)")
+    );
+
+    let mut value = [0; 72];
+    value[0] = 1;
+    let update_market = KaminoLendingProgramIx::UpdateLendingMarket(UpdateLendingMarketIxArgs {
+        mode: UpdateLendingMarketMode::UpdateEmergencyMode as u64,
+        value,
+    });
+    assert_eq!(
+        preview(
+            &update_market,
+            &[Pubkey::default(), reserve.lending_market],
+            None
+        ),
+        format!(
+            "Set the emergency mode of lending market {} to enabled",
+            reserve.lending_market
+        )
+    );
+}
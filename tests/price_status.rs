@@ -0,0 +1,173 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+
+const NOW: i64 = 1_700_000_000;
+
+fn price(value: Fraction, timestamp: i64) -> TimestampedPrice {
+    TimestampedPrice {
+        price: value,
+        timestamp: timestamp as u64,
+    }
+}
+
+/// A token accepting prices between 0.98 and 1.02 that are at most a minute old, with a TWAP
+/// at most two minutes old and within 1% of the price.
+fn token_info() -> TokenInfo {
+    let mut token_info = TokenInfo::deserialize(&mut [0u8; 1_000].as_slice()).unwrap();
+    token_info.heuristic = PriceHeuristic {
+        lower: 98,
+        upper: 102,
+        exp: 2,
+    };
+    token_info.max_age_price_seconds = 60;
+    token_info.max_age_twap_seconds = 120;
+    token_info.max_twap_divergence_bps = 100;
+    token_info
+}
+
+#[test]
+fn heuristic_bounds_are_inclusive_and_optional() {
+    let heuristic = token_info().heuristic;
+    assert_eq!(heuristic.check(Fraction::from_percent(98u8)), Ok(()));
+    assert_eq!(heuristic.check(Fraction::from_percent(102u8)), Ok(()));
+    assert_eq!(
+        heuristic.check(Fraction::from_bps(9_799u16)),
+        Err(KaminoLendingError::PriceIsLowerThanHeuristic)
+    );
+    assert_eq!(
+        heuristic.check(Fraction::from_bps(10_201u16)),
+        Err(KaminoLendingError::PriceIsBiggerThanHeuristic)
+    );
+
+    let lower_only = PriceHeuristic {
+        upper: 0,
+        ..heuristic
+    };
+    assert_eq!(lower_only.check(Fraction::from_num(1_000_000)), Ok(()));
+    let disabled = PriceHeuristic {
+        lower: 0,
+        upper: 0,
+        exp: 2,
+    };
+    assert!(!disabled.is_enabled());
+    assert_eq!(disabled.check(Fraction::ZERO), Ok(()));
+
+    let overflowing = PriceHeuristic {
+        exp: 40,
+        ..heuristic
+    };
+    assert_eq!(
+        overflowing.check(Fraction::ONE),
+        Err(KaminoLendingError::InvalidOracleConfig)
+    );
+}
+
+#[test]
+fn twap_divergence_is_measured_in_bps_of_the_twap() {
+    let twap = Fraction::from_num(200);
+    // 625 bps of 200 is 12.5, both bounds included.
+    assert_eq!(
+        check_twap_divergence(Fraction::from_ratio(425, 2), twap, 625),
+        Ok(())
+    );
+    assert_eq!(
+        check_twap_divergence(Fraction::from_ratio(375, 2), twap, 625),
+        Ok(())
+    );
+    assert_eq!(
+        check_twap_divergence(Fraction::from_ratio(2_126, 10), twap, 625),
+        Err(KaminoLendingError::PriceTooDivergentFromTwap)
+    );
+    assert_eq!(
+        check_twap_divergence(Fraction::from_ratio(1_874, 10), twap, 625),
+        Err(KaminoLendingError::PriceTooDivergentFromTwap)
+    );
+    assert_eq!(check_twap_divergence(twap, twap, 0), Ok(()));
+    assert_eq!(
+        check_twap_divergence(Fraction::from_num(201), twap, 0),
+        Err(KaminoLendingError::PriceTooDivergentFromTwap)
+    );
+    assert_eq!(
+        check_twap_divergence(Fraction::MAX, Fraction::MAX, 1),
+        Err(KaminoLendingError::MathOverflow)
+    );
+}
+
+#[test]
+fn validate_price_sets_a_flag_per_passing_check() {
+    let token_info = token_info();
+    let fresh = TimestampedPriceWithTwap {
+        price: price(Fraction::ONE, NOW - 60),
+        twap: Some(price(Fraction::from_bps(10_050u16), NOW - 120)),
+    };
+    let validation = token_info.validate_price(&fresh, NOW);
+    assert_eq!(validation.status, PriceStatusFlags::ALL_CHECKS);
+    assert!(validation.is_usable());
+    assert_eq!(validation.failures().count(), 0);
+
+    let stale = TimestampedPriceWithTwap {
+        price: price(Fraction::from_percent(97u8), NOW - 61),
+        twap: Some(price(Fraction::ONE, NOW - 121)),
+    };
+    let validation = token_info.validate_price(&stale, NOW);
+    assert_eq!(
+        validation.failures().collect::<Vec<_>>(),
+        vec![
+            (PriceCheck::PriceAge, KaminoLendingError::PriceTooOld),
+            (PriceCheck::TwapAge, KaminoLendingError::PriceTooOld),
+            (
+                PriceCheck::TwapDivergence,
+                KaminoLendingError::PriceTooDivergentFromTwap
+            ),
+            (
+                PriceCheck::Heuristic,
+                KaminoLendingError::PriceIsLowerThanHeuristic
+            ),
+        ]
+    );
+    assert_eq!(
+        validation.status,
+        PriceStatusFlags(
+            PriceStatusFlags::PRICE_LOADED.0 | PriceStatusFlags::PRICE_USAGE_ALLOWED.0
+        )
+    );
+    assert!(!validation.is_usable_for_liquidation());
+
+    let future = TimestampedPriceWithTwap {
+        price: price(Fraction::ONE, NOW + 1),
+        twap: None,
+    };
+    let validation = token_info.validate_price(&future, NOW);
+    assert_eq!(
+        validation.price_age,
+        Err(KaminoLendingError::LastTimestampGreaterThanCurrent)
+    );
+    assert_eq!(
+        validation.twap_age,
+        Err(KaminoLendingError::InvalidTwapPrice)
+    );
+}
+
+#[test]
+fn validate_price_skips_twap_checks_when_disabled() {
+    let mut token_info = token_info();
+    token_info.max_twap_divergence_bps = 0;
+    token_info.block_price_usage = 1;
+    let price = TimestampedPriceWithTwap {
+        price: price(Fraction::ONE, NOW),
+        twap: None,
+    };
+    let validation = token_info.validate_price(&price, NOW);
+    assert_eq!(validation.twap_age, Ok(()));
+    assert_eq!(validation.twap_divergence, Ok(()));
+    assert_eq!(
+        validation.usage_allowed,
+        Err(KaminoLendingError::PriceNotValid)
+    );
+    assert_eq!(
+        validation.status.missing(PriceStatusFlags::ALL_CHECKS),
+        PriceStatusFlags::PRICE_USAGE_ALLOWED
+    );
+    assert!(!validation.is_usable_for_liquidation());
+}
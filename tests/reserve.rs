@@ -0,0 +1,68 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+
+/// A zeroed reserve holding `available_amount` liquidity and `borrowed_amount` debt against
+/// `mint_total_supply` cTokens.
+fn reserve_with(available_amount: u64, borrowed_amount: u64, mint_total_supply: u64) -> Reserve {
+    let mut reserve = Reserve::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    reserve.liquidity.available_amount = available_amount;
+    reserve.liquidity.borrowed_amount_sf = Fraction::from_num(borrowed_amount).to_bits();
+    reserve.collateral.mint_total_supply = mint_total_supply;
+    reserve
+}
+
+#[test]
+fn collateral_exchange_round_trips() {
+    // 1.5 liquidity per cToken.
+    let reserve = reserve_with(1_200_000, 300_000, 1_000_000);
+    assert_eq!(
+        reserve.collateral_to_liquidity(1_000),
+        Fraction::from_num(1_500)
+    );
+    assert_eq!(
+        reserve.liquidity_to_collateral(Fraction::from_num(1_500)),
+        1_000
+    );
+    assert_eq!(reserve.liquidity_to_collateral(Fraction::from_num(2)), 1);
+    for collateral_amount in [0, 1, 7, 1_000, 123_456_789, u64::MAX / 4] {
+        let liquidity = reserve.collateral_to_liquidity(collateral_amount);
+        assert_eq!(
+            reserve.liquidity_to_collateral(liquidity),
+            collateral_amount
+        );
+    }
+
+    // An exchange rate without an exact binary representation rounds down by at most one.
+    let reserve = reserve_with(1_000_000, 0, 3_000_000);
+    for collateral_amount in [1, 2, 3, 1_000, 999_999_999] {
+        let liquidity = reserve.collateral_to_liquidity(collateral_amount);
+        let collateral = reserve.liquidity_to_collateral(liquidity);
+        assert!(collateral <= collateral_amount && collateral + 1 >= collateral_amount);
+    }
+}
+
+#[test]
+fn empty_reserve_exchanges_one_to_one() {
+    let reserve = reserve_with(0, 0, 0);
+    assert_eq!(reserve.collateral_to_liquidity(42), Fraction::from_num(42));
+    assert_eq!(
+        reserve.liquidity_to_collateral(Fraction::from_ratio(85, 2)),
+        42
+    );
+}
+
+#[test]
+fn oversized_fee_fields_saturate() {
+    let mut reserve = reserve_with(1_000, 0, 1_000);
+    reserve.liquidity.borrowed_amount_sf = u128::MAX;
+    reserve.liquidity.accumulated_protocol_fees_sf = u128::MAX;
+    reserve.liquidity.accumulated_referrer_fees_sf = u128::MAX;
+    assert_eq!(reserve.liquidity.accumulated_fees(), Fraction::MAX);
+    assert_eq!(reserve.total_supply(), Fraction::ZERO);
+    assert_eq!(reserve.utilization_rate(), Fraction::ZERO);
+
+    reserve.liquidity.accumulated_protocol_fees_sf = 0;
+    reserve.liquidity.accumulated_referrer_fees_sf = 0;
+    assert_eq!(reserve.total_supply(), Fraction::MAX);
+}
@@ -0,0 +1,203 @@
+use kamino_lending_interface::*;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+mod common;
+
+use common::{obligation, reserve, USDC_RESERVE};
+
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+#[test]
+fn borrow_v2_keys_come_from_reserve_and_obligation() {
+    let mut reserve = reserve();
+    let mut obligation = obligation();
+    let reserve_pubkey = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let obligation_pubkey = Pubkey::new_unique();
+    let user_ata = Pubkey::new_unique();
+    let farm = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+    reserve.farm_debt = farm;
+    reserve.liquidity.token_program = Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap();
+    obligation.referrer = referrer;
+
+    let keys = BorrowObligationLiquidityV2Keys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation,
+        &obligation_pubkey,
+        &user_ata,
+    );
+    assert_eq!(keys.borrow_accounts_owner, obligation.owner);
+    assert_eq!(keys.borrow_accounts_obligation, obligation_pubkey);
+    assert_eq!(keys.borrow_accounts_lending_market, reserve.lending_market);
+    assert_eq!(
+        keys.borrow_accounts_lending_market_authority,
+        Pubkey::find_program_address(&[b"lma", reserve.lending_market.as_ref()], &ID).0
+    );
+    assert_eq!(keys.borrow_accounts_borrow_reserve, reserve_pubkey);
+    assert_eq!(
+        keys.borrow_accounts_borrow_reserve_liquidity_mint,
+        reserve.liquidity.mint_pubkey
+    );
+    assert_eq!(
+        keys.borrow_accounts_reserve_source_liquidity,
+        reserve.liquidity.supply_vault
+    );
+    assert_eq!(
+        keys.borrow_accounts_borrow_reserve_liquidity_fee_receiver,
+        reserve.liquidity.fee_vault
+    );
+    assert_eq!(keys.borrow_accounts_user_destination_liquidity, user_ata);
+    assert_eq!(
+        keys.borrow_accounts_referrer_token_state,
+        find_referrer_token_state_address(&referrer, &reserve_pubkey).0
+    );
+    assert_eq!(
+        keys.borrow_accounts_token_program,
+        reserve.liquidity.token_program
+    );
+    assert_eq!(keys.farms_accounts_reserve_farm_state, farm);
+    assert_eq!(
+        keys.farms_accounts_obligation_farm_user_state,
+        find_obligation_farm_user_state_address(&farm, &obligation_pubkey).0
+    );
+    assert_eq!(keys.farms_program, FARMS_PROGRAM_ID);
+}
+
+#[test]
+fn missing_optional_accounts_are_the_program_id() {
+    let mut reserve = reserve();
+    let mut obligation = obligation();
+    reserve.farm_collateral = Pubkey::default();
+    reserve.farm_debt = Pubkey::default();
+    reserve.config.token_info.pyth_configuration.price = Pubkey::default();
+    obligation.referrer = Pubkey::default();
+    let reserve_pubkey = Pubkey::new_unique();
+    let obligation_pubkey = Pubkey::new_unique();
+
+    let borrow = BorrowObligationLiquidityV2Keys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation,
+        &obligation_pubkey,
+        &Pubkey::new_unique(),
+    );
+    assert_eq!(borrow.borrow_accounts_referrer_token_state, ID);
+    assert_eq!(borrow.farms_accounts_obligation_farm_user_state, ID);
+    assert_eq!(borrow.farms_accounts_reserve_farm_state, ID);
+
+    let deposit = DepositReserveLiquidityAndObligationCollateralV2Keys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation,
+        &obligation_pubkey,
+        &Pubkey::new_unique(),
+    );
+    assert_eq!(
+        deposit.deposit_accounts_placeholder_user_destination_collateral,
+        ID
+    );
+    assert_eq!(
+        deposit.deposit_accounts_collateral_token_program,
+        TOKEN_PROGRAM_ID
+    );
+    assert_eq!(deposit.farms_accounts_reserve_farm_state, ID);
+
+    assert_eq!(
+        RefreshReserveKeys::resolve(&reserve, &reserve_pubkey).pyth_oracle,
+        ID
+    );
+    assert!(InitObligationFarmsForReserveKeys::resolve(
+        &reserve,
+        &reserve_pubkey,
+        &obligation,
+        &obligation_pubkey,
+        ReserveFarmKind::Collateral,
+    )
+    .is_none());
+}
+
+#[test]
+fn liquidation_keys_use_each_reserve() {
+    let repay_reserve = reserve();
+    let mut withdraw_reserve = reserve();
+    withdraw_reserve.liquidity.mint_pubkey = Pubkey::new_unique();
+    withdraw_reserve.liquidity.supply_vault = Pubkey::new_unique();
+    withdraw_reserve.collateral.supply_vault = Pubkey::new_unique();
+    withdraw_reserve.farm_collateral = Pubkey::new_unique();
+    let (repay_pubkey, withdraw_pubkey, obligation_pubkey) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let liquidator = LiquidatorTokenAccounts {
+        liquidator: Pubkey::new_unique(),
+        source_liquidity: Pubkey::new_unique(),
+        destination_collateral: Pubkey::new_unique(),
+        destination_liquidity: Pubkey::new_unique(),
+    };
+    let keys = LiquidateObligationAndRedeemReserveCollateralV2Keys::resolve(
+        &repay_reserve,
+        &repay_pubkey,
+        &withdraw_reserve,
+        &withdraw_pubkey,
+        &obligation_pubkey,
+        &liquidator,
+    );
+    assert_eq!(
+        keys.liquidation_accounts_repay_reserve_liquidity_supply,
+        repay_reserve.liquidity.supply_vault
+    );
+    assert_eq!(
+        keys.liquidation_accounts_withdraw_reserve_liquidity_supply,
+        withdraw_reserve.liquidity.supply_vault
+    );
+    assert_eq!(
+        keys.liquidation_accounts_withdraw_reserve_collateral_supply,
+        withdraw_reserve.collateral.supply_vault
+    );
+    assert_eq!(
+        keys.collateral_farms_accounts_reserve_farm_state,
+        withdraw_reserve.farm_collateral
+    );
+    assert_eq!(
+        keys.liquidation_accounts_user_source_liquidity,
+        liquidator.source_liquidity
+    );
+    let metas: [AccountMeta;
+        LIQUIDATE_OBLIGATION_AND_REDEEM_RESERVE_COLLATERAL_V2_IX_ACCOUNTS_LEN] = keys.into();
+    assert!(metas[0].is_signer);
+}
+
+#[test]
+fn init_obligation_derives_obligation_and_metadata() {
+    let owner = Pubkey::new_unique();
+    let lending_market = Pubkey::new_unique();
+    let keys = InitObligationKeys::resolve(
+        &owner,
+        &lending_market,
+        &InitObligationArgs { tag: 0, id: 0 },
+        &Pubkey::default(),
+        &Pubkey::default(),
+    );
+    let default = Pubkey::default();
+    let seeds: [&[u8]; 6] = [
+        &[0],
+        &[0],
+        owner.as_ref(),
+        lending_market.as_ref(),
+        default.as_ref(),
+        default.as_ref(),
+    ];
+    assert_eq!(keys.obligation, Pubkey::find_program_address(&seeds, &ID).0);
+    assert_eq!(
+        keys.owner_user_metadata,
+        Pubkey::find_program_address(&[b"user_meta", owner.as_ref()], &ID).0
+    );
+    assert_eq!(
+        InitUserMetadataKeys::resolve(&owner, None).referrer_user_metadata,
+        ID
+    );
+}
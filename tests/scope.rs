@@ -0,0 +1,132 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+const S: u16 = SCOPE_CHAIN_SENTINEL;
+
+/// An `OraclePrices` account holding 1.25 at index 3, 150.5 at index 7, a zero price at index 8
+/// and 149 at index 9, updated at the given unix timestamps.
+fn prices() -> OraclePrices {
+    let mut prices = OraclePrices::deserialize(&mut vec![0u8; 40_000].as_slice()).unwrap();
+    for (index, value, exp, unix_timestamp) in [
+        (3, 125, 2, 1_000),
+        (7, 1_505, 1, 990),
+        (8, 0, 0, 1_000),
+        (9, 149, 0, 900),
+    ] {
+        prices.prices[index].price = ScopePrice { value, exp };
+        prices.prices[index].unix_timestamp = unix_timestamp;
+    }
+    prices
+}
+
+fn token_info(price_chain: [u16; 4], twap_chain: [u16; 4]) -> TokenInfo {
+    let mut token_info = TokenInfo::deserialize(&mut [0u8; 1_000].as_slice()).unwrap();
+    token_info.scope_configuration = ScopeConfiguration {
+        price_feed: Pubkey::new_unique(),
+        price_chain,
+        twap_chain,
+    };
+    token_info.max_age_price_seconds = 60;
+    token_info.max_age_twap_seconds = 120;
+    token_info
+}
+
+#[test]
+fn chain_price_multiplies_links_and_keeps_the_oldest_timestamp() {
+    let prices = prices();
+    assert_eq!(
+        prices.chain_price(&[3, S, S, S]),
+        Ok(TimestampedPrice {
+            price: Fraction::from_ratio(5, 4),
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        prices.chain_price(&[3, 7, S, S]),
+        Ok(TimestampedPrice {
+            price: Fraction::from_ratio(1_505, 8),
+            timestamp: 990,
+        })
+    );
+    // Links after the first sentinel are ignored.
+    assert_eq!(
+        prices.chain_price(&[3, S, 8, 600]),
+        prices.chain_price(&[3, S, S, S])
+    );
+}
+
+#[test]
+fn chain_price_rejects_invalid_chains() {
+    let prices = prices();
+    assert_eq!(
+        prices.chain_price(&[S; 4]),
+        Err(KaminoLendingError::NoPriceFound)
+    );
+    assert_eq!(
+        prices.chain_price(&[600, S, S, S]),
+        Err(KaminoLendingError::InvalidOracleConfig)
+    );
+    assert_eq!(
+        prices.chain_price(&[3, SCOPE_MAX_ENTRIES as u16, S, S]),
+        Err(KaminoLendingError::InvalidOracleConfig)
+    );
+    assert_eq!(
+        prices.chain_price(&[3, 8, S, S]),
+        Err(KaminoLendingError::PriceIsZero)
+    );
+}
+
+#[test]
+fn scope_price_rejects_stale_prices() {
+    let prices = prices();
+    let token_info = token_info([3, 7, S, S], [S; 4]);
+    let expected = prices.chain_price(&[3, 7, S, S]).unwrap();
+    assert_eq!(
+        token_info.scope_price(&prices, 1_050),
+        Ok(TimestampedPriceWithTwap {
+            price: expected,
+            twap: None,
+        })
+    );
+    assert_eq!(
+        token_info.scope_price(&prices, 1_051),
+        Err(KaminoLendingError::PriceTooOld)
+    );
+    // A price stamped after `now` has no age.
+    assert!(token_info.scope_price(&prices, 500).is_ok());
+
+    let mut disabled = token_info.clone();
+    disabled.scope_configuration.price_feed = Pubkey::default();
+    assert_eq!(
+        disabled.scope_price(&prices, 1_000),
+        Err(KaminoLendingError::InvalidOracleConfig)
+    );
+}
+
+#[test]
+fn scope_price_resolves_the_twap_when_enabled() {
+    let prices = prices();
+    let mut token_info = token_info([3, 7, S, S], [9, S, S, S]);
+    assert_eq!(token_info.scope_price(&prices, 1_000).unwrap().twap, None);
+
+    token_info.max_twap_divergence_bps = 100;
+    assert_eq!(
+        token_info.scope_price(&prices, 1_020).unwrap().twap,
+        Some(TimestampedPrice {
+            price: Fraction::from_num(149),
+            timestamp: 900,
+        })
+    );
+    assert_eq!(
+        token_info.scope_price(&prices, 1_021),
+        Err(KaminoLendingError::PriceTooOld)
+    );
+
+    token_info.scope_configuration.twap_chain = [8, S, S, S];
+    assert_eq!(
+        token_info.scope_price(&prices, 1_000),
+        Err(KaminoLendingError::InvalidTwapPrice)
+    );
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "serde")]
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::serde_helpers::WithoutPadding;
+use kamino_lending_interface::*;
+use serde_json::{json, Value};
+
+mod common;
+
+use common::{fixture, lending_market, obligation, reserve};
+
+#[test]
+fn reserve_human_readable_fields() {
+    let value = serde_json::to_value(reserve()).unwrap();
+    assert_eq!(
+        value["lending_market"],
+        "7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF"
+    );
+    assert_eq!(
+        value["liquidity"]["mint_pubkey"],
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+    );
+    // 0.99998 is not representable; the string is exact rather than rounded for display.
+    assert_eq!(
+        value["liquidity"]["market_price_sf"],
+        "0.9999799999999999999"
+    );
+    assert_eq!(value["config"]["token_info"]["name"], "USDC");
+    assert_eq!(value["config"]["fees"]["borrow_fee_sf"], "0");
+    assert_eq!(value["padding"].as_array().unwrap().len(), 207);
+}
+
+#[test]
+fn accounts_json_round_trip() {
+    let reserve = reserve();
+    let json = serde_json::to_string(&reserve).unwrap();
+    assert_eq!(serde_json::from_str::<Reserve>(&json).unwrap(), reserve);
+
+    let obligation = ObligationAccount(obligation());
+    let json = serde_json::to_string(&obligation).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ObligationAccount>(&json).unwrap(),
+        obligation
+    );
+
+    let market = lending_market();
+    let value = serde_json::to_value(&market).unwrap();
+    assert_eq!(value["name"], "Main Market");
+    assert_eq!(value["quote_currency"], "USD");
+    assert_eq!(
+        serde_json::from_value::<LendingMarket>(value).unwrap(),
+        market
+    );
+}
+
+#[test]
+fn padding_is_omitted_on_request() {
+    let reserve = reserve();
+    let value = serde_json::to_value(WithoutPadding(&reserve)).unwrap();
+    let keys = |value: &Value| {
+        value
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    assert!(!keys(&value).iter().any(|key| key.starts_with("padding")));
+    assert!(!keys(&value["liquidity"]).contains(&"padding2".to_string()));
+    assert!(!keys(&value["config"]).contains(&"reserved1".to_string()));
+    assert!(keys(&value).contains(&"version".to_string()));
+    // Only the wrapped value is affected; missing padding deserializes as zeroes.
+    assert!(serde_json::to_value(&reserve)
+        .unwrap()
+        .get("padding")
+        .is_some());
+    assert_eq!(serde_json::from_value::<Reserve>(value).unwrap(), reserve);
+
+    // Nested in sequences, maps, options and enums.
+    let obligations = std::collections::BTreeMap::from([("a", Some(vec![obligation()]))]);
+    let value = serde_json::to_value(WithoutPadding(&obligations)).unwrap();
+    let obligation = &value["a"][0];
+    assert!(obligation.get("padding3").is_none());
+    assert!(obligation["deposits"][0].get("padding").is_none());
+    assert!(obligation["deposits"][0].get("deposit_reserve").is_some());
+    let account = KaminoLendingAccount::Reserve(Box::new(reserve));
+    let account = serde_json::to_value(WithoutPadding(&account)).unwrap();
+    assert!(account["Reserve"].get("padding").is_none());
+    assert!(account["Reserve"]["liquidity"].get("mint_pubkey").is_some());
+}
+
+#[test]
+fn names_are_trimmed_and_validated() {
+    let mut market = lending_market();
+    market.name = [0; 32];
+    assert_eq!(serde_json::to_value(&market).unwrap()["name"], "");
+
+    market.name[..2].copy_from_slice(&[0xff, 0xfe]);
+    let value = serde_json::to_value(&market).unwrap();
+    assert_eq!(value["name"][0], 255);
+    assert_eq!(
+        serde_json::from_value::<LendingMarket>(value).unwrap(),
+        market
+    );
+
+    let mut value = serde_json::to_value(lending_market()).unwrap();
+    value["name"] = json!("x".repeat(33));
+    assert!(serde_json::from_value::<LendingMarket>(value).is_err());
+}
+
+#[test]
+fn instructions_and_keys_round_trip() {
+    let fixtures = String::from_utf8(fixture("instructions.txt")).unwrap();
+    for line in fixtures.lines() {
+        let (_, hex) = line.split_once(' ').unwrap();
+        let data: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        let ix = KaminoLendingProgramIx::deserialize(&data).unwrap();
+        let json = serde_json::to_string(&ix).unwrap();
+        assert_eq!(
+            serde_json::from_str::<KaminoLendingProgramIx>(&json).unwrap(),
+            ix,
+            "{json}"
+        );
+    }
+
+    let keys = RefreshReserveKeys {
+        reserve: "D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59"
+            .parse()
+            .unwrap(),
+        lending_market: "7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF"
+            .parse()
+            .unwrap(),
+        pyth_oracle: ID,
+        switchboard_price_oracle: ID,
+        switchboard_twap_oracle: ID,
+        scope_prices: ID,
+    };
+    let value = serde_json::to_value(keys).unwrap();
+    assert_eq!(value["pyth_oracle"], ID.to_string());
+    assert_eq!(
+        serde_json::from_value::<RefreshReserveKeys>(value).unwrap(),
+        keys
+    );
+}
+
+#[test]
+fn errors_serialize_by_name() {
+    let err = KaminoLendingError::InvalidMarketAuthority;
+    assert_eq!(serde_json::to_value(err).unwrap(), "InvalidMarketAuthority");
+    assert_eq!(
+        serde_json::from_value::<KaminoLendingError>(json!("InvalidMarketAuthority")).unwrap(),
+        err
+    );
+}
+
+#[test]
+fn fractions_serialize_as_exact_decimals() {
+    assert_eq!(
+        serde_json::to_value(Fraction::from_percent(50u8)).unwrap(),
+        "0.5"
+    );
+    assert_eq!(
+        serde_json::from_value::<Fraction>(json!("1.25")).unwrap(),
+        Fraction::from_ratio(5, 4)
+    );
+}
+
+#[test]
+fn market_snapshot_round_trip() {
+    let market = lending_market();
+    let market_address = "7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF"
+        .parse()
+        .unwrap();
+    let reserve_address = "D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59"
+        .parse()
+        .unwrap();
+    let obligation_address = solana_program::pubkey::Pubkey::new_unique();
+    let snapshot = MarketSnapshot::from_accounts(
+        market_address,
+        market,
+        [(reserve_address, reserve())],
+        [(obligation_address, obligation())],
+    )
+    .unwrap();
+    let value = serde_json::to_value(&snapshot).unwrap();
+    assert!(value["reserves"]["D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59"].is_object());
+    assert!(value["obligations"][obligation_address.to_string()].is_object());
+    assert_eq!(
+        serde_json::from_value::<MarketSnapshot>(value.clone()).unwrap(),
+        snapshot
+    );
+
+    let mut foreign = value;
+    foreign["lending_market_address"] = json!(ID.to_string());
+    assert!(serde_json::from_value::<MarketSnapshot>(foreign).is_err());
+}
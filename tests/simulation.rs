@@ -0,0 +1,220 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+mod common;
+
+use common::{synthetic, NOW, SOL_RESERVE, USDC_RESERVE};
+
+/// The common market with SOL at 150 USDC and no borrow limits on USDC.
+fn market() -> (LendingMarket, Obligation, Vec<(Pubkey, Reserve)>) {
+    let (lending_market, obligation, mut reserves) = common::market(150);
+    let usdc = &mut reserves[0].1;
+    usdc.config.borrow_limit = u64::MAX;
+    usdc.config.borrow_limit_outside_elevation_group = u64::MAX;
+    (lending_market, obligation, reserves)
+}
+
+#[test]
+fn steps_report_values_and_liquidation_prices() {
+    let (lending_market, obligation, reserves) = market();
+    let usdc = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let sol_mint = reserves[1].1.liquidity.mint_pubkey;
+    let before = obligation
+        .compute_values(&lending_market, &reserves)
+        .unwrap();
+
+    let steps = simulate_obligation_actions(
+        &obligation,
+        &lending_market,
+        &reserves,
+        &[
+            ObligationAction::Borrow {
+                reserve: usdc,
+                liquidity_amount: 100_000_000,
+            },
+            ObligationAction::Repay {
+                reserve: usdc,
+                liquidity_amount: u64::MAX,
+            },
+        ],
+        NOW,
+    )
+    .unwrap();
+
+    let borrowed = &steps[0].values;
+    assert!(borrowed.loan_to_value() > before.loan_to_value());
+    assert!(
+        borrowed.borrow(&usdc).unwrap().borrowed_amount
+            > before.borrow(&usdc).unwrap().borrowed_amount
+    );
+    let prices = &steps[0].liquidation_prices;
+    assert_eq!(prices.len(), 2);
+    let sol_price = prices.iter().find(|price| price.mint == sol_mint).unwrap();
+    let usdc_price = prices.iter().find(|price| price.mint != sol_mint).unwrap();
+    assert!(sol_price.falling);
+    assert!(sol_price.liquidation_price < sol_price.current_price);
+    assert!(!usdc_price.falling);
+    assert!(usdc_price.liquidation_price > usdc_price.current_price);
+
+    let repaid = &steps[1].values;
+    assert!(repaid.borrows.is_empty());
+    assert!(!repaid.has_debt());
+    assert!(steps[1].liquidation_prices.is_empty());
+}
+
+#[test]
+fn fails_with_the_program_error_and_keeps_completed_steps() {
+    let (lending_market, obligation, reserves) = market();
+    let usdc = Pubkey::from_str(USDC_RESERVE).unwrap();
+    let sol = Pubkey::from_str(SOL_RESERVE).unwrap();
+    let sol_mint = reserves[1].1.liquidity.mint_pubkey;
+
+    let err = simulate_obligation_actions(
+        &obligation,
+        &lending_market,
+        &reserves,
+        &[
+            ObligationAction::SetPrice {
+                mint: sol_mint,
+                price: Fraction::from_num(1),
+            },
+            ObligationAction::Borrow {
+                reserve: usdc,
+                liquidity_amount: 1_000_000,
+            },
+        ],
+        NOW,
+    )
+    .unwrap_err();
+    assert_eq!(err.step, 1);
+    assert_eq!(err.error, KaminoLendingError::BorrowTooLarge);
+    assert_eq!(err.completed.len(), 1);
+    assert!(err.completed[0].values.is_liquidatable());
+
+    let deposited_amount = obligation.find_deposit(&sol).unwrap().deposited_amount;
+    let mut simulator = ObligationSimulator::new(lending_market, obligation, reserves, NOW);
+    let unchanged = simulator.clone();
+    assert_eq!(
+        simulator.apply(&ObligationAction::Withdraw {
+            reserve: sol,
+            collateral_amount: deposited_amount,
+        }),
+        Err(KaminoLendingError::WithdrawTooLarge)
+    );
+    assert_eq!(
+        simulator.apply(&ObligationAction::Repay {
+            reserve: sol,
+            liquidity_amount: 1,
+        }),
+        Err(KaminoLendingError::ObligationLiquidityEmpty)
+    );
+    assert_eq!(simulator, unchanged);
+}
+
+#[test]
+fn rejects_actions_leaving_a_worse_and_unhealthy_ltv() {
+    let (lending_market, obligation, mut reserves) = synthetic::market();
+    // A borrow up to the 500 allowed borrow value reaches the liquidation threshold when both are
+    // at 50%.
+    let borrow = ObligationAction::Borrow {
+        reserve: synthetic::DEBT,
+        liquidity_amount: 300_000_000,
+    };
+    let mut simulator = ObligationSimulator::new(
+        lending_market.clone(),
+        obligation.clone(),
+        reserves.clone(),
+        NOW,
+    );
+    let step = simulator.apply(&borrow).unwrap();
+    assert_eq!(
+        step.values.borrow_factor_adjusted_debt_value,
+        Fraction::from_num(500)
+    );
+    assert!(!step.values.is_liquidatable());
+
+    reserves[0].1.config.liquidation_threshold_pct = 50;
+    let mut simulator = ObligationSimulator::new(lending_market, obligation, reserves, NOW);
+    let unchanged = simulator.clone();
+    assert_eq!(
+        simulator.apply(&borrow),
+        Err(KaminoLendingError::WorseLtvThanUnhealthyLtv)
+    );
+    assert_eq!(simulator, unchanged);
+    // Not when the action leaves the obligation healthy.
+    assert!(simulator
+        .apply(&ObligationAction::Borrow {
+            reserve: synthetic::DEBT,
+            liquidity_amount: 299_999_999,
+        })
+        .is_ok());
+}
+
+#[test]
+fn withdrawing_everything_with_debt_stops_at_the_max_ltv() {
+    let (lending_market, obligation, reserves) = synthetic::market();
+    let withdraw_all = ObligationAction::Withdraw {
+        reserve: synthetic::COLLATERAL,
+        collateral_amount: u64::MAX,
+    };
+    let mut simulator = ObligationSimulator::new(lending_market, obligation, reserves, NOW);
+    // 300 of the 500 allowed borrow value is unused, so 600 of the 1000 deposited can go.
+    let step = simulator.apply(&withdraw_all).unwrap();
+    assert_eq!(step.values.deposits[0].collateral_amount, 4_000_000);
+    assert_eq!(step.values.allowed_borrow_value, Fraction::from_num(200));
+
+    let unchanged = simulator.clone();
+    assert_eq!(
+        simulator.apply(&withdraw_all),
+        Err(KaminoLendingError::WithdrawTooLarge)
+    );
+    assert_eq!(simulator, unchanged);
+}
+
+#[test]
+fn borrow_and_repay_restore_elevation_group_borrows() {
+    let (mut lending_market, mut obligation, mut reserves) = synthetic::market();
+    lending_market.elevation_groups[0] = ElevationGroup {
+        max_liquidation_bonus_bps: 0,
+        id: 1,
+        ltv_pct: 75,
+        liquidation_threshold_pct: 80,
+        allow_new_loans: 1,
+        max_reserves_as_collateral: 1,
+        padding0: 0,
+        debt_reserve: synthetic::DEBT,
+        padding1: [0; 4],
+    };
+    obligation.elevation_group = 1;
+    for (_, reserve) in &mut reserves {
+        reserve.config.elevation_groups[0] = 1;
+        reserve
+            .config
+            .borrow_limit_against_this_collateral_in_elevation_group[0] = u64::MAX;
+    }
+    reserves[0]
+        .1
+        .borrowed_amounts_against_this_reserve_in_elevation_groups[0] = 200_000_000;
+    let borrowed_against = |simulator: &ObligationSimulator| {
+        simulator.reserves()[&synthetic::COLLATERAL]
+            .borrowed_amounts_against_this_reserve_in_elevation_groups[0]
+    };
+
+    let mut simulator = ObligationSimulator::new(lending_market, obligation, reserves, NOW);
+    simulator
+        .apply(&ObligationAction::Borrow {
+            reserve: synthetic::DEBT,
+            liquidity_amount: 100_000_000,
+        })
+        .unwrap();
+    assert_eq!(borrowed_against(&simulator), 300_000_000);
+    simulator
+        .apply(&ObligationAction::Repay {
+            reserve: synthetic::DEBT,
+            liquidity_amount: 100_000_000,
+        })
+        .unwrap();
+    assert_eq!(borrowed_against(&simulator), 200_000_000);
+}
@@ -0,0 +1,141 @@
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+
+mod common;
+
+use common::synthetic::{self, COLLATERAL, DEBT, LENDING_MARKET};
+
+const OBLIGATION: Pubkey = Pubkey::new_from_array([4; 32]);
+const OWNER: Pubkey = Pubkey::new_from_array([5; 32]);
+const OTHER_MARKET: Pubkey = Pubkey::new_from_array([6; 32]);
+const COLLATERAL_MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+const OTHER_MINT: Pubkey = Pubkey::new_from_array([8; 32]);
+
+/// The synthetic collateral reserve, lending `COLLATERAL_MINT`.
+fn reserve() -> Reserve {
+    let mut reserve = synthetic::reserve(100, 1_000_000_000, 0);
+    reserve.liquidity.mint_pubkey = COLLATERAL_MINT;
+    reserve
+}
+
+fn reserve_data(reserve: Reserve) -> Vec<u8> {
+    ReserveAccount(reserve).try_to_vec().unwrap()
+}
+
+/// The synthetic obligation, owned by `OWNER`.
+fn obligation_data() -> Vec<u8> {
+    let (_, mut obligation, _) = synthetic::market();
+    obligation.owner = OWNER;
+    ObligationAccount(obligation).try_to_vec().unwrap()
+}
+
+/// The synthetic market with its collateral reserve, the debt reserve moved to another market
+/// and lending `OTHER_MINT`, and the obligation.
+fn source() -> InMemoryAccountSource {
+    let (lending_market, _, _) = synthetic::market();
+    let mut source = InMemoryAccountSource::new();
+    source.insert_program_account(
+        LENDING_MARKET,
+        LendingMarketAccount(lending_market).try_to_vec().unwrap(),
+    );
+    source.insert_program_account(COLLATERAL, reserve_data(reserve()));
+    let mut foreign = reserve();
+    foreign.lending_market = OTHER_MARKET;
+    foreign.liquidity.mint_pubkey = OTHER_MINT;
+    source.insert_program_account(DEBT, reserve_data(foreign));
+    source.insert_program_account(OBLIGATION, obligation_data());
+    source
+}
+
+#[test]
+fn load_keeps_the_market_reserves_and_obligations() {
+    let source = source();
+    let snapshot = MarketSnapshot::load_with_obligations(&source, &LENDING_MARKET).unwrap();
+    assert_eq!(
+        snapshot.reserves().keys().collect::<Vec<_>>(),
+        vec![&COLLATERAL]
+    );
+    assert_eq!(
+        snapshot
+            .reserve_by_mint(&COLLATERAL_MINT)
+            .map(|(address, _)| *address),
+        Some(COLLATERAL)
+    );
+    assert_eq!(snapshot.reserve_by_mint(&OTHER_MINT), None);
+    assert_eq!(
+        snapshot
+            .obligations_by_owner(&OWNER)
+            .map(|(address, _)| *address)
+            .collect::<Vec<_>>(),
+        vec![OBLIGATION]
+    );
+    let obligation = snapshot.obligation(&OBLIGATION).unwrap();
+    assert_eq!(snapshot.missing_reserves(obligation), vec![DEBT]);
+}
+
+#[test]
+fn accounts_of_other_markets_or_programs_are_rejected() {
+    let mut source = source();
+    let mut snapshot = MarketSnapshot::load(&source, &LENDING_MARKET).unwrap();
+
+    let mut foreign = reserve();
+    foreign.lending_market = OTHER_MARKET;
+    assert!(matches!(
+        snapshot.insert_reserve(DEBT, foreign),
+        Err(SnapshotError::ForeignAccount { address, lending_market })
+            if address == DEBT && lending_market == OTHER_MARKET
+    ));
+    assert!(matches!(
+        snapshot.load_obligations(&source, &[DEBT]),
+        Err(SnapshotError::InvalidAccountData { address, .. }) if address == DEBT
+    ));
+    assert!(matches!(
+        snapshot.load_obligations(&source, &[Pubkey::new_unique()]),
+        Err(SnapshotError::AccountNotFound(_))
+    ));
+
+    source.insert(
+        OBLIGATION,
+        RawAccount {
+            lamports: 0,
+            owner: OTHER_MARKET,
+            data: obligation_data(),
+        },
+    );
+    assert!(matches!(
+        snapshot.load_obligations(&source, &[OBLIGATION]),
+        Err(SnapshotError::InvalidAccountOwner { owner, .. }) if owner == OTHER_MARKET
+    ));
+    assert!(snapshot.obligations().is_empty());
+}
+
+#[test]
+fn replacing_a_reserve_reindexes_its_mint() {
+    let source = source();
+    let mut snapshot = MarketSnapshot::load(&source, &LENDING_MARKET).unwrap();
+    let mut replaced = reserve();
+    replaced.liquidity.mint_pubkey = OTHER_MINT;
+    snapshot.insert_reserve(COLLATERAL, replaced).unwrap();
+    assert_eq!(snapshot.reserves().len(), 1);
+    assert_eq!(snapshot.reserve_by_mint(&COLLATERAL_MINT), None);
+    assert_eq!(
+        snapshot
+            .reserve_by_mint(&OTHER_MINT)
+            .map(|(address, _)| *address),
+        Some(COLLATERAL)
+    );
+
+    // Once the collateral mint indexes another reserve, moving the first one off it leaves the index
+    // alone.
+    snapshot.insert_reserve(COLLATERAL, reserve()).unwrap();
+    snapshot.insert_reserve(DEBT, reserve()).unwrap();
+    let mut replaced = reserve();
+    replaced.liquidity.mint_pubkey = OTHER_MINT;
+    snapshot.insert_reserve(COLLATERAL, replaced).unwrap();
+    assert_eq!(
+        snapshot
+            .reserve_by_mint(&COLLATERAL_MINT)
+            .map(|(address, _)| *address),
+        Some(DEBT)
+    );
+}
@@ -0,0 +1,193 @@
+use kamino_lending_interface::fraction::Fraction;
+use kamino_lending_interface::*;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+mod common;
+
+use common::synthetic::{self, COLLATERAL, DEBT};
+use common::{market, SOL_RESERVE, USDC_RESERVE};
+
+/// The common market with SOL at 150 USDC, plus an obligation on a reserve missing from the
+/// snapshot.
+fn snapshot() -> (MarketSnapshot, Pubkey, Pubkey) {
+    let (lending_market, obligation, reserves) = market(150);
+    let sol_mint = reserves[1].1.liquidity.mint_pubkey;
+    let mut orphan = obligation.clone();
+    orphan.deposits[0].deposit_reserve = Pubkey::new_unique();
+    let obligation_pubkey = Pubkey::new_unique();
+    let snapshot = MarketSnapshot::from_accounts(
+        reserves[0].1.lending_market,
+        lending_market,
+        reserves,
+        [
+            (obligation_pubkey, obligation),
+            (Pubkey::new_unique(), orphan),
+        ],
+    )
+    .unwrap();
+    (snapshot, obligation_pubkey, sol_mint)
+}
+
+#[test]
+fn shocks_turn_debt_liquidatable_then_bad() {
+    let (snapshot, obligation_pubkey, sol_mint) = snapshot();
+    let usdc = Pubkey::from_str(USDC_RESERVE).unwrap();
+
+    let calm = stress_test(&snapshot, &[]);
+    assert_eq!(calm.totals.obligations, 1);
+    assert_eq!(calm.skipped.len(), 1);
+    assert_eq!(
+        calm.skipped[0].error,
+        KaminoLendingError::InvalidObligationCollateral
+    );
+    assert_eq!(calm.totals.liquidatable_obligations, 0);
+    assert!(calm.totals.bad_debt_value.is_zero());
+    assert_eq!(calm.obligations[0].address, obligation_pubkey);
+    assert_eq!(
+        calm.obligations[0].loan_to_value,
+        calm.obligations[0].loan_to_value_before
+    );
+
+    let crash = stress_test(&snapshot, &[PriceShock::drop_pct(sol_mint, 50)]);
+    let row = &crash.obligations[0];
+    assert!(row.liquidatable);
+    assert!(row.loan_to_value > row.loan_to_value_before);
+    assert!(!row.liquidation_bonus.is_zero());
+    assert!(row.liquidatable_debt_value > Fraction::ZERO);
+    assert!(row.liquidatable_debt_value <= row.borrowed_value);
+    let sol_row = crash
+        .reserves
+        .iter()
+        .find(|reserve| reserve.mint == sol_mint)
+        .unwrap();
+    assert_eq!(sol_row.price, Fraction::from_num(75));
+    assert_eq!(sol_row.price_before, Fraction::from_num(150));
+    assert!(!sol_row.seized_collateral_value.is_zero());
+
+    let wipeout = stress_test(&snapshot, &[PriceShock::drop_pct(sol_mint, 99)]);
+    let row = &wipeout.obligations[0];
+    assert!(row.loan_to_value >= Fraction::ONE);
+    assert!(row.bad_debt_value > Fraction::ZERO);
+    assert_eq!(wipeout.totals.obligations_with_bad_debt, 1);
+    let usdc_row = wipeout
+        .reserves
+        .iter()
+        .find(|reserve| reserve.address == usdc)
+        .unwrap();
+    assert_eq!(usdc_row.bad_debt_value, row.bad_debt_value);
+    let bad_debt: Vec<_> = wipeout.bad_debt().collect();
+    assert_eq!(bad_debt, [(&usdc, usdc_row.bad_debt_amount)]);
+    assert!(usdc_row.bad_debt_amount > 0);
+}
+
+#[test]
+fn csv_tables() {
+    let (snapshot, obligation_pubkey, sol_mint) = snapshot();
+    let report = stress_test(
+        &snapshot,
+        &[PriceShock::new(sol_mint, Fraction::from_ratio(1, 64))],
+    );
+
+    let obligations = report.obligations_csv();
+    let lines: Vec<_> = obligations.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("address,owner,loan_to_value_before,"));
+    assert!(lines[1].starts_with(&format!("{obligation_pubkey},")));
+    assert_eq!(lines[1].split(',').count(), lines[0].split(',').count());
+
+    let reserves = report.reserves_csv();
+    let lines: Vec<_> = reserves.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].ends_with(",bad_debt_value,bad_debt_amount"));
+    let sol_line = lines.iter().find(|line| line.contains(",SOL,")).unwrap();
+    assert!(
+        sol_line.starts_with(&format!("{SOL_RESERVE},{sol_mint},SOL,150,2.34375,")),
+        "{sol_line}"
+    );
+}
+
+#[test]
+fn sums_saturate_instead_of_overflowing() {
+    let (lending_market, obligation, reserves) = market(150);
+    let sol_mint = reserves[1].1.liquidity.mint_pubkey;
+    let snapshot = MarketSnapshot::from_accounts(
+        reserves[0].1.lending_market,
+        lending_market,
+        reserves,
+        [
+            (Pubkey::new_unique(), obligation.clone()),
+            (Pubkey::new_unique(), obligation),
+        ],
+    )
+    .unwrap();
+    // Each obligation's 41.25 SOL is then worth about 2e20, together more than a `Fraction` holds.
+    let shock = PriceShock::new(sol_mint, Fraction::from_num(32_000_000_000_000_000));
+    let report = stress_test(&snapshot, &[shock]);
+    assert!(report.skipped.is_empty());
+    assert_eq!(report.totals.obligations, 2);
+    let deposited_value = report.obligations[0].deposited_value;
+    assert!(deposited_value > Fraction::MAX.checked_div_int(2).unwrap());
+    assert_eq!(report.totals.deposited_value, Fraction::MAX);
+    let sol_row = report
+        .reserves
+        .iter()
+        .find(|reserve| reserve.mint == sol_mint)
+        .unwrap();
+    assert_eq!(sol_row.deposited_value, Fraction::MAX);
+}
+
+/// The synthetic market with a second collateral and debt reserve, full liquidations allowed and
+/// no liquidation bonus, and one obligation holding `deposits` and `borrows` in whole tokens.
+fn two_position_report(deposits: [u64; 2], borrows: [u64; 2]) -> StressTestReport {
+    const OTHER_COLLATERAL: Pubkey = Pubkey::new_from_array([4; 32]);
+    const OTHER_DEBT: Pubkey = Pubkey::new_from_array([5; 32]);
+    let (mut lending_market, mut obligation, mut reserves) = synthetic::market();
+    lending_market.liquidation_max_debt_close_factor_pct = 100;
+    lending_market.max_liquidatable_debt_market_value_at_once = u64::MAX;
+    reserves.push((OTHER_COLLATERAL, synthetic::reserve(100, 1_000_000_000, 0)));
+    reserves.push((OTHER_DEBT, synthetic::reserve(1, 1_000_000_000, 0)));
+    for (index, (deposit_reserve, amount)) in [COLLATERAL, OTHER_COLLATERAL]
+        .into_iter()
+        .zip(deposits)
+        .enumerate()
+    {
+        obligation.deposits[index].deposit_reserve = deposit_reserve;
+        obligation.deposits[index].deposited_amount = amount * 1_000_000;
+    }
+    for (index, (borrow_reserve, amount)) in [DEBT, OTHER_DEBT].into_iter().zip(borrows).enumerate()
+    {
+        obligation.borrows[index].borrow_reserve = borrow_reserve;
+        obligation.borrows[index].borrowed_amount_sf =
+            Fraction::from_num(amount * 1_000_000).to_bits();
+    }
+    let snapshot = MarketSnapshot::from_accounts(
+        synthetic::LENDING_MARKET,
+        lending_market,
+        reserves,
+        [(Pubkey::new_unique(), obligation)],
+    )
+    .unwrap();
+    stress_test(&snapshot, &[])
+}
+
+#[test]
+fn liquidatable_debt_is_capped_by_the_largest_positions() {
+    // 1200 deposited, 720 unhealthy borrow value: the 500 borrow caps the 800 of debt.
+    let report = two_position_report([10, 2], [500, 300]);
+    let row = &report.obligations[0];
+    assert!(row.liquidatable);
+    assert_eq!(row.liquidation_bonus, Fraction::ZERO);
+    assert_eq!(row.liquidatable_debt_value, Fraction::from_num(500));
+    assert!(row.bad_debt_value.is_zero());
+
+    // 700 deposited, 420 unhealthy borrow value: the 400 deposit caps the 600 of debt.
+    let report = two_position_report([4, 3], [500, 100]);
+    let row = &report.obligations[0];
+    assert!(row.liquidatable);
+    assert_eq!(row.liquidatable_debt_value, Fraction::from_num(400));
+    assert_eq!(
+        report.totals.liquidatable_debt_value,
+        Fraction::from_num(400)
+    );
+}
@@ -0,0 +1,73 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use kamino_lending_interface::*;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::fmt::Debug;
+
+/// Decodes a value from the front of `data`, then checks it encodes back to the bytes it was read
+/// from and decodes again to itself.
+fn check_round_trip<T>(data: &[u8]) -> Result<(), TestCaseError>
+where
+    T: BorshDeserialize + BorshSerialize + PartialEq + Debug,
+{
+    let mut reader = data;
+    let decoded = T::deserialize(&mut reader);
+    prop_assume!(decoded.is_ok(), "not a valid encoding");
+    let value = decoded.unwrap();
+    let consumed = data.len() - reader.len();
+    let encoded = value.try_to_vec().unwrap();
+    prop_assert_eq!(&encoded[..], &data[..consumed]);
+    prop_assert_eq!(T::try_from_slice(&encoded).unwrap(), value);
+    Ok(())
+}
+
+macro_rules! round_trip_tests {
+    ($($test:ident: $ty:ty, $first_byte:expr;)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $test(first_byte in $first_byte, rest in vec(any::<u8>(), 2048)) {
+                    let mut data = vec![first_byte];
+                    data.extend(rest);
+                    check_round_trip::<$ty>(&data)?;
+                }
+            )*
+        }
+    };
+}
+
+round_trip_tests! {
+    update_config_mode: UpdateConfigMode, 0u8..49;
+    update_lending_market_config_value: UpdateLendingMarketConfigValue, 0u8..9;
+    update_lending_market_mode: UpdateLendingMarketMode, 0u8..21;
+    last_update: LastUpdate, any::<u8>();
+    elevation_group: ElevationGroup, any::<u8>();
+    init_obligation_args: InitObligationArgs, any::<u8>();
+    obligation_collateral: ObligationCollateral, any::<u8>();
+    obligation_liquidity: ObligationLiquidity, any::<u8>();
+    asset_tier: AssetTier, 0u8..3;
+    big_fraction_bytes: BigFractionBytes, any::<u8>();
+    fee_calculation: FeeCalculation, 0u8..2;
+    reserve_collateral: ReserveCollateral, any::<u8>();
+    reserve_config: ReserveConfig, any::<u8>();
+    reserve_farm_kind: ReserveFarmKind, 0u8..2;
+    reserve_fees: ReserveFees, any::<u8>();
+    reserve_liquidity: ReserveLiquidity, any::<u8>();
+    reserve_status: ReserveStatus, 0u8..3;
+    withdrawal_caps: WithdrawalCaps, any::<u8>();
+    price_heuristic: PriceHeuristic, any::<u8>();
+    pyth_configuration: PythConfiguration, any::<u8>();
+    scope_configuration: ScopeConfiguration, any::<u8>();
+    switchboard_configuration: SwitchboardConfiguration, any::<u8>();
+    token_info: TokenInfo, any::<u8>();
+    borrow_rate_curve: BorrowRateCurve, any::<u8>();
+    curve_point: CurvePoint, any::<u8>();
+}
+
+#[test]
+fn out_of_range_enum_tags_are_rejected() {
+    assert!(AssetTier::try_from_slice(&[3]).is_err());
+    assert!(ReserveStatus::try_from_slice(&[3]).is_err());
+    assert!(UpdateConfigMode::try_from_slice(&[49]).is_err());
+    assert!(UpdateLendingMarketMode::try_from_slice(&[21]).is_err());
+}
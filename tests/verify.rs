@@ -0,0 +1,178 @@
+use kamino_lending_interface::offsets::*;
+use kamino_lending_interface::*;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+
+mod common;
+
+use common::fixture;
+
+struct TestAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 0,
+            data,
+            is_signer: false,
+        }
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            false,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+/// Accounts of a valid `borrow_obligation_liquidity`, in instruction order.
+fn borrow_accounts() -> Vec<TestAccount> {
+    let reserve = ReserveAccount::deserialize(&fixture("reserve.bin"))
+        .unwrap()
+        .0;
+    let mut obligation_data = fixture("obligation.bin");
+    obligation_data[OBLIGATION_REFERRER_OFFSET..OBLIGATION_REFERRER_OFFSET + 32].fill(0);
+    let obligation = ObligationAccount::deserialize(&obligation_data).unwrap().0;
+    let (authority, bump) = find_lending_market_authority_address(&reserve.lending_market);
+    let mut market_data = fixture("lending_market.bin");
+    market_data[LENDING_MARKET_BUMP_SEED_OFFSET] = bump;
+
+    let mut owner = TestAccount::new(obligation.owner, Pubkey::default(), Vec::new());
+    owner.is_signer = true;
+    let token_program = reserve.liquidity.token_program;
+    vec![
+        owner,
+        TestAccount::new(Pubkey::new_unique(), ID, obligation_data),
+        TestAccount::new(reserve.lending_market, ID, market_data),
+        TestAccount::new(authority, Pubkey::default(), Vec::new()),
+        TestAccount::new(Pubkey::new_unique(), ID, fixture("reserve.bin")),
+        TestAccount::new(reserve.liquidity.mint_pubkey, token_program, Vec::new()),
+        TestAccount::new(reserve.liquidity.supply_vault, token_program, Vec::new()),
+        TestAccount::new(reserve.liquidity.fee_vault, token_program, Vec::new()),
+        TestAccount::new(Pubkey::new_unique(), token_program, Vec::new()),
+        TestAccount::new(ID, Pubkey::default(), Vec::new()),
+        TestAccount::new(token_program, Pubkey::default(), Vec::new()),
+        TestAccount::new(sysvar::instructions::ID, Pubkey::default(), Vec::new()),
+    ]
+}
+
+fn verify_borrow(accounts: &mut [TestAccount]) -> Result<(), AccountVerificationError> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let accounts = BorrowObligationLiquidityAccounts {
+        owner: &infos[0],
+        obligation: &infos[1],
+        lending_market: &infos[2],
+        lending_market_authority: &infos[3],
+        borrow_reserve: &infos[4],
+        borrow_reserve_liquidity_mint: &infos[5],
+        reserve_source_liquidity: &infos[6],
+        borrow_reserve_liquidity_fee_receiver: &infos[7],
+        user_destination_liquidity: &infos[8],
+        referrer_token_state: &infos[9],
+        token_program: &infos[10],
+        instruction_sysvar_account: &infos[11],
+    };
+    borrow_obligation_liquidity_verify_account_semantics(accounts)
+}
+
+#[test]
+fn valid_accounts_pass() {
+    assert_eq!(verify_borrow(&mut borrow_accounts()), Ok(()));
+}
+
+#[test]
+fn errors_name_the_offending_field() {
+    let mut accounts = borrow_accounts();
+    accounts[7].key = Pubkey::new_unique();
+    let err = verify_borrow(&mut accounts).unwrap_err();
+    assert_eq!(err.field(), "borrow_reserve_liquidity_fee_receiver");
+    assert!(matches!(err, AccountVerificationError::WrongAccount { .. }));
+    assert_eq!(ProgramError::from(err), ProgramError::InvalidArgument);
+
+    let mut accounts = borrow_accounts();
+    accounts[0].is_signer = false;
+    assert_eq!(
+        verify_borrow(&mut accounts),
+        Err(AccountVerificationError::MissingSignature { field: "owner" })
+    );
+
+    let mut accounts = borrow_accounts();
+    accounts[0].key = Pubkey::new_unique();
+    assert_eq!(verify_borrow(&mut accounts).unwrap_err().field(), "owner");
+
+    let mut accounts = borrow_accounts();
+    accounts[3].key = Pubkey::new_unique();
+    assert_eq!(
+        verify_borrow(&mut accounts).unwrap_err().field(),
+        "lending_market_authority"
+    );
+}
+
+#[test]
+fn cross_account_relationships_are_checked() {
+    let mut accounts = borrow_accounts();
+    let foreign_market = Pubkey::new_unique();
+    accounts[4].data[RESERVE_LENDING_MARKET_OFFSET..RESERVE_LENDING_MARKET_OFFSET + 32]
+        .copy_from_slice(foreign_market.as_ref());
+    let market = accounts[2].key;
+    assert_eq!(
+        verify_borrow(&mut accounts),
+        Err(AccountVerificationError::ForeignAccount {
+            field: "borrow_reserve",
+            expected_lending_market: market,
+            lending_market: foreign_market,
+        })
+    );
+
+    let mut accounts = borrow_accounts();
+    let other_token_program = Pubkey::new_unique();
+    accounts[5].owner = other_token_program;
+    accounts[10].key = other_token_program;
+    assert_eq!(
+        verify_borrow(&mut accounts).unwrap_err().field(),
+        "token_program"
+    );
+
+    let mut accounts = borrow_accounts();
+    accounts[5].owner = Pubkey::new_unique();
+    assert_eq!(
+        verify_borrow(&mut accounts).unwrap_err().field(),
+        "borrow_reserve_liquidity_mint"
+    );
+
+    let mut accounts = borrow_accounts();
+    accounts[4].owner = Pubkey::new_unique();
+    assert!(matches!(
+        verify_borrow(&mut accounts),
+        Err(AccountVerificationError::InvalidOwner {
+            field: "borrow_reserve",
+            ..
+        })
+    ));
+
+    let mut accounts = borrow_accounts();
+    accounts[4].data = fixture("obligation.bin");
+    assert_eq!(
+        verify_borrow(&mut accounts),
+        Err(AccountVerificationError::InvalidAccountData {
+            field: "borrow_reserve"
+        })
+    );
+}
@@ -0,0 +1,97 @@
+use borsh::BorshDeserialize;
+use kamino_lending_interface::*;
+
+const START: u64 = 1_700_000_000;
+const NOW: i64 = START as i64;
+
+/// 1_000 tokens per hour, of which 600 were withdrawn since the interval started at `START`.
+fn caps() -> WithdrawalCaps {
+    WithdrawalCaps {
+        config_capacity: 1_000,
+        current_total: 600,
+        last_interval_start_timestamp: START,
+        config_interval_length_seconds: 3_600,
+    }
+}
+
+#[test]
+fn capacity_is_restored_when_the_interval_rolls_over() {
+    let caps = caps();
+    assert_eq!(caps.remaining_capacity(NOW + 3_599), 400);
+    assert_eq!(caps.time_until_reset(NOW + 3_599), Some(1));
+    assert_eq!(caps.remaining_capacity(NOW + 3_600), 1_000);
+    assert_eq!(caps.current_total_at(NOW + 3_600), 0);
+    assert_eq!(caps.time_until_reset(NOW + 3_600), Some(0));
+    assert_eq!(caps.time_until_reset(NOW + 10_000), Some(0));
+
+    assert_eq!(
+        caps.after_withdrawal(401, NOW + 3_599),
+        Err(KaminoLendingError::WithdrawalCapReached)
+    );
+    let rolled_over = caps.after_withdrawal(1_000, NOW + 4_000).unwrap();
+    assert_eq!(rolled_over.current_total, 1_000);
+    assert_eq!(rolled_over.last_interval_start_timestamp, START + 4_000);
+    assert_eq!(rolled_over.time_until_reset(NOW + 4_000), Some(3_600));
+    assert_eq!(rolled_over.remaining_capacity(NOW + 4_000), 0);
+}
+
+#[test]
+fn withdrawals_and_deposits_move_the_current_total() {
+    let caps = caps();
+    let withdrawn = caps.after_withdrawal(400, NOW + 10).unwrap();
+    assert_eq!(withdrawn.current_total, 1_000);
+    assert_eq!(withdrawn.last_interval_start_timestamp, START);
+    assert_eq!(
+        withdrawn.after_withdrawal(1, NOW + 10),
+        Err(KaminoLendingError::WithdrawalCapReached)
+    );
+
+    // Deposits can take the total below zero, leaving more than the configured capacity.
+    let deposited = caps.after_deposit(800, NOW + 10);
+    assert_eq!(deposited.current_total, -200);
+    assert_eq!(deposited.remaining_capacity(NOW + 10), 1_200);
+    // A deposit after the interval ends starts a new one.
+    let deposited = caps.after_deposit(800, NOW + 3_600);
+    assert_eq!(deposited.current_total, -800);
+    assert_eq!(deposited.last_interval_start_timestamp, START + 3_600);
+
+    assert_eq!(
+        caps.after_withdrawal(u64::MAX, NOW),
+        Err(KaminoLendingError::IntegerOverflow)
+    );
+}
+
+#[test]
+fn zero_capacity_or_interval_disables_the_cap() {
+    for disabled in [
+        WithdrawalCaps {
+            config_capacity: 0,
+            ..caps()
+        },
+        WithdrawalCaps {
+            config_interval_length_seconds: 0,
+            ..caps()
+        },
+    ] {
+        assert!(!disabled.is_enabled());
+        assert_eq!(disabled.remaining_capacity(NOW), u64::MAX);
+        assert_eq!(disabled.time_until_reset(NOW), None);
+        assert_eq!(disabled.current_total_at(NOW + 3_600), 600);
+        assert_eq!(
+            disabled.after_withdrawal(u64::MAX, NOW),
+            Ok(disabled.clone())
+        );
+        assert_eq!(disabled.after_deposit(500, NOW), disabled);
+    }
+}
+
+#[test]
+fn reserve_limits_combine_available_liquidity_and_caps() {
+    let mut reserve = Reserve::deserialize(&mut [0u8; 10_000].as_slice()).unwrap();
+    reserve.liquidity.available_amount = 500;
+    reserve.config.deposit_withdrawal_cap = caps();
+    assert_eq!(reserve.max_withdrawable_liquidity(NOW), 400);
+    assert_eq!(reserve.max_withdrawable_liquidity(NOW + 3_600), 500);
+    // The debt cap is zeroed, i.e. disabled.
+    assert_eq!(reserve.max_borrowable_liquidity(NOW), 500);
+}
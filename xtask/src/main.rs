@@ -0,0 +1,196 @@
+//! Repository tasks, run from anywhere in the workspace with `cargo xtask <task>`.
+//!
+//! - `serde-attrs`: reapplies the `serde` feature attributes to the solores-generated modules.
+//!   solores only derives `serde::Serialize`/`Deserialize` on some generated types and knows
+//!   nothing of the `crate::serde_helpers` adapters, so run this after regenerating
+//!   `src/accounts.rs`, `src/errors.rs`, `src/instructions.rs` and `src/typedefs.rs`. Existing
+//!   `serde` attributes are removed and added again from the rules below, so it is idempotent.
+//! - `serde-attrs --check`: fails instead of writing if any generated module would change.
+use std::{env, fs, path::Path, process::ExitCode};
+
+const USAGE: &str = "usage: cargo xtask serde-attrs [--check]";
+
+const GENERATED: [&str; 4] = ["accounts.rs", "errors.rs", "instructions.rs", "typedefs.rs"];
+const SERDE_DERIVE: &str =
+    r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#;
+const SERDE_ATTR: &str = r#"#[cfg_attr(feature = "serde", serde("#;
+/// Fixed-size UTF-8 strings.
+const NAME_FIELDS: [&str; 2] = ["name", "quote_currency"];
+/// Arrays up to this length are supported by serde natively.
+const SERDE_MAX_ARRAY: usize = 32;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["serde-attrs"] => serde_attrs(false),
+        ["serde-attrs", "--check"] => serde_attrs(true),
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serde_attrs(check: bool) -> ExitCode {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .join("src");
+    let mut stale = Vec::new();
+    for name in GENERATED {
+        let path = src.join(name);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        let lines: Vec<&str> = text.split('\n').collect();
+        let updated = apply(&strip(&lines)).join("\n");
+        if updated == text {
+            continue;
+        }
+        if check {
+            stale.push(name);
+        } else if let Err(err) = fs::write(&path, updated) {
+            eprintln!("error: {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    if stale.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    eprintln!(
+        "error: serde attributes are out of date in src/{}; run `cargo xtask serde-attrs`",
+        stale.join(", src/")
+    );
+    ExitCode::FAILURE
+}
+
+fn indent(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn is_word(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// `pub <name>: <type>,` as `(indent, name, type)`.
+fn field(line: &str) -> Option<(&str, &str, &str)> {
+    let indent = indent(line);
+    let rest = line[indent.len()..]
+        .strip_prefix("pub ")?
+        .strip_suffix(',')?;
+    let (name, ty) = rest.split_once(": ")?;
+    (is_word(name) && !ty.is_empty()).then_some((indent, name, ty))
+}
+
+/// Length of an array type `[<elem>; <len>]`.
+fn array_len(ty: &str) -> Option<usize> {
+    let (elem, len) = ty.strip_prefix('[')?.strip_suffix(']')?.split_once("; ")?;
+    if !is_word(elem) || !len.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    len.parse().ok()
+}
+
+/// A one-line tuple variant `<Name>(Pubkey),` or `<Name>([u8; 32]),` as `(indent, name, type)`.
+fn tuple_variant(line: &str) -> Option<(&str, &str, &str)> {
+    let indent = indent(line);
+    let (name, ty) = line[indent.len()..].strip_suffix("),")?.split_once('(')?;
+    (is_word(name) && matches!(ty, "Pubkey" | "[u8; 32]")).then_some((indent, name, ty))
+}
+
+/// The first line of a tuple variant split over several lines, `<Name>(`.
+fn opens_variant(line: &str) -> bool {
+    line[indent(line).len()..]
+        .strip_suffix('(')
+        .is_some_and(is_word)
+}
+
+fn serde_attr(indent: &str, args: &[String]) -> String {
+    format!("{indent}{SERDE_ATTR}{}))]", args.join(", "))
+}
+
+fn helper(name: &str) -> String {
+    format!("\"crate::serde_helpers::{name}\"")
+}
+
+fn field_args(name: &str, ty: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    if name.contains("padding") || name.starts_with("reserved") {
+        args.push(format!("default = {}", helper("zeroed")));
+    }
+    let adapter = match ty {
+        "Pubkey" => Some("pubkey"),
+        "u128" if name.ends_with("_sf") => Some("sf"),
+        "u64" if name.ends_with("_sf") => Some("sf_u64"),
+        "[u8; 32]" if NAME_FIELDS.contains(&name) => Some("name"),
+        _ if array_len(ty).is_some_and(|len| len > SERDE_MAX_ARRAY) => Some("big_array"),
+        _ => None,
+    };
+    if let Some(adapter) = adapter {
+        args.push(format!("with = {}", helper(adapter)));
+    }
+    args
+}
+
+/// Removes field attributes and folds the tuple variants they split back into one line.
+fn strip(lines: &[&str]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if line.trim().starts_with(SERDE_ATTR) {
+            let split_variant = out.last().is_some_and(|last| opens_variant(last))
+                && lines.get(i + 2).is_some_and(|close| close.trim() == "),");
+            if split_variant {
+                let last = out.last_mut().expect("checked above");
+                last.push_str(lines[i + 1].trim().trim_end_matches(','));
+                last.push_str("),");
+                i += 3;
+            } else {
+                i += 1;
+            }
+            continue;
+        }
+        out.push(line.to_string());
+        i += 1;
+    }
+    out
+}
+
+fn apply(lines: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let next = lines.get(i + 1).map_or("", String::as_str);
+        if line.trim().starts_with("#[derive(") && !next.contains("'me") {
+            out.push(line.clone());
+            if next.trim() != SERDE_DERIVE {
+                out.push(format!("{}{SERDE_DERIVE}", indent(line)));
+            }
+            continue;
+        }
+        if let Some((indent, name, ty)) = field(line) {
+            let args = field_args(name, ty);
+            if !args.is_empty() {
+                out.push(serde_attr(indent, &args));
+            }
+            out.push(line.clone());
+            continue;
+        }
+        if let Some((indent, name, ty)) = tuple_variant(line) {
+            let args = field_args(&name.to_lowercase(), ty);
+            if !args.is_empty() {
+                out.push(format!("{indent}{name}("));
+                out.push(serde_attr(&format!("{indent}    "), &args));
+                out.push(format!("{indent}    {ty},"));
+                out.push(format!("{indent}),"));
+                continue;
+            }
+        }
+        out.push(line.clone());
+    }
+    out
+}